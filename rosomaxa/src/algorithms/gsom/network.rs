@@ -8,7 +8,7 @@ use rand::prelude::SliceRandom;
 use rayon::iter::Either;
 use rustc_hash::FxHasher;
 use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::BuildHasherDefault;
 use std::iter::once;
 use std::marker::PhantomData;
@@ -493,7 +493,8 @@ where
 
         // create initial node coordinates and data assignments (by index)
         let grid_size = (initial_node_indices.len() as f64).sqrt().ceil() as i32;
-        let mut node_assignments: HashMap<Coordinate, Vec<usize>> = initial_node_indices
+        // NOTE use ordered map to keep node creation order (and random usage) repeatable
+        let mut node_assignments: BTreeMap<Coordinate, Vec<usize>> = initial_node_indices
             .iter()
             .enumerate()
             .map(|(grid_idx, &data_idx)| {
@@ -152,4 +152,9 @@ where
     pub fn get_params(&self) -> (Float, Float, Float, Float, usize) {
         (self.alpha, self.beta, self.mu, self.v, self.n)
     }
+
+    /// Sets learned params (alpha, beta, mean, variance) and usage amount, e.g. to restore previously learned state.
+    pub fn set_params(&mut self, params: (Float, Float, Float, Float, usize)) {
+        (self.alpha, self.beta, self.mu, self.v, self.n) = params;
+    }
 }
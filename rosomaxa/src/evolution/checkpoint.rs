@@ -0,0 +1,20 @@
+use crate::hyper::HeuristicOperatorState;
+use crate::prelude::*;
+
+/// Keeps evolution progress which can be stored and used later to resume evolution.
+/// Solutions are not part of checkpoint as their representation is specific to the problem.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct EvolutionCheckpoint {
+    /// A last finished generation.
+    pub generation: usize,
+    /// An improvement ratio from the beginning.
+    pub improvement_all_ratio: Float,
+    /// An improvement ratio for last 1000 generations.
+    pub improvement_1000_ratio: Float,
+    /// A learned state of hyper heuristic.
+    pub heuristic: Vec<HeuristicOperatorState>,
+}
+
+/// A function which is called periodically with a heuristic context and evolution checkpoint.
+/// It is supposed to store checkpoint together with solutions from the context's population.
+pub type CheckpointWriter<C> = Box<dyn Fn(&C, &EvolutionCheckpoint) + Send + Sync>;
@@ -1,3 +1,4 @@
+use crate::evolution::strategies::AcceptanceCriterion;
 use crate::evolution::*;
 use crate::hyper::*;
 use crate::termination::*;
@@ -96,6 +97,10 @@ where
 
     objective: Option<Arc<dyn HeuristicObjective<Solution = S>>>,
 
+    checkpoint: Option<(usize, CheckpointWriter<C>)>,
+    resume: Option<EvolutionCheckpoint>,
+    acceptance: Option<AcceptanceCriterion>,
+
     initial: InitialConfig<C, O, S>,
     processing: ProcessingConfig<C, O, S>,
 }
@@ -120,6 +125,9 @@ where
             search_operators: None,
             diversify_operators: None,
             objective: None,
+            checkpoint: None,
+            resume: None,
+            acceptance: None,
             initial: InitialConfig { operators: vec![], max_size: 4, quota: 0.05, individuals: vec![] },
             processing: ProcessingConfig { context: vec![], solution: vec![] },
         }
@@ -230,6 +238,27 @@ where
         self
     }
 
+    /// Sets checkpoint writer called every `interval` generations to store evolution progress.
+    /// Default is no checkpoint. Not supported by a custom strategy.
+    pub fn with_checkpoint(mut self, interval: usize, writer: CheckpointWriter<C>) -> Self {
+        self.checkpoint = Some((interval, writer));
+        self
+    }
+
+    /// Sets checkpoint to resume evolution from. Solutions stored together with the checkpoint
+    /// are expected to be passed as initial solutions. Not supported by a custom strategy.
+    pub fn with_resume(mut self, checkpoint: EvolutionCheckpoint) -> Self {
+        self.resume = Some(checkpoint);
+        self
+    }
+
+    /// Sets acceptance criterion to use a single trajectory search instead of population based one.
+    /// Default is none. Not supported by a custom strategy and cannot be combined with checkpoint.
+    pub fn with_acceptance(mut self, criterion: AcceptanceCriterion) -> Self {
+        self.acceptance = Some(criterion);
+        self
+    }
+
     /// Gets termination criterias.
     #[allow(clippy::type_complexity)]
     fn get_termination(
@@ -307,8 +336,18 @@ where
         let termination =
             Self::get_termination(&logger, self.max_generations, self.max_time, self.min_cv, self.target_proximity)?;
 
+        if self.acceptance.is_some() && (self.checkpoint.is_some() || self.resume.is_some()) {
+            return Err("checkpoint is not supported by acceptance strategy".into());
+        }
+
+        let mut initial = self.initial;
+        if self.resume.is_some() {
+            // NOTE population is restored from initial solutions, so no new ones are built
+            initial.max_size = initial.individuals.len();
+        }
+
         Ok(EvolutionConfig {
-            initial: self.initial,
+            initial,
             strategy: match self.strategy {
                 Some(strategy) => {
                     (logger)("configured to use a custom strategy");
@@ -324,7 +363,31 @@ where
                             context.environment(),
                         )),
                     };
-                    Box::new(strategies::Iterative::new(heuristic, 1))
+
+                    match self.acceptance {
+                        Some(criterion) => {
+                            (logger)(format!("configured to use acceptance strategy: {criterion:?}").as_str());
+                            Box::new(strategies::Acceptance::new(heuristic, criterion, 1))
+                        }
+                        _ => {
+                            let strategy = strategies::Iterative::new(heuristic, 1);
+                            let strategy = match self.checkpoint {
+                                Some((interval, writer)) => {
+                                    (logger)(
+                                        format!("configured to use checkpoint with interval: {interval}").as_str(),
+                                    );
+                                    strategy.with_checkpoint(interval, writer)
+                                }
+                                _ => strategy,
+                            };
+                            let strategy = match self.resume {
+                                Some(checkpoint) => strategy.with_resume(checkpoint),
+                                _ => strategy,
+                            };
+
+                            Box::new(strategy)
+                        }
+                    }
                 }
             },
             context,
@@ -2,6 +2,9 @@
 
 use crate::prelude::*;
 
+mod checkpoint;
+pub use self::checkpoint::*;
+
 mod config;
 pub use self::config::*;
 
@@ -1,6 +1,6 @@
 use crate::evolution::EvolutionResult;
 use crate::prelude::*;
-use crate::utils::Timer;
+use crate::utils::{Timer, sequential_execute};
 
 /// An entity which simulates evolution process.
 pub struct EvolutionSimulator<C, O, S>
@@ -30,6 +30,14 @@ where
     /// Runs evolution for given `problem` using evolution `config`.
     /// Returns populations filled with solutions.
     pub fn run(self) -> EvolutionResult<S> {
+        if self.config.context.environment().is_deterministic {
+            sequential_execute(|| self.run_evolution())?
+        } else {
+            self.run_evolution()
+        }
+    }
+
+    fn run_evolution(self) -> EvolutionResult<S> {
         let mut config = self.config;
 
         let hooks = config.processing;
@@ -0,0 +1,197 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/evolution/strategies/acceptance_test.rs"]
+mod acceptance_test;
+
+use super::*;
+use crate::utils::Timer;
+use std::cmp::Ordering;
+
+/// Specifies a criterion which decides whether a candidate solution replaces the current one.
+/// Worse candidates are evaluated using relative change of the first different fitness value.
+#[derive(Clone, Debug)]
+pub enum AcceptanceCriterion {
+    /// Simulated annealing: a worse candidate is accepted with probability `exp(-change / temperature)`.
+    SimulatedAnnealing {
+        /// An initial temperature.
+        initial_temperature: Float,
+        /// A cooling rate which is applied to temperature every generation.
+        cooling_rate: Float,
+    },
+
+    /// Threshold accepting: a worse candidate is accepted when its change is below threshold.
+    ThresholdAccepting {
+        /// An initial threshold.
+        initial_threshold: Float,
+        /// A decay rate which is applied to threshold every generation.
+        decay_rate: Float,
+    },
+
+    /// Late acceptance hill climbing: a candidate is accepted when it is not worse than the current
+    /// solution or the one which was current `history_length` generations ago.
+    LateAcceptance {
+        /// A length of fitness history.
+        history_length: usize,
+    },
+}
+
+/// A single trajectory evolution algorithm which searches around the current solution and replaces it
+/// with the best offspring when acceptance criterion allows it. All offspring are added to population,
+/// so the best known solution is kept even when a worse one is accepted.
+pub struct Acceptance<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    desired_solutions_amount: usize,
+    heuristic: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
+    criterion: AcceptanceCriterion,
+}
+
+impl<C, O, S> Acceptance<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    /// Creates a new instance of `Acceptance`.
+    pub fn new(
+        heuristic: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
+        criterion: AcceptanceCriterion,
+        desired_solutions_amount: usize,
+    ) -> Self {
+        Self { heuristic, criterion, desired_solutions_amount }
+    }
+}
+
+impl<C, O, S> EvolutionStrategy for Acceptance<C, O, S>
+where
+    C: HeuristicContext<Objective = O, Solution = S>,
+    O: HeuristicObjective<Solution = S>,
+    S: HeuristicSolution,
+{
+    type Context = C;
+    type Objective = O;
+    type Solution = S;
+
+    fn run(
+        &mut self,
+        heuristic_ctx: Self::Context,
+        termination: Box<dyn Termination<Context = Self::Context, Objective = Self::Objective>>,
+    ) -> EvolutionResult<Self::Solution> {
+        let mut heuristic_ctx = heuristic_ctx;
+        let random = heuristic_ctx.environment().random.clone();
+
+        let mut current = heuristic_ctx
+            .ranked()
+            .next()
+            .map(|solution| solution.deep_copy())
+            .ok_or_else(|| GenericError::from("cannot find initial solution"))?;
+        let mut history = match &self.criterion {
+            AcceptanceCriterion::LateAcceptance { history_length } => {
+                vec![current.fitness().collect::<Vec<_>>(); (*history_length).max(1)]
+            }
+            _ => Vec::default(),
+        };
+        let mut iteration = 0;
+
+        loop {
+            let is_terminated = termination.is_termination(&mut heuristic_ctx);
+            let is_quota_reached = heuristic_ctx.environment().quota.as_ref().is_some_and(|q| q.is_reached());
+
+            if is_terminated || is_quota_reached {
+                break;
+            }
+
+            let generation_time = Timer::start();
+
+            // NOTE search around the current solution using the same amount of parallel searches as selection
+            let batch_size = heuristic_ctx.selected().count().max(1);
+            let offspring = self.heuristic.search_many(&heuristic_ctx, vec![&current; batch_size]);
+
+            let objective = heuristic_ctx.objective();
+            if let Some(candidate) = offspring.iter().min_by(|a, b| objective.total_order(a, b)) {
+                let is_improved = objective.total_order(candidate, &current) != Ordering::Greater;
+                if is_accepted(
+                    &self.criterion,
+                    iteration,
+                    is_improved,
+                    &current,
+                    candidate,
+                    &mut history,
+                    random.as_ref(),
+                ) {
+                    current = candidate.deep_copy();
+                }
+            }
+
+            let termination_estimate = termination.estimate(&heuristic_ctx);
+
+            heuristic_ctx.on_generation(offspring, termination_estimate, generation_time);
+            iteration += 1;
+        }
+
+        // NOTE give a chance to report internal state of heuristic
+        (heuristic_ctx.environment().logger)(&format!("{}", self.heuristic));
+
+        let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
+
+        let solutions =
+            population.ranked().map(|solution| solution.deep_copy()).take(self.desired_solutions_amount).collect();
+
+        Ok((solutions, telemetry_metrics))
+    }
+}
+
+/// Checks whether candidate should replace the current solution. Late acceptance history is updated.
+fn is_accepted<S: HeuristicSolution>(
+    criterion: &AcceptanceCriterion,
+    iteration: usize,
+    is_improved: bool,
+    current: &S,
+    candidate: &S,
+    history: &mut [Vec<Float>],
+    random: &dyn Random,
+) -> bool {
+    match criterion {
+        AcceptanceCriterion::SimulatedAnnealing { initial_temperature, cooling_rate } => {
+            let temperature = initial_temperature * cooling_rate.powf(iteration as Float);
+
+            is_improved
+                || (temperature > 0.
+                    && random.uniform_real(0., 1.) < (-get_relative_change(current, candidate) / temperature).exp())
+        }
+        AcceptanceCriterion::ThresholdAccepting { initial_threshold, decay_rate } => {
+            let threshold = initial_threshold * decay_rate.powf(iteration as Float);
+
+            is_improved || get_relative_change(current, candidate) < threshold
+        }
+        AcceptanceCriterion::LateAcceptance { .. } => {
+            let idx = iteration % history.len();
+            let is_accepted = is_improved || compare_fitness(candidate, history[idx].as_slice()) != Ordering::Greater;
+
+            history[idx] = if is_accepted { candidate.fitness().collect() } else { current.fitness().collect() };
+
+            is_accepted
+        }
+    }
+}
+
+/// Returns relative change of the first different fitness value as fitness is compared lexicographically.
+fn get_relative_change<S: HeuristicSolution>(current: &S, candidate: &S) -> Float {
+    current
+        .fitness()
+        .zip(candidate.fitness())
+        .find(|(a, b)| a != b)
+        .map(|(a, b)| (a - b).abs() / a.abs().max(b.abs()))
+        .unwrap_or_default()
+}
+
+fn compare_fitness<S: HeuristicSolution>(solution: &S, fitness: &[Float]) -> Ordering {
+    solution
+        .fitness()
+        .zip(fitness.iter())
+        .map(|(a, b)| a.total_cmp(b))
+        .find(|o| *o != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
@@ -10,6 +10,8 @@ where
 {
     desired_solutions_amount: usize,
     heuristic: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
+    checkpoint: Option<(usize, CheckpointWriter<C>)>,
+    resume: Option<EvolutionCheckpoint>,
 }
 
 impl<C, O, S> Iterative<C, O, S>
@@ -23,7 +25,33 @@ where
         heuristic: Box<dyn HyperHeuristic<Context = C, Objective = O, Solution = S>>,
         desired_solutions_amount: usize,
     ) -> Self {
-        Self { heuristic, desired_solutions_amount }
+        Self { heuristic, desired_solutions_amount, checkpoint: None, resume: None }
+    }
+
+    /// Sets checkpoint writer which is called every `interval` generations and once evolution is finished.
+    pub fn with_checkpoint(mut self, interval: usize, writer: CheckpointWriter<C>) -> Self {
+        self.checkpoint = Some((interval.max(1), writer));
+        self
+    }
+
+    /// Sets checkpoint to resume evolution from: heuristic state and search statistics are restored from it.
+    pub fn with_resume(mut self, checkpoint: EvolutionCheckpoint) -> Self {
+        self.resume = Some(checkpoint);
+        self
+    }
+
+    fn write_checkpoint(&self, heuristic_ctx: &C) {
+        if let Some((_, writer)) = self.checkpoint.as_ref() {
+            let statistics = heuristic_ctx.statistics();
+            let checkpoint = EvolutionCheckpoint {
+                generation: statistics.generation,
+                improvement_all_ratio: statistics.improvement_all_ratio,
+                improvement_1000_ratio: statistics.improvement_1000_ratio,
+                heuristic: self.heuristic.get_state(),
+            };
+
+            (writer)(heuristic_ctx, &checkpoint);
+        }
     }
 }
 
@@ -43,7 +71,15 @@ where
         termination: Box<dyn Termination<Context = Self::Context, Objective = Self::Objective>>,
     ) -> EvolutionResult<Self::Solution> {
         let mut heuristic_ctx = heuristic_ctx;
-        let heuristic = &mut self.heuristic;
+
+        if let Some(checkpoint) = self.resume.take() {
+            (heuristic_ctx.environment().logger)(&format!(
+                "resume evolution from generation {}",
+                checkpoint.generation
+            ));
+            self.heuristic.set_state(checkpoint.heuristic.as_slice());
+            heuristic_ctx.on_resume(&checkpoint);
+        }
 
         loop {
             let is_terminated = termination.is_termination(&mut heuristic_ctx);
@@ -60,20 +96,31 @@ where
             let diverse_offspring = if heuristic_ctx.selection_phase() == SelectionPhase::Exploitation {
                 Vec::default()
             } else {
-                heuristic.diversify_many(&heuristic_ctx, parents.clone())
+                self.heuristic.diversify_many(&heuristic_ctx, parents.clone())
             };
 
-            let search_offspring = heuristic.search_many(&heuristic_ctx, parents);
+            let search_offspring = self.heuristic.search_many(&heuristic_ctx, parents);
 
             let offspring = search_offspring.into_iter().chain(diverse_offspring).collect::<Vec<_>>();
 
             let termination_estimate = termination.estimate(&heuristic_ctx);
 
             heuristic_ctx.on_generation(offspring, termination_estimate, generation_time);
+
+            if self
+                .checkpoint
+                .as_ref()
+                .is_some_and(|(interval, _)| heuristic_ctx.statistics().generation.is_multiple_of(*interval))
+            {
+                self.write_checkpoint(&heuristic_ctx);
+            }
         }
 
+        // NOTE store final state as well, so interrupted evolution can be continued
+        self.write_checkpoint(&heuristic_ctx);
+
         // NOTE give a chance to report internal state of heuristic
-        (heuristic_ctx.environment().logger)(&format!("{heuristic}"));
+        (heuristic_ctx.environment().logger)(&format!("{}", self.heuristic));
 
         let (population, telemetry_metrics) = heuristic_ctx.on_result()?;
 
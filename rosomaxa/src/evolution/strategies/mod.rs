@@ -2,11 +2,14 @@
 
 use super::*;
 
+mod acceptance;
+pub use self::acceptance::{Acceptance, AcceptanceCriterion};
+
 mod iterative;
 pub use self::iterative::Iterative;
 
 /// An evolution algorithm strategy.
-pub trait EvolutionStrategy {
+pub trait EvolutionStrategy: Send {
     /// A heuristic context type.
     type Context: HeuristicContext<Objective = Self::Objective, Solution = Self::Solution>;
     /// A heuristic objective type.
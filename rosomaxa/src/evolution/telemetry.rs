@@ -5,6 +5,7 @@
 mod telemetry_test;
 
 use crate::algorithms::math::relative_distance;
+use crate::evolution::EvolutionCheckpoint;
 use crate::prelude::*;
 use crate::utils::Timer;
 use crate::{DynHeuristicPopulation, RemedianUsize};
@@ -119,6 +120,12 @@ where
         }
     }
 
+    /// Disables heuristic speed estimation which relies on time measurements, so the speed stays unknown.
+    pub fn without_speed_estimation(mut self) -> Self {
+        self.speed_tracker.is_enabled = false;
+        self
+    }
+
     /// Reports initial solution statistics.
     pub fn on_initial(&mut self, solution: &S, item_time: Timer) {
         match &self.mode {
@@ -191,6 +198,27 @@ where
         }
     }
 
+    /// Restores generation statistics from the checkpoint, so evolution continues from the next generation.
+    pub fn on_resume(&mut self, checkpoint: &EvolutionCheckpoint) {
+        let generation = checkpoint.generation;
+
+        self.metrics.generations = generation;
+        self.improvement_tracker.restore(
+            generation,
+            checkpoint.improvement_all_ratio,
+            checkpoint.improvement_1000_ratio,
+        );
+        self.speed_tracker.restart(generation + 1);
+        self.next_generation = Some(generation + 1);
+
+        self.statistics = HeuristicStatistics {
+            generation,
+            improvement_all_ratio: self.improvement_tracker.i_all_ratio,
+            improvement_1000_ratio: self.improvement_tracker.i_1000_ratio,
+            ..self.statistics.clone()
+        };
+    }
+
     /// Reports population state.
     fn on_population(
         &mut self,
@@ -359,9 +387,28 @@ impl ImprovementTracker {
         self.i_all_ratio = (self.total_improvements as Float) / ((generation + 1) as Float);
         self.i_1000_ratio = (improvements as Float) / ((generation + 1).min(self.buffer.len()) as Float);
     }
+
+    pub fn restore(&mut self, generation: usize, i_all_ratio: Float, i_1000_ratio: Float) {
+        let length = self.buffer.len();
+        let total = generation + 1;
+        let recent = total.min(length);
+        let recent_improvements = ((i_1000_ratio * recent as Float).round() as usize).min(recent);
+
+        self.total_improvements = (i_all_ratio * total as Float).round() as usize;
+
+        // NOTE exact generations of recent improvements are not known, so they are treated as the latest ones
+        self.buffer.iter_mut().for_each(|is_improved| *is_improved = false);
+        (0..recent_improvements).for_each(|offset| self.buffer[(generation - offset) % length] = true);
+
+        self.is_last_improved = recent_improvements > 0;
+        self.i_all_ratio = self.total_improvements as Float / total as Float;
+        self.i_1000_ratio = recent_improvements as Float / recent as Float;
+    }
 }
 
 struct SpeedTracker {
+    is_enabled: bool,
+    initial_generation: usize,
     initial_estimate: Float,
     initial_time: Float,
     last_time: Float,
@@ -372,6 +419,8 @@ struct SpeedTracker {
 impl Default for SpeedTracker {
     fn default() -> Self {
         Self {
+            is_enabled: true,
+            initial_generation: 0,
             initial_estimate: 0.,
             initial_time: 0.,
             last_time: 0.,
@@ -383,8 +432,12 @@ impl Default for SpeedTracker {
 
 impl SpeedTracker {
     pub fn track(&mut self, generation: usize, time: &Timer, termination_estimate: Float) {
+        if !self.is_enabled {
+            return;
+        }
+
         let elapsed = (time.elapsed_millis() as Float) * 1000.;
-        if generation == 0 {
+        if generation == self.initial_generation {
             self.initial_estimate = termination_estimate;
             self.initial_time = elapsed;
             self.last_time = elapsed;
@@ -395,7 +448,7 @@ impl SpeedTracker {
 
             // average gen/sec speed excluding initial solutions
             let average = if elapsed > self.initial_time {
-                generation as Float / ((elapsed - self.initial_time) / 1_000_000.)
+                (generation - self.initial_generation) as Float / ((elapsed - self.initial_time) / 1_000_000.)
             } else {
                 1000.
             };
@@ -425,6 +478,11 @@ impl SpeedTracker {
         }
     }
 
+    /// Starts speed estimation from given generation as speed of previous run is not known.
+    pub fn restart(&mut self, generation: usize) {
+        self.initial_generation = generation;
+    }
+
     pub fn get_current_speed(&self) -> HeuristicSpeed {
         self.speed.clone()
     }
@@ -6,6 +6,7 @@ mod example_test;
 
 use crate::algorithms::gsom::Input;
 use crate::evolution::objectives::HeuristicObjective;
+use crate::evolution::strategies::AcceptanceCriterion;
 use crate::evolution::*;
 use crate::hyper::*;
 use crate::population::{Alternative, RosomaxaSolution};
@@ -315,6 +316,7 @@ pub struct Solver {
     search_operators: Vec<(TargetSearchOperator, String, Float)>,
     diversify_operators: Vec<TargetDiversifyOperator>,
     context_factory: Option<ContextFactory>,
+    acceptance: Option<AcceptanceCriterion>,
 }
 
 impl Default for Solver {
@@ -334,6 +336,7 @@ impl Default for Solver {
             search_operators: vec![],
             diversify_operators: vec![],
             context_factory: None,
+            acceptance: None,
         }
     }
 }
@@ -417,6 +420,12 @@ impl Solver {
         self
     }
 
+    /// Sets acceptance criterion to use a single trajectory search.
+    pub fn with_acceptance(mut self, criterion: AcceptanceCriterion) -> Self {
+        self.acceptance = Some(criterion);
+        self
+    }
+
     /// Runs the solver using configuration provided through fluent interface methods.
     pub fn solve(self) -> Result<(SolverSolutions, Option<TelemetryMetrics>), GenericError> {
         // create an environment based on max_time and logger parameters supplied
@@ -480,8 +489,12 @@ impl Solver {
             .with_max_time(self.max_time)
             .with_max_generations(self.max_generations)
             .with_target_proximity(self.target_proximity)
-            .with_initial(self.initial_params.0, self.initial_params.1, initial_operators)
-            .build()?;
+            .with_initial(self.initial_params.0, self.initial_params.1, initial_operators);
+        let config = match self.acceptance {
+            Some(criterion) => config.with_acceptance(criterion),
+            _ => config,
+        }
+        .build()?;
 
         // solve the problem
         let (solutions, metrics) = EvolutionSimulator::new(config)?.run()?;
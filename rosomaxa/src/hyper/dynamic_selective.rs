@@ -84,6 +84,14 @@ where
     fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution> {
         diversify_solutions(heuristic_ctx, solutions, self.diversify_operators.as_slice())
     }
+
+    fn get_state(&self) -> Vec<HeuristicOperatorState> {
+        self.agent.get_state()
+    }
+
+    fn set_state(&mut self, state: &[HeuristicOperatorState]) {
+        self.agent.set_state(state)
+    }
 }
 
 impl<C, O, S> DynamicSelective<C, O, S>
@@ -184,7 +192,9 @@ where
         let (new_solution, duration) =
             Timer::measure_duration(|| self.operator.search(context.heuristic_ctx, context.solution));
 
-        let duration = duration.as_millis() as usize;
+        // NOTE measured duration makes rewards non-repeatable, so all operators are considered equally fast
+        let duration =
+            if context.heuristic_ctx.environment().is_deterministic { 1 } else { duration.as_millis() as usize };
 
         // Compute reward using the simplified V2.1 formula.
         let reward =
@@ -309,6 +319,33 @@ where
         self.tracker.observe_sample(generation, feedback.sample.clone());
     }
 
+    /// Returns learned parameters of all slot machines.
+    pub fn get_state(&self) -> Vec<HeuristicOperatorState> {
+        // NOTE iterate states in fixed order as hash map iteration order is not stable
+        [SearchState::BestKnown, SearchState::Diverse]
+            .iter()
+            .filter_map(|state| self.slot_machines.get(state).map(|slots| (state, slots)))
+            .flat_map(|(state, slots)| {
+                slots.iter().map(move |(slot, name)| {
+                    let (alpha, beta, mu, v, n) = slot.get_params();
+                    HeuristicOperatorState { state: state.to_string(), name: name.clone(), alpha, beta, mu, v, n }
+                })
+            })
+            .collect()
+    }
+
+    /// Restores learned parameters of slot machines matched by search state and operator name.
+    pub fn set_state(&mut self, state: &[HeuristicOperatorState]) {
+        self.slot_machines.iter_mut().for_each(|(search_state, slots)| {
+            let search_state = search_state.to_string();
+            slots.iter_mut().for_each(|(slot, name)| {
+                if let Some(params) = state.iter().find(|params| params.state == search_state && params.name == *name) {
+                    slot.set_params((params.alpha, params.beta, params.mu, params.v, params.n));
+                }
+            });
+        });
+    }
+
     /// Updates statistics about heuristic internal parameters.
     pub fn save_params(&mut self, generation: usize) {
         if !self.tracker.telemetry_enabled() {
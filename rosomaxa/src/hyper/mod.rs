@@ -39,7 +39,7 @@ pub trait HeuristicDiversifyOperator {
 }
 
 /// Represents a hyper heuristic functionality.
-pub trait HyperHeuristic: Display {
+pub trait HyperHeuristic: Display + Send {
     /// A heuristic context type.
     type Context: HeuristicContext<Objective = Self::Objective, Solution = Self::Solution>;
     /// A heuristic objective type.
@@ -62,6 +62,35 @@ pub trait HyperHeuristic: Display {
     /// As the `diversify` method requires a mutable reference, implementations of `diversify_many` is
     /// supposed to run its logic in parallel for each solution.
     fn diversify_many(&self, heuristic_ctx: &Self::Context, solutions: Vec<&Self::Solution>) -> Vec<Self::Solution>;
+
+    /// Returns learned state of the heuristic operators which can be used later to restore the heuristic.
+    /// Default implementation has no state to be returned.
+    fn get_state(&self) -> Vec<HeuristicOperatorState> {
+        Vec::default()
+    }
+
+    /// Restores learned state of the heuristic operators. Unknown operators are ignored.
+    /// Default implementation has no state to be restored.
+    fn set_state(&mut self, _state: &[HeuristicOperatorState]) {}
+}
+
+/// Keeps learned parameters of a heuristic operator used within specific search state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HeuristicOperatorState {
+    /// A search state name.
+    pub state: String,
+    /// An operator name.
+    pub name: String,
+    /// Shape parameter of the estimated reward distribution.
+    pub alpha: Float,
+    /// Rate parameter of the estimated reward distribution.
+    pub beta: Float,
+    /// Estimated mean of the reward.
+    pub mu: Float,
+    /// Estimated variance of the reward.
+    pub v: Float,
+    /// Usage amount.
+    pub n: usize,
 }
 
 /// Gets probability to run diversify search.
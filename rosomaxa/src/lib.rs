@@ -58,7 +58,7 @@ pub mod termination;
 pub mod utils;
 
 use crate::algorithms::math::RemedianUsize;
-use crate::evolution::{Telemetry, TelemetryMetrics, TelemetryMode};
+use crate::evolution::{EvolutionCheckpoint, Telemetry, TelemetryMetrics, TelemetryMode};
 use crate::population::*;
 use crate::prelude::*;
 use crate::utils::Timer;
@@ -111,6 +111,10 @@ pub trait HeuristicContext: Send + Sync {
 
     /// Returns final population and telemetry metrics
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution>;
+
+    /// Restores search progress from the checkpoint when evolution is resumed.
+    /// Default implementation ignores it.
+    fn on_resume(&mut self, _checkpoint: &EvolutionCheckpoint) {}
 }
 
 /// A refinement statistics to track evolution progress.
@@ -173,6 +177,7 @@ where
         environment: Arc<Environment>,
     ) -> Self {
         let telemetry = Telemetry::new(telemetry_mode);
+        let telemetry = if environment.is_deterministic { telemetry.without_speed_estimation() } else { telemetry };
         Self { objective, population, telemetry, environment }
     }
 
@@ -230,6 +235,10 @@ where
         self.population.on_generation(self.telemetry.get_statistics());
     }
 
+    fn on_resume(&mut self, checkpoint: &EvolutionCheckpoint) {
+        self.telemetry.on_resume(checkpoint);
+    }
+
     fn on_result(self) -> Result<(Box<DynHeuristicPopulation<O, S>>, Option<TelemetryMetrics>), GenericError> {
         let mut telemetry = self.telemetry;
 
@@ -292,7 +301,14 @@ pub trait Stateful {
 
 /// Gets default population selection size.
 pub fn get_default_selection_size(environment: &Environment) -> usize {
-    environment.parallelism.available_cpus().min(8)
+    const MAX_SELECTION_SIZE: usize = 8;
+
+    // NOTE amount of cpus differs from machine to machine, so it is not used for repeatable runs
+    if environment.is_deterministic {
+        MAX_SELECTION_SIZE
+    } else {
+        environment.parallelism.available_cpus().min(MAX_SELECTION_SIZE)
+    }
 }
 
 /// Gets default population algorithm.
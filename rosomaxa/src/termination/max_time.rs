@@ -43,7 +43,12 @@ where
         self.start.elapsed_secs_as_float() > self.limit_in_secs
     }
 
-    fn estimate(&self, _: &Self::Context) -> Float {
+    fn estimate(&self, heuristic_ctx: &Self::Context) -> Float {
+        // NOTE elapsed time is not repeatable, so it should not affect search decisions
+        if heuristic_ctx.environment().is_deterministic {
+            return 0.;
+        }
+
         (self.start.elapsed_secs_as_float() / self.limit_in_secs).min(1.)
     }
 }
@@ -30,6 +30,10 @@ pub struct Environment {
 
     /// A boolean flag which signalizes that experimental behavior is allowed.
     pub is_experimental: bool,
+
+    /// A boolean flag which signalizes that algorithm should be repeatable: parallel operations are run
+    /// sequentially and no decision depends on time measurements. Requires seeded random to be used.
+    pub is_deterministic: bool,
 }
 
 impl Environment {
@@ -49,7 +53,12 @@ impl Environment {
         logger: InfoLogger,
         is_experimental: bool,
     ) -> Self {
-        Self { random, quota, parallelism, logger, is_experimental }
+        Self { random, quota, parallelism, logger, is_experimental, is_deterministic: false }
+    }
+
+    /// Creates an instance of `Environment` which is deterministic for given seed.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { random: Arc::new(DefaultRandom::new_with_seed(seed)), is_deterministic: true, ..Self::default() }
     }
 }
 
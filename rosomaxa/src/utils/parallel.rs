@@ -9,9 +9,11 @@ pub use self::actual::map_reduce;
 pub use self::actual::parallel_collect;
 pub use self::actual::parallel_foreach_mut;
 pub use self::actual::parallel_into_collect;
+pub use self::actual::sequential_execute;
 
 #[cfg(not(target_arch = "wasm32"))]
 mod actual {
+    use crate::utils::GenericResult;
     use rayon::prelude::*;
     use rayon::{ThreadPool as RayonThreadPool, ThreadPoolBuilder};
 
@@ -102,10 +104,27 @@ mod actual {
     {
         source.par_iter_mut().for_each(action)
     }
+
+    /// Executes given operation on a single thread pool, so all parallel operations started from it
+    /// are run sequentially and their order of execution is deterministic.
+    pub fn sequential_execute<OP, R>(op: OP) -> GenericResult<R>
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(1)
+            .build()
+            .map_err(|err| format!("cannot build a single thread pool: {err}"))?;
+
+        Ok(pool.install(op))
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
 mod actual {
+    use crate::utils::GenericResult;
+
     /// Represents a thread pool wrapper.
     pub struct ThreadPool;
 
@@ -189,4 +208,13 @@ mod actual {
     {
         source.iter_mut().for_each(action)
     }
+
+    /// Executes given operation synchronously.
+    pub fn sequential_execute<OP, R>(op: OP) -> GenericResult<R>
+    where
+        OP: FnOnce() -> R + Send,
+        R: Send,
+    {
+        Ok(op())
+    }
 }
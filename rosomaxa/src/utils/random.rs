@@ -8,7 +8,7 @@ use rand::prelude::*;
 use rand_distr::{Gamma, Normal};
 use std::cell::RefCell;
 use std::cmp::Ordering;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 /// Provides the way to sample from different distributions.
 pub trait DistributionSampler {
@@ -80,13 +80,20 @@ impl DistributionSampler for DefaultDistributionSampler {
 /// A default random implementation.
 #[derive(Default)]
 pub struct DefaultRandom {
-    use_repeatable: bool,
+    source: RngSource,
 }
 
 impl DefaultRandom {
     /// Creates an instance of `DefaultRandom` with repeatable (predictable) random generation.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { source: RngSource::Repeatable }
+    }
+
+    /// Creates an instance of `DefaultRandom` which owns random generator initialized with given seed.
+    /// Unlike repeatable one, its sequence does not depend on other random usages within the same thread,
+    /// so two instances with the same seed produce the same values when used sequentially.
+    pub fn new_with_seed(seed: u64) -> Self {
+        Self { source: RngSource::Seeded(Arc::new(Mutex::new(SmallRng::seed_from_u64(seed)))) }
     }
 }
 
@@ -129,7 +136,7 @@ impl Random for DefaultRandom {
     }
 
     fn get_rng(&self) -> RandomGen {
-        RandomGen { use_repeatable: self.use_repeatable }
+        RandomGen { source: self.source.clone() }
     }
 }
 
@@ -141,56 +148,60 @@ thread_local! {
     static REPEATABLE_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::seed_from_u64(0));
 }
 
+/// Specifies a source of random values used by random generator.
+#[derive(Clone, Debug, Default)]
+enum RngSource {
+    /// Uses thread local generator seeded from thread_rng.
+    #[default]
+    Randomized,
+    /// Uses thread local generator seeded with 0.
+    Repeatable,
+    /// Uses shared generator seeded with specific value.
+    Seeded(Arc<Mutex<SmallRng>>),
+}
+
 /// Provides underlying random generator API.
 #[derive(Clone, Debug)]
 pub struct RandomGen {
-    use_repeatable: bool,
+    source: RngSource,
 }
 
 impl RandomGen {
     /// Creates an instance of `RandomGen` using random generator with fixed seed.
     pub fn new_repeatable() -> Self {
-        Self { use_repeatable: true }
+        Self { source: RngSource::Repeatable }
     }
 
     /// Creates an instance of `RandomGen` using random generator with randomized seed.
     pub fn new_randomized() -> Self {
-        Self { use_repeatable: false }
+        Self { source: RngSource::Randomized }
+    }
+
+    fn with_rng<R>(&self, func: impl FnOnce(&mut SmallRng) -> R) -> R {
+        // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
+        match &self.source {
+            RngSource::Randomized => RANDOMIZED_RNG.with(|t| func(&mut t.borrow_mut())),
+            RngSource::Repeatable => REPEATABLE_RNG.with(|t| func(&mut t.borrow_mut())),
+            RngSource::Seeded(rng) => func(&mut rng.lock().expect("cannot lock seeded rng")),
+        }
     }
 }
 
 impl RngCore for RandomGen {
     fn next_u32(&mut self) -> u32 {
-        // NOTE use 'likely!' macro for better branch prediction once it is stabilized?
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u32())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u32())
-        }
+        self.with_rng(|rng| rng.next_u32())
     }
 
     fn next_u64(&mut self) -> u64 {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().next_u64())
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().next_u64())
-        }
+        self.with_rng(|rng| rng.next_u64())
     }
 
     fn fill_bytes(&mut self, dest: &mut [u8]) {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.fill_bytes(dest))
     }
 
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
-        if self.use_repeatable {
-            REPEATABLE_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        } else {
-            RANDOMIZED_RNG.with(|t| t.borrow_mut().try_fill_bytes(dest))
-        }
+        self.with_rng(|rng| rng.try_fill_bytes(dest))
     }
 }
 
@@ -0,0 +1,99 @@
+use super::*;
+use crate::example::*;
+use crate::helpers::utils::create_test_random;
+use crate::utils::{DefaultRandom, Noise};
+use std::sync::Arc;
+
+fn create_solution(fitness: Float) -> VectorSolution {
+    VectorSolution::new(vec![], fitness, vec![])
+}
+
+parameterized_test! {can_accept_worse_solution_with_threshold, (iteration, initial_threshold, expected), {
+    can_accept_worse_solution_with_threshold_impl(iteration, initial_threshold, expected);
+}}
+
+can_accept_worse_solution_with_threshold! {
+    case_01: (0, 0.1, true),
+    case_02: (2, 0.1, false),
+    case_03: (0, 0.01, false),
+}
+
+fn can_accept_worse_solution_with_threshold_impl(iteration: usize, initial_threshold: Float, expected: bool) {
+    let criterion = AcceptanceCriterion::ThresholdAccepting { initial_threshold, decay_rate: 0.5 };
+    let (current, candidate) = (create_solution(100.), create_solution(105.));
+
+    let result =
+        is_accepted(&criterion, iteration, false, &current, &candidate, &mut [], create_test_random().as_ref());
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_accept_solution_with_annealing, (initial_temperature, is_improved, expected), {
+    can_accept_solution_with_annealing_impl(initial_temperature, is_improved, expected);
+}}
+
+can_accept_solution_with_annealing! {
+    case_01_improved: (0., true, true),
+    case_02_frozen: (0., false, false),
+    case_03_hot: (1E9, false, true),
+}
+
+fn can_accept_solution_with_annealing_impl(initial_temperature: Float, is_improved: bool, expected: bool) {
+    let criterion = AcceptanceCriterion::SimulatedAnnealing { initial_temperature, cooling_rate: 0.99 };
+    let (current, candidate) = (create_solution(100.), create_solution(105.));
+
+    let result = is_accepted(&criterion, 0, is_improved, &current, &candidate, &mut [], create_test_random().as_ref());
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_accept_solution_using_late_history() {
+    let criterion = AcceptanceCriterion::LateAcceptance { history_length: 2 };
+    let random = create_test_random();
+    let (current, candidate) = (create_solution(100.), create_solution(105.));
+    let mut history = vec![vec![110.], vec![90.]];
+
+    let first = is_accepted(&criterion, 0, false, &current, &candidate, &mut history, random.as_ref());
+    let second = is_accepted(&criterion, 1, false, &current, &candidate, &mut history, random.as_ref());
+
+    assert!(first);
+    assert!(!second);
+    assert_eq!(history, vec![vec![105.], vec![100.]]);
+}
+
+#[test]
+fn can_get_relative_change() {
+    assert_eq!(get_relative_change(&create_solution(100.), &create_solution(125.)), 0.2);
+    assert_eq!(get_relative_change(&create_solution(100.), &create_solution(100.)), 0.);
+}
+
+parameterized_test! {can_solve_rosenbrock_with_acceptance, criterion, {
+    can_solve_rosenbrock_with_acceptance_impl(criterion);
+}}
+
+can_solve_rosenbrock_with_acceptance! {
+    case_01_annealing: AcceptanceCriterion::SimulatedAnnealing { initial_temperature: 0.01, cooling_rate: 0.99 },
+    case_02_threshold: AcceptanceCriterion::ThresholdAccepting { initial_threshold: 0.01, decay_rate: 0.99 },
+    case_03_late: AcceptanceCriterion::LateAcceptance { history_length: 10 },
+}
+
+fn can_solve_rosenbrock_with_acceptance_impl(criterion: AcceptanceCriterion) {
+    let random = Arc::new(DefaultRandom::default());
+    let noise = |range| VectorHeuristicOperatorMode::JustNoise(Noise::new_with_ratio(1., range, random.clone()));
+
+    let (solutions, _) = Solver::default()
+        .with_fitness_fn(create_rosenbrock_function())
+        .with_init_solutions(vec![vec![2., 2.]])
+        .with_search_operator(noise((-0.05, 0.05)), "first", 1.)
+        .with_search_operator(noise((-0.01, 0.01)), "second", 1.)
+        .with_diversify_operator(noise((-0.5, 0.5)))
+        .with_acceptance(criterion)
+        .with_termination(Some(5), Some(1000), None, None)
+        .solve()
+        .expect("cannot build and use solver");
+
+    assert_eq!(solutions.len(), 1);
+    let (_, fitness) = solutions.first().unwrap();
+    assert!(*fitness < 0.1);
+}
@@ -38,3 +38,25 @@ fn can_update_statistic() {
     telemetry.on_generation(population, 0., Timer::start(), true);
     compare_statistic(telemetry.get_statistics(), (1000, 2. / 1001., 0.001));
 }
+
+#[test]
+fn can_resume_statistic() {
+    let environment = Arc::new(Environment::default());
+    let objective = create_example_objective();
+    let selection_size = get_default_selection_size(environment.as_ref());
+    let population = get_default_population(objective.clone(), VectorRosomaxaContext, environment, selection_size);
+    let population = population.as_ref();
+    let checkpoint = EvolutionCheckpoint {
+        generation: 1999,
+        improvement_all_ratio: 0.1,
+        improvement_1000_ratio: 0.05,
+        heuristic: vec![],
+    };
+
+    let mut telemetry = Telemetry::new(TelemetryMode::None);
+    telemetry.on_resume(&checkpoint);
+    compare_statistic(telemetry.get_statistics(), (1999, 0.1, 0.05));
+
+    telemetry.on_generation(population, 0., Timer::start(), true);
+    compare_statistic(telemetry.get_statistics(), (2000, 201. / 2001., 0.051));
+}
@@ -117,3 +117,40 @@ fn can_handle_equal_fitness_solutions() {
 
     assert_eq!(distance, 0.)
 }
+
+#[test]
+fn can_restore_heuristic_state() {
+    struct CopyHeuristicOperator;
+    impl HeuristicSearchOperator for CopyHeuristicOperator {
+        type Context = VectorContext;
+        type Objective = VectorObjective;
+        type Solution = VectorSolution;
+
+        fn search(&self, _: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+            solution.deep_copy()
+        }
+    }
+    let environment = Environment::default();
+    let create_heuristic = || {
+        DynamicSelective::<VectorContext, VectorObjective, VectorSolution>::new(
+            vec![
+                (Arc::new(CopyHeuristicOperator), "first".to_string(), 1.),
+                (Arc::new(CopyHeuristicOperator), "second".to_string(), 2.),
+            ],
+            vec![],
+            &environment,
+        )
+    };
+    let solution = VectorSolution::new(vec![0., 0.], 0., vec![0., 0.]);
+    let mut heuristic = create_heuristic();
+    heuristic.search_many(&create_default_heuristic_context(), (0..10).map(|_| &solution).collect());
+
+    let state = heuristic.get_state();
+    let mut restored = create_heuristic();
+    assert_ne!(restored.get_state(), state);
+    restored.set_state(state.as_slice());
+
+    assert_eq!(state.len(), 4);
+    assert_eq!(state.iter().map(|params| params.n).sum::<usize>(), 10);
+    assert_eq!(restored.get_state(), state);
+}
@@ -29,3 +29,12 @@ fn can_use_map_reduce_for_slice() {
 
     assert_eq!(result, 6);
 }
+
+#[test]
+fn can_run_parallel_operations_sequentially() {
+    let source = (0..1000).collect::<Vec<_>>();
+
+    let thread_ids = sequential_execute(|| parallel_collect(&source, |_| std::thread::current().id())).unwrap();
+
+    assert!(thread_ids.windows(2).all(|ids| ids[0] == ids[1]));
+}
@@ -20,3 +20,14 @@ fn can_return_weights() {
         assert!((actual_ratio - expected_ratio).abs() < 0.05);
     });
 }
+
+#[test]
+fn can_produce_same_values_with_same_seed() {
+    let get_values = |seed: u64| {
+        let random = DefaultRandom::new_with_seed(seed);
+        (0..100).map(|_| random.uniform_int(0, 1000)).collect::<Vec<_>>()
+    };
+
+    assert_eq!(get_values(7), get_values(7));
+    assert_ne!(get_values(7), get_values(8));
+}
@@ -0,0 +1,147 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/feasibility_test.rs"]
+mod feasibility_test;
+
+use super::*;
+use clap::ArgAction;
+use vrp_cli::extensions::feasibility::check_pragmatic_candidates;
+use vrp_core::prelude::{Float, GenericError};
+use vrp_pragmatic::format::feasibility::ReoptimizationOptions;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "PROBLEM";
+const SOLUTION_ARG_NAME: &str = "solution";
+const MATRIX_ARG_NAME: &str = "matrix";
+const CANDIDATES_ARG_NAME: &str = "candidates";
+const ACCEPT_ARG_NAME: &str = "accept";
+const REOPTIMIZE_ARG_NAME: &str = "reoptimize";
+const MAX_PICKUP_SHIFT_ARG_NAME: &str = "max-pickup-shift";
+const OUT_RESULT_ARG_NAME: &str = "out-result";
+const OUT_SOLUTION_ARG_NAME: &str = "out-solution";
+
+pub fn get_feasibility_app() -> Command {
+    Command::new("feasibility")
+        .about("Checks whether new jobs can be inserted into existing solution")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::new(SOLUTION_ARG_NAME)
+                .help("Sets solution file with the current schedule")
+                .short('s')
+                .long(SOLUTION_ARG_NAME)
+                .required(true),
+        )
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix which includes candidate locations")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .num_args(1..)
+                .required(true),
+        )
+        .arg(
+            Arg::new(CANDIDATES_ARG_NAME)
+                .help("Specifies path to file with a json list of candidate jobs")
+                .short('c')
+                .long(CANDIDATES_ARG_NAME)
+                .required(true),
+        )
+        .arg(
+            Arg::new(ACCEPT_ARG_NAME)
+                .help("Accepts every feasible candidate using its best insertion before checking the next one")
+                .short('a')
+                .long(ACCEPT_ARG_NAME)
+                .required(false)
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new(REOPTIMIZE_ARG_NAME)
+                .help("Specifies time limit in milliseconds for local re-optimization of a route after accepting a job")
+                .short('r')
+                .long(REOPTIMIZE_ARG_NAME)
+                .required(false)
+                .requires(ACCEPT_ARG_NAME),
+        )
+        .arg(
+            Arg::new(MAX_PICKUP_SHIFT_ARG_NAME)
+                .help("Specifies max shift in seconds of scheduled pickup times during re-optimization (default: 300)")
+                .long(MAX_PICKUP_SHIFT_ARG_NAME)
+                .required(false)
+                .requires(REOPTIMIZE_ARG_NAME),
+        )
+        .arg(
+            Arg::new(OUT_RESULT_ARG_NAME)
+                .help("Specifies path to the file for json-lines result output; writes to stdout when omitted")
+                .short('o')
+                .long(OUT_RESULT_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(OUT_SOLUTION_ARG_NAME)
+                .help("Specifies path to the file for updated solution output")
+                .long(OUT_SOLUTION_ARG_NAME)
+                .required(false)
+                .requires(ACCEPT_ARG_NAME),
+        )
+}
+
+pub fn run_feasibility(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let problem_format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+    if problem_format != "pragmatic" {
+        return Err(format!("unknown problem format: '{problem_format}'").into());
+    }
+
+    let problem_path = matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap();
+    let problem_reader = BufReader::new(open_file(problem_path, "problem"));
+    let solution_reader = BufReader::new(open_file(matches.get_one::<String>(SOLUTION_ARG_NAME).unwrap(), "solution"));
+    let matrices_readers = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .unwrap()
+        .map(|path| BufReader::new(open_file(path, "routing matrix")))
+        .collect();
+    let candidates_reader =
+        BufReader::new(open_file(matches.get_one::<String>(CANDIDATES_ARG_NAME).unwrap(), "candidates"));
+    let is_accept_set = matches.get_one::<bool>(ACCEPT_ARG_NAME).copied().unwrap_or(false);
+    let reoptimization = get_reoptimization_options(matches, REOPTIMIZE_ARG_NAME, MAX_PICKUP_SHIFT_ARG_NAME)?;
+
+    let out_result = matches.get_one::<String>(OUT_RESULT_ARG_NAME).map(|path| create_file(path, "feasibility result"));
+    let mut results_writer = out_writer_func(out_result);
+
+    let solution = check_pragmatic_candidates(
+        problem_reader,
+        solution_reader,
+        matrices_readers,
+        candidates_reader,
+        is_accept_set,
+        reoptimization,
+        &mut results_writer,
+    )?;
+
+    match (solution, matches.get_one::<String>(OUT_SOLUTION_ARG_NAME)) {
+        (Some(solution), Some(path)) => create_write_buffer(Some(create_file(path, "out solution")))
+            .write_all(solution.as_bytes())
+            .map_err(|err| format!("cannot write solution: '{err}'").into()),
+        _ => Ok(()),
+    }
+}
+
+pub(crate) fn get_reoptimization_options(
+    matches: &ArgMatches,
+    time_limit_arg_name: &str,
+    max_pickup_shift_arg_name: &str,
+) -> Result<Option<ReoptimizationOptions>, GenericError> {
+    let time_limit = parse_int_value::<usize>(matches, time_limit_arg_name, "reoptimization time limit")?;
+    let max_pickup_shift = parse_float_value::<Float>(matches, max_pickup_shift_arg_name, "max pickup shift")?;
+
+    Ok(time_limit.map(|time_limit| {
+        let defaults = ReoptimizationOptions::default();
+        ReoptimizationOptions {
+            time_limit,
+            max_pickup_shift: max_pickup_shift.unwrap_or(defaults.max_pickup_shift),
+            ..defaults
+        }
+    }))
+}
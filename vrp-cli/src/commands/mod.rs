@@ -2,8 +2,10 @@ use clap::{Arg, ArgMatches, Command};
 
 pub mod analyze;
 pub mod check;
+pub mod feasibility;
 pub mod generate;
 pub mod import;
+pub mod serve;
 pub mod solve;
 
 use std::fs::File;
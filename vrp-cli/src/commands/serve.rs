@@ -0,0 +1,93 @@
+#[cfg(test)]
+#[path = "../../tests/unit/commands/serve_test.rs"]
+mod serve_test;
+
+use super::feasibility::get_reoptimization_options;
+use super::*;
+use std::io::stdin;
+use vrp_cli::extensions::feasibility::{create_feasibility_context, run_insertion_service};
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::problem::deserialize_locations;
+
+const FORMAT_ARG_NAME: &str = "FORMAT";
+const PROBLEM_ARG_NAME: &str = "PROBLEM";
+const SOLUTION_ARG_NAME: &str = "solution";
+const MATRIX_ARG_NAME: &str = "matrix";
+const LOCATIONS_ARG_NAME: &str = "locations";
+const REOPTIMIZE_ARG_NAME: &str = "reoptimize";
+const MAX_PICKUP_SHIFT_ARG_NAME: &str = "max-pickup-shift";
+
+pub fn get_serve_app() -> Command {
+    Command::new("serve")
+        .about("Runs insertion service which processes json-lines commands from stdin against in-memory solution")
+        .arg(Arg::new(FORMAT_ARG_NAME).help("Specifies input type").required(true).value_parser(["pragmatic"]).index(1))
+        .arg(Arg::new(PROBLEM_ARG_NAME).help("Sets the problem file to use").required(true).index(2))
+        .arg(
+            Arg::new(SOLUTION_ARG_NAME)
+                .help("Sets solution file with the initial schedule")
+                .short('s')
+                .long(SOLUTION_ARG_NAME)
+                .required(true),
+        )
+        .arg(
+            Arg::new(MATRIX_ARG_NAME)
+                .help("Specifies path to file with routing matrix")
+                .short('m')
+                .long(MATRIX_ARG_NAME)
+                .num_args(1..)
+                .required(true),
+        )
+        .arg(
+            Arg::new(LOCATIONS_ARG_NAME)
+                .help("Specifies path to file with a json list of future job locations which are not in the problem")
+                .short('l')
+                .long(LOCATIONS_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(REOPTIMIZE_ARG_NAME)
+                .help("Specifies time limit in milliseconds for local re-optimization of a route after accepting a job")
+                .short('r')
+                .long(REOPTIMIZE_ARG_NAME)
+                .required(false),
+        )
+        .arg(
+            Arg::new(MAX_PICKUP_SHIFT_ARG_NAME)
+                .help("Specifies max shift in seconds of scheduled pickup times during re-optimization (default: 300)")
+                .long(MAX_PICKUP_SHIFT_ARG_NAME)
+                .required(false)
+                .requires(REOPTIMIZE_ARG_NAME),
+        )
+}
+
+pub fn run_serve(
+    matches: &ArgMatches,
+    out_writer_func: fn(Option<File>) -> BufWriter<Box<dyn Write>>,
+) -> Result<(), GenericError> {
+    let problem_format = matches.get_one::<String>(FORMAT_ARG_NAME).unwrap();
+    if problem_format != "pragmatic" {
+        return Err(format!("unknown problem format: '{problem_format}'").into());
+    }
+
+    let problem_reader = BufReader::new(open_file(matches.get_one::<String>(PROBLEM_ARG_NAME).unwrap(), "problem"));
+    let solution_reader = BufReader::new(open_file(matches.get_one::<String>(SOLUTION_ARG_NAME).unwrap(), "solution"));
+    let matrices_readers = matches
+        .get_many::<String>(MATRIX_ARG_NAME)
+        .unwrap()
+        .map(|path| BufReader::new(open_file(path, "routing matrix")))
+        .collect();
+    let extra_locations = matches
+        .get_one::<String>(LOCATIONS_ARG_NAME)
+        .map(|path| deserialize_locations(BufReader::new(open_file(path, "locations"))))
+        .transpose()
+        .map_err(|err| format!("cannot read locations: '{err}'"))?
+        .unwrap_or_default();
+
+    let reoptimization = get_reoptimization_options(matches, REOPTIMIZE_ARG_NAME, MAX_PICKUP_SHIFT_ARG_NAME)?;
+
+    let ctx =
+        create_feasibility_context(problem_reader, solution_reader, matrices_readers, extra_locations.as_slice())?;
+    let ctx = if let Some(options) = reoptimization { ctx.with_reoptimization(options) } else { ctx };
+
+    run_insertion_service(ctx, stdin().lock(), &mut out_writer_func(None))
+}
@@ -7,9 +7,11 @@ use super::*;
 use clap::ArgAction;
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use vrp_cli::core::solver::TargetHeuristic;
+use vrp_cli::extensions::solve::checkpoint::{create_checkpoint_writer, read_checkpoint};
 use vrp_cli::extensions::solve::config::create_builder_from_config_file;
 use vrp_cli::extensions::solve::formats::*;
 use vrp_core::construction::heuristics::InsertionContext;
@@ -45,6 +47,10 @@ const PARALLELISM_ARG_NAME: &str = "parallelism";
 const HEURISTIC_ARG_NAME: &str = "heuristic";
 const EXPERIMENTAL_ARG_NAME: &str = "experimental";
 const ROUNDED_ARG_NAME: &str = "round";
+const SEED_ARG_NAME: &str = "seed";
+const CHECKPOINT_ARG_NAME: &str = "checkpoint";
+const CHECKPOINT_INTERVAL_ARG_NAME: &str = "checkpoint-interval";
+const RESUME_ARG_NAME: &str = "resume";
 
 pub fn get_solve_app() -> Command {
     Command::new("solve")
@@ -243,6 +249,38 @@ pub fn get_solve_app() -> Command {
                 .required(false)
                 .action(ArgAction::SetTrue)
         )
+        .arg(
+            Arg::new(SEED_ARG_NAME)
+                .help("Specifies random seed to make the run repeatable. Parallel operations are run sequentially and parallelism setting is ignored.")
+                .long(SEED_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(CHECKPOINT_ARG_NAME)
+                .help("Specifies path to file where evolution progress is periodically stored. Only pragmatic format is supported")
+                .long(CHECKPOINT_ARG_NAME)
+                .required(false)
+        )
+        .arg(
+            Arg::new(CHECKPOINT_INTERVAL_ARG_NAME)
+                .help("Specifies how often (in generations) checkpoint is written. Min is 1, default is 100")
+                .long(CHECKPOINT_INTERVAL_ARG_NAME)
+                .required(false)
+                .requires(CHECKPOINT_ARG_NAME)
+        )
+        .arg(
+            Arg::new(RESUME_ARG_NAME)
+                .help("Specifies path to checkpoint file to resume evolution from. Only pragmatic format is supported")
+                .long(RESUME_ARG_NAME)
+                .required(false)
+                .conflicts_with(INIT_SOLUTION_ARG_NAME)
+        )
+}
+
+/// Specifies how evolution progress is stored and restored.
+struct CheckpointSettings {
+    writer: Option<(usize, CheckpointWriter<RefinementContext>)>,
+    resume: Option<EvolutionCheckpoint>,
 }
 
 /// Runs solver commands.
@@ -273,6 +311,13 @@ pub fn run_solve(
     let is_get_locations_set = matches.get_one::<bool>(GET_LOCATIONS_ARG_NAME).copied().unwrap_or(false);
     let is_check_requested = matches.get_one::<bool>(CHECK_ARG_NAME).copied().unwrap_or(false);
 
+    let resume = matches.get_one::<String>(RESUME_ARG_NAME).map(|path| open_file(path, "checkpoint"));
+    let checkpoint_path = matches.get_one::<String>(CHECKPOINT_ARG_NAME).map(PathBuf::from);
+    let checkpoint_interval = get_positive_usize(matches, CHECKPOINT_INTERVAL_ARG_NAME, "checkpoint interval")?;
+    if (resume.is_some() || checkpoint_path.is_some()) && problem_format != "pragmatic" {
+        return Err(format!("checkpoint is not supported for '{problem_format}' format").into());
+    }
+
     match formats.get(problem_format.as_str()) {
         Some((
             ProblemReader(problem_reader),
@@ -290,16 +335,35 @@ pub fn run_solve(
                     Ok(problem) => {
                         let problem = Arc::new(problem);
 
-                        let init_solutions = read_init_solutions_if_necessary(
-                            problem.clone(),
-                            environment.clone(),
-                            init_solution,
-                            init_reader,
-                        )?;
+                        let (init_solutions, resume) = match resume {
+                            Some(file) => read_checkpoint(BufReader::new(file), problem.clone(), environment.clone())
+                                .map(|(checkpoint, solutions)| (solutions, Some(checkpoint)))
+                                .map_err(|err| format!("cannot read checkpoint: '{err}'"))?,
+                            _ => (
+                                read_init_solutions_if_necessary(
+                                    problem.clone(),
+                                    environment.clone(),
+                                    init_solution,
+                                    init_reader,
+                                )?,
+                                None,
+                            ),
+                        };
+
+                        let checkpoint = CheckpointSettings {
+                            writer: checkpoint_path.map(|path| {
+                                (checkpoint_interval.unwrap_or(100), create_checkpoint_writer(problem.clone(), path))
+                            }),
+                            resume,
+                        };
 
                         let solver = match config {
-                            Some(config) => from_config_parameters(problem.clone(), init_solutions, config)?,
-                            _ => from_cli_parameters(problem.clone(), environment, init_solutions, matches)?,
+                            Some(config) => {
+                                from_config_parameters(problem.clone(), init_solutions, checkpoint, config)?
+                            }
+                            _ => {
+                                from_cli_parameters(problem.clone(), environment, init_solutions, checkpoint, matches)?
+                            }
                         };
 
                         let solution = solver.solve().map_err(|err| format!("cannot find any solution: '{err}'"))?;
@@ -354,10 +418,11 @@ fn read_init_solutions_if_necessary(
 fn from_config_parameters(
     problem: Arc<Problem>,
     init_solutions: Vec<InsertionContext>,
+    checkpoint: CheckpointSettings,
     config: File,
 ) -> GenericResult<Solver> {
     create_builder_from_config_file(problem.clone(), init_solutions, BufReader::new(config))
-        .and_then(|builder| builder.build())
+        .and_then(|builder| configure_checkpoint(builder, checkpoint).build())
         .map(|config| Solver::new(problem.clone(), config))
         .map_err(|err| format!("cannot read config: '{err}'").into())
 }
@@ -366,6 +431,7 @@ fn from_cli_parameters(
     problem: Arc<Problem>,
     environment: Arc<Environment>,
     init_solutions: Vec<InsertionContext>,
+    checkpoint: CheckpointSettings,
     matches: &ArgMatches,
 ) -> GenericResult<Solver> {
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
@@ -425,8 +491,7 @@ fn from_cli_parameters(
         builder = builder.set_construction_job_cap(construction_job_cap);
     }
 
-    let config = builder
-        .prebuild()?
+    let config = configure_checkpoint(builder.prebuild()?, checkpoint)
         .with_init_solutions(init_solutions, init_size)
         .with_max_generations(max_generations)
         .with_max_time(max_time)
@@ -442,6 +507,18 @@ fn from_cli_parameters(
     Ok(Solver::new(problem, config))
 }
 
+fn configure_checkpoint(builder: ProblemConfigBuilder, checkpoint: CheckpointSettings) -> ProblemConfigBuilder {
+    let builder = match checkpoint.writer {
+        Some((interval, writer)) => builder.with_checkpoint(interval, writer),
+        _ => builder,
+    };
+
+    match checkpoint.resume {
+        Some(resume) => builder.with_resume(resume),
+        _ => builder,
+    }
+}
+
 fn get_min_cv(matches: &ArgMatches) -> GenericResult<Option<(String, usize, Float, bool)>> {
     let err_result = Err("cannot parse min_cv parameter".into());
     matches
@@ -486,6 +563,12 @@ fn get_environment(matches: &ArgMatches) -> GenericResult<Arc<Environment>> {
     let max_time = parse_int_value::<usize>(matches, TIME_ARG_NAME, "max time")?;
     let quota = Some(create_interruption_quota(max_time));
     let is_experimental = matches.get_one::<bool>(EXPERIMENTAL_ARG_NAME).copied().unwrap_or(false);
+    let seed = parse_int_value::<u64>(matches, SEED_ARG_NAME, "seed")?;
+    let is_deterministic = seed.is_some();
+    let random: Arc<dyn Random> = match seed {
+        Some(seed) => Arc::new(DefaultRandom::new_with_seed(seed)),
+        None => Arc::new(DefaultRandom::default()),
+    };
 
     matches
         .get_one::<String>(PARALLELISM_ARG_NAME)
@@ -493,24 +576,28 @@ fn get_environment(matches: &ArgMatches) -> GenericResult<Arc<Environment>> {
             if let [num_thread_pools, threads_per_pool] =
                 arg.split(',').filter_map(|line| line.parse::<usize>().ok()).collect::<Vec<_>>().as_slice()
             {
-                let parallelism = Parallelism::new(*num_thread_pools, *threads_per_pool);
+                // NOTE thread pools run operations concurrently, so they are not used for repeatable runs
+                let parallelism = if is_deterministic {
+                    Parallelism::default()
+                } else {
+                    Parallelism::new(*num_thread_pools, *threads_per_pool)
+                };
                 let logger: InfoLogger = if matches.get_one::<bool>(LOG_ARG_NAME).copied().unwrap_or(false) {
                     Arc::new(|msg: &str| println!("{msg}"))
                 } else {
                     Arc::new(|_: &str| {})
                 };
-                Ok(Arc::new(Environment::new(
-                    Arc::new(DefaultRandom::default()),
-                    quota.clone(),
-                    parallelism,
-                    logger,
-                    is_experimental,
-                )))
+                Ok(Arc::new(Environment {
+                    is_deterministic,
+                    ..Environment::new(random.clone(), quota.clone(), parallelism, logger, is_experimental)
+                }))
             } else {
                 Err("cannot parse parallelism parameter".into())
             }
         })
-        .unwrap_or_else(|| Ok(Arc::new(Environment { quota, is_experimental, ..Environment::default() })))
+        .unwrap_or_else(|| {
+            Ok(Arc::new(Environment { random, quota, is_experimental, is_deterministic, ..Environment::default() }))
+        })
 }
 
 fn get_matrix_files(matches: &ArgMatches) -> Option<Vec<File>> {
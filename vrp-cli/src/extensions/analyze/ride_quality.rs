@@ -1,9 +1,12 @@
 use serde::Serialize;
 use std::collections::HashMap;
+use vrp_core::construction::features::get_direct_ride_duration;
+use vrp_core::models::common::Profile;
+use vrp_core::models::problem::TransportCost;
 use vrp_core::prelude::GenericError;
-use vrp_pragmatic::format::problem::{JobPlace, Matrix, Problem};
+use vrp_pragmatic::format::problem::{JobPlace, Matrix, PragmaticProblem, Problem};
 use vrp_pragmatic::format::solution::{Activity, Interval, Solution};
-use vrp_pragmatic::format::{CoordIndex, Location};
+use vrp_pragmatic::format::{CoordIndex, Location, VehicleTypeDimension};
 
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -120,7 +123,7 @@ struct ObservedActivity {
 struct ObservedJob {
     vehicle_id: String,
     vehicle_type_id: String,
-    matrix_profile: String,
+    profile: Profile,
     pickups: Vec<ObservedActivity>,
     deliveries: Vec<ObservedActivity>,
 }
@@ -141,20 +144,24 @@ pub fn get_ride_quality_serialized(
         return Err("minimum direct time must be at least one second".into());
     }
 
+    validate_matrices(matrices)?;
+
     let coord_index = CoordIndex::new(problem);
-    let matrix_size = validate_matrices(matrices)?;
+    let core_problem = (problem.clone(), matrices.to_vec())
+        .read_pragmatic()
+        .map_err(|errs| format!("cannot read pragmatic problem: '{errs}'"))?;
 
-    let vehicle_profiles = problem
+    let vehicle_profiles = core_problem
         .fleet
         .vehicles
         .iter()
-        .map(|vehicle| (vehicle.type_id.as_str(), vehicle.profile.matrix.as_str()))
+        .filter_map(|vehicle| vehicle.dimens.get_vehicle_type().map(|type_id| (type_id.as_str(), &vehicle.profile)))
         .collect::<HashMap<_, _>>();
     let jobs = problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
     let mut observed_jobs: HashMap<String, ObservedJob> = HashMap::new();
 
     for tour in &solution.tours {
-        let matrix_profile = vehicle_profiles
+        let profile = vehicle_profiles
             .get(tour.type_id.as_str())
             .ok_or_else(|| format!("cannot find vehicle type '{}' in problem", tour.type_id))?;
 
@@ -175,7 +182,7 @@ pub fn get_ride_quality_serialized(
                 if entry.vehicle_id.is_empty() {
                     entry.vehicle_id.clone_from(&tour.vehicle_id);
                     entry.vehicle_type_id.clone_from(&tour.type_id);
-                    entry.matrix_profile = (*matrix_profile).to_string();
+                    entry.profile = (*profile).clone();
                 } else if entry.vehicle_id != tour.vehicle_id {
                     return Err(format!("job '{}' has activities on multiple vehicles", activity.job_id).into());
                 }
@@ -239,10 +246,9 @@ pub fn get_ride_quality_serialized(
             }
 
             let Some(direct_seconds) = get_direct_time(
-                matrices,
-                &observed_job.matrix_profile,
+                core_problem.transport.as_ref(),
+                &observed_job.profile,
                 &coord_index,
-                matrix_size,
                 pickup_location,
                 delivery_location,
             ) else {
@@ -374,29 +380,19 @@ fn find_place<'a>(
 }
 
 fn get_direct_time(
-    matrices: &[Matrix],
-    profile: &str,
+    transport: &dyn TransportCost,
+    profile: &Profile,
     coord_index: &CoordIndex,
-    matrix_size: usize,
     from: &Location,
     to: &Location,
 ) -> Option<i64> {
-    let matrix = matrices
-        .iter()
-        .find(|matrix| matrix.profile.as_deref() == Some(profile))
-        .or_else(|| (matrices.len() == 1).then(|| &matrices[0]))?;
     let from = coord_index.get_by_loc(from)?;
     let to = coord_index.get_by_loc(to)?;
-    let index = from.checked_mul(matrix_size)?.checked_add(to)?;
-
-    if matrix.error_codes.as_ref().and_then(|codes| codes.get(index)).is_some_and(|code| *code > 0) {
-        return None;
-    }
 
-    matrix.travel_times.get(index).copied().filter(|time| *time >= 0)
+    get_direct_ride_duration(transport, profile, from, to).map(|duration| duration.round() as i64)
 }
 
-fn validate_matrices(matrices: &[Matrix]) -> Result<usize, GenericError> {
+fn validate_matrices(matrices: &[Matrix]) -> Result<(), GenericError> {
     if matrices.is_empty() {
         return Err("ride-quality analysis requires at least one routing matrix".into());
     }
@@ -421,7 +417,7 @@ fn validate_matrices(matrices: &[Matrix]) -> Result<usize, GenericError> {
         .into());
     }
 
-    Ok(matrix_size)
+    Ok(())
 }
 
 fn interval_seconds(start: &str, end: &str) -> f64 {
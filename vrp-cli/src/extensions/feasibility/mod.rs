@@ -0,0 +1,95 @@
+//! A helper module which contains functionality to check insertion feasibility of new jobs
+//! against an existing solution.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/feasibility/feasibility_test.rs"]
+mod feasibility_test;
+
+mod service;
+pub use self::service::*;
+
+use serde::Serialize;
+use std::io::{BufReader, Read, Write};
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::Location;
+use vrp_pragmatic::format::feasibility::{
+    FeasibilityContext, FeasibilityResult, ReoptimizationOptions, VehicleFeasibility, job_locations,
+};
+use vrp_pragmatic::format::problem::{Job, deserialize_matrix, deserialize_problem};
+
+/// A feasibility result of a single candidate job.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CandidateFeasibility<'a> {
+    job_id: &'a str,
+    #[serde(flatten)]
+    result: FeasibilityResult,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    accepted: Option<VehicleFeasibility>,
+}
+
+/// Creates a feasibility context from pragmatic problem, solution and routing matrices.
+/// `extra_locations` are locations of future candidate jobs which are not part of the problem:
+/// the routing matrices are expected to have them after all problem locations.
+pub fn create_feasibility_context<R: Read>(
+    problem_reader: BufReader<R>,
+    solution_reader: BufReader<R>,
+    matrices_readers: Vec<BufReader<R>>,
+    extra_locations: &[Location],
+) -> Result<FeasibilityContext, GenericError> {
+    let problem = deserialize_problem(problem_reader).map_err(|err| format!("cannot read problem: '{err}'"))?;
+    let matrices = matrices_readers
+        .into_iter()
+        .map(deserialize_matrix)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|err| format!("cannot read matrix: '{err}'"))?;
+    let solution = read_to_string(solution_reader, "solution")?;
+
+    FeasibilityContext::new(problem, matrices, solution.as_str(), extra_locations)
+}
+
+/// Checks candidate jobs against pragmatic problem and solution, writing one json line per candidate
+/// into `results_writer`. When `accept` is set, every feasible candidate is committed to the
+/// solution using its best insertion, so the next candidates are checked against the updated state.
+/// When `reoptimization` is set, the touched route is improved after each accepted candidate.
+/// Returns the updated solution serialized in pragmatic format when `accept` is set.
+pub fn check_pragmatic_candidates<R: Read, W: Write>(
+    problem_reader: BufReader<R>,
+    solution_reader: BufReader<R>,
+    matrices_readers: Vec<BufReader<R>>,
+    candidates_reader: BufReader<R>,
+    accept: bool,
+    reoptimization: Option<ReoptimizationOptions>,
+    results_writer: &mut W,
+) -> Result<Option<String>, GenericError> {
+    let candidates: Vec<Job> =
+        serde_json::from_reader(candidates_reader).map_err(|err| format!("cannot read candidate jobs: '{err}'"))?;
+
+    let extra_locations = candidates.iter().flat_map(job_locations).collect::<Vec<_>>();
+    let mut ctx =
+        create_feasibility_context(problem_reader, solution_reader, matrices_readers, extra_locations.as_slice())?;
+    if let Some(options) = reoptimization {
+        ctx = ctx.with_reoptimization(options);
+    }
+
+    for candidate in candidates.iter() {
+        let result = ctx.check_job(candidate)?;
+        let accepted = if accept && result.is_feasible { Some(ctx.accept_job(candidate)?) } else { None };
+
+        let line = serde_json::to_string(&CandidateFeasibility { job_id: candidate.id.as_str(), result, accepted })
+            .map_err(|err| format!("cannot serialize feasibility result: '{err}'"))?;
+
+        writeln!(results_writer, "{line}")
+            .and_then(|_| results_writer.flush())
+            .map_err(|err| format!("cannot write feasibility result: '{err}'"))?;
+    }
+
+    if accept { ctx.to_solution_json().map(Some) } else { Ok(None) }
+}
+
+fn read_to_string<R: Read>(mut reader: BufReader<R>, description: &str) -> Result<String, GenericError> {
+    let mut content = String::new();
+    reader.read_to_string(&mut content).map_err(|err| format!("cannot read {description}: '{err}'"))?;
+
+    Ok(content)
+}
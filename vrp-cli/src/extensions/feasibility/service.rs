@@ -0,0 +1,94 @@
+//! Provides a long-running insertion service which keeps solution state in memory and processes
+//! json-lines commands.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/feasibility/service_test.rs"]
+mod service_test;
+
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+use vrp_core::prelude::GenericError;
+use vrp_pragmatic::format::feasibility::{
+    CancellationResult, FeasibilityContext, FeasibilityResult, VehicleFeasibility,
+};
+use vrp_pragmatic::format::problem::Job;
+use vrp_pragmatic::format::solution::{Solution, deserialize_solution};
+
+/// A command accepted by insertion service.
+#[derive(Deserialize)]
+#[serde(tag = "command", rename_all = "kebab-case")]
+enum ServiceCommand {
+    /// Checks whether the job can be inserted.
+    Check { job: Job },
+    /// Inserts the job using its best insertion.
+    Accept { job: Job },
+    /// Removes the job from the solution.
+    Cancel {
+        #[serde(rename = "jobId")]
+        job_id: String,
+    },
+    /// Returns the current solution.
+    Snapshot,
+}
+
+/// A response produced by insertion service for each command.
+#[derive(Serialize)]
+#[serde(tag = "command", rename_all = "kebab-case")]
+enum ServiceResponse {
+    Ready,
+    Check { result: FeasibilityResult },
+    Accept { result: VehicleFeasibility },
+    Cancel { result: CancellationResult },
+    Snapshot { solution: Box<Solution> },
+    Error { message: String },
+}
+
+/// Runs insertion service: reads one json command per line from `input` and writes one json
+/// response per line to `output` until the input is exhausted. A `ready` line is written first to
+/// signal that the state is loaded. Invalid commands produce error responses and do not stop the service.
+pub fn run_insertion_service<R: BufRead, W: Write>(
+    mut ctx: FeasibilityContext,
+    input: R,
+    output: &mut W,
+) -> Result<(), GenericError> {
+    write_response(output, &ServiceResponse::Ready)?;
+
+    for line in input.lines() {
+        let line = line.map_err(|err| format!("cannot read command: '{err}'"))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let response = serde_json::from_str::<ServiceCommand>(line.as_str())
+            .map_err(|err| GenericError::from(format!("cannot parse command: '{err}'")))
+            .and_then(|command| execute_command(&mut ctx, command))
+            .unwrap_or_else(|err| ServiceResponse::Error { message: err.to_string() });
+
+        write_response(output, &response)?;
+    }
+
+    Ok(())
+}
+
+fn write_response<W: Write>(output: &mut W, response: &ServiceResponse) -> Result<(), GenericError> {
+    let response = serde_json::to_string(response).map_err(|err| format!("cannot serialize response: '{err}'"))?;
+
+    writeln!(output, "{response}")
+        .and_then(|_| output.flush())
+        .map_err(|err| format!("cannot write response: '{err}'").into())
+}
+
+fn execute_command(ctx: &mut FeasibilityContext, command: ServiceCommand) -> Result<ServiceResponse, GenericError> {
+    Ok(match command {
+        ServiceCommand::Check { job } => ServiceResponse::Check { result: ctx.check_job(&job)? },
+        ServiceCommand::Accept { job } => ServiceResponse::Accept { result: ctx.accept_job(&job)? },
+        ServiceCommand::Cancel { job_id } => ServiceResponse::Cancel { result: ctx.cancel_job(job_id.as_str())? },
+        ServiceCommand::Snapshot => {
+            let solution = ctx.to_solution_json()?;
+            let solution = deserialize_solution(std::io::BufReader::new(solution.as_bytes()))
+                .map_err(|err| format!("cannot read solution snapshot: '{err}'"))?;
+
+            ServiceResponse::Snapshot { solution: Box::new(solution) }
+        }
+    })
+}
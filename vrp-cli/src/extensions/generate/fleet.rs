@@ -35,11 +35,18 @@ pub(crate) fn generate_fleet(problem_proto: &Problem, vehicle_types_size: usize)
                 skills: get_random_item(skills.as_slice(), &rnd).expect("cannot find any skills").clone(),
                 limits: get_random_item(limits.as_slice(), &rnd).expect("cannot find any limits").clone(),
                 lifo_tags: None,
+                driving_rules: None,
             }
         })
         .collect();
 
-    Fleet { vehicles, profiles, resources: None, capacity_dimensions: None }
+    Fleet {
+        vehicles,
+        drivers: problem_proto.fleet.drivers.clone(),
+        profiles,
+        resources: None,
+        capacity_dimensions: None,
+    }
 }
 
 fn get_from_vehicle<F, T>(problem_proto: &Problem, func: F) -> Vec<T>
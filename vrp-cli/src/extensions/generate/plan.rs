@@ -39,6 +39,7 @@ pub(crate) fn generate_plan(
                             times: get_random_item(time_windows.as_slice(), &rnd).cloned(),
                             tag: place.tag.clone(),
                             requested_time: place.requested_time.clone(),
+                            parking: place.parking,
                         })
                         .collect(),
                     demand: if keep_original_demand {
@@ -76,11 +77,16 @@ pub(crate) fn generate_plan(
                 lifo_tag: job_proto.lifo_tag.clone(),
                 fixed_order: job_proto.fixed_order,
                 max_ride_duration: job_proto.max_ride_duration,
+                relative_max_ride_duration: job_proto.relative_max_ride_duration.clone(),
+                soft_max_ride_duration: job_proto.soft_max_ride_duration,
+                soft_time_windows: job_proto.soft_time_windows.clone(),
+                requested_time_penalty: job_proto.requested_time_penalty.clone(),
+                dwell_time: job_proto.dwell_time.clone(),
             }
         })
         .collect();
 
-    Ok(Plan { jobs, relations: None, clustering: None })
+    Ok(Plan { jobs, relations: None, clustering: None, replanning: None })
 }
 
 type LocationFn = Box<dyn Fn(&DefaultRandom) -> Location>;
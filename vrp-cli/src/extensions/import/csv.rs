@@ -72,6 +72,7 @@ mod actual {
                 times: parse_tw(job.tw_start.clone(), job.tw_end.clone()).map(|tw| vec![tw]),
                 tag: None,
                 requested_time: None,
+                parking: None,
             }],
             demand: if job.demand != 0 { Some(vec![job.demand.abs()]) } else { None },
             named_demand: None,
@@ -105,6 +106,11 @@ mod actual {
                 lifo_tag: None,
                 fixed_order: None,
                 max_ride_duration: None,
+                relative_max_ride_duration: None,
+                soft_max_ride_duration: None,
+                soft_time_windows: None,
+                requested_time_penalty: None,
+                dwell_time: None,
             })
             .collect();
 
@@ -140,6 +146,7 @@ mod actual {
                     skills: None,
                     limits: None,
                     lifo_tags: None,
+                    driving_rules: None,
                 }
             })
             .collect();
@@ -166,9 +173,10 @@ mod actual {
         let matrix_profile_names = vehicles.iter().map(|v| v.profile.matrix.clone()).collect::<HashSet<_>>();
 
         Ok(Problem {
-            plan: Plan { jobs, relations: None, clustering: None },
+            plan: Plan { jobs, relations: None, clustering: None, replanning: None },
             fleet: Fleet {
                 vehicles,
+                drivers: None,
                 profiles: matrix_profile_names.into_iter().map(|name| MatrixProfile { name, speed: None }).collect(),
                 resources: None,
                 capacity_dimensions: None,
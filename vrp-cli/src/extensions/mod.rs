@@ -5,6 +5,8 @@ pub mod analyze;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod check;
 #[cfg(not(target_arch = "wasm32"))]
+pub mod feasibility;
+#[cfg(not(target_arch = "wasm32"))]
 pub mod generate;
 
 pub mod import;
@@ -0,0 +1,150 @@
+//! Contains functionality to store evolution progress into checkpoint file and restore it later.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/extensions/solve/checkpoint_test.rs"]
+mod checkpoint_test;
+
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use vrp_core::construction::heuristics::InsertionContext;
+use vrp_core::models::{Problem, Solution};
+use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::{CheckpointWriter, EvolutionCheckpoint};
+use vrp_core::rosomaxa::hyper::HeuristicOperatorState;
+use vrp_core::rosomaxa::{HeuristicContext, HeuristicSolution};
+use vrp_core::solver::RefinementContext;
+use vrp_pragmatic::format::solution::{Solution as ApiSolution, create_init_solution, create_pragmatic_solution};
+
+/// A checkpoint of evolution run: search progress and population solutions in pragmatic format.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    /// A last finished generation.
+    pub generation: usize,
+    /// An improvement ratio from the beginning.
+    pub improvement_all_ratio: Float,
+    /// An improvement ratio for last 1000 generations.
+    pub improvement_1000_ratio: Float,
+    /// Learned state of hyper heuristic operators.
+    pub heuristic: Vec<OperatorState>,
+    /// Population solutions ordered by their rank.
+    pub solutions: Vec<ApiSolution>,
+}
+
+/// A learned state of a heuristic operator.
+#[derive(Clone, Deserialize, Serialize, Debug)]
+pub struct OperatorState {
+    /// A search state name.
+    pub state: String,
+    /// An operator name.
+    pub name: String,
+    /// Shape parameter of the estimated reward distribution.
+    pub alpha: Float,
+    /// Rate parameter of the estimated reward distribution.
+    pub beta: Float,
+    /// Estimated mean of the reward.
+    pub mu: Float,
+    /// Estimated variance of the reward.
+    pub v: Float,
+    /// Usage amount.
+    pub n: usize,
+}
+
+/// Creates a checkpoint writer which stores population solutions in pragmatic format into the file.
+/// The file is replaced only when the checkpoint is fully written.
+pub fn create_checkpoint_writer(problem: Arc<Problem>, path: PathBuf) -> CheckpointWriter<RefinementContext> {
+    Box::new(move |refinement_ctx: &RefinementContext, checkpoint: &EvolutionCheckpoint| {
+        let solutions = refinement_ctx
+            .ranked()
+            .map(|insertion_ctx| {
+                create_pragmatic_solution(problem.as_ref(), &Solution::from(insertion_ctx.deep_copy()))
+            })
+            .collect();
+
+        let checkpoint = Checkpoint {
+            generation: checkpoint.generation,
+            improvement_all_ratio: checkpoint.improvement_all_ratio,
+            improvement_1000_ratio: checkpoint.improvement_1000_ratio,
+            heuristic: checkpoint.heuristic.iter().map(OperatorState::from).collect(),
+            solutions,
+        };
+
+        if let Err(err) = write_checkpoint(&checkpoint, &path) {
+            (refinement_ctx.environment.logger)(&format!("cannot write checkpoint to '{}': '{err}'", path.display()));
+        }
+    })
+}
+
+/// Reads checkpoint and creates solutions from it to be used as initial population.
+pub fn read_checkpoint<R: Read>(
+    reader: BufReader<R>,
+    problem: Arc<Problem>,
+    environment: Arc<Environment>,
+) -> GenericResult<(EvolutionCheckpoint, Vec<InsertionContext>)> {
+    let checkpoint: Checkpoint =
+        serde_json::from_reader(reader).map_err(|err| format!("cannot deserialize checkpoint: '{err}'"))?;
+
+    let solutions = checkpoint
+        .solutions
+        .iter()
+        .map(|solution| {
+            create_init_solution(solution, problem.clone(), environment.random.clone()).map(|solution| {
+                InsertionContext::new_from_solution(problem.clone(), (solution, None), environment.clone())
+            })
+        })
+        .collect::<GenericResult<Vec<_>>>()?;
+
+    if solutions.is_empty() {
+        return Err("checkpoint has no solutions".into());
+    }
+
+    let checkpoint = EvolutionCheckpoint {
+        generation: checkpoint.generation,
+        improvement_all_ratio: checkpoint.improvement_all_ratio,
+        improvement_1000_ratio: checkpoint.improvement_1000_ratio,
+        heuristic: checkpoint.heuristic.into_iter().map(HeuristicOperatorState::from).collect(),
+    };
+
+    Ok((checkpoint, solutions))
+}
+
+fn write_checkpoint(checkpoint: &Checkpoint, path: &Path) -> GenericResult<()> {
+    let temp_path = path.with_extension("tmp");
+
+    let mut writer = BufWriter::new(File::create(&temp_path)?);
+    serde_json::to_writer(&mut writer, checkpoint).map_err(|err| err.to_string())?;
+    writer.into_inner().map_err(|err| err.to_string())?;
+
+    std::fs::rename(temp_path, path).map_err(From::from)
+}
+
+impl From<&HeuristicOperatorState> for OperatorState {
+    fn from(state: &HeuristicOperatorState) -> Self {
+        Self {
+            state: state.state.clone(),
+            name: state.name.clone(),
+            alpha: state.alpha,
+            beta: state.beta,
+            mu: state.mu,
+            v: state.v,
+            n: state.n,
+        }
+    }
+}
+
+impl From<OperatorState> for HeuristicOperatorState {
+    fn from(state: OperatorState) -> Self {
+        Self {
+            state: state.state,
+            name: state.name,
+            alpha: state.alpha,
+            beta: state.beta,
+            mu: state.mu,
+            v: state.v,
+            n: state.n,
+        }
+    }
+}
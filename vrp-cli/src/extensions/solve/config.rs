@@ -15,6 +15,7 @@ use vrp_core::construction::heuristics::InsertionContext;
 use vrp_core::models::GoalContext;
 use vrp_core::models::common::Footprint;
 use vrp_core::prelude::*;
+use vrp_core::rosomaxa::evolution::strategies::AcceptanceCriterion;
 use vrp_core::rosomaxa::evolution::{InitialOperator, TelemetryMode};
 use vrp_core::rosomaxa::get_default_selection_size;
 use vrp_core::rosomaxa::prelude::*;
@@ -46,6 +47,7 @@ pub struct Config {
 pub struct EvolutionConfig {
     pub initial: Option<InitialConfig>,
     pub population: Option<PopulationType>,
+    pub strategy: Option<StrategyType>,
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -92,6 +94,44 @@ pub enum PopulationType {
     },
 }
 
+/// An evolution strategy configuration.
+#[derive(Clone, Deserialize, Debug)]
+#[serde(tag = "type")]
+#[serde(rename_all = "camelCase")]
+pub enum StrategyType {
+    /// A default strategy which improves population iteratively.
+    #[serde(rename(deserialize = "iterative"))]
+    Iterative,
+
+    /// A simulated annealing which accepts worse solutions with decreasing probability.
+    #[serde(rename(deserialize = "simulated-annealing"))]
+    #[serde(rename_all = "camelCase")]
+    SimulatedAnnealing {
+        /// An initial temperature. Default is 0.01.
+        initial_temperature: Option<Float>,
+        /// A cooling rate. Default is 0.999.
+        cooling_rate: Option<Float>,
+    },
+
+    /// A threshold accepting which accepts worse solutions within decreasing threshold.
+    #[serde(rename(deserialize = "threshold-accepting"))]
+    #[serde(rename_all = "camelCase")]
+    ThresholdAccepting {
+        /// An initial threshold. Default is 0.01.
+        initial_threshold: Option<Float>,
+        /// A decay rate. Default is 0.999.
+        decay_rate: Option<Float>,
+    },
+
+    /// A late acceptance hill climbing which compares solutions with ones from fitness history.
+    #[serde(rename(deserialize = "late-acceptance"))]
+    #[serde(rename_all = "camelCase")]
+    LateAcceptance {
+        /// A fitness history length. Default is 100.
+        history_length: Option<usize>,
+    },
+}
+
 /// An initial solution configuration.
 #[derive(Clone, Deserialize, Debug)]
 pub struct InitialConfig {
@@ -151,6 +191,15 @@ pub enum HyperType {
 #[derive(Clone, Deserialize, Debug)]
 #[serde(tag = "type")]
 pub enum SearchOperatorType {
+    /// A crossover which exchanges routes with another solution from population.
+    #[serde(rename(deserialize = "crossover"))]
+    Crossover {
+        /// Probability of operator.
+        probability: OperatorProbabilityType,
+        /// Recreate methods used to insert jobs left without route.
+        recreates: Vec<RecreateMethod>,
+    },
+
     /// A metaheuristic which splits problem into smaller and solves them independently.
     #[serde(rename(deserialize = "decomposition"))]
     #[serde(rename_all = "camelCase")]
@@ -342,6 +391,12 @@ pub enum LocalOperatorType {
 
     #[serde(rename(deserialize = "sequence"))]
     Sequence { weight: usize },
+
+    #[serde(rename(deserialize = "two-opt-star"))]
+    TwoOptStar { weight: usize },
+
+    #[serde(rename(deserialize = "or-opt"))]
+    OrOpt { weight: usize },
 }
 
 #[derive(Clone, Deserialize, Debug)]
@@ -407,6 +462,10 @@ pub struct EnvironmentConfig {
 
     /// Specifies experimental behavior flag.
     pub is_experimental: Option<bool>,
+
+    /// Specifies random seed. When set, the solver runs parallel operations sequentially, ignores
+    /// parallelism settings and avoids time dependent decisions, so the same seed produces the same solution.
+    pub seed: Option<u64>,
 }
 
 /// Data parallelism configuration.
@@ -539,11 +598,51 @@ fn configure_from_evolution(
 
             builder = builder.with_context(RefinementContext::new(problem, population, telemetry_mode, environment));
         }
+
+        if let Some(strategy) = &config.strategy {
+            builder = match create_acceptance_criterion(strategy)? {
+                Some(criterion) => builder.with_acceptance(criterion),
+                _ => builder,
+            };
+        }
     }
 
     Ok(builder)
 }
 
+fn create_acceptance_criterion(strategy: &StrategyType) -> Result<Option<AcceptanceCriterion>, GenericError> {
+    let check_rate = |name: &str, rate: Float| {
+        if rate > 0. && rate <= 1. {
+            Ok(rate)
+        } else {
+            Err(format!("{name} must be in (0, 1] range. Specified: {rate}"))
+        }
+    };
+    let check_initial = |name: &str, value: Float| {
+        if value >= 0. { Ok(value) } else { Err(format!("{name} must be non-negative. Specified: {value}")) }
+    };
+
+    Ok(match strategy {
+        StrategyType::Iterative => None,
+        StrategyType::SimulatedAnnealing { initial_temperature, cooling_rate } => {
+            Some(AcceptanceCriterion::SimulatedAnnealing {
+                initial_temperature: check_initial("initial temperature", initial_temperature.unwrap_or(0.01))?,
+                cooling_rate: check_rate("cooling rate", cooling_rate.unwrap_or(0.999))?,
+            })
+        }
+        StrategyType::ThresholdAccepting { initial_threshold, decay_rate } => {
+            Some(AcceptanceCriterion::ThresholdAccepting {
+                initial_threshold: check_initial("initial threshold", initial_threshold.unwrap_or(0.01))?,
+                decay_rate: check_rate("decay rate", decay_rate.unwrap_or(0.999))?,
+            })
+        }
+        StrategyType::LateAcceptance { history_length } => match history_length.unwrap_or(100) {
+            0 => return Err("history length must be greater than 0".into()),
+            history_length => Some(AcceptanceCriterion::LateAcceptance { history_length }),
+        },
+    })
+}
+
 fn configure_from_hyper(
     mut builder: ProblemConfigBuilder,
     problem: Arc<Problem>,
@@ -657,6 +756,15 @@ fn create_operator(
                 create_operator_probability(probability, environment.random.clone()),
             )
         }
+        SearchOperatorType::Crossover { probability, recreates } => {
+            let recreate = Arc::new(WeightedRecreate::new(
+                recreates.iter().map(|r| create_recreate_method(r, environment.clone())).collect(),
+            ));
+            (
+                Arc::new(CrossoverSearch::new(recreate)),
+                create_operator_probability(probability, environment.random.clone()),
+            )
+        }
         SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
             let operator = create_local_search(times, inners, environment.random.clone());
             (Arc::new(LocalSearch::new(operator)), create_operator_probability(probability, environment.random.clone()))
@@ -756,6 +864,8 @@ fn create_local_search(
                 (Arc::new(ExchangeIntraRouteRandom::new(noise.probability, noise.min, noise.max)), *weight)
             }
             LocalOperatorType::Sequence { weight } => (Arc::new(ExchangeSequence::default()), *weight),
+            LocalOperatorType::TwoOptStar { weight } => (Arc::new(ExchangeTails::default()), *weight),
+            LocalOperatorType::OrOpt { weight } => (Arc::new(RelocateSegment::default()), *weight),
         })
         .collect::<Vec<_>>();
 
@@ -823,6 +933,13 @@ fn configure_from_environment(
         environment.is_experimental = is_experimental;
     }
 
+    if let Some(seed) = environment_config.as_ref().and_then(|c| c.seed) {
+        // NOTE thread pools run operations concurrently, so they are not used for repeatable runs
+        environment.random = Arc::new(DefaultRandom::new_with_seed(seed));
+        environment.parallelism = Parallelism::default();
+        environment.is_deterministic = true;
+    }
+
     Arc::new(environment)
 }
 
@@ -1,4 +1,5 @@
 //! Solve command helpers
 
+pub mod checkpoint;
 pub mod config;
 pub mod formats;
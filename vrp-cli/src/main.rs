@@ -15,10 +15,12 @@ fn main() {
 #[cfg(not(target_arch = "wasm32"))]
 mod cli {
     use super::commands::import::{get_import_app, run_import};
+    use super::commands::serve::{get_serve_app, run_serve};
     use super::commands::solve::{get_solve_app, run_solve};
     use crate::commands::analyze::{get_analyze_app, run_analyze};
     use crate::commands::check::{get_check_app, run_check};
     use crate::commands::create_write_buffer;
+    use crate::commands::feasibility::{get_feasibility_app, run_feasibility};
     use crate::commands::generate::{get_generate_app, run_generate};
     use clap::{ArgMatches, Command};
     use std::process;
@@ -36,7 +38,9 @@ mod cli {
             .subcommand(get_solve_app())
             .subcommand(get_import_app())
             .subcommand(get_check_app())
+            .subcommand(get_feasibility_app())
             .subcommand(get_generate_app())
+            .subcommand(get_serve_app())
     }
 
     pub fn run_subcommand(arg_matches: ArgMatches) {
@@ -45,7 +49,9 @@ mod cli {
             Some(("solve", solve_matches)) => run_solve(solve_matches, create_write_buffer),
             Some(("import", import_matches)) => run_import(import_matches),
             Some(("check", check_matches)) => run_check(check_matches),
+            Some(("feasibility", feasibility_matches)) => run_feasibility(feasibility_matches, create_write_buffer),
             Some(("generate", generate_matches)) => run_generate(generate_matches),
+            Some(("serve", serve_matches)) => run_serve(serve_matches, create_write_buffer),
             _ => {
                 eprintln!("no subcommand was used. Use -h to print help information.");
                 process::exit(1);
@@ -17,6 +17,11 @@ pub fn create_empty_job() -> Job {
         lifo_tag: None,
         fixed_order: None,
         max_ride_duration: None,
+        relative_max_ride_duration: None,
+        soft_max_ride_duration: None,
+        soft_time_windows: None,
+        requested_time_penalty: None,
+        dwell_time: None,
     }
 }
 
@@ -31,11 +36,12 @@ pub fn create_empty_job_place() -> JobPlace {
         times: None,
         tag: None,
         requested_time: None,
+        parking: None,
     }
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, replanning: None }
 }
 
 pub fn create_test_vehicle_type() -> VehicleType {
@@ -62,6 +68,7 @@ pub fn create_test_vehicle_type() -> VehicleType {
         skills: None,
         limits: None,
         lifo_tags: None,
+        driving_rules: None,
     }
 }
 
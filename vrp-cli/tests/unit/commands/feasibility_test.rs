@@ -0,0 +1,90 @@
+use super::*;
+use crate::cli::{get_app, run_subcommand};
+use std::fs;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+const PRAGMATIC_CANDIDATES_PATH: &str = "../examples/data/pragmatic/simple.basic.candidates.json";
+
+#[test]
+fn can_run_feasibility_with_accept() {
+    let out_result = tempfile::NamedTempFile::new().unwrap();
+    let out_solution = tempfile::NamedTempFile::new().unwrap();
+    let args = vec![
+        "vrp-cli",
+        "feasibility",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--candidates",
+        PRAGMATIC_CANDIDATES_PATH,
+        "--accept",
+        "--out-result",
+        out_result.path().to_str().unwrap(),
+        "--out-solution",
+        out_solution.path().to_str().unwrap(),
+    ];
+    let matches = get_app().try_get_matches_from(args).unwrap();
+
+    run_subcommand(matches);
+
+    assert_eq!(fs::read_to_string(out_result.path()).unwrap().lines().count(), 2);
+    assert!(fs::read_to_string(out_solution.path()).unwrap().contains("job4"));
+}
+
+#[test]
+fn can_detect_out_solution_without_accept() {
+    let args = vec![
+        "vrp-cli",
+        "feasibility",
+        "pragmatic",
+        PRAGMATIC_PROBLEM_PATH,
+        "--solution",
+        PRAGMATIC_SOLUTION_PATH,
+        "--matrix",
+        PRAGMATIC_MATRIX_PATH,
+        "--candidates",
+        PRAGMATIC_CANDIDATES_PATH,
+        "--out-solution",
+        "/some/path",
+    ];
+
+    assert!(get_app().try_get_matches_from(args).is_err());
+}
+
+#[test]
+fn can_get_reoptimization_options_with_max_pickup_shift() {
+    let get_options = |extra_args: Vec<&str>| {
+        let mut args = vec![
+            "vrp-cli",
+            "feasibility",
+            "pragmatic",
+            PRAGMATIC_PROBLEM_PATH,
+            "--solution",
+            PRAGMATIC_SOLUTION_PATH,
+            "--matrix",
+            PRAGMATIC_MATRIX_PATH,
+            "--candidates",
+            PRAGMATIC_CANDIDATES_PATH,
+            "--accept",
+            "--reoptimize",
+            "50",
+        ];
+        args.extend(extra_args);
+        let matches = get_app().try_get_matches_from(args).unwrap();
+        let (_, matches) = matches.subcommand().unwrap();
+
+        get_reoptimization_options(matches, REOPTIMIZE_ARG_NAME, MAX_PICKUP_SHIFT_ARG_NAME).unwrap().unwrap()
+    };
+
+    let options = get_options(vec![]);
+    assert_eq!(options.time_limit, 50);
+    assert_eq!(options.max_pickup_shift, 300.);
+
+    let options = get_options(vec!["--max-pickup-shift", "120"]);
+    assert_eq!(options.max_pickup_shift, 120.);
+}
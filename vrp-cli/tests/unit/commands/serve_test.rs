@@ -0,0 +1,27 @@
+use super::*;
+
+#[test]
+fn can_configure_serve_command() {
+    let args = vec![
+        "serve",
+        "pragmatic",
+        "problem.json",
+        "--solution",
+        "solution.json",
+        "--matrix",
+        "matrix.json",
+        "--locations",
+        "locations.json",
+        "--reoptimize",
+        "100",
+    ];
+
+    assert!(get_serve_app().try_get_matches_from(args).is_ok());
+}
+
+#[test]
+fn can_detect_missing_solution_in_serve_command() {
+    let args = vec!["serve", "pragmatic", "problem.json", "--matrix", "matrix.json"];
+
+    assert!(get_serve_app().try_get_matches_from(args).is_err());
+}
@@ -144,6 +144,76 @@ fn can_specify_parallelism() {
     }
 }
 
+#[test]
+fn can_specify_seed() {
+    for (params, result) in [
+        (vec!["--seed", "7"], Ok((true, 0))),
+        (vec!["--seed", "7", "--parallelism", "3,1"], Ok((true, 0))),
+        (vec!["--parallelism", "3,1"], Ok((false, 3))),
+        (vec!["--seed=-1"], Err(())),
+        (vec![], Ok((false, 0))),
+    ] {
+        let matches = get_solomon_matches(params.as_slice());
+
+        let settings =
+            get_environment(&matches).map(|e| (e.is_deterministic, e.parallelism.thread_pool_size())).map_err(|_| ());
+
+        assert_eq!(settings, result);
+    }
+}
+
+#[test]
+fn can_solve_problem_repeatably_with_seed() {
+    let solve = || {
+        let out_result = tempfile::NamedTempFile::new().unwrap();
+        let args = vec![
+            "vrp-cli",
+            "solve",
+            "solomon",
+            SOLOMON_PROBLEM_PATH,
+            "--seed",
+            "7",
+            "--max-generations",
+            "20",
+            "--out-result",
+            out_result.path().to_str().unwrap(),
+        ];
+        run_subcommand(get_app().try_get_matches_from(args).unwrap());
+
+        std::fs::read_to_string(out_result.path()).unwrap()
+    };
+
+    assert_eq!(solve(), solve());
+}
+
+#[test]
+fn can_resume_pragmatic_problem_from_checkpoint() {
+    let checkpoint = tempfile::NamedTempFile::new().unwrap();
+    let checkpoint_path = checkpoint.path().to_str().unwrap();
+    let solve = |params: &[&str]| {
+        let args = [&["solve", "pragmatic", PRAGMATIC_PROBLEM_PATH], params].concat();
+        run_solve_without_writer(&get_solve_app().try_get_matches_from(args).unwrap());
+    };
+
+    solve(&["--max-generations", "10", "--checkpoint", checkpoint_path, "--checkpoint-interval", "5"]);
+    solve(&["--max-generations", "20", "--resume", checkpoint_path, "--checkpoint", checkpoint_path]);
+
+    let checkpoint = std::fs::read_to_string(checkpoint.path()).unwrap();
+    assert!(checkpoint.starts_with(r#"{"generation":20,"#));
+}
+
+#[test]
+fn can_reject_checkpoint_for_non_pragmatic_format() {
+    let matches = get_solomon_matches(&["--checkpoint", "checkpoint.json"]);
+
+    let result = run_solve(&matches, |_| BufWriter::new(Box::new(DummyWrite {})));
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("checkpoint is not supported for 'solomon' format".into())
+    );
+}
+
 #[test]
 fn can_use_init_size() {
     for (params, result) in [
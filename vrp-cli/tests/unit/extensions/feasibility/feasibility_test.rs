@@ -0,0 +1,93 @@
+use super::*;
+use std::fs::File;
+use vrp_pragmatic::format::feasibility::ReoptimizationOptions;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+const PRAGMATIC_CANDIDATES_PATH: &str = "../examples/data/pragmatic/simple.basic.candidates.json";
+
+fn reader(path: &str) -> BufReader<File> {
+    BufReader::new(File::open(path).expect("cannot open test file"))
+}
+
+fn run_check(accept: bool) -> (Vec<serde_json::Value>, Option<String>) {
+    run_check_with_reoptimization(accept, None)
+}
+
+fn run_check_with_reoptimization(
+    accept: bool,
+    reoptimization: Option<ReoptimizationOptions>,
+) -> (Vec<serde_json::Value>, Option<String>) {
+    let mut writer = Vec::new();
+    let solution = check_pragmatic_candidates(
+        reader(PRAGMATIC_PROBLEM_PATH),
+        reader(PRAGMATIC_SOLUTION_PATH),
+        vec![reader(PRAGMATIC_MATRIX_PATH)],
+        reader(PRAGMATIC_CANDIDATES_PATH),
+        accept,
+        reoptimization,
+        &mut writer,
+    )
+    .expect("cannot check candidates");
+
+    let results = String::from_utf8(writer)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).expect("cannot parse result line"))
+        .collect();
+
+    (results, solution)
+}
+
+#[test]
+fn can_check_candidates_without_accept() {
+    let (results, solution) = run_check(false);
+
+    assert!(solution.is_none());
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["jobId"], "job4");
+    assert_eq!(results[0]["isFeasible"], true);
+    assert!(results[0].get("accepted").is_none());
+    assert_eq!(results[1]["jobId"], "job5");
+    assert_eq!(results[1]["isFeasible"], false);
+}
+
+#[test]
+fn can_accept_feasible_candidates() {
+    let (results, solution) = run_check(true);
+
+    assert_eq!(results[0]["accepted"]["vehicleId"], "vehicle_1");
+    assert!(results[1].get("accepted").is_none());
+    let solution = solution.expect("no updated solution");
+    assert!(solution.contains("job4"));
+    assert!(!solution.contains("job5"));
+}
+
+#[test]
+fn can_check_candidates_with_reoptimization() {
+    let (results, solution) = run_check_with_reoptimization(
+        true,
+        Some(ReoptimizationOptions { time_limit: 100, max_iterations: 10, ..ReoptimizationOptions::default() }),
+    );
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0]["accepted"]["vehicleId"], "vehicle_1");
+    let solution = solution.expect("no updated solution");
+    assert!(["job1", "job2", "job3", "job4"].iter().all(|job_id| solution.contains(job_id)));
+}
+
+#[test]
+fn can_detect_invalid_candidates_file() {
+    let result = check_pragmatic_candidates(
+        reader(PRAGMATIC_PROBLEM_PATH),
+        reader(PRAGMATIC_SOLUTION_PATH),
+        vec![reader(PRAGMATIC_MATRIX_PATH)],
+        reader(PRAGMATIC_MATRIX_PATH),
+        false,
+        None,
+        &mut Vec::new(),
+    );
+
+    assert!(result.unwrap_err().to_string().starts_with("cannot read candidate jobs"));
+}
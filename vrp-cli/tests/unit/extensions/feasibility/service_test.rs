@@ -0,0 +1,88 @@
+use super::*;
+use crate::extensions::feasibility::create_feasibility_context;
+use std::fs::File;
+use std::io::BufReader;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+const PRAGMATIC_MATRIX_PATH: &str = "../examples/data/pragmatic/simple.basic.matrix.json";
+const PRAGMATIC_SOLUTION_PATH: &str = "../examples/data/pragmatic/simple.basic.solution.json";
+
+const CANDIDATE_JOB: &str = r#"{"id":"job4","deliveries":[{"places":[{"location":{"lat":52.5165,"lng":13.3808},"duration":120.0}],"demand":[1]}]}"#;
+
+fn reader(path: &str) -> BufReader<File> {
+    BufReader::new(File::open(path).expect("cannot open test file"))
+}
+
+fn run_service(commands: &[String]) -> Vec<serde_json::Value> {
+    let ctx = create_feasibility_context(
+        reader(PRAGMATIC_PROBLEM_PATH),
+        reader(PRAGMATIC_SOLUTION_PATH),
+        vec![reader(PRAGMATIC_MATRIX_PATH)],
+        &[],
+    )
+    .expect("cannot create feasibility context");
+    let input = commands.join("\n");
+    let mut output = Vec::new();
+
+    run_insertion_service(ctx, input.as_bytes(), &mut output).expect("cannot run service");
+
+    let mut responses = String::from_utf8(output)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).expect("cannot parse response line"))
+        .collect::<Vec<_>>();
+    assert_eq!(responses.remove(0)["command"], "ready");
+
+    responses
+}
+
+fn get_tour_job_ids(snapshot: &serde_json::Value) -> Vec<String> {
+    snapshot["solution"]["tours"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .flat_map(|tour| tour["stops"].as_array().unwrap().iter())
+        .flat_map(|stop| stop["activities"].as_array().unwrap().iter())
+        .map(|activity| activity["jobId"].as_str().unwrap().to_string())
+        .collect()
+}
+
+#[test]
+fn can_process_commands_against_in_memory_state() {
+    let responses = run_service(&[
+        format!(r#"{{"command":"check","job":{CANDIDATE_JOB}}}"#),
+        format!(r#"{{"command":"accept","job":{CANDIDATE_JOB}}}"#),
+        r#"{"command":"snapshot"}"#.to_string(),
+        r#"{"command":"cancel","jobId":"job1"}"#.to_string(),
+        r#"{"command":"snapshot"}"#.to_string(),
+    ]);
+
+    assert_eq!(responses.len(), 5);
+    assert_eq!(responses[0]["command"], "check");
+    assert_eq!(responses[0]["result"]["isFeasible"], true);
+    assert_eq!(responses[1]["command"], "accept");
+    assert_eq!(responses[1]["result"]["vehicleId"], "vehicle_1");
+    assert!(get_tour_job_ids(&responses[2]).contains(&"job4".to_string()));
+    assert_eq!(responses[3]["command"], "cancel");
+    assert_eq!(responses[3]["result"]["jobId"], "job1");
+    assert_eq!(responses[3]["result"]["vehicleId"], "vehicle_1");
+    let job_ids = get_tour_job_ids(&responses[4]);
+    assert!(job_ids.contains(&"job4".to_string()));
+    assert!(!job_ids.contains(&"job1".to_string()));
+}
+
+#[test]
+fn can_keep_running_after_invalid_commands() {
+    let responses = run_service(&[
+        "not a json".to_string(),
+        "".to_string(),
+        r#"{"command":"cancel","jobId":"unknown"}"#.to_string(),
+        r#"{"command":"snapshot"}"#.to_string(),
+    ]);
+
+    assert_eq!(responses.len(), 3);
+    assert_eq!(responses[0]["command"], "error");
+    assert!(responses[0]["message"].as_str().unwrap().starts_with("cannot parse command"));
+    assert_eq!(responses[1]["command"], "error");
+    assert_eq!(responses[2]["command"], "snapshot");
+}
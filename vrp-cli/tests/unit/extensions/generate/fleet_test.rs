@@ -8,6 +8,7 @@ fn can_generate_fleet_of_specific_size() {
         plan: create_empty_plan(),
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
+            drivers: None,
             profiles: vec![MatrixProfile { name: "normal_car".to_string(), speed: None }],
             resources: None,
             capacity_dimensions: None,
@@ -0,0 +1,46 @@
+use super::*;
+use vrp_core::solver::{Solver, VrpConfigBuilder};
+use vrp_pragmatic::format::problem::PragmaticProblem;
+
+const PRAGMATIC_PROBLEM_PATH: &str = "../examples/data/pragmatic/simple.basic.problem.json";
+
+fn create_problem() -> Arc<Problem> {
+    let file = File::open(PRAGMATIC_PROBLEM_PATH).expect("cannot open problem file");
+
+    Arc::new(BufReader::new(file).read_pragmatic().expect("cannot read problem"))
+}
+
+#[test]
+fn can_write_and_read_checkpoint() {
+    let problem = create_problem();
+    let checkpoint_file = tempfile::NamedTempFile::new().unwrap();
+    let writer = create_checkpoint_writer(problem.clone(), checkpoint_file.path().to_path_buf());
+    let config = VrpConfigBuilder::new(problem.clone())
+        .prebuild()
+        .and_then(|builder| builder.with_max_generations(Some(10)).with_checkpoint(3, writer).build())
+        .expect("cannot build config");
+    Solver::new(problem.clone(), config).solve().expect("cannot solve problem");
+
+    let (checkpoint, solutions) = read_checkpoint(
+        BufReader::new(File::open(checkpoint_file.path()).unwrap()),
+        problem,
+        Arc::new(Environment::default()),
+    )
+    .expect("cannot read checkpoint");
+
+    assert_eq!(checkpoint.generation, 10);
+    assert!(!checkpoint.heuristic.is_empty());
+    assert!(!solutions.is_empty());
+    assert!(solutions.iter().all(|insertion_ctx| insertion_ctx.solution.required.is_empty()));
+}
+
+#[test]
+fn can_reject_checkpoint_without_solutions() {
+    let checkpoint =
+        r#"{"generation":10,"improvementAllRatio":0.1,"improvement1000Ratio":0.1,"heuristic":[],"solutions":[]}"#;
+
+    let result =
+        read_checkpoint(BufReader::new(checkpoint.as_bytes()), create_problem(), Arc::new(Environment::default()));
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some("checkpoint has no solutions".to_string()));
+}
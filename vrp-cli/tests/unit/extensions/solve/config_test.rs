@@ -49,12 +49,13 @@ fn can_read_full_config() {
         }
         _ => unreachable!(),
     }
+    assert!(matches!(evolution_config.strategy, Some(StrategyType::Iterative)));
 
     let hyper_config = config.hyper.expect("cannot get hyper");
     match hyper_config {
         HyperType::StaticSelective { operators } => {
             let operators = operators.expect("cannot get operators");
-            assert_eq!(operators.len(), 4);
+            assert_eq!(operators.len(), 5);
             match operators.first().unwrap() {
                 SearchOperatorType::Decomposition { routes, repeat, probability } => {
                     assert_eq!(*repeat, 4);
@@ -76,7 +77,9 @@ fn can_read_full_config() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.05);
                     assert_eq!(*times, MinMaxConfig { min: 1, max: 2 });
-                    assert_eq!(inners.len(), 4);
+                    assert_eq!(inners.len(), 6);
+                    assert!(matches!(inners[4], LocalOperatorType::TwoOptStar { weight: 50 }));
+                    assert!(matches!(inners[5], LocalOperatorType::OrOpt { weight: 50 }));
                 }
                 _ => unreachable!(),
             }
@@ -90,6 +93,14 @@ fn can_read_full_config() {
                 _ => unreachable!(),
             }
 
+            match operators.get(3).unwrap() {
+                SearchOperatorType::Crossover { probability, recreates } => {
+                    assert_eq!(as_scalar_probability(probability), 0.05);
+                    assert_eq!(recreates.len(), 2);
+                }
+                _ => unreachable!(),
+            }
+
             match operators.last().unwrap() {
                 SearchOperatorType::LocalSearch { probability, times, operators: inners } => {
                     assert_eq!(as_scalar_probability(probability), 0.01);
@@ -160,6 +171,41 @@ fn can_read_blinks_recreate_variants() {
     assert!(matches!(recreates[3], RecreateMethod::BlinksSampledTimeWindowStart { weight: 4 }));
 }
 
+#[test]
+fn can_configure_crossover_search() {
+    let json = r#"{
+      "evolution": {
+        "population": { "type": "elitism", "maxSize": 4 }
+      },
+      "hyper": {
+        "type": "static-selective",
+        "operators": [
+          {
+            "type": "crossover",
+            "probability": { "scalar": 0.5 },
+            "recreates": [{ "type": "cheapest", "weight": 1 }]
+          },
+          {
+            "type": "ruin-recreate",
+            "probability": { "scalar": 1.0 },
+            "ruins": [{ "methods": [{ "type": "random-job", "probability": 1.0, "min": 1, "max": 3 }], "weight": 1 }],
+            "recreates": [{ "type": "cheapest", "weight": 1 }]
+          }
+        ]
+      },
+      "termination": { "maxGenerations": 50 }
+    }"#;
+    let config = read_config(BufReader::new(json.as_bytes())).unwrap();
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert!(solution.unassigned.is_empty());
+}
+
 #[test]
 fn can_disable_infeasible_dynamic_diversification() {
     let json = r#"{
@@ -207,6 +253,75 @@ fn can_configure_telemetry_metrics() {
     assert_eq!(metrics.evolution.len(), 10 + 1);
 }
 
+#[test]
+fn can_configure_seed() {
+    let config = Config {
+        evolution: None,
+        hyper: None,
+        termination: Some(TerminationConfig { max_time: None, max_generations: Some(50), variation: None }),
+        environment: Some(EnvironmentConfig { parallelism: None, logging: None, is_experimental: None, seed: Some(7) }),
+        telemetry: None,
+        output: None,
+    };
+    let solve = || {
+        let problem = create_example_problem();
+        let builder = create_builder_from_config(problem.clone(), Vec::default(), &config).unwrap();
+        let solution = builder.build().map(|evolution_config| Solver::new(problem, evolution_config)).unwrap();
+        let solution = solution.solve().unwrap();
+
+        let routes = solution
+            .routes
+            .iter()
+            .map(|route| route.tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        (solution.cost, routes)
+    };
+
+    assert!(configure_from_environment(&config.environment, None).is_deterministic);
+    assert_eq!(solve(), solve());
+}
+
+#[test]
+fn can_configure_acceptance_strategy() {
+    let json = r#"{
+      "evolution": {
+        "strategy": { "type": "late-acceptance", "historyLength": 10 }
+      },
+      "termination": { "maxGenerations": 50 }
+    }"#;
+    let config = read_config(BufReader::new(json.as_bytes())).unwrap();
+
+    let solution = create_builder_from_config(create_example_problem(), Vec::default(), &config)
+        .and_then(|config_builder| config_builder.build())
+        .map(|evolution_config| Solver::new(create_example_problem(), evolution_config))
+        .and_then(|solver| solver.solve())
+        .unwrap();
+
+    assert!(matches!(
+        config.evolution.and_then(|evolution| evolution.strategy),
+        Some(StrategyType::LateAcceptance { history_length: Some(10) })
+    ));
+    assert!(!solution.routes.is_empty());
+}
+
+#[test]
+fn can_reject_invalid_acceptance_strategy() {
+    let json = r#"{
+      "evolution": {
+        "strategy": { "type": "simulated-annealing", "coolingRate": 1.5 }
+      }
+    }"#;
+    let config = read_config(BufReader::new(json.as_bytes())).unwrap();
+
+    let result = create_builder_from_config(create_example_problem(), Vec::default(), &config);
+
+    assert_eq!(
+        result.err().map(|err| err.to_string()),
+        Some("cooling rate must be in (0, 1] range. Specified: 1.5".to_string())
+    );
+}
+
 fn as_scalar_probability(probability: &OperatorProbabilityType) -> Float {
     match probability {
         OperatorProbabilityType::Scalar { scalar } => *scalar,
@@ -9,6 +9,7 @@ fn can_get_locations_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 1.), create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
+            drivers: None,
             profiles: vec![],
             resources: None,
             capacity_dimensions: None,
@@ -27,6 +28,7 @@ fn can_get_solution_serialized() {
         plan: Plan { jobs: vec![create_test_job(1., 0.)], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![create_test_vehicle_type()],
+            drivers: None,
             profiles: vec![MatrixProfile { name: "car".to_string(), speed: None }],
             resources: None,
             capacity_dimensions: None,
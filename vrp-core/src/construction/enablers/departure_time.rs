@@ -4,9 +4,14 @@ mod departure_time_test;
 
 use crate::construction::enablers::*;
 use crate::construction::heuristics::RouteContext;
-use crate::models::common::Timestamp;
+use crate::models::common::{Duration, Timestamp};
 use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
 use rosomaxa::prelude::Float;
+use std::iter::once;
+use std::ops::ControlFlow;
+
+/// Specifies amount of iterations used to find the latest feasible departure time.
+const DEPARTURE_SEARCH_ITERATIONS: usize = 16;
 
 /// Tries to move forward route's departure time.
 pub fn advance_departure_time(
@@ -15,7 +20,7 @@ pub fn advance_departure_time(
     transport: &dyn TransportCost,
     consider_whole_tour: bool,
 ) {
-    if let Some(new_departure_time) = try_advance_departure_time(route_ctx, transport, consider_whole_tour) {
+    if let Some(new_departure_time) = try_advance_departure_time(route_ctx, activity, transport, consider_whole_tour) {
         update_route_departure(route_ctx, activity, transport, new_departure_time);
     }
 }
@@ -29,6 +34,7 @@ pub fn recede_departure_time(route_ctx: &mut RouteContext, activity: &dyn Activi
 
 fn try_advance_departure_time(
     route_ctx: &RouteContext,
+    activity: &dyn ActivityCost,
     transport: &dyn TransportCost,
     optimize_whole_tour: bool,
 ) -> Option<Timestamp> {
@@ -49,14 +55,17 @@ fn try_advance_departure_time(
                 (total_waiting_time + waiting_time, waiting_time + remaining_time.min(max_shift))
             });
         let departure_shift = total_waiting_time.min(max_shift);
+        let new_departure_time = (start.schedule.departure + departure_shift).min(latest_allowed_departure);
 
-        (start.schedule.departure + departure_shift).min(latest_allowed_departure)
+        optimize_time_dependent_departure(route_ctx, activity, transport, last_departure_time, new_departure_time)
     } else {
+        // NOTE FIFO property of travel times guarantees that this is the latest departure time
+        // which still allows to arrive at the start of the first activity's time window
         let start_to_first = transport.duration(
             route,
             start.place.location,
             first.place.location,
-            TravelTime::Departure(last_departure_time),
+            TravelTime::Arrival(first.place.time.start),
         );
 
         #[allow(clippy::manual_clamp)]
@@ -66,6 +75,95 @@ fn try_advance_departure_time(
     if new_departure_time > last_departure_time { Some(new_departure_time) } else { None }
 }
 
+/// Finds departure time within given range which minimizes total tour duration when travel times are
+/// time-dependent. Relies on FIFO property: if a departure time is feasible, any earlier one is feasible too.
+fn optimize_time_dependent_departure(
+    route_ctx: &RouteContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    earliest: Timestamp,
+    latest: Timestamp,
+) -> Timestamp {
+    let breakpoints = transport.time_breakpoints(&route_ctx.route().actor.vehicle.profile);
+    if breakpoints.is_empty() || latest <= earliest {
+        return latest;
+    }
+
+    let get_duration = |departure| get_tour_duration(route_ctx, activity, transport, departure);
+
+    let latest = if get_duration(latest).is_some() {
+        latest
+    } else {
+        (0..DEPARTURE_SEARCH_ITERATIONS)
+            .fold((earliest, latest), |(left, right), _| {
+                let middle = (left + right) / 2.;
+                if get_duration(middle).is_some() { (middle, right) } else { (left, middle) }
+            })
+            .0
+    };
+
+    // NOTE travel duration functions are piecewise-linear, so check their breakpoints within the range
+    // together with the departure which arrives exactly at the start of the first activity's time window
+    let route = route_ctx.route();
+    let first_departure = route.tour.start().zip(route.tour.get(1)).map(|(start, first)| {
+        let arrival = first.place.time.start;
+        arrival - transport.duration(route, start.place.location, first.place.location, TravelTime::Arrival(arrival))
+    });
+
+    let mut candidates = breakpoints
+        .iter()
+        .copied()
+        .chain(first_departure)
+        .filter(|&departure| departure > earliest && departure < latest)
+        .chain(once(latest))
+        .collect::<Vec<_>>();
+    candidates.sort_by(|a, b| a.total_cmp(b));
+
+    candidates
+        .into_iter()
+        .filter_map(|departure| get_duration(departure).map(|duration| (departure, duration)))
+        .fold((earliest, get_duration(earliest).unwrap_or(Float::MAX)), |best, candidate| {
+            if candidate.1 <= best.1 { candidate } else { best }
+        })
+        .0
+}
+
+/// Simulates tour schedule with given departure time and returns its total duration if it is feasible.
+fn get_tour_duration(
+    route_ctx: &RouteContext,
+    activity: &dyn ActivityCost,
+    transport: &dyn TransportCost,
+    departure: Timestamp,
+) -> Option<Duration> {
+    let route = route_ctx.route();
+    let start = route.tour.start()?;
+
+    let (_, end) =
+        route.tour.all_activities().skip(1).try_fold((start, departure), |(prev, prev_departure), act| {
+            let arrival = prev_departure
+                + transport.duration(
+                    route,
+                    prev.place.location,
+                    act.place.location,
+                    TravelTime::Departure(prev_departure),
+                );
+
+            if arrival > act.place.time.end {
+                return None;
+            }
+
+            match activity.estimate_departure_after(route, prev, act, arrival) {
+                ControlFlow::Continue(departure) => Some((act, departure)),
+                ControlFlow::Break(_) => None,
+            }
+        })?;
+
+    let duration = end - departure;
+    let limit = route_ctx.state().get_limit_duration().copied().unwrap_or(Float::MAX);
+
+    (duration <= limit).then_some(duration)
+}
+
 fn try_recede_departure_time(route_ctx: &RouteContext) -> Option<Timestamp> {
     let first = route_ctx.route().tour.get(1)?;
     let start = route_ctx.route().tour.start()?;
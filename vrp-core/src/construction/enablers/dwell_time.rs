@@ -0,0 +1,167 @@
+//! Provides a way to model service (dwell) time which depends on job demand and is shared between
+//! consecutive activities at the same stop.
+//!
+//! # Semantics
+//! - A job specifies its dwell time model via `JobDwellTime` dimension: a fixed setup time per stop
+//!   plus per-unit time for each demand dimension.
+//! - The full dwell time is expected to be a part of job place duration, see [DwellTime::get_duration].
+//! - When an activity follows another activity with dwell time at the same location, the setup is
+//!   charged only once: departure time is reduced by the setup time shared with the previous activity.
+//! - A job place can also specify parking time via `JobParking` dimension which is a part of place duration.
+//!   Parking is done on arrival before waiting for time window start and is charged only once for consecutive
+//!   activities at the same location in the same way as setup. As parking is done before service start,
+//!   arrival plus charged parking time has to be within the time window.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/enablers/dwell_time_test.rs"]
+mod dwell_time_test;
+
+use crate::models::common::*;
+use crate::models::problem::ActivityCost;
+use crate::models::solution::{Activity, Route};
+use rosomaxa::prelude::Float;
+use std::ops::ControlFlow;
+use std::sync::Arc;
+
+/// Specifies a dwell time model of the job.
+#[derive(Clone, Debug, Default)]
+pub struct DwellTime {
+    /// A fixed setup time charged once per stop.
+    pub setup: Duration,
+    /// A time per unit of demand for each demand dimension.
+    pub per_unit: Vec<Duration>,
+}
+
+impl DwellTime {
+    /// Returns total dwell time for given demand: setup time plus per-unit time of each demand dimension.
+    pub fn get_duration(&self, demand: &[i32]) -> Duration {
+        self.setup
+            + self
+                .per_unit
+                .iter()
+                .zip(demand.iter())
+                .map(|(&per_unit, &value)| per_unit * value.unsigned_abs() as Float)
+                .sum::<Duration>()
+    }
+}
+
+custom_dimension!(pub JobDwellTime typeof DwellTime);
+// NOTE parking times are indexed by job place index
+custom_dimension!(pub JobParking typeof Vec<Duration>);
+
+/// Returns setup time of the activity which is already charged by the previous activity at the same stop.
+pub fn get_shared_setup(prev: &Activity, activity: &Activity) -> Duration {
+    if prev.place.location != activity.place.location {
+        return Duration::default();
+    }
+
+    let get_setup = |activity: &Activity| {
+        activity.job.as_ref().and_then(|single| single.dimens.get_job_dwell_time()).map(|dwell_time| dwell_time.setup)
+    };
+
+    get_setup(prev).zip(get_setup(activity)).map_or(Duration::default(), |(prev, setup)| prev.min(setup))
+}
+
+/// Returns parking time of the activity's job place.
+pub fn get_parking(activity: &Activity) -> Duration {
+    activity
+        .job
+        .as_ref()
+        .and_then(|single| single.dimens.get_job_parking())
+        .and_then(|parking| parking.get(activity.place.idx))
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Returns parking time of the activity which is already charged by the previous activity at the same stop.
+pub fn get_shared_parking(prev: &Activity, activity: &Activity) -> Duration {
+    if prev.place.location != activity.place.location {
+        return Duration::default();
+    }
+
+    get_parking(prev).min(get_parking(activity))
+}
+
+/// Returns parking time of the activity which is not charged yet by the previous activity at the same stop.
+pub fn get_charged_parking(prev: &Activity, activity: &Activity) -> Duration {
+    get_parking(activity) - get_shared_parking(prev, activity)
+}
+
+/// Provides way to calculate activity costs which charges dwell setup and parking time once per stop.
+pub struct DwellActivityCost {
+    inner: Arc<dyn ActivityCost>,
+}
+
+impl DwellActivityCost {
+    /// Creates a new instance of `DwellActivityCost` which decorates given activity cost.
+    pub fn new(inner: Arc<dyn ActivityCost>) -> Self {
+        Self { inner }
+    }
+}
+
+impl ActivityCost for DwellActivityCost {
+    fn cost(&self, route: &Route, activity: &Activity, arrival: Timestamp) -> Cost {
+        self.inner.cost(route, activity, arrival)
+    }
+
+    fn estimate_departure(
+        &self,
+        route: &Route,
+        activity: &Activity,
+        arrival: Timestamp,
+    ) -> ControlFlow<Timestamp, Timestamp> {
+        self.inner.estimate_departure(route, activity, arrival)
+    }
+
+    fn estimate_arrival(
+        &self,
+        route: &Route,
+        activity: &Activity,
+        departure: Timestamp,
+    ) -> ControlFlow<Timestamp, Timestamp> {
+        // NOTE without previous activity nothing is shared, so the whole parking time is charged
+        let parking = get_parking(activity);
+
+        shift_arrival(self.inner.estimate_arrival(route, activity, departure + parking), parking)
+    }
+
+    fn estimate_departure_after(
+        &self,
+        route: &Route,
+        prev: &Activity,
+        activity: &Activity,
+        arrival: Timestamp,
+    ) -> ControlFlow<Timestamp, Timestamp> {
+        let charged_parking = get_charged_parking(prev, activity);
+        let correction = get_parking(activity) + get_shared_setup(prev, activity);
+
+        // NOTE parking is done before waiting, so it shifts arrival instead of being a part of service
+        match self.inner.estimate_departure_after(route, prev, activity, arrival + charged_parking) {
+            ControlFlow::Continue(departure) => ControlFlow::Continue(departure - correction),
+            ControlFlow::Break(departure) => ControlFlow::Break(departure - correction),
+        }
+    }
+
+    fn estimate_arrival_after(
+        &self,
+        route: &Route,
+        prev: &Activity,
+        activity: &Activity,
+        departure: Timestamp,
+    ) -> ControlFlow<Timestamp, Timestamp> {
+        let charged_parking = get_charged_parking(prev, activity);
+        let correction = get_parking(activity) + get_shared_setup(prev, activity);
+
+        // NOTE arrival plus charged parking has to be within time window, so the latest arrival is shifted back
+        let arrival = self.inner.estimate_arrival_after(route, prev, activity, departure + correction);
+
+        shift_arrival(arrival, charged_parking)
+    }
+}
+
+fn shift_arrival(arrival: ControlFlow<Timestamp, Timestamp>, parking: Duration) -> ControlFlow<Timestamp, Timestamp> {
+    match arrival {
+        ControlFlow::Continue(arrival) => ControlFlow::Continue(arrival - parking),
+        ControlFlow::Break(arrival) => ControlFlow::Break(arrival - parking),
+    }
+}
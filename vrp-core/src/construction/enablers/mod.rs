@@ -7,6 +7,9 @@ pub use self::conditional_job::*;
 mod departure_time;
 pub use self::departure_time::*;
 
+mod dwell_time;
+pub use self::dwell_time::*;
+
 mod feature_combinator;
 pub use self::feature_combinator::*;
 
@@ -148,6 +148,10 @@ impl TransportCost for DynamicTransportCost {
         self.inner.distance(route, from, to, travel_time)
     }
 
+    fn time_breakpoints(&self, profile: &Profile) -> &[Timestamp] {
+        self.inner.time_breakpoints(profile)
+    }
+
     fn size(&self) -> usize {
         self.inner.size()
     }
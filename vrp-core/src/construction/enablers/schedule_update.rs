@@ -1,12 +1,15 @@
+use crate::construction::enablers::get_charged_parking;
 use crate::construction::heuristics::{RouteContext, RouteState};
 use crate::models::OP_START_MSG;
 use crate::models::common::{Distance, Duration, Schedule, Timestamp};
-use crate::models::problem::{ActivityCost, TransportCost, TravelTime};
+use crate::models::problem::{ActivityCost, Job, TransportCost, TravelTime};
 use rosomaxa::prelude::Float;
 use rosomaxa::utils::UnwrapValue;
 
 custom_activity_state!(pub(crate) LatestArrival typeof Timestamp);
 custom_activity_state!(pub(crate) WaitingTime typeof Timestamp);
+// NOTE queuing duration is set by features which make vehicle wait for a shared resource before service
+custom_activity_state!(pub(crate) QueuingDuration typeof Duration);
 custom_tour_state!(pub TotalDistance typeof Distance);
 custom_tour_state!(pub TotalDuration typeof Duration);
 custom_tour_state!(pub(crate) LimitDuration typeof Duration);
@@ -18,6 +21,36 @@ pub fn update_route_schedule(route_ctx: &mut RouteContext, activity: &dyn Activi
     update_statistics(route_ctx, transport);
 }
 
+/// Keeps queuing durations with their activities after the job is inserted into the route.
+/// These durations are set by other features using activity indices, so they are shifted by adding
+/// zero durations for the activities of the inserted job. Then the schedule is not updated using
+/// durations of other activities till the features recalculate them.
+pub(crate) fn shift_queuing_durations(route_ctx: &mut RouteContext, job: &Job) {
+    let (route, state) = route_ctx.as_mut();
+    let total = route.tour.total();
+    let inserted = route
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter(|(_, activity)| activity.has_same_job(job))
+        .map(|(activity_idx, _)| activity_idx)
+        .collect::<Vec<_>>();
+
+    let durations = state
+        .get_activity_states::<QueuingDurationActivityStateKey, Duration>()
+        .filter(|durations| durations.len() + inserted.len() == total)
+        .map(|durations| {
+            inserted.iter().fold(durations.clone(), |mut acc, &activity_idx| {
+                acc.insert(activity_idx, Duration::default());
+                acc
+            })
+        });
+
+    if let Some(durations) = durations {
+        state.set_queuing_duration_states(durations);
+    }
+}
+
 /// Updates route departure to the new one.
 pub fn update_route_departure(
     route_ctx: &mut RouteContext,
@@ -39,10 +72,12 @@ fn update_schedules(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, t
 
     (1..route_ctx.route().tour.total()).fold(init, |(loc, dep), activity_idx| {
         let (location, arrival, departure) = {
+            let prev = route_ctx.route().tour.get(activity_idx - 1).unwrap();
             let a = route_ctx.route().tour.get(activity_idx).unwrap();
             let location = a.place.location;
             let arrival = dep + transport.duration(route_ctx.route(), loc, location, TravelTime::Departure(dep));
-            let departure = activity.estimate_departure(route_ctx.route(), a, arrival).unwrap_value();
+            let departure = activity.estimate_departure_after(route_ctx.route(), prev, a, arrival).unwrap_value()
+                + get_queuing_duration(route_ctx.state(), activity_idx);
 
             (location, arrival, departure)
         };
@@ -71,7 +106,10 @@ fn update_states(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, tran
     let mut latest_arrivals = Vec::with_capacity(route.tour.total());
     let mut waiting_times = Vec::with_capacity(route.tour.total());
 
-    route.tour.all_activities().rev().fold(init, |acc, act| {
+    let state = route_ctx.state();
+    let last_idx = route.tour.total() - 1;
+
+    route.tour.all_activities().rev().enumerate().fold(init, |acc, (rev_idx, act)| {
         if act.job.is_none() {
             latest_arrivals.push(Default::default());
             waiting_times.push(Default::default());
@@ -79,12 +117,15 @@ fn update_states(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, tran
         }
 
         let (end_time, prev_loc, waiting) = acc;
+        let activity_idx = last_idx - rev_idx;
+        let prev = route.tour.get(activity_idx - 1).expect("job activity without previous activity");
         let latest_arrival_time = if end_time == Float::MAX {
-            act.place.time.end
+            act.place.time.end - get_charged_parking(prev, act)
         } else {
-            let latest_departure =
-                end_time - transport.duration(route, act.place.location, prev_loc, TravelTime::Arrival(end_time));
-            activity.estimate_arrival(route, act, latest_departure).unwrap_value()
+            let latest_departure = end_time
+                - transport.duration(route, act.place.location, prev_loc, TravelTime::Arrival(end_time))
+                - get_queuing_duration(state, activity_idx);
+            activity.estimate_arrival_after(route, prev, act, latest_departure).unwrap_value()
         };
         let future_waiting = waiting + (act.place.time.start - act.schedule.arrival).max(0.);
 
@@ -107,6 +148,10 @@ fn update_states(route_ctx: &mut RouteContext, activity: &dyn ActivityCost, tran
     route_ctx.state_mut().set_waiting_time_states(waiting_times);
 }
 
+fn get_queuing_duration(state: &RouteState, activity_idx: usize) -> Duration {
+    state.get_queuing_duration_at(activity_idx).copied().unwrap_or_default()
+}
+
 fn update_statistics(route_ctx: &mut RouteContext, transport: &dyn TransportCost) {
     let (route, state) = route_ctx.as_mut();
 
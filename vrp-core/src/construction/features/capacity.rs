@@ -10,7 +10,7 @@ use crate::models::solution::Activity;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
-custom_activity_state!(pub(crate) CurrentCapacity typeof T: LoadOps);
+custom_activity_state!(pub CurrentCapacity typeof T: LoadOps);
 
 custom_activity_state!(pub(crate) MaxFutureCapacity typeof T: LoadOps);
 
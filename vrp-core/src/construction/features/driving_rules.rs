@@ -0,0 +1,605 @@
+//! A feature to model statutory driving time rules (e.g. EU 561/2006 style): cumulative driving time
+//! has to be interrupted by a rest which can be split into two parts, and daily driving time is limited.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/driving_rules_test.rs"]
+mod driving_rules_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::solution::{Activity, Route};
+use std::collections::HashSet;
+use std::iter::once;
+use std::sync::Arc;
+
+/// A duration of a day used to group tours for daily driving time limit.
+const DAY_DURATION: Duration = 86400.;
+
+/// Specifies statutory driving time rules.
+#[derive(Clone, Copy, Debug)]
+pub struct DrivingRules {
+    /// Maximum cumulative driving time before a rest has to be taken.
+    pub max_driving_time: Duration,
+
+    /// Minimum rest duration which resets cumulative driving time.
+    pub rest_duration: Duration,
+
+    /// Minimum durations of the first and the second rest parts: when both are taken in that order,
+    /// cumulative driving time is reset.
+    pub split_rest: Option<(Duration, Duration)>,
+
+    /// Maximum total driving time within a day. It is applied to all tours of the same driver or,
+    /// if driver has no shifts, to all tours of the same vehicle.
+    pub max_daily_driving_time: Option<Duration>,
+}
+
+impl DrivingRules {
+    /// Checks whether rest resets cumulative driving time. Returns `None` if it does, otherwise
+    /// returns a flag whether the first part of split rest is taken.
+    fn take_rest(&self, is_split: bool, rest: Duration) -> Option<bool> {
+        match self.split_rest {
+            _ if rest >= self.rest_duration => None,
+            Some((_, second)) if is_split && rest >= second => None,
+            Some((first, _)) if rest >= first => Some(true),
+            _ => Some(is_split),
+        }
+    }
+}
+
+/// Provides a way to build the driving rules feature. Rest jobs are used as markers which are inserted
+/// by the solver when cumulative driving time exceeds the limit.
+pub struct DrivingRulesFeatureBuilder {
+    name: String,
+    violation_code: Option<ViolationCode>,
+    transport: Option<Arc<dyn TransportCost>>,
+    belongs_to_route_fn: Option<BelongsToRouteFn>,
+    is_rest_single_fn: Option<RestSingleFn>,
+    is_break_single_fn: Option<RestSingleFn>,
+    driving_rules_fn: Option<DrivingRulesFn>,
+}
+
+impl DrivingRulesFeatureBuilder {
+    /// Creates a new instance of `DrivingRulesFeatureBuilder`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            violation_code: None,
+            transport: None,
+            belongs_to_route_fn: None,
+            is_rest_single_fn: None,
+            is_break_single_fn: None,
+            driving_rules_fn: None,
+        }
+    }
+
+    /// Sets constraint violation code which is used to report back the reason of job's unassignment.
+    pub fn set_violation_code(mut self, violation_code: ViolationCode) -> Self {
+        self.violation_code = Some(violation_code);
+        self
+    }
+
+    /// Sets transport costs to estimate driving time.
+    pub fn set_transport(mut self, transport: Arc<dyn TransportCost>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets a function which specifies whether a given single job can be considered as a rest job.
+    pub fn set_is_rest_single<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> bool + Send + Sync + 'static,
+    {
+        self.is_rest_single_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function which specifies whether a given single job is a break which duration is also
+    /// considered as rest. If not set, only rest jobs are considered.
+    pub fn set_is_break_single<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> bool + Send + Sync + 'static,
+    {
+        self.is_break_single_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function which specifies whether a given route can serve a given job. This function
+    /// should return false, if the job is not rest.
+    pub fn set_belongs_to_route<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Route, &Job) -> bool + Send + Sync + 'static,
+    {
+        self.belongs_to_route_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Specifies driving rules function. It should return the same rules for the same actor all the time.
+    pub fn set_driving_rules<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor) -> Option<DrivingRules> + Send + Sync + 'static,
+    {
+        self.driving_rules_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Builds the driving rules feature if all dependencies are set.
+    pub fn build(&mut self) -> GenericResult<Feature> {
+        let is_rest_single_fn =
+            self.is_rest_single_fn.take().ok_or_else(|| GenericError::from("is_rest_single must be set"))?;
+        let is_break_single_fn = self.is_break_single_fn.take().unwrap_or_else(|| Arc::new(|_| false));
+        let is_assignable_fn =
+            self.belongs_to_route_fn.take().ok_or_else(|| GenericError::from("belongs_to_route must be set"))?;
+        let transport = self.transport.take().ok_or_else(|| GenericError::from("transport must be set"))?;
+        let driving_rules_fn =
+            self.driving_rules_fn.take().ok_or_else(|| GenericError::from("driving_rules must be set"))?;
+
+        let code = self.violation_code.unwrap_or_default();
+
+        let driving = DrivingTime { transport, is_rest_single_fn, is_break_single_fn, driving_rules_fn };
+        let daily_driving = DailyDrivingState { driving: driving.clone(), code };
+
+        let feature = create_multi_trip_feature(
+            self.name.as_str(),
+            code,
+            MarkerInsertionPolicy::Any,
+            Arc::new(DrivingRulesMultiTrip {
+                route_intervals: RouteIntervals::Multiple {
+                    is_marker_single_fn: driving.is_rest_single_fn.clone(),
+                    is_new_interval_needed_fn: Arc::new({
+                        let driving = driving.clone();
+                        move |route_ctx| {
+                            driving.get_rules(route_ctx.route()).is_some_and(|rules| {
+                                (0..route_ctx.route().tour.total()).any(|idx| {
+                                    let segment_time =
+                                        route_ctx.state().get_driving_segment_time_at(idx).copied().unwrap_or_default();
+                                    segment_time > rules.max_driving_time
+                                })
+                            })
+                        }
+                    }),
+                    is_obsolete_interval_fn: Arc::new({
+                        let driving = driving.clone();
+                        move |route_ctx, _, right| {
+                            let route = route_ctx.route();
+                            driving.get_rules(route).is_some_and(|rules| {
+                                let stops = route
+                                    .tour
+                                    .all_activities()
+                                    .enumerate()
+                                    .filter(|(idx, _)| *idx != right.start)
+                                    .map(|(_, activity)| driving.get_stop(activity));
+
+                                driving.get_max_driving(route, &rules, stops) <= rules.max_driving_time
+                            })
+                        }
+                    }),
+                    is_assignable_fn,
+                    intervals_state: Arc::new(DrivingRestIntervalsState),
+                },
+                driving,
+                code,
+            }),
+        )?;
+
+        Ok(Feature {
+            state: feature
+                .state
+                .map(|inner| Arc::new(DrivingRulesState { inner, daily_driving }) as Arc<dyn FeatureState>),
+            ..feature
+        })
+    }
+}
+
+type BelongsToRouteFn = Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>;
+type RestSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+type DrivingRulesFn = Arc<dyn Fn(&Actor) -> Option<DrivingRules> + Send + Sync>;
+
+/// A route stop used to calculate driving time: location, departure time and optional rest duration.
+type DrivingStop = (Location, Timestamp, Option<Duration>);
+
+custom_route_intervals_state!(DrivingRestIntervals);
+custom_activity_state!(DrivingTime typeof Duration);
+custom_activity_state!(DrivingSegmentTime typeof Duration);
+custom_tour_state!(TotalDrivingTime typeof Duration);
+
+/// Calculates driving time along the route.
+#[derive(Clone)]
+struct DrivingTime {
+    transport: Arc<dyn TransportCost>,
+    is_rest_single_fn: RestSingleFn,
+    is_break_single_fn: RestSingleFn,
+    driving_rules_fn: DrivingRulesFn,
+}
+
+impl DrivingTime {
+    fn get_rules(&self, route: &Route) -> Option<DrivingRules> {
+        (self.driving_rules_fn)(route.actor.as_ref())
+    }
+
+    fn is_rest(&self, single: &Single) -> bool {
+        (self.is_rest_single_fn)(single) || (self.is_break_single_fn)(single)
+    }
+
+    fn get_stop(&self, activity: &Activity) -> DrivingStop {
+        let rest = activity.job.as_ref().filter(|single| self.is_rest(single)).map(|_| activity.place.duration);
+
+        (activity.place.location, activity.schedule.departure, rest)
+    }
+
+    fn get_leg_duration(&self, route: &Route, from: Location, to: Location, departure: Timestamp) -> Duration {
+        self.transport.duration(route, from, to, TravelTime::Departure(departure))
+    }
+
+    /// Returns driving time counters for each stop together with total driving time.
+    fn get_counters(
+        &self,
+        route: &Route,
+        rules: &DrivingRules,
+        stops: impl Iterator<Item = DrivingStop>,
+    ) -> (Vec<DrivingCounter>, Duration) {
+        let (counters, _, _, total) = stops.fold(
+            (Vec::new(), None::<(Location, Timestamp)>, (Duration::default(), false), Duration::default()),
+            |(mut counters, prev, (driving, is_split), total), (location, departure, rest)| {
+                let leg = prev.map_or(Duration::default(), |(prev_location, prev_departure)| {
+                    self.get_leg_duration(route, prev_location, location, prev_departure)
+                });
+
+                let arrival = driving + leg;
+                let (counter, is_split) = match rest.map_or(Some(is_split), |rest| rules.take_rest(is_split, rest)) {
+                    Some(is_split) => (DrivingCounter { arrival, departure: arrival, is_reset: false }, is_split),
+                    None => (DrivingCounter { arrival, departure: Duration::default(), is_reset: true }, false),
+                };
+
+                counters.push(counter);
+
+                (counters, Some((location, departure)), (counter.departure, is_split), total + leg)
+            },
+        );
+
+        (counters, total)
+    }
+
+    /// Returns maximum cumulative driving time for given route stops.
+    fn get_max_driving(
+        &self,
+        route: &Route,
+        rules: &DrivingRules,
+        stops: impl Iterator<Item = DrivingStop>,
+    ) -> Duration {
+        let (counters, _) = self.get_counters(route, rules, stops);
+
+        get_max_arrival(counters.as_slice())
+    }
+}
+
+/// Keeps driving time since the last rest for a route stop.
+#[derive(Clone, Copy)]
+struct DrivingCounter {
+    /// Driving time on arrival.
+    arrival: Duration,
+    /// Driving time on departure.
+    departure: Duration,
+    /// Whether rest at the stop resets driving time.
+    is_reset: bool,
+}
+
+fn get_max_arrival(counters: &[DrivingCounter]) -> Duration {
+    counters.iter().map(|counter| counter.arrival).fold(Duration::default(), Duration::max)
+}
+
+struct DrivingRulesMultiTrip {
+    route_intervals: RouteIntervals,
+    driving: DrivingTime,
+    code: ViolationCode,
+}
+
+impl MultiTrip for DrivingRulesMultiTrip {
+    fn get_route_intervals(&self) -> &RouteIntervals {
+        &self.route_intervals
+    }
+
+    fn get_constraint(&self) -> &dyn FeatureConstraint {
+        self
+    }
+
+    fn recalculate_states(&self, route_ctx: &mut RouteContext) {
+        let Some(rules) = self.driving.get_rules(route_ctx.route()) else { return };
+
+        let route = route_ctx.route();
+        let stops = route.tour.all_activities().map(|activity| self.driving.get_stop(activity));
+        let (counters, total) = self.driving.get_counters(route, &rules, stops);
+
+        // NOTE a segment ends at activity which resets driving time or at the last activity
+        let segment_times = counters
+            .iter()
+            .rev()
+            .fold((Vec::with_capacity(counters.len()), None), |(mut acc, next_segment), counter| {
+                let segment = match next_segment {
+                    Some(next_segment) if !counter.is_reset => next_segment,
+                    _ => counter.arrival,
+                };
+                acc.push(segment);
+
+                (acc, Some(segment))
+            })
+            .0
+            .into_iter()
+            .rev()
+            .collect();
+
+        let driving_times = counters.into_iter().map(|counter| counter.departure).collect();
+
+        let state = route_ctx.state_mut();
+        state.set_driving_time_states(driving_times);
+        state.set_driving_segment_time_states(segment_times);
+        state.set_total_driving_time(total);
+    }
+
+    fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
+        let routes = &mut solution_ctx.routes;
+
+        let jobs: HashSet<_> = if route_indices.is_empty() {
+            solution_ctx
+                .ignored
+                .iter()
+                .filter(|job| job.as_single().is_some_and(|single| (self.driving.is_rest_single_fn)(single)))
+                .cloned()
+                .collect()
+        } else {
+            routes
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| route_indices.contains(idx))
+                .flat_map(|(_, route_ctx)| {
+                    solution_ctx
+                        .ignored
+                        .iter()
+                        .filter(|job| self.route_intervals.is_marker_assignable(route_ctx.route(), job))
+                })
+                .cloned()
+                .collect()
+        };
+
+        if jobs.is_empty() {
+            false
+        } else {
+            solution_ctx.ignored.retain(|job| !jobs.contains(job));
+            solution_ctx.locked.extend(jobs.iter().cloned());
+            solution_ctx.required.extend(jobs);
+
+            true
+        }
+    }
+}
+
+impl FeatureConstraint for DrivingRulesMultiTrip {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl DrivingRulesMultiTrip {
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let route = route_ctx.route();
+        let rules = self.driving.get_rules(route)?;
+
+        let is_rest = activity_ctx.target.job.as_ref().is_some_and(|single| self.driving.is_rest(single));
+
+        let (max_driving, total) = if is_rest {
+            // NOTE rest changes how driving time is accumulated further, so recalculate the whole route
+            let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+            let target_departure = prev.schedule.departure
+                + self.driving.get_leg_duration(
+                    route,
+                    prev.place.location,
+                    target.place.location,
+                    prev.schedule.departure,
+                )
+                + target.place.duration;
+            let target_stop = (target.place.location, target_departure, Some(target.place.duration));
+
+            let stops = route.tour.all_activities().enumerate().flat_map(|(idx, activity)| {
+                once(self.driving.get_stop(activity)).chain((idx == activity_ctx.index).then_some(target_stop))
+            });
+            let (counters, total) = self.driving.get_counters(route, &rules, stops);
+
+            (get_max_arrival(counters.as_slice()), total)
+        } else {
+            let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+            let prev_departure = prev.schedule.departure;
+            let prev_to_target =
+                self.driving.get_leg_duration(route, prev.place.location, target.place.location, prev_departure);
+            let prev_driving =
+                self.get_state(route_ctx, activity_ctx.index, |state, idx| state.get_driving_time_at(idx));
+
+            let (max_driving, delta) = match activity_ctx.next {
+                Some(next) => {
+                    let target_departure = prev_departure + prev_to_target + target.place.duration;
+                    let target_to_next = self.driving.get_leg_duration(
+                        route,
+                        target.place.location,
+                        next.place.location,
+                        target_departure,
+                    );
+                    let prev_to_next =
+                        self.driving.get_leg_duration(route, prev.place.location, next.place.location, prev_departure);
+                    let delta = prev_to_target + target_to_next - prev_to_next;
+                    let segment_time = self.get_state(route_ctx, activity_ctx.index + 1, |state, idx| {
+                        state.get_driving_segment_time_at(idx)
+                    });
+
+                    ((prev_driving + prev_to_target).max(segment_time + delta), delta)
+                }
+                None => (prev_driving + prev_to_target, prev_to_target),
+            };
+
+            (max_driving, route_ctx.state().get_total_driving_time().copied().unwrap_or_default() + delta)
+        };
+
+        let is_daily_violation = rules.max_daily_driving_time.is_some_and(|max_daily_driving_time| {
+            total + get_other_daily_driving(solution_ctx, route_ctx) > max_daily_driving_time
+        });
+
+        if max_driving > rules.max_driving_time || is_daily_violation {
+            ConstraintViolation::skip(self.code)
+        } else {
+            None
+        }
+    }
+
+    fn get_state(
+        &self,
+        route_ctx: &RouteContext,
+        activity_idx: usize,
+        get_fn: impl Fn(&RouteState, usize) -> Option<&Duration>,
+    ) -> Duration {
+        get_fn(route_ctx.state(), activity_idx).copied().unwrap_or_default()
+    }
+}
+
+/// Keeps multi trip state of rests and repairs daily driving time limit on solution level.
+struct DrivingRulesState {
+    inner: Arc<dyn FeatureState>,
+    daily_driving: DailyDrivingState,
+}
+
+impl FeatureState for DrivingRulesState {
+    fn notify_failure(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], jobs: &[Job]) -> bool {
+        self.inner.notify_failure(solution_ctx, route_indices, jobs)
+    }
+
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
+        self.inner.accept_insertion(solution_ctx, route_index, job)
+    }
+
+    fn accept_route_state(&self, route_ctx: &mut RouteContext) {
+        self.inner.accept_route_state(route_ctx)
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        self.inner.accept_solution_state(solution_ctx);
+        self.daily_driving.accept_solution_state(solution_ctx);
+    }
+}
+
+struct DailyDrivingState {
+    driving: DrivingTime,
+    code: ViolationCode,
+}
+
+impl DailyDrivingState {
+    /// Removes jobs of tours which exceed daily driving time limit together with earlier tours of the
+    /// same driver (or vehicle). Such tours can appear when a move is evaluated on a part of the solution
+    /// only, so removed jobs are left to the normal unassigned/recreate flow.
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let route_indices = self.get_violating_routes(solution_ctx.routes.as_slice());
+        if route_indices.is_empty() {
+            return;
+        }
+
+        let mut invalid_jobs = Vec::new();
+        for route_idx in route_indices {
+            let route_ctx = &mut solution_ctx.routes[route_idx];
+            let jobs = route_ctx
+                .route()
+                .tour
+                .jobs()
+                .filter(|job| !job.as_single().is_some_and(|single| self.driving.is_rest(single)))
+                .filter(|job| !solution_ctx.locked.contains(job))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            for job in jobs {
+                if route_ctx.route_mut().tour.remove(&job) {
+                    route_ctx.mark_stale(true);
+                    invalid_jobs.push(job);
+                }
+            }
+        }
+
+        solution_ctx.unassigned.extend(invalid_jobs.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+
+    fn get_violating_routes(&self, routes: &[RouteContext]) -> Vec<usize> {
+        let get_departure =
+            |route: &Route| route.tour.start().map_or(Timestamp::default(), |start| start.schedule.departure);
+
+        (0..routes.len())
+            .filter(|&route_idx| {
+                let route = routes[route_idx].route();
+                let Some(max_daily_driving_time) =
+                    self.driving.get_rules(route).and_then(|rules| rules.max_daily_driving_time)
+                else {
+                    return false;
+                };
+
+                let (departure, day) = (get_departure(route), get_day(route));
+                let total: Duration = routes
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_idx, other)| {
+                        let other = other.route();
+                        let other_departure = get_departure(other);
+                        let is_earlier =
+                            other_departure < departure || (other_departure == departure && *other_idx <= route_idx);
+
+                        is_earlier
+                            && is_same_worker(route.actor.as_ref(), other.actor.as_ref())
+                            && get_day(other) == day
+                    })
+                    .filter_map(|(_, other)| other.state().get_total_driving_time().copied())
+                    .sum();
+
+                total > max_daily_driving_time
+            })
+            .collect()
+    }
+}
+
+/// Returns total driving time of other tours of the same driver (or vehicle) within the same day.
+fn get_other_daily_driving(solution_ctx: &SolutionContext, route_ctx: &RouteContext) -> Duration {
+    let actor = route_ctx.route().actor.as_ref();
+    let day = get_day(route_ctx.route());
+
+    solution_ctx
+        .routes
+        .iter()
+        .filter(|other| {
+            let other_actor = other.route().actor.as_ref();
+            !std::ptr::eq(actor, other_actor) && is_same_worker(actor, other_actor) && get_day(other.route()) == day
+        })
+        .filter_map(|other| other.state().get_total_driving_time().copied())
+        .sum()
+}
+
+fn is_same_worker(actor: &Actor, other: &Actor) -> bool {
+    if actor.driver.details.is_empty() {
+        // NOTE vehicle shifts can be defined as separate vehicles with the same id
+        match (actor.vehicle.dimens.get_vehicle_id(), other.vehicle.dimens.get_vehicle_id()) {
+            (Some(vehicle_id), Some(other_id)) => vehicle_id == other_id,
+            _ => Arc::ptr_eq(&actor.vehicle, &other.vehicle),
+        }
+    } else {
+        Arc::ptr_eq(&actor.driver, &other.driver)
+    }
+}
+
+fn get_day(route: &Route) -> i64 {
+    let departure = route.tour.start().map_or(Timestamp::default(), |start| start.schedule.departure);
+
+    (departure / DAY_DURATION).floor() as i64
+}
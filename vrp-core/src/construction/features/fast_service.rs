@@ -190,7 +190,9 @@ impl FastServiceObjective {
         let (_, (prev_to_tar_dur, _)) = calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
         let arrival = activity_ctx.prev.schedule.departure + prev_to_tar_dur;
 
-        self.activity.estimate_departure(route_ctx.route(), activity_ctx.target, arrival).unwrap_value()
+        self.activity
+            .estimate_departure_after(route_ctx.route(), activity_ctx.prev, activity_ctx.target, arrival)
+            .unwrap_value()
     }
 
     fn get_cost_for_multi_job(&self, route_ctx: &RouteContext, activity_ctx: &ActivityContext) -> Cost {
@@ -28,6 +28,8 @@
 //! - When encountering a pickup with tag T and group G, push G onto the stack for tag T
 //! - When encountering a delivery with tag T and group G, verify it matches the top of stack T, then pop
 //! - If delivery doesn't match stack top for its tag, the tour violates LIFO ordering
+//! - Jobs which are already onboard at the shift start (see `JobOnboardSince`) have no pickups:
+//!   they are pushed onto their stacks before the tour starts in order of their onboard time
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/lifo_ordering_test.rs"]
@@ -127,7 +129,8 @@ impl LifoOrderingConstraint {
     ) -> bool {
         let tour = &route_ctx.route().tour;
         // Separate stack per tag
-        let mut stacks: FxHashMap<String, Vec<LifoGroupId>> = FxHashMap::default();
+        let mut stacks = self
+            .get_onboard_stacks(tour.all_activities().chain(std::iter::once(activity_ctx.target)), vehicle_lifo_tags);
 
         // `activity_ctx.index` is the leg index, which equals the index of `activity_ctx.prev`.
         // The target is inserted AFTER prev, so prev must be processed BEFORE target.
@@ -156,6 +159,30 @@ impl LifoOrderingConstraint {
         false // No LIFO violation
     }
 
+    /// Creates initial stacks from activities of jobs which are already onboard at the shift start.
+    fn get_onboard_stacks<'a>(
+        &self,
+        activities: impl Iterator<Item = &'a Activity>,
+        vehicle_lifo_tags: &FxHashSet<String>,
+    ) -> FxHashMap<String, Vec<LifoGroupId>> {
+        let mut onboard = activities
+            .filter_map(|activity| activity.job.as_ref())
+            .filter_map(|single| {
+                let since = single.dimens.get_job_onboard_since().copied()?;
+                let lifo_tag = single.dimens.get_lifo_tag().filter(|tag| vehicle_lifo_tags.contains(*tag))?;
+                let lifo_group_id = single.dimens.get_lifo_group().copied()?;
+
+                Some((since, lifo_tag.clone(), lifo_group_id))
+            })
+            .collect::<Vec<_>>();
+        onboard.sort_by(|(a, ..), (b, ..)| a.total_cmp(b));
+
+        onboard.into_iter().fold(FxHashMap::default(), |mut stacks, (_, lifo_tag, lifo_group_id)| {
+            stacks.entry(lifo_tag).or_default().push(lifo_group_id);
+            stacks
+        })
+    }
+
     /// Processes a single activity, updating the appropriate LIFO stack.
     ///
     /// Returns Err if the activity violates LIFO ordering (delivery doesn't match stack top for its tag).
@@ -188,7 +215,7 @@ impl LifoOrderingConstraint {
         if self.is_pickup(single) {
             // Pickup: push group ID onto this tag's stack
             stack.push(lifo_group_id);
-        } else if self.is_delivery(single) {
+        } else if self.is_delivery(single) || single.dimens.get_job_onboard_since().is_some() {
             // Delivery: must match top of this tag's stack (LIFO)
             if stack.last() == Some(&lifo_group_id) {
                 stack.pop();
@@ -12,11 +12,16 @@ pub use self::breaks::*;
 
 mod capacity;
 pub(crate) use self::capacity::MaxVehicleLoadTourState;
-pub use self::capacity::{CapacityFeatureBuilder, JobDemandDimension, VehicleCapacityDimension};
+pub use self::capacity::{
+    CapacityFeatureBuilder, CurrentCapacityActivityState, JobDemandDimension, VehicleCapacityDimension,
+};
 
 mod compatibility;
 pub use self::compatibility::{JobCompatibilityDimension, create_compatibility_feature};
 
+mod driving_rules;
+pub use self::driving_rules::{DrivingRules, DrivingRulesFeatureBuilder};
+
 mod fast_service;
 pub use self::fast_service::FastServiceFeatureBuilder;
 
@@ -42,13 +47,19 @@ mod reachable;
 pub use self::reachable::create_reachable_feature;
 
 mod recharge;
-pub use self::recharge::RechargeFeatureBuilder;
+pub use self::recharge::{BatteryModel, RechargeFeatureBuilder, get_charging_time};
 
 mod reloads;
 pub use self::reloads::{ReloadFeatureFactory, ReloadIntervalsTourState, SharedResource, SharedResourceId};
 
+mod resource_occupancy;
+pub use self::resource_occupancy::ResourceOccupancyFeatureBuilder;
+pub(crate) use self::resource_occupancy::has_resource_occupancy;
+
 mod skills;
-pub use self::skills::{JobSkills, JobSkillsDimension, VehicleSkillsDimension, create_skills_feature};
+pub use self::skills::{
+    DriverSkillsDimension, JobSkills, JobSkillsDimension, VehicleSkillsDimension, create_skills_feature,
+};
 
 mod preferences;
 pub use self::preferences::{
@@ -75,13 +86,26 @@ pub use self::lifo_ordering::{
     LifoGroupDimension, LifoGroupId, LifoTagDimension, VehicleLifoTagsDimension, create_lifo_ordering_feature,
 };
 
+mod onboard_waiting;
+pub use self::onboard_waiting::{VehicleMaxOnboardWaitingDimension, create_max_onboard_waiting_feature};
+
 mod requested_time;
 pub use self::requested_time::{
-    JobRequestedTimesDimension, RequestedTimePenalty, RequestedTimes, create_requested_time_feature,
+    JobRequestedTimePenaltiesDimension, JobRequestedTimesDimension, RequestedTimePenalties, RequestedTimePenalty,
+    RequestedTimePenaltyShape, RequestedTimes, create_requested_time_feature,
 };
 
 mod ride_duration;
-pub use self::ride_duration::{JobMaxRideDurationDimension, create_max_ride_duration_feature};
+pub use self::ride_duration::{
+    JobMaxRideDurationDimension, JobOnboardSinceDimension, JobRelativeMaxRideDurationDimension,
+    JobSoftMaxRideDurationDimension, RelativeRideDuration, create_max_ride_duration_feature,
+    create_minimize_excess_ride_time_feature, get_direct_ride_duration,
+};
+
+mod soft_time_windows;
+pub use self::soft_time_windows::{
+    JobSoftTimeWindowsDimension, SoftTimeWindowPenalty, create_minimize_lateness_feature, get_activity_lateness,
+};
 
 mod solo_riding;
 pub use self::solo_riding::{JobSoloRidingDimension, create_solo_riding_feature, is_solo_job};
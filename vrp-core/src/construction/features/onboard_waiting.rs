@@ -0,0 +1,213 @@
+//! Provides a feature to limit waiting time at stops while passengers are onboard.
+//!
+//! # Semantics
+//! - A vehicle specifies max onboard waiting via `VehicleMaxOnboardWaiting` dimension.
+//! - Waiting is the time between arrival at the activity and its time window start.
+//! - Waiting is limited only when some pickup-delivery job is onboard on arrival: it was picked up
+//!   earlier in the tour and its delivery is not done yet.
+//! - Jobs which are already onboard at the shift start (see `JobOnboardSince`) are treated as picked
+//!   up before the first activity of the tour.
+//! - A job with a pickup, but without delivery in the tour yet (e.g. during insertion of a multi job)
+//!   is not considered as onboard.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/onboard_waiting_test.rs"]
+mod onboard_waiting_test;
+
+use super::solo_riding::{get_dynamic_delivery_count, is_dynamic_delivery, is_dynamic_pickup, is_onboard};
+use super::*;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Activity;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+custom_dimension!(pub VehicleMaxOnboardWaiting typeof Duration);
+
+/// Creates a feature which limits waiting time at stops while pickup-delivery jobs are onboard.
+pub fn create_max_onboard_waiting_feature(
+    name: &str,
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_constraint(MaxOnboardWaitingConstraint { code, transport })
+        .with_state(MaxOnboardWaitingState { code })
+        .build()
+}
+
+struct MaxOnboardWaitingConstraint {
+    code: ViolationCode,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureConstraint for MaxOnboardWaitingConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        let MoveContext::Activity { route_ctx, activity_ctx, .. } = move_ctx else {
+            return None;
+        };
+
+        let max_waiting = route_ctx.route().actor.vehicle.dimens.get_vehicle_max_onboard_waiting().copied()?;
+
+        if self.has_onboard_waiting_violation(route_ctx, activity_ctx, max_waiting) {
+            Some(ConstraintViolation { code: self.code, stopped: false })
+        } else {
+            None
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+impl MaxOnboardWaitingConstraint {
+    fn has_onboard_waiting_violation(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        max_waiting: Duration,
+    ) -> bool {
+        let route = route_ctx.route();
+        let tour = &route.tour;
+
+        let activities = || {
+            tour.all_activities()
+                .take(activity_ctx.index + 1)
+                .chain(std::iter::once(activity_ctx.target))
+                .chain(tour.all_activities().skip(activity_ctx.index + 1))
+        };
+
+        let mut tracker = OnboardTracker::new(activities());
+
+        // NOTE activities through `index` precede the insertion and keep their current schedule,
+        // the inserted activity and every downstream activity are rescheduled.
+        let mut location = activity_ctx.prev.place.location;
+        let mut departure = activity_ctx.prev.schedule.departure;
+
+        activities().enumerate().any(|(idx, activity)| {
+            let arrival = if idx <= activity_ctx.index {
+                activity.schedule.arrival
+            } else {
+                let travel = TravelTime::Departure(departure);
+                let arrival = departure + self.transport.duration(route, location, activity.place.location, travel);
+                departure = arrival.max(activity.place.time.start) + activity.place.duration;
+                location = activity.place.location;
+
+                arrival
+            };
+
+            let waiting = (activity.place.time.start - arrival).max(0.);
+            let is_violated = tracker.has_onboard() && waiting > max_waiting;
+
+            tracker.process(activity);
+
+            is_violated
+        })
+    }
+}
+
+struct MaxOnboardWaitingState {
+    code: ViolationCode,
+}
+
+impl FeatureState for MaxOnboardWaitingState {
+    fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
+
+    fn accept_route_state(&self, _: &mut RouteContext) {}
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        // Removing another job can make arrivals earlier, so waiting with passengers onboard can
+        // exceed the limit. Such a removal is not evaluated as an insertion move, so remove onboard
+        // jobs and let the normal unassigned/recreate flow try them again.
+        let mut invalid_jobs = Vec::new();
+        for route_ctx in &mut solution_ctx.routes {
+            let jobs = get_violating_jobs(route_ctx);
+            for job in jobs.into_iter().filter(|job| !solution_ctx.locked.contains(job)) {
+                if route_ctx.route_mut().tour.remove(&job) {
+                    route_ctx.mark_stale(true);
+                    invalid_jobs.push(job);
+                }
+            }
+        }
+
+        solution_ctx.unassigned.extend(invalid_jobs.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.code))));
+    }
+}
+
+/// Returns jobs which are onboard while waiting time exceeds the limit using the current route schedule.
+fn get_violating_jobs(route_ctx: &RouteContext) -> Vec<Job> {
+    let route = route_ctx.route();
+    let Some(max_waiting) = route.actor.vehicle.dimens.get_vehicle_max_onboard_waiting().copied() else {
+        return vec![];
+    };
+
+    let mut tracker = OnboardTracker::new(route.tour.all_activities());
+
+    // NOTE keep jobs in tour order to have deterministic removal
+    route.tour.all_activities().fold(Vec::new(), |mut jobs, activity| {
+        let waiting = (activity.place.time.start - activity.schedule.arrival).max(0.);
+        if waiting > max_waiting {
+            let onboard = route.tour.jobs().filter(|job| tracker.onboard.contains(*job) && !jobs.contains(*job));
+            jobs.extend(onboard.cloned().collect::<Vec<_>>());
+        }
+
+        tracker.process(activity);
+
+        jobs
+    })
+}
+
+/// Tracks pickup-delivery jobs which are onboard while activities of the tour are processed.
+struct OnboardTracker {
+    delivered_jobs: FxHashSet<Job>,
+    onboard: FxHashSet<Job>,
+    completed_deliveries: FxHashMap<Job, usize>,
+}
+
+impl OnboardTracker {
+    fn new<'a>(activities: impl Iterator<Item = &'a Activity>) -> Self {
+        let mut delivered_jobs = FxHashSet::default();
+        let mut onboard = FxHashSet::default();
+
+        activities.for_each(|activity| {
+            let Some(single) = activity.job.as_ref() else { return };
+            let Some(job) = activity.retrieve_job() else { return };
+
+            if is_onboard(single) {
+                onboard.insert(job.clone());
+            }
+
+            if is_dynamic_delivery(single) || is_onboard(single) {
+                delivered_jobs.insert(job);
+            }
+        });
+
+        Self { delivered_jobs, onboard, completed_deliveries: FxHashMap::default() }
+    }
+
+    fn has_onboard(&self) -> bool {
+        !self.onboard.is_empty()
+    }
+
+    fn process(&mut self, activity: &Activity) {
+        let Some(single) = activity.job.as_ref() else { return };
+        let Some(job) = activity.retrieve_job() else { return };
+
+        if !self.delivered_jobs.contains(&job) {
+            return;
+        }
+
+        if is_dynamic_pickup(single) {
+            self.onboard.insert(job.clone());
+        }
+
+        if is_dynamic_delivery(single) || is_onboard(single) {
+            let completed = self.completed_deliveries.entry(job.clone()).or_default();
+            *completed += 1;
+
+            if *completed >= get_dynamic_delivery_count(&job) {
+                self.onboard.remove(&job);
+            }
+        }
+    }
+}
@@ -7,19 +7,37 @@ mod recharge_test;
 
 use super::*;
 use crate::construction::enablers::*;
-use crate::models::solution::Route;
+use crate::models::solution::{Activity, Route};
+use crate::utils::Either;
 use std::collections::HashSet;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
+/// Specifies battery parameters of an electric vehicle which are used to track its state of charge.
+#[derive(Clone, Debug)]
+pub struct BatteryModel {
+    /// Battery capacity.
+    pub capacity: Float,
+    /// Energy available at the start of the tour.
+    pub initial: Float,
+    /// Minimum energy which has to be kept in the battery.
+    pub minimum: Float,
+}
+
 /// Provides a way to build the recharge/refuel feature.
 #[allow(clippy::type_complexity)]
 pub struct RechargeFeatureBuilder {
     name: String,
     violation_code: Option<ViolationCode>,
     transport: Option<Arc<dyn TransportCost>>,
+    activity: Option<Arc<dyn ActivityCost>>,
     belongs_to_route_fn: Option<Arc<dyn Fn(&Route, &Job) -> bool + Send + Sync>>,
     is_recharge_single_fn: Option<RechargeSingleFn>,
     distance_limit_fn: Option<RechargeDistanceLimitFn>,
+    battery_fn: Option<RechargeBatteryFn>,
+    consumption_rate_fn: Option<RechargeConsumptionRateFn>,
+    job_load_fn: Option<RechargeJobLoadFn>,
+    charging_power_fn: Option<RechargeChargingPowerFn>,
 }
 
 impl RechargeFeatureBuilder {
@@ -31,7 +49,12 @@ impl RechargeFeatureBuilder {
             is_recharge_single_fn: None,
             belongs_to_route_fn: None,
             distance_limit_fn: None,
+            battery_fn: None,
+            consumption_rate_fn: None,
+            job_load_fn: None,
+            charging_power_fn: None,
             transport: None,
+            activity: None,
         }
     }
 
@@ -76,6 +99,58 @@ impl RechargeFeatureBuilder {
         self
     }
 
+    /// Sets activity costs which are used to update route schedule when charging duration changes.
+    /// Required only when battery model is used.
+    pub fn set_activity(mut self, activity: Arc<dyn ActivityCost>) -> Self {
+        self.activity = Some(activity);
+        self
+    }
+
+    /// Specifies a battery model function. When it returns a value for the actor, the feature tracks
+    /// vehicle's state of charge instead of applying distance limit. Stations charge only the energy
+    /// needed to reach the next station or the tour end, so their duration depends on the charged amount.
+    /// As charging time changes route schedule, the feature should be added before features which keep
+    /// schedule dependent state.
+    pub fn set_battery<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Actor) -> Option<BatteryModel> + Send + Sync + 'static,
+    {
+        self.battery_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Specifies a function which returns energy consumed per distance unit on the leg starting at
+    /// the activity with given index when the vehicle carries given extra load in addition to its
+    /// current one. Required when battery model is used.
+    pub fn set_consumption_rate<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&RouteContext, usize, Float) -> Float + Send + Sync + 'static,
+    {
+        self.consumption_rate_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Specifies a function which returns extra load carried on legs before (delivery) and after (pickup)
+    /// the job's activity. It is used to evaluate how insertion changes consumption on the whole route.
+    /// If it is not set, jobs are assumed not to change vehicle's load.
+    pub fn set_job_load<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> (Float, Float) + Send + Sync + 'static,
+    {
+        self.job_load_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Specifies a function which returns charging power (energy per time unit) of the recharge station.
+    /// If it is not set or returns no value, charging takes no extra time.
+    pub fn set_charging_power<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Single) -> Option<Float> + Send + Sync + 'static,
+    {
+        self.charging_power_fn = Some(Arc::new(func));
+        self
+    }
+
     /// Builds the recharge feature if all dependencies are set.
     pub fn build(&mut self) -> GenericResult<Feature> {
         let is_marker_single_fn =
@@ -84,9 +159,25 @@ impl RechargeFeatureBuilder {
             self.belongs_to_route_fn.take().ok_or_else(|| GenericError::from("belongs_to_route must be set"))?;
 
         let transport = self.transport.take().ok_or_else(|| GenericError::from("transport must be set"))?;
-        let distance_limit_fn =
-            self.distance_limit_fn.take().ok_or_else(|| GenericError::from("distance_limit must be set"))?;
 
+        let energy = match (self.battery_fn.take(), self.consumption_rate_fn.take()) {
+            (Some(battery_fn), Some(consumption_rate_fn)) => Some(EnergyModel {
+                battery_fn,
+                consumption_rate_fn,
+                job_load_fn: self.job_load_fn.take(),
+                charging_power_fn: self.charging_power_fn.take(),
+                activity: self.activity.take().ok_or_else(|| GenericError::from("activity must be set"))?,
+            }),
+            (None, None) => None,
+            _ => return Err("battery and consumption_rate must be set together".into()),
+        };
+
+        let distance_limit_fn = self.distance_limit_fn.take();
+        if distance_limit_fn.is_none() && energy.is_none() {
+            return Err("distance_limit or battery must be set".into());
+        }
+
+        let limits = Arc::new(RechargeLimits { distance_limit_fn, energy });
         let code = self.violation_code.unwrap_or_default();
 
         create_multi_trip_feature(
@@ -97,27 +188,30 @@ impl RechargeFeatureBuilder {
                 route_intervals: RouteIntervals::Multiple {
                     is_marker_single_fn: is_marker_single_fn.clone(),
                     is_new_interval_needed_fn: Arc::new({
-                        let distance_limit_fn = distance_limit_fn.clone();
+                        let limits = limits.clone();
                         move |route_ctx| {
-                            route_ctx
-                                .route()
-                                .tour
-                                .end_idx()
-                                .map(|end_idx| {
-                                    let current: Distance = route_ctx
+                            let actor = route_ctx.route().actor.as_ref();
+                            let last_idx = route_ctx.route().tour.total() - 1;
+
+                            // NOTE when there are no intervals yet, the whole route is considered as the first one
+                            get_marker_intervals(route_ctx)
+                                .map(|intervals| Either::Left(intervals.iter().copied()))
+                                .unwrap_or_else(|| Either::Right(std::iter::once((0, last_idx))))
+                                .any(|(start_idx, end_idx)| {
+                                    let current: Float = route_ctx
                                         .state()
-                                        .get_recharge_distance_at(end_idx)
+                                        .get_recharge_distance_at(get_end_idx(route_ctx, end_idx))
                                         .copied()
                                         .unwrap_or_default();
 
-                                    (distance_limit_fn)(route_ctx.route().actor.as_ref())
+                                    limits
+                                        .get_threshold(actor, start_idx == 0)
                                         .is_some_and(|threshold| current > threshold)
                                 })
-                                .unwrap_or(false)
                         }
                     }),
                     is_obsolete_interval_fn: Arc::new({
-                        let distance_limit_fn = distance_limit_fn.clone();
+                        let limits = limits.clone();
                         let transport = transport.clone();
                         let get_counter = move |route_ctx: &RouteContext, activity_idx: usize| {
                             route_ctx
@@ -144,9 +238,11 @@ impl RechargeFeatureBuilder {
 
                             let new_distance = get_counter(route_ctx, left.end) + get_counter(route_ctx, end_idx)
                                 - get_counter(route_ctx, right.start + 1)
-                                + get_distance(route_ctx.route(), left.end, right.start + 1);
+                                + get_distance(route_ctx.route(), left.end, right.start + 1)
+                                    * limits.get_consumption_rate(route_ctx, left.end, 0.);
 
-                            (distance_limit_fn)(route_ctx.route().actor.as_ref())
+                            limits
+                                .get_threshold(route_ctx.route().actor.as_ref(), left.start == 0)
                                 .is_some_and(|threshold| new_distance <= threshold)
                         }
                     }),
@@ -155,7 +251,7 @@ impl RechargeFeatureBuilder {
                 },
                 transport,
                 code,
-                distance_limit_fn,
+                limits,
                 recharge_single_fn: is_marker_single_fn.clone(),
             }),
         )
@@ -164,15 +260,78 @@ impl RechargeFeatureBuilder {
 
 type RechargeDistanceLimitFn = Arc<dyn Fn(&Actor) -> Option<Distance> + Send + Sync>;
 type RechargeSingleFn = Arc<dyn Fn(&Single) -> bool + Send + Sync>;
+type RechargeBatteryFn = Arc<dyn Fn(&Actor) -> Option<BatteryModel> + Send + Sync>;
+type RechargeConsumptionRateFn = Arc<dyn Fn(&RouteContext, usize, Float) -> Float + Send + Sync>;
+type RechargeJobLoadFn = Arc<dyn Fn(&Single) -> (Float, Float) + Send + Sync>;
+type RechargeChargingPowerFn = Arc<dyn Fn(&Single) -> Option<Float> + Send + Sync>;
 
 custom_route_intervals_state!(RechargeIntervals);
+// NOTE keeps consumed energy instead of distance when battery model is used
 custom_activity_state!(RechargeDistance typeof Distance);
+custom_activity_state!(RechargeChargedEnergy typeof Float);
+
+struct EnergyModel {
+    battery_fn: RechargeBatteryFn,
+    consumption_rate_fn: RechargeConsumptionRateFn,
+    job_load_fn: Option<RechargeJobLoadFn>,
+    charging_power_fn: Option<RechargeChargingPowerFn>,
+    activity: Arc<dyn ActivityCost>,
+}
+
+/// Keeps limits of the recharge intervals: either a distance limit or a battery model.
+struct RechargeLimits {
+    distance_limit_fn: Option<RechargeDistanceLimitFn>,
+    energy: Option<EnergyModel>,
+}
+
+impl RechargeLimits {
+    fn get_battery(&self, actor: &Actor) -> Option<BatteryModel> {
+        self.energy.as_ref().and_then(|energy| (energy.battery_fn)(actor))
+    }
+
+    /// Returns max amount of distance (energy) which can be consumed within the interval.
+    fn get_threshold(&self, actor: &Actor, is_first_interval: bool) -> Option<Float> {
+        match self.get_battery(actor) {
+            Some(battery) => Some(if is_first_interval { battery.initial } else { battery.capacity } - battery.minimum),
+            None => self.distance_limit_fn.as_ref().and_then(|distance_limit_fn| (distance_limit_fn)(actor)),
+        }
+    }
+
+    /// Returns amount consumed per distance unit on the leg starting at given activity.
+    fn get_consumption_rate(&self, route_ctx: &RouteContext, activity_idx: usize, extra_load: Float) -> Float {
+        match self.energy.as_ref() {
+            Some(energy) if (energy.battery_fn)(route_ctx.route().actor.as_ref()).is_some() => {
+                (energy.consumption_rate_fn)(route_ctx, activity_idx, extra_load)
+            }
+            _ => 1.,
+        }
+    }
+
+    /// Returns extra load carried before and after the job's activity.
+    fn get_job_load(&self, single: &Single) -> (Float, Float) {
+        self.energy
+            .as_ref()
+            .and_then(|energy| energy.job_load_fn.as_ref())
+            .map_or((0., 0.), |job_load_fn| (job_load_fn)(single))
+    }
+
+    /// Returns time needed to charge given amount of energy at the station.
+    fn get_charging_duration(&self, station: &Activity, energy: Float) -> Duration {
+        self.energy
+            .as_ref()
+            .and_then(|model| model.charging_power_fn.as_ref())
+            .zip(station.job.as_ref())
+            .and_then(|(charging_power_fn, single)| (charging_power_fn)(single))
+            .filter(|&power| power > 0.)
+            .map_or(Duration::default(), |power| energy / power)
+    }
+}
 
 struct RechargeableMultiTrip {
     route_intervals: RouteIntervals,
     transport: Arc<dyn TransportCost>,
     code: ViolationCode,
-    distance_limit_fn: RechargeDistanceLimitFn,
+    limits: Arc<RechargeLimits>,
     recharge_single_fn: RechargeSingleFn,
 }
 
@@ -186,7 +345,7 @@ impl MultiTrip for RechargeableMultiTrip {
     }
 
     fn recalculate_states(&self, route_ctx: &mut RouteContext) {
-        if (self.distance_limit_fn)(route_ctx.route().actor.as_ref()).is_none() {
+        if self.limits.get_threshold(route_ctx.route().actor.as_ref(), true).is_none() {
             return;
         }
 
@@ -194,7 +353,7 @@ impl MultiTrip for RechargeableMultiTrip {
         let marker_intervals = self.route_intervals.resolve_marker_intervals(route_ctx).collect::<Vec<_>>();
         let mut distance_counters = vec![Distance::default(); route_ctx.route().tour.total()];
 
-        marker_intervals.into_iter().for_each(|(start_idx, end_idx)| {
+        marker_intervals.iter().for_each(|&(start_idx, end_idx)| {
             let route = route_ctx.route();
 
             let end_idx = if end_idx != last_idx { end_idx + 1 } else { end_idx };
@@ -215,7 +374,7 @@ impl MultiTrip for RechargeableMultiTrip {
                         next.place.location,
                         TravelTime::Departure(prev.schedule.departure),
                     );
-                    let counter = acc + distance;
+                    let counter = acc + distance * self.limits.get_consumption_rate(route_ctx, activity_idx, 0.);
                     let next_idx = activity_idx + 1;
 
                     distance_counters[next_idx] = counter;
@@ -225,6 +384,10 @@ impl MultiTrip for RechargeableMultiTrip {
         });
 
         route_ctx.state_mut().set_recharge_distance_states(distance_counters);
+
+        if let Some(battery) = self.limits.get_battery(route_ctx.route().actor.as_ref()) {
+            self.recalculate_charging(route_ctx, &battery, marker_intervals.as_slice());
+        }
     }
 
     fn try_recover(&self, solution_ctx: &mut SolutionContext, route_indices: &[usize], _: &[Job]) -> bool {
@@ -287,52 +450,310 @@ impl RechargeableMultiTrip {
         route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ConstraintViolation> {
-        let threshold = (self.distance_limit_fn)(route_ctx.route().actor.as_ref())?;
+        let actor = route_ctx.route().actor.as_ref();
 
-        let interval_distance = self
+        let (start_idx, raw_end_idx) = self
             .route_intervals
             .resolve_marker_intervals(route_ctx)
             .find(|(_, end_idx)| activity_ctx.index <= *end_idx)
-            .map(|(_, end_idx)| get_end_idx(route_ctx, end_idx))
-            .map(|end_idx| self.get_distance(route_ctx, end_idx))
             .expect("invalid markers state");
+        let end_idx = get_end_idx(route_ctx, raw_end_idx);
+        let interval_distance = self.get_distance(route_ctx, end_idx);
+
+        let threshold = self.limits.get_threshold(actor, start_idx == 0)?;
 
         let is_new_recharge = activity_ctx.target.job.as_ref().is_some_and(|single| (self.recharge_single_fn)(single));
 
-        let is_violation = if is_new_recharge {
+        let is_violation = if let Some(battery) = self.limits.get_battery(actor) {
+            !self.is_energy_feasible(route_ctx, activity_ctx, &battery, is_new_recharge)
+        } else if is_new_recharge {
             let ((prev_to_tar_distance, tar_to_next_distance), _) =
                 calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
 
             // S ----- A ---- [X] ------ B ----- F
 
-            let current_distance = self.get_distance(route_ctx, activity_ctx.index);
+            let current_distance =
+                self.get_interval_distance(route_ctx, start_idx, activity_ctx.index) + prev_to_tar_distance;
             // check S->X
-            let is_begin_violates = (current_distance + prev_to_tar_distance) > threshold;
+            let is_begin_violates = current_distance > threshold;
             // check X->F
-            let is_end_violates = if activity_ctx.next.is_some() {
+            let is_end_violates = activity_ctx.next.is_some_and(|_| {
                 let next_distance = self.get_distance(route_ctx, activity_ctx.index + 1);
-                let new_interval_distance = interval_distance - next_distance;
+                let new_interval_distance = interval_distance - next_distance + tar_to_next_distance;
 
-                (new_interval_distance + tar_to_next_distance) > threshold
-            } else {
-                false
-            };
+                new_interval_distance > self.limits.get_threshold(actor, false).unwrap_or(threshold)
+            });
 
             is_begin_violates || is_end_violates
         } else {
             let (distance_delta, _) = calculate_travel_delta(route_ctx, activity_ctx, self.transport.as_ref());
 
-            (interval_distance + distance_delta) > threshold
+            interval_distance + distance_delta > threshold
         };
 
         if is_violation { ConstraintViolation::skip(self.code) } else { None }
     }
+
+    /// Simulates state of charge along the whole route with the target activity inserted. The job's load
+    /// changes consumption on all legs before (delivery) and after (pickup) its activity, so all intervals
+    /// are re-evaluated. Returns false if the minimum cannot be kept or the schedule cannot absorb changed
+    /// charging time.
+    fn is_energy_feasible(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        battery: &BatteryModel,
+        is_new_recharge: bool,
+    ) -> bool {
+        let route = route_ctx.route();
+        let prev_idx = activity_ctx.index;
+        // NOTE assume conservatively that the job's load is carried from the tour start and till the tour end
+        let (before_load, after_load) = match (is_new_recharge, activity_ctx.target.job.as_ref()) {
+            (false, Some(single)) => self.limits.get_job_load(single),
+            _ => (0., 0.),
+        };
+        let stations = self
+            .route_intervals
+            .resolve_marker_intervals(route_ctx)
+            .map(|(start_idx, _)| start_idx)
+            .filter(|&start_idx| start_idx > 0)
+            .collect::<HashSet<_>>();
+
+        let get_leg_energy = |activity_idx: usize, extra_load: Float| {
+            let (from, to) = (route.tour.get(activity_idx - 1).unwrap(), route.tour.get(activity_idx).unwrap());
+            let distance = self.transport.distance(
+                route,
+                from.place.location,
+                to.place.location,
+                TravelTime::Departure(from.schedule.departure),
+            );
+
+            distance * self.limits.get_consumption_rate(route_ctx, activity_idx - 1, extra_load)
+        };
+        let ((prev_to_tar_distance, tar_to_next_distance), _) =
+            calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+
+        // NOTE keeps activity index (none for the target) and energy consumed on the leg to it
+        let legs = std::iter::once((Some(0), Float::default()))
+            .chain((1..=prev_idx).map(|idx| (Some(idx), get_leg_energy(idx, before_load))))
+            .chain(std::iter::once((
+                None,
+                prev_to_tar_distance * self.limits.get_consumption_rate(route_ctx, prev_idx, before_load),
+            )))
+            .chain(activity_ctx.next.into_iter().flat_map(|_| {
+                std::iter::once((
+                    Some(prev_idx + 1),
+                    tar_to_next_distance * self.limits.get_consumption_rate(route_ctx, prev_idx, after_load),
+                ))
+                .chain((prev_idx + 2..route.tour.total()).map(|idx| (Some(idx), get_leg_energy(idx, after_load))))
+            }))
+            .collect::<Vec<_>>();
+        let is_station =
+            |activity_idx: Option<usize>| activity_idx.map_or(is_new_recharge, |idx| stations.contains(&idx));
+
+        // NOTE energy needed to reach the next station or the tour end from each activity
+        let mut needed = vec![Float::default(); legs.len()];
+        (0..legs.len().saturating_sub(1)).rev().for_each(|idx| {
+            let (next_activity_idx, next_energy) = legs[idx + 1];
+            needed[idx] = next_energy + if is_station(next_activity_idx) { 0. } else { needed[idx + 1] };
+        });
+
+        let mut delays = Vec::new();
+        let mut target_extra = Duration::default();
+        let mut state_of_charge = battery.initial;
+
+        for (leg_idx, &(activity_idx, energy)) in legs.iter().enumerate() {
+            state_of_charge -= energy;
+
+            if state_of_charge < battery.minimum {
+                return false;
+            }
+
+            if leg_idx == 0 || !is_station(activity_idx) {
+                continue;
+            }
+
+            // NOTE a station charges only the energy needed to reach the next station or the tour end
+            let charged = get_charged_energy(battery, state_of_charge, needed[leg_idx]);
+            state_of_charge += charged;
+
+            match activity_idx {
+                Some(station_idx) => {
+                    let station = route.tour.get(station_idx).unwrap();
+                    let old_charged =
+                        route_ctx.state().get_recharge_charged_energy_at(station_idx).copied().unwrap_or_default();
+                    let delay = self.limits.get_charging_duration(station, charged - old_charged);
+
+                    if delay > 0. {
+                        delays.push((station_idx, delay));
+                    }
+                }
+                None => target_extra = self.limits.get_charging_duration(activity_ctx.target, charged),
+            }
+        }
+
+        self.can_delay(route_ctx, activity_ctx, delays.as_slice(), target_extra)
+    }
+
+    /// Checks whether route schedule stays feasible when charging takes more time: departure from
+    /// stations before the target is delayed, target's service is extended and/or departure from
+    /// stations after the target is delayed.
+    fn can_delay(
+        &self,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+        delays: &[(usize, Duration)],
+        target_extra: Duration,
+    ) -> bool {
+        if delays.is_empty() && target_extra <= 0. {
+            return true;
+        }
+
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+        let is_delay_feasible = |activity_idx: usize, delay: Duration| {
+            route
+                .tour
+                .get(activity_idx)
+                .zip(route_ctx.state().get_latest_arrival_at(activity_idx))
+                .is_none_or(|(activity, &latest_arrival)| activity.schedule.arrival + delay <= latest_arrival)
+        };
+        // NOTE activities after a station are delayed by charging at all preceding stations at most
+        let get_total_delay = |init: Duration, delays: &[(usize, Duration)]| {
+            delays.iter().try_fold(init, |acc, &(station_idx, delay)| {
+                let acc = acc + delay;
+                is_delay_feasible(station_idx + 1, acc).then_some(acc)
+            })
+        };
+
+        let split_idx = delays.partition_point(|(station_idx, _)| *station_idx <= activity_ctx.index);
+        let (upstream, downstream) = delays.split_at(split_idx);
+
+        let Some(upstream_delay) = get_total_delay(Duration::default(), upstream) else { return false };
+
+        let departure = prev.schedule.departure + upstream_delay;
+        let arrival = departure
+            + self.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(departure),
+            );
+
+        if arrival > target.place.time.end {
+            return false;
+        }
+
+        let Some(next) = activity_ctx.next else { return true };
+
+        let departure = match self.get_activity_cost().estimate_departure_after(route, prev, target, arrival) {
+            ControlFlow::Continue(departure) => departure + target_extra,
+            ControlFlow::Break(_) => return false,
+        };
+        let next_arrival = departure
+            + self.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let shift = next_arrival - next.schedule.arrival;
+
+        is_delay_feasible(activity_ctx.index + 1, shift) && get_total_delay(shift.max(0.), downstream).is_some()
+    }
+
+    /// Recalculates energy charged at each station. Charging time is kept as a part of the station's service
+    /// duration, so route schedule is updated if it has changed.
+    fn recalculate_charging(&self, route_ctx: &mut RouteContext, battery: &BatteryModel, intervals: &[(usize, usize)]) {
+        let total = route_ctx.route().tour.total();
+        let mut charged_energies = vec![Float::default(); total];
+        let mut charging_durations = Vec::with_capacity(intervals.len());
+
+        // NOTE the fold keeps state of charge on arrival at the interval end which is the next station
+        let _ = intervals.iter().fold(battery.initial, |arrival_soc, &(start_idx, end_idx)| {
+            let end_idx = get_end_idx(route_ctx, end_idx);
+            let needed = self.get_distance(route_ctx, end_idx);
+
+            let departure_soc = if start_idx == 0 {
+                arrival_soc
+            } else {
+                // NOTE charge only the energy needed to reach the next station or the tour end
+                let charged = get_charged_energy(battery, arrival_soc, needed);
+
+                if let Some(station) = route_ctx.route().tour.get(start_idx) {
+                    charging_durations.push((start_idx, self.limits.get_charging_duration(station, charged)));
+                }
+
+                charged_energies[start_idx] = charged;
+
+                arrival_soc + charged
+            };
+
+            departure_soc - needed
+        });
+
+        route_ctx.state_mut().set_recharge_charged_energy_states(charged_energies);
+
+        // NOTE current schedule is built with previous charging durations which can be outdated
+        let is_changed = charging_durations.into_iter().fold(false, |is_changed, (activity_idx, charging)| {
+            let station = route_ctx.route_mut().tour.get_mut(activity_idx).expect("invalid station index");
+            let duration = get_base_duration(station) + charging;
+
+            if station.place.duration != duration {
+                station.place.duration = duration;
+                true
+            } else {
+                is_changed
+            }
+        });
+
+        if is_changed {
+            update_route_schedule(route_ctx, self.get_activity_cost(), self.transport.as_ref());
+        }
+    }
+
+    fn get_activity_cost(&self) -> &dyn ActivityCost {
+        self.limits.energy.as_ref().expect("no energy model").activity.as_ref()
+    }
 }
 
 impl RechargeableMultiTrip {
     fn get_distance(&self, route_ctx: &RouteContext, activity_idx: usize) -> Distance {
         route_ctx.state().get_recharge_distance_at(activity_idx).copied().unwrap_or(Distance::default())
     }
+
+    /// Returns distance (energy) consumed within the interval which starts at given index.
+    fn get_interval_distance(&self, route_ctx: &RouteContext, start_idx: usize, activity_idx: usize) -> Distance {
+        // NOTE a station keeps the counter of the previous interval
+        if start_idx > 0 && activity_idx == start_idx {
+            Distance::default()
+        } else {
+            self.get_distance(route_ctx, activity_idx)
+        }
+    }
+}
+
+/// Returns time spent on charging at the station activity: it is a part of the activity's service duration
+/// above the duration of the job place.
+pub fn get_charging_time(station: &Activity) -> Duration {
+    (station.place.duration - get_base_duration(station)).max(0.)
+}
+
+fn get_base_duration(station: &Activity) -> Duration {
+    station
+        .job
+        .as_ref()
+        .and_then(|single| single.places.get(station.place.idx))
+        .map_or(station.place.duration, |place| place.duration)
+}
+
+fn get_marker_intervals(route_ctx: &RouteContext) -> Option<&Vec<(usize, usize)>> {
+    route_ctx.state().get_recharge_intervals()
+}
+
+/// Returns energy which has to be charged to consume the needed amount keeping the minimum in the battery.
+fn get_charged_energy(battery: &BatteryModel, arrival_soc: Float, needed: Float) -> Float {
+    (needed + battery.minimum - arrival_soc).clamp(0., (battery.capacity - arrival_soc).max(0.))
 }
 
 fn get_end_idx(route_ctx: &RouteContext, end_idx: usize) -> usize {
@@ -1,4 +1,8 @@
 //! Provides a feature to minimize deviation from requested service times.
+//!
+//! By default, deviation is penalized linearly using the same penalty for all jobs. A job can override
+//! the penalty per place (e.g. to make being late for a medical appointment much more expensive) and
+//! the penalty can grow non-linearly with the deviation.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/requested_time_test.rs"]
@@ -15,7 +19,45 @@ use std::sync::Arc;
 /// Key is the place index, value is the requested service-start timestamp.
 pub type RequestedTimes = HashMap<usize, Timestamp>;
 
+/// Stores requested time penalties which override the objective's one for each place index in a job.
+pub type RequestedTimePenalties = HashMap<usize, RequestedTimePenalty>;
+
 custom_dimension!(pub JobRequestedTimes typeof RequestedTimes);
+custom_dimension!(pub JobRequestedTimePenalties typeof RequestedTimePenalties);
+
+/// Specifies how requested time penalty grows with the deviation.
+#[derive(Clone, Debug, Default)]
+pub enum RequestedTimePenaltyShape {
+    /// Penalty grows linearly with the deviation.
+    #[default]
+    Linear,
+    /// Penalty grows with the squared deviation expressed in minutes.
+    Quadratic,
+    /// Penalty grows linearly within segments. Each segment is defined by the deviation (in seconds)
+    /// from which it starts and a multiplier applied to the penalty within the segment. Segments
+    /// are expected to be sorted by their start; the deviation before the first segment has multiplier 1.
+    Piecewise(Vec<(Duration, Float)>),
+}
+
+impl RequestedTimePenaltyShape {
+    /// Returns deviation scaled according to the shape.
+    fn scale(&self, deviation: Duration) -> Duration {
+        match self {
+            Self::Linear => deviation,
+            Self::Quadratic => deviation * deviation / 60.0,
+            Self::Piecewise(segments) => {
+                let (scaled, start, multiplier) = segments
+                    .iter()
+                    .take_while(|(segment_start, _)| deviation > *segment_start)
+                    .fold((0.0, 0.0, 1.0), |(scaled, start, multiplier), &(segment_start, segment_multiplier)| {
+                        (scaled + (segment_start - start).max(0.0) * multiplier, segment_start, segment_multiplier)
+                    });
+
+                scaled + (deviation - start) * multiplier
+            }
+        }
+    }
+}
 
 /// Penalty configuration for requested time deviations.
 #[derive(Clone, Debug)]
@@ -24,6 +66,8 @@ pub struct RequestedTimePenalty {
     pub early_penalty_per_second: Cost,
     /// Penalty per second for starting service late (after requested time).
     pub late_penalty_per_second: Cost,
+    /// Specifies how penalty grows with the deviation.
+    pub shape: RequestedTimePenaltyShape,
 }
 
 impl Default for RequestedTimePenalty {
@@ -32,6 +76,7 @@ impl Default for RequestedTimePenalty {
             // Default: 1.0 penalty per minute = 1/60 per second
             early_penalty_per_second: 1.0 / 60.0,
             late_penalty_per_second: 1.0 / 60.0,
+            shape: RequestedTimePenaltyShape::Linear,
         }
     }
 }
@@ -42,17 +87,24 @@ impl RequestedTimePenalty {
         Self {
             early_penalty_per_second: early_penalty_per_minute / 60.0,
             late_penalty_per_second: late_penalty_per_minute / 60.0,
+            shape: RequestedTimePenaltyShape::Linear,
         }
     }
 
+    /// Sets the shape of the penalty.
+    pub fn with_shape(mut self, shape: RequestedTimePenaltyShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
     /// Calculates the penalty for a given deviation from requested time.
     fn calculate_penalty(&self, service_start: Timestamp, requested: Timestamp) -> Cost {
         if service_start < requested {
             // Early service
-            (requested - service_start) * self.early_penalty_per_second
+            self.shape.scale(requested - service_start) * self.early_penalty_per_second
         } else {
             // Late service (or on time = 0 penalty)
-            (service_start - requested) * self.late_penalty_per_second
+            self.shape.scale(service_start - requested) * self.late_penalty_per_second
         }
     }
 }
@@ -61,6 +113,7 @@ impl RequestedTimePenalty {
 ///
 /// Jobs with requested times specified (via `JobRequestedTimes` dimension) will be
 /// penalized based on how far the actual service start deviates from the requested time.
+/// The given penalty is used unless the job overrides it via `JobRequestedTimePenalties` dimension.
 pub fn create_requested_time_feature(
     name: &str,
     penalty: RequestedTimePenalty,
@@ -145,7 +198,12 @@ impl RequestedTimeObjective {
         let single = activity.job.as_ref()?;
         let requested_times = single.dimens.get_job_requested_times()?;
         let requested_time = requested_times.get(&activity.place.idx)?;
+        let penalty = single
+            .dimens
+            .get_job_requested_time_penalties()
+            .and_then(|penalties| penalties.get(&activity.place.idx))
+            .unwrap_or(self.penalty.as_ref());
 
-        Some(self.penalty.calculate_penalty(service_start, *requested_time))
+        Some(penalty.calculate_penalty(service_start, *requested_time))
     }
 }
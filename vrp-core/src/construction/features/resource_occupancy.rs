@@ -0,0 +1,451 @@
+//! A feature to model shared resources which can be occupied only by a limited amount of vehicles
+//! at the same time, e.g. connectors of a charging station or docking doors of a depot. When the
+//! resource is fully occupied, the vehicle waits in the queue till one of the slots is released.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/resource_occupancy_test.rs"]
+mod resource_occupancy_test;
+
+use super::*;
+use crate::construction::enablers::*;
+use crate::models::solution::Activity;
+use std::collections::{HashMap, HashSet};
+use std::ops::ControlFlow;
+
+custom_activity_state!(OccupancySlack typeof Duration);
+custom_solution_state!(ResourceOccupancy typeof ResourceOccupancyMap);
+
+type ResourceOccupancyMap = HashMap<SharedResourceId, Vec<(Arc<Actor>, TimeWindow)>>;
+type OccupancyResourceFn = Arc<dyn Fn(&Activity) -> Option<(SharedResourceId, usize)> + Send + Sync>;
+type PartialSolutionFn = Arc<dyn Fn(&SolutionContext) -> bool + Send + Sync>;
+
+/// Provides a way to build a feature which limits simultaneous usage of shared resources.
+pub struct ResourceOccupancyFeatureBuilder {
+    name: String,
+    violation_code: Option<ViolationCode>,
+    transport: Option<Arc<dyn TransportCost>>,
+    activity: Option<Arc<dyn ActivityCost>>,
+    resource_fn: Option<OccupancyResourceFn>,
+    is_partial_solution_fn: Option<PartialSolutionFn>,
+}
+
+impl ResourceOccupancyFeatureBuilder {
+    /// Creates a new instance of `ResourceOccupancyFeatureBuilder`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            violation_code: None,
+            transport: None,
+            activity: None,
+            resource_fn: None,
+            is_partial_solution_fn: None,
+        }
+    }
+
+    /// Sets constraint violation code which is used to report back the reason of job's unassignment.
+    pub fn set_violation_code(mut self, violation_code: ViolationCode) -> Self {
+        self.violation_code = Some(violation_code);
+        self
+    }
+
+    /// Sets transport costs to estimate arrival time.
+    pub fn set_transport(mut self, transport: Arc<dyn TransportCost>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Sets activity costs to estimate departure time.
+    pub fn set_activity(mut self, activity: Arc<dyn ActivityCost>) -> Self {
+        self.activity = Some(activity);
+        self
+    }
+
+    /// Sets a function which returns a shared resource id and its capacity (an amount of vehicles
+    /// which can use it simultaneously) for the activity which occupies the resource.
+    pub fn set_resource<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&Activity) -> Option<(SharedResourceId, usize)> + Send + Sync + 'static,
+    {
+        self.resource_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function which tells whether solution is partial. Resource occupancy cannot be
+    /// estimated for partial solutions, so no new resource usage is allowed there.
+    pub fn set_is_partial_solution<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&SolutionContext) -> bool + Send + Sync + 'static,
+    {
+        self.is_partial_solution_fn = Some(Arc::new(func));
+        self
+    }
+
+    /// Builds the resource occupancy feature.
+    pub fn build(mut self) -> GenericResult<Feature> {
+        let violation_code = self.violation_code.unwrap_or_default();
+        let transport = self.transport.take().ok_or_else(|| GenericError::from("transport must be set"))?;
+        let activity = self.activity.take().ok_or_else(|| GenericError::from("activity must be set"))?;
+        let resource_fn = self.resource_fn.take().ok_or_else(|| GenericError::from("resource_fn must be set"))?;
+        let is_partial_solution_fn = self.is_partial_solution_fn.take().unwrap_or_else(|| Arc::new(|_| false));
+
+        let occupancy = Arc::new(Occupancy { transport, activity, resource_fn, is_partial_solution_fn });
+
+        FeatureBuilder::default()
+            .with_name(self.name.as_str())
+            .with_constraint(OccupancyConstraint { violation_code, occupancy: occupancy.clone() })
+            .with_state(OccupancyState { violation_code, occupancy })
+            .build()
+    }
+}
+
+struct Occupancy {
+    transport: Arc<dyn TransportCost>,
+    activity: Arc<dyn ActivityCost>,
+    resource_fn: OccupancyResourceFn,
+    is_partial_solution_fn: PartialSolutionFn,
+}
+
+impl Occupancy {
+    /// Returns resource usage of the activity within the time interval when it is occupied.
+    fn get_usage(&self, activity: &Activity, queuing: Duration) -> Option<(SharedResourceId, usize, TimeWindow)> {
+        (self.resource_fn)(activity).map(|(resource_id, capacity)| {
+            let start = activity.schedule.arrival.max(activity.place.time.start) + queuing;
+            (resource_id, capacity, TimeWindow::new(start, activity.schedule.departure.max(start)))
+        })
+    }
+
+    fn get_route_usage<'a>(
+        &'a self,
+        route_ctx: &'a RouteContext,
+    ) -> impl Iterator<Item = (usize, SharedResourceId, usize, TimeWindow)> + 'a {
+        route_ctx.route().tour.all_activities().enumerate().filter_map(|(activity_idx, activity)| {
+            self.get_usage(activity, get_queuing(route_ctx, activity_idx))
+                .map(|(resource_id, capacity, time)| (activity_idx, resource_id, capacity, time))
+        })
+    }
+
+    fn get_blocked_times(
+        &self,
+        occupancy: &ResourceOccupancyMap,
+        actor: &Arc<Actor>,
+        resource_id: SharedResourceId,
+        capacity: usize,
+    ) -> Vec<TimeWindow> {
+        occupancy.get(&resource_id).map_or_else(Vec::default, |usage| {
+            get_blocked_times(
+                usage.iter().filter(|(other, _)| !Arc::ptr_eq(other, actor)).map(|(_, time)| time),
+                capacity,
+            )
+        })
+    }
+
+    /// Calculates for each activity how much its arrival can be delayed without causing resource
+    /// conflicts with other routes.
+    fn get_slacks(&self, route_ctx: &RouteContext, occupancy: &ResourceOccupancyMap) -> Vec<Duration> {
+        let route = route_ctx.route();
+        let mut slacks = vec![Float::MAX; route.tour.total()];
+
+        (0..route.tour.total()).rev().fold(Float::MAX, |next_slack, activity_idx| {
+            let activity = route.tour.get(activity_idx).expect("invalid activity index");
+            let queuing = get_queuing(route_ctx, activity_idx);
+            let waiting = (activity.place.time.start - activity.schedule.arrival).max(0.) + queuing;
+            let own_slack = self.get_usage(activity, queuing).map_or(Float::MAX, |(resource_id, capacity, time)| {
+                self.get_blocked_times(occupancy, &route.actor, resource_id, capacity)
+                    .into_iter()
+                    .filter(|blocked| blocked.start >= time.end)
+                    .fold(Float::MAX, |acc, blocked| acc.min(blocked.start - time.end))
+            });
+
+            let slack = own_slack.min(next_slack) + waiting;
+            slacks[activity_idx] = slack;
+
+            slack
+        });
+
+        slacks
+    }
+
+    fn get_occupancy(&self, routes: &[RouteContext]) -> ResourceOccupancyMap {
+        routes.iter().fold(ResourceOccupancyMap::default(), |mut acc, route_ctx| {
+            self.add_route_occupancy(&mut acc, route_ctx);
+            acc
+        })
+    }
+
+    fn add_route_occupancy(&self, occupancy: &mut ResourceOccupancyMap, route_ctx: &RouteContext) {
+        self.get_route_usage(route_ctx).for_each(|(_, resource_id, _, time)| {
+            occupancy.entry(resource_id).or_default().push((route_ctx.route().actor.clone(), time));
+        });
+    }
+
+    /// Moves resource usage of the route activities to the earliest time when the resource is available.
+    /// Such delay is kept as activity's queuing duration, so the vehicle waits for the resource.
+    /// Returns jobs which cannot start using the resource within their time windows.
+    fn reschedule_route(&self, route_ctx: &mut RouteContext, occupancy: &mut ResourceOccupancyMap) -> Vec<Job> {
+        let actor = route_ctx.route().actor.clone();
+        let activity_indices = self.get_route_usage(route_ctx).map(|(idx, ..)| idx).collect::<Vec<_>>();
+
+        // NOTE activity indices might be shifted after insertion, so keep queuing only for resource usage
+        let stored = (0..route_ctx.route().tour.total()).map(|idx| get_queuing(route_ctx, idx)).collect::<Vec<_>>();
+        let mut queuing = vec![Duration::default(); stored.len()];
+        activity_indices.iter().for_each(|&idx| queuing[idx] = stored[idx]);
+        if queuing != stored {
+            self.update_queuing(route_ctx, queuing.clone());
+        }
+
+        let late_jobs = activity_indices
+            .into_iter()
+            .filter_map(|activity_idx| {
+                let activity = route_ctx.route().tour.get(activity_idx).expect("invalid activity index");
+                let (resource_id, capacity, time) = self.get_usage(activity, queuing[activity_idx])?;
+
+                let earliest = activity.schedule.arrival.max(activity.place.time.start);
+                let blocked = self.get_blocked_times(occupancy, &actor, resource_id, capacity);
+                let start = get_earliest_start(&blocked, earliest, time.duration());
+                let late_job = if start > activity.place.time.end { activity.retrieve_job() } else { None };
+
+                if start != time.start {
+                    queuing[activity_idx] = start - earliest;
+                    self.update_queuing(route_ctx, queuing.clone());
+                }
+
+                late_job
+            })
+            .collect();
+
+        occupancy.values_mut().for_each(|usage| usage.retain(|(other, _)| !Arc::ptr_eq(other, &actor)));
+        self.add_route_occupancy(occupancy, route_ctx);
+
+        late_jobs
+    }
+
+    fn update_queuing(&self, route_ctx: &mut RouteContext, queuing: Vec<Duration>) {
+        route_ctx.state_mut().set_queuing_duration_states(queuing);
+        update_route_schedule(route_ctx, self.activity.as_ref(), self.transport.as_ref());
+    }
+
+    fn update_occupancy(&self, solution_ctx: &mut SolutionContext, route_index: Option<usize>) -> Vec<Job> {
+        // NOTE: we cannot estimate resource occupancy in partial solutions, so prevent any delays
+        if (self.is_partial_solution_fn)(solution_ctx) {
+            solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+                let slacks = vec![0.; route_ctx.route().tour.total()];
+                route_ctx.state_mut().set_occupancy_slack_states(slacks);
+            });
+            return vec![];
+        }
+
+        let mut occupancy = self.get_occupancy(solution_ctx.routes.as_slice());
+
+        // NOTE: process modified route first as its new resource usage was evaluated against others
+        let total_routes = solution_ctx.routes.len();
+        let late_jobs = route_index
+            .into_iter()
+            .chain((0..total_routes).filter(|&idx| Some(idx) != route_index))
+            .flat_map(|idx| self.reschedule_route(&mut solution_ctx.routes[idx], &mut occupancy))
+            .collect();
+
+        solution_ctx.routes.iter_mut().for_each(|route_ctx| {
+            let slacks = self.get_slacks(route_ctx, &occupancy);
+            route_ctx.state_mut().set_occupancy_slack_states(slacks);
+        });
+
+        solution_ctx.state.set_resource_occupancy(occupancy);
+
+        late_jobs
+    }
+}
+
+struct OccupancyConstraint {
+    violation_code: ViolationCode,
+    occupancy: Arc<Occupancy>,
+}
+
+impl OccupancyConstraint {
+    fn evaluate_activity(
+        &self,
+        solution_ctx: &SolutionContext,
+        route_ctx: &RouteContext,
+        activity_ctx: &ActivityContext,
+    ) -> Option<ConstraintViolation> {
+        let target_resource = (self.occupancy.resource_fn)(activity_ctx.target);
+        let next_slack = activity_ctx.next.map(|_| self.get_slack(solution_ctx, route_ctx, activity_ctx.index + 1));
+
+        if target_resource.is_none() && next_slack.is_none_or(|slack| slack == Float::MAX) {
+            return None;
+        }
+
+        let route = route_ctx.route();
+        let (prev, target) = (activity_ctx.prev, activity_ctx.target);
+
+        let arrival = prev.schedule.departure
+            + self.occupancy.transport.duration(
+                route,
+                prev.place.location,
+                target.place.location,
+                TravelTime::Departure(prev.schedule.departure),
+            );
+        let mut departure = match self.occupancy.activity.estimate_departure_after(route, prev, target, arrival) {
+            ControlFlow::Continue(departure) | ControlFlow::Break(departure) => departure,
+        };
+        let mut is_queued = false;
+
+        if let Some((resource_id, capacity)) = target_resource {
+            if (self.occupancy.is_partial_solution_fn)(solution_ctx) {
+                return ConstraintViolation::skip(self.violation_code);
+            }
+
+            let service_start = arrival.max(target.place.time.start);
+            let blocked = solution_ctx.state.get_resource_occupancy().map_or_else(Vec::default, |occupancy| {
+                self.occupancy.get_blocked_times(occupancy, &route.actor, resource_id, capacity)
+            });
+            let start = get_earliest_start(&blocked, service_start, departure - service_start);
+
+            if start > target.place.time.end {
+                return ConstraintViolation::skip(self.violation_code);
+            }
+
+            is_queued = start > service_start;
+            departure += start - service_start;
+        }
+
+        let (next, next_slack) = match activity_ctx.next.zip(next_slack) {
+            Some(next) => next,
+            None => return ConstraintViolation::success(),
+        };
+
+        let next_arrival = departure
+            + self.occupancy.transport.duration(
+                route,
+                target.place.location,
+                next.place.location,
+                TravelTime::Departure(departure),
+            );
+        let delay = next_arrival - next.schedule.arrival;
+
+        // NOTE waiting in the queue is not considered by other constraints
+        let latest_arrival = route_ctx
+            .state()
+            .get_latest_arrival_at(activity_ctx.index + 1)
+            .copied()
+            .filter(|_| next.job.is_some())
+            .unwrap_or(next.place.time.end);
+
+        if delay > next_slack || (is_queued && next_arrival > latest_arrival) {
+            ConstraintViolation::skip(self.violation_code)
+        } else {
+            ConstraintViolation::success()
+        }
+    }
+
+    fn get_slack(&self, solution_ctx: &SolutionContext, route_ctx: &RouteContext, activity_idx: usize) -> Duration {
+        route_ctx.state().get_occupancy_slack_at(activity_idx).copied().unwrap_or_else(|| {
+            // NOTE route state was reset, calculate slack using the last known resource occupancy
+            solution_ctx.state.get_resource_occupancy().map_or(Float::MAX, |occupancy| {
+                self.occupancy.get_slacks(route_ctx, occupancy).get(activity_idx).copied().unwrap_or(Float::MAX)
+            })
+        })
+    }
+}
+
+impl FeatureConstraint for OccupancyConstraint {
+    fn evaluate(&self, move_ctx: &MoveContext<'_>) -> Option<ConstraintViolation> {
+        match move_ctx {
+            MoveContext::Route { .. } => None,
+            MoveContext::Activity { solution_ctx, route_ctx, activity_ctx } => {
+                self.evaluate_activity(solution_ctx, route_ctx, activity_ctx)
+            }
+        }
+    }
+
+    fn merge(&self, source: Job, _: Job) -> Result<Job, ViolationCode> {
+        Ok(source)
+    }
+}
+
+struct OccupancyState {
+    violation_code: ViolationCode,
+    occupancy: Arc<Occupancy>,
+}
+
+impl FeatureState for OccupancyState {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+        // NOTE: insertion is evaluated against current occupancy, late jobs are handled on solution level
+        self.occupancy.update_occupancy(solution_ctx, Some(route_index));
+    }
+
+    fn accept_route_state(&self, _: &mut RouteContext) {
+        // NOTE: occupancy of other routes is unknown here, so the slack state is left unset and
+        //       it is estimated on demand using the last known resource occupancy.
+    }
+
+    fn accept_solution_state(&self, solution_ctx: &mut SolutionContext) {
+        let late_jobs = self.occupancy.update_occupancy(solution_ctx, None);
+
+        // NOTE: resource cannot be occupied within job's time window, so the job is left unassigned
+        let late_jobs = late_jobs.into_iter().filter(|job| !solution_ctx.locked.contains(job)).collect::<HashSet<_>>();
+
+        late_jobs.iter().for_each(|job| {
+            solution_ctx.routes.iter_mut().filter(|route_ctx| route_ctx.route().tour.contains(job)).for_each(
+                |route_ctx| {
+                    assert!(route_ctx.route_mut().tour.remove(job), "cannot remove job from the tour");
+                },
+            )
+        });
+
+        solution_ctx
+            .unassigned
+            .extend(late_jobs.into_iter().map(|job| (job, UnassignmentInfo::Simple(self.violation_code))));
+    }
+}
+
+/// Checks whether some shared resource is occupied in the solution. Resource occupancy depends on all routes,
+/// so such solution has to be restored on solution level after routes are changed independently.
+pub(crate) fn has_resource_occupancy(solution_ctx: &SolutionContext) -> bool {
+    solution_ctx
+        .state
+        .get_resource_occupancy()
+        .is_some_and(|occupancy| occupancy.values().any(|usage| !usage.is_empty()))
+}
+
+/// Returns time intervals when all resource slots are occupied.
+fn get_blocked_times<'a>(usage: impl Iterator<Item = &'a TimeWindow>, capacity: usize) -> Vec<TimeWindow> {
+    let mut events = usage
+        .filter(|time| time.end > time.start)
+        .flat_map(|time| [(time.start, 1_i64), (time.end, -1_i64)])
+        .collect::<Vec<_>>();
+    // NOTE intervals are half-open: release at the same time is processed before occupation
+    events.sort_by(|(a_time, a_delta), (b_time, b_delta)| a_time.total_cmp(b_time).then(a_delta.cmp(b_delta)));
+
+    let capacity = capacity as i64;
+    let (blocked, _, _) = events.into_iter().fold(
+        (Vec::<TimeWindow>::default(), 0_i64, Float::default()),
+        |(mut blocked, used, blocked_start), (time, delta)| {
+            let new_used = used + delta;
+            let blocked_start = if used < capacity && new_used >= capacity { time } else { blocked_start };
+
+            if used >= capacity && new_used < capacity && time > blocked_start {
+                blocked.push(TimeWindow::new(blocked_start, time));
+            }
+
+            (blocked, new_used, blocked_start)
+        },
+    );
+
+    blocked
+}
+
+/// Returns the earliest time not earlier than given one when the resource can be occupied
+/// for the given duration.
+fn get_earliest_start(blocked: &[TimeWindow], earliest: Timestamp, duration: Duration) -> Timestamp {
+    if duration <= 0. {
+        return earliest;
+    }
+
+    blocked.iter().fold(earliest, |start, blocked| {
+        if blocked.end <= start || blocked.start >= start + duration { start } else { blocked.end }
+    })
+}
+
+fn get_queuing(route_ctx: &RouteContext, activity_idx: usize) -> Duration {
+    route_ctx.state().get_queuing_duration_at(activity_idx).copied().unwrap_or_default()
+}
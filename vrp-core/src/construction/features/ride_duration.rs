@@ -8,27 +8,72 @@
 //!
 //! # How It Works
 //! - Jobs with `maxRideDuration` set will have the max duration stored in the Multi job dimensions
+//! - Jobs can also have max ride duration relative to the direct travel duration between pickup and
+//!   delivery (`max(offset + factor * direct, min)`) stored in `JobRelativeMaxRideDuration` dimension.
+//!   When both limits are specified, the smaller one is used. The direct travel duration is taken from
+//!   the routing matrix as is: it does not depend on departure time and profile scale
 //! - When evaluating insertions, the constraint checks if the delivery would occur within
 //!   the allowed time from when the corresponding pickup departs
 //! - This is a hard constraint - violations result in the insertion being rejected
+//! - Jobs which are already onboard at the shift start have deliveries only: their ride starts
+//!   at the time stored in `JobOnboardSince` dimension of their activities. As their pickup location
+//!   is unknown, only absolute max ride duration is applied to them
+//!
+//! Additionally, a soft variant is provided as an objective which minimizes the excess ride time:
+//! ride duration above the job's soft max ride duration or above the target relative to the direct
+//! travel duration.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/ride_duration_test.rs"]
 mod ride_duration_test;
 
 use super::*;
-use crate::models::common::{ConfigurableLoad, Duration, MultiDimLoad, SingleDimLoad, Timestamp};
-use crate::models::problem::{Multi, Single, TransportCost, TravelTime};
-use crate::models::solution::Activity;
+use crate::models::common::{ConfigurableLoad, Duration, Location, MultiDimLoad, Profile, SingleDimLoad, Timestamp};
+use crate::models::problem::{Single, TransportCost, TravelTime};
+use crate::models::solution::{Activity, Route};
 use std::collections::HashMap;
+use std::collections::hash_map::Entry;
 use std::sync::Arc;
 
+/// Specifies max ride duration relative to the direct travel duration between pickup and delivery.
+#[derive(Clone, Debug)]
+pub struct RelativeRideDuration {
+    /// A constant part of the limit.
+    pub offset: Duration,
+    /// A factor applied to the direct travel duration.
+    pub factor: Float,
+    /// A minimum limit.
+    pub min: Duration,
+}
+
+impl RelativeRideDuration {
+    /// Returns max ride duration for the given direct travel duration.
+    pub fn get_limit(&self, direct: Duration) -> Duration {
+        (self.offset + self.factor * direct).max(self.min)
+    }
+}
+
 custom_dimension!(pub JobMaxRideDuration typeof Duration);
+custom_dimension!(pub JobRelativeMaxRideDuration typeof RelativeRideDuration);
+custom_dimension!(pub JobSoftMaxRideDuration typeof Duration);
+custom_dimension!(pub JobOnboardSince typeof Timestamp);
+
+/// Returns direct travel duration between pickup and delivery locations which is used as a baseline
+/// for relative ride duration limits: time-independent duration from the profile's routing matrix
+/// without profile scale applied. Returns `None` if the delivery location is not reachable.
+pub fn get_direct_ride_duration(
+    transport: &dyn TransportCost,
+    profile: &Profile,
+    from: Location,
+    to: Location,
+) -> Option<Duration> {
+    Some(transport.duration_approx(&Profile::new(profile.index, None), from, to)).filter(|duration| *duration >= 0.)
+}
 
 /// Creates a max ride duration feature as a hard constraint.
 ///
 /// This feature enforces that the time between pickup departure and delivery arrival
-/// does not exceed the job's `maxRideDuration` value.
+/// does not exceed the job's `maxRideDuration` value or the limit relative to the direct travel duration.
 pub fn create_max_ride_duration_feature(
     name: &str,
     code: ViolationCode,
@@ -36,8 +81,22 @@ pub fn create_max_ride_duration_feature(
 ) -> Result<Feature, GenericError> {
     FeatureBuilder::default()
         .with_name(name)
-        .with_constraint(MaxRideDurationConstraint { code, transport })
-        .with_state(MaxRideDurationState {})
+        .with_constraint(MaxRideDurationConstraint { code, transport: transport.clone() })
+        .with_state(MaxRideDurationState { transport })
+        .build()
+}
+
+/// Creates a feature which minimizes ride duration above the target (excess ride time) of
+/// pickup-delivery jobs. The target is the job's soft max ride duration (`JobSoftMaxRideDuration`
+/// dimension) or, if it is not specified, the given one relative to the direct travel duration.
+pub fn create_minimize_excess_ride_time_feature(
+    name: &str,
+    target: RelativeRideDuration,
+    transport: Arc<dyn TransportCost>,
+) -> Result<Feature, GenericError> {
+    FeatureBuilder::default()
+        .with_name(name)
+        .with_objective(MinimizeExcessRideTimeObjective { target, transport })
         .build()
 }
 
@@ -46,7 +105,9 @@ struct MaxRideDurationConstraint {
     transport: Arc<dyn TransportCost>,
 }
 
-struct MaxRideDurationState {}
+struct MaxRideDurationState {
+    transport: Arc<dyn TransportCost>,
+}
 
 impl FeatureState for MaxRideDurationState {
     fn accept_insertion(&self, _: &mut SolutionContext, _: usize, _: &Job) {}
@@ -60,7 +121,7 @@ impl FeatureState for MaxRideDurationState {
         // unassigned/recreate flow try it again in a subsequent search iteration.
         let mut invalid_jobs = Vec::new();
         for route_ctx in &mut solution_ctx.routes {
-            let jobs = get_violating_jobs(route_ctx);
+            let jobs = get_violating_jobs(route_ctx, self.transport.as_ref());
             for job in jobs {
                 if route_ctx.route_mut().tour.remove(&job) {
                     route_ctx.mark_stale(true);
@@ -83,7 +144,7 @@ impl FeatureConstraint for MaxRideDurationConstraint {
 
     fn merge(&self, source: Job, _candidate: Job) -> Result<Job, ViolationCode> {
         // Don't allow merging jobs with max ride duration
-        if source.dimens().get_job_max_ride_duration().is_some() { Err(self.code) } else { Ok(source) }
+        if has_ride_duration_limit(source.dimens()) { Err(self.code) } else { Ok(source) }
     }
 }
 
@@ -94,105 +155,212 @@ impl MaxRideDurationConstraint {
         route_ctx: &RouteContext,
         activity_ctx: &ActivityContext,
     ) -> Option<ConstraintViolation> {
-        let route = route_ctx.route();
-        let tour = &route.tour;
-        let mut intervals = HashMap::<usize, RideInterval>::new();
-
-        // Activities through `index` precede the insertion and keep their current schedule.
-        for idx in 0..=activity_ctx.index {
-            if let Some(activity) = tour.get(idx) {
-                self.record_interval(activity, activity.schedule.arrival, activity.schedule.departure, &mut intervals);
-            }
-        }
+        let intervals = project_intervals(route_ctx, activity_ctx, self.transport.as_ref(), &get_hard_limits);
 
-        // Project the inserted activity and every downstream activity using the same
-        // earliest-arrival scheduling rule as the route schedule updater. This is
-        // necessary even when the inserted activity belongs to another job: a stop
-        // inserted between an existing pickup and delivery can lengthen that ride.
-        let mut location = activity_ctx.prev.place.location;
-        let mut departure = activity_ctx.prev.schedule.departure;
-
-        let mut project = |activity: &Activity| {
-            let arrival = departure
-                + self.transport.duration(route, location, activity.place.location, TravelTime::Departure(departure));
-            departure = arrival.max(activity.place.time.start) + activity.place.duration;
-            location = activity.place.location;
-            self.record_interval(activity, arrival, departure, &mut intervals);
-        };
+        intervals
+            .values()
+            .any(|interval| interval.is_violated(route_ctx.route(), self.transport.as_ref()))
+            .then_some(ConstraintViolation { code: self.code, stopped: false })
+    }
+}
+
+struct MinimizeExcessRideTimeObjective {
+    target: RelativeRideDuration,
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureObjective for MinimizeExcessRideTimeObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution.solution.routes.iter().map(|route_ctx| self.get_route_excess(route_ctx)).sum()
+    }
 
-        project(activity_ctx.target);
-        for idx in activity_ctx.index + 1..tour.total() {
-            if let Some(activity) = tour.get(idx) {
-                project(activity);
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let get_limits = |job: &Job| self.get_soft_limits(job);
+                let intervals = project_intervals(route_ctx, activity_ctx, self.transport.as_ref(), &get_limits);
+
+                get_total_excess(route_ctx.route(), self.transport.as_ref(), intervals.values())
+                    - self.get_route_excess(route_ctx)
             }
         }
+    }
+}
 
-        intervals.values().find_map(|interval| {
-            interval.pickup_departure.zip(interval.delivery_service_start).and_then(|(pickup, delivery)| {
-                (delivery - pickup > interval.limit).then_some(ConstraintViolation { code: self.code, stopped: false })
-            })
-        })
+impl MinimizeExcessRideTimeObjective {
+    /// Returns ride target of the job: either its soft max ride duration or the one relative to
+    /// the direct travel duration for pickup-delivery jobs.
+    fn get_soft_limits(&self, job: &Job) -> Option<RideLimits> {
+        match job.dimens().get_job_soft_max_ride_duration() {
+            Some(&target) => Some((Some(target), None)),
+            None => matches!(job, Job::Multi(_)).then(|| (None, Some(self.target.clone()))),
+        }
     }
 
-    fn record_interval(
-        &self,
-        activity: &Activity,
-        arrival: Timestamp,
-        departure: Timestamp,
-        intervals: &mut HashMap<usize, RideInterval>,
-    ) {
-        record_interval(activity, arrival, departure, intervals);
+    fn get_route_excess(&self, route_ctx: &RouteContext) -> Cost {
+        let intervals = get_route_intervals(route_ctx, &|job| self.get_soft_limits(job));
+
+        get_total_excess(route_ctx.route(), self.transport.as_ref(), intervals.values())
     }
 }
 
+/// Specifies absolute and relative ride duration limits.
+type RideLimits = (Option<Duration>, Option<RelativeRideDuration>);
+
 struct RideInterval {
-    job: Arc<Multi>,
-    limit: Duration,
+    limit: Option<Duration>,
+    relative_limit: Option<RelativeRideDuration>,
     pickup_departure: Option<Timestamp>,
+    pickup_location: Option<Location>,
     delivery_service_start: Option<Timestamp>,
+    delivery_location: Option<Location>,
 }
 
-fn get_violating_jobs(route_ctx: &RouteContext) -> Vec<Job> {
-    let mut intervals = HashMap::<usize, RideInterval>::new();
+impl RideInterval {
+    /// Returns the difference between ride duration and the smallest of absolute and relative limits.
+    fn get_excess(&self, route: &Route, transport: &dyn TransportCost) -> Option<Duration> {
+        let (pickup, delivery) = self.pickup_departure.zip(self.delivery_service_start)?;
+
+        let relative_limit = self.relative_limit.as_ref().zip(self.pickup_location.zip(self.delivery_location)).map(
+            |(relative_limit, (from, to))| {
+                let profile = &route.actor.vehicle.profile;
+                relative_limit.get_limit(get_direct_ride_duration(transport, profile, from, to).unwrap_or_default())
+            },
+        );
+
+        let limit = match (self.limit, relative_limit) {
+            (Some(limit), Some(relative_limit)) => Some(limit.min(relative_limit)),
+            (limit, relative_limit) => limit.or(relative_limit),
+        };
+
+        limit.map(|limit| delivery - pickup - limit)
+    }
+
+    /// Checks whether ride duration exceeds the smallest of absolute and relative limits.
+    fn is_violated(&self, route: &Route, transport: &dyn TransportCost) -> bool {
+        self.get_excess(route, transport).is_some_and(|excess| excess > 0.)
+    }
+}
+
+fn has_ride_duration_limit(dimens: &Dimensions) -> bool {
+    dimens.get_job_max_ride_duration().is_some() || dimens.get_job_relative_max_ride_duration().is_some()
+}
+
+fn get_hard_limits(job: &Job) -> Option<RideLimits> {
+    let limit = job.dimens().get_job_max_ride_duration().copied();
+    let relative_limit = job.dimens().get_job_relative_max_ride_duration().cloned();
+
+    (limit.is_some() || relative_limit.is_some()).then_some((limit, relative_limit))
+}
+
+fn get_total_excess<'a>(
+    route: &Route,
+    transport: &dyn TransportCost,
+    intervals: impl Iterator<Item = &'a RideInterval>,
+) -> Cost {
+    intervals.filter_map(|interval| interval.get_excess(route, transport)).map(|excess| excess.max(0.)).sum()
+}
+
+fn get_violating_jobs(route_ctx: &RouteContext, transport: &dyn TransportCost) -> Vec<Job> {
+    get_route_intervals(route_ctx, &get_hard_limits)
+        .into_iter()
+        .filter(|(_, interval)| interval.is_violated(route_ctx.route(), transport))
+        .map(|(job, _)| job)
+        .collect()
+}
+
+/// Gets ride intervals using the current route schedule.
+fn get_route_intervals(
+    route_ctx: &RouteContext,
+    get_limits: &dyn Fn(&Job) -> Option<RideLimits>,
+) -> HashMap<Job, RideInterval> {
+    let mut intervals = HashMap::<Job, RideInterval>::new();
     route_ctx.route().tour.all_activities().for_each(|activity| {
-        record_interval(activity, activity.schedule.arrival, activity.schedule.departure, &mut intervals)
+        record_interval(activity, activity.schedule.arrival, activity.schedule.departure, &mut intervals, get_limits)
     });
 
     intervals
-        .into_values()
-        .filter(|interval| {
-            interval
-                .pickup_departure
-                .zip(interval.delivery_service_start)
-                .is_some_and(|(pickup, delivery)| delivery - pickup > interval.limit)
-        })
-        .map(|interval| Job::Multi(interval.job))
-        .collect()
+}
+
+/// Gets ride intervals as if the target activity is inserted into the route.
+fn project_intervals(
+    route_ctx: &RouteContext,
+    activity_ctx: &ActivityContext,
+    transport: &dyn TransportCost,
+    get_limits: &dyn Fn(&Job) -> Option<RideLimits>,
+) -> HashMap<Job, RideInterval> {
+    let route = route_ctx.route();
+    let tour = &route.tour;
+    let mut intervals = HashMap::<Job, RideInterval>::new();
+
+    // Activities through `index` precede the insertion and keep their current schedule.
+    tour.all_activities().take(activity_ctx.index + 1).for_each(|activity| {
+        record_interval(activity, activity.schedule.arrival, activity.schedule.departure, &mut intervals, get_limits)
+    });
+
+    // Project the inserted activity and every downstream activity using the same
+    // earliest-arrival scheduling rule as the route schedule updater. This is
+    // necessary even when the inserted activity belongs to another job: a stop
+    // inserted between an existing pickup and delivery can lengthen that ride.
+    let mut location = activity_ctx.prev.place.location;
+    let mut departure = activity_ctx.prev.schedule.departure;
+
+    let mut project = |activity: &Activity| {
+        let arrival =
+            departure + transport.duration(route, location, activity.place.location, TravelTime::Departure(departure));
+        departure = arrival.max(activity.place.time.start) + activity.place.duration;
+        location = activity.place.location;
+        record_interval(activity, arrival, departure, &mut intervals, get_limits);
+    };
+
+    project(activity_ctx.target);
+    for idx in activity_ctx.index + 1..tour.total() {
+        if let Some(activity) = tour.get(idx) {
+            project(activity);
+        }
+    }
+
+    intervals
 }
 
 fn record_interval(
     activity: &Activity,
     arrival: Timestamp,
     departure: Timestamp,
-    intervals: &mut HashMap<usize, RideInterval>,
+    intervals: &mut HashMap<Job, RideInterval>,
+    get_limits: &dyn Fn(&Job) -> Option<RideLimits>,
 ) {
     let Some(single) = activity.job.as_ref() else { return };
-    let Some(multi) = Multi::roots(single) else { return };
-    let Some(limit) = multi.dimens.get_job_max_ride_duration().copied() else { return };
-    let key = Arc::as_ptr(&multi) as usize;
-    let interval = intervals.entry(key).or_insert_with(|| RideInterval {
-        job: multi,
-        limit,
-        pickup_departure: None,
-        delivery_service_start: None,
-    });
+    let Some(job) = activity.retrieve_job() else { return };
+
+    let onboard_since = single.dimens.get_job_onboard_since().copied();
+    let interval = match intervals.entry(job) {
+        Entry::Occupied(entry) => entry.into_mut(),
+        Entry::Vacant(entry) => {
+            let Some((limit, relative_limit)) = get_limits(entry.key()) else { return };
+            entry.insert(RideInterval {
+                limit,
+                relative_limit,
+                pickup_departure: onboard_since,
+                pickup_location: None,
+                delivery_service_start: None,
+                delivery_location: None,
+            })
+        }
+    };
 
     if is_pickup(single) {
-        interval.pickup_departure = Some(interval.pickup_departure.map_or(departure, |value| value.min(departure)));
-    } else if is_delivery(single) {
+        if interval.pickup_departure.is_none_or(|value| departure < value) {
+            interval.pickup_departure = Some(departure);
+            interval.pickup_location = Some(activity.place.location);
+        }
+    } else if is_delivery(single) || onboard_since.is_some() {
         let service_start = arrival.max(activity.place.time.start);
-        interval.delivery_service_start =
-            Some(interval.delivery_service_start.map_or(service_start, |value| value.max(service_start)));
+        if interval.delivery_service_start.is_none_or(|value| service_start > value) {
+            interval.delivery_service_start = Some(service_start);
+            interval.delivery_location = Some(activity.place.location);
+        }
     }
 }
 
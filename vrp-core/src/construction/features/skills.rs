@@ -1,14 +1,16 @@
-//! A job-vehicle skills feature.
+//! A job-actor skills feature: actor skills are the skills of its vehicle and its driver.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/skills_test.rs"]
 mod skills_test;
 
 use super::*;
+use std::borrow::Cow;
 use std::collections::HashSet;
 
 custom_dimension!(pub JobSkills typeof JobSkills);
 custom_dimension!(pub VehicleSkills typeof HashSet<String>);
+custom_dimension!(pub DriverSkills typeof HashSet<String>);
 
 /// A job skills limitation for a vehicle.
 pub struct JobSkills {
@@ -44,10 +46,11 @@ impl FeatureConstraint for SkillsConstraint {
         match move_ctx {
             MoveContext::Route { route_ctx, job, .. } => {
                 if let Some(job_skills) = job.dimens().get_job_skills() {
-                    let vehicle_skills = route_ctx.route().actor.vehicle.dimens.get_vehicle_skills();
-                    let is_ok = check_all_of(job_skills, &vehicle_skills)
-                        && check_one_of(job_skills, &vehicle_skills)
-                        && check_none_of(job_skills, &vehicle_skills);
+                    let actor_skills = get_actor_skills(route_ctx.route().actor.as_ref());
+                    let actor_skills = actor_skills.as_deref();
+                    let is_ok = check_all_of(job_skills, &actor_skills)
+                        && check_one_of(job_skills, &actor_skills)
+                        && check_none_of(job_skills, &actor_skills);
                     if !is_ok {
                         return ConstraintViolation::fail(self.code);
                     }
@@ -86,27 +89,38 @@ impl FeatureConstraint for SkillsConstraint {
     }
 }
 
-fn check_all_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
-    match (job_skills.all_of.as_ref(), vehicle_skills) {
-        (Some(job_skills), Some(vehicle_skills)) => job_skills.is_subset(vehicle_skills),
+/// Returns combined skills of actor's vehicle and driver.
+fn get_actor_skills(actor: &Actor) -> Option<Cow<'_, HashSet<String>>> {
+    match (actor.vehicle.dimens.get_vehicle_skills(), actor.driver.dimens.get_driver_skills()) {
+        (Some(vehicle_skills), Some(driver_skills)) => {
+            Some(Cow::Owned(vehicle_skills.union(driver_skills).cloned().collect()))
+        }
+        (Some(skills), None) | (None, Some(skills)) => Some(Cow::Borrowed(skills)),
+        (None, None) => None,
+    }
+}
+
+fn check_all_of(job_skills: &JobSkills, actor_skills: &Option<&HashSet<String>>) -> bool {
+    match (job_skills.all_of.as_ref(), actor_skills) {
+        (Some(job_skills), Some(actor_skills)) => job_skills.is_subset(actor_skills),
         (Some(skills), None) if skills.is_empty() => true,
         (Some(_), None) => false,
         _ => true,
     }
 }
 
-fn check_one_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
-    match (job_skills.one_of.as_ref(), vehicle_skills) {
-        (Some(job_skills), Some(vehicle_skills)) => job_skills.iter().any(|skill| vehicle_skills.contains(skill)),
+fn check_one_of(job_skills: &JobSkills, actor_skills: &Option<&HashSet<String>>) -> bool {
+    match (job_skills.one_of.as_ref(), actor_skills) {
+        (Some(job_skills), Some(actor_skills)) => job_skills.iter().any(|skill| actor_skills.contains(skill)),
         (Some(skills), None) if skills.is_empty() => true,
         (Some(_), None) => false,
         _ => true,
     }
 }
 
-fn check_none_of(job_skills: &JobSkills, vehicle_skills: &Option<&HashSet<String>>) -> bool {
-    match (job_skills.none_of.as_ref(), vehicle_skills) {
-        (Some(job_skills), Some(vehicle_skills)) => job_skills.is_disjoint(vehicle_skills),
+fn check_none_of(job_skills: &JobSkills, actor_skills: &Option<&HashSet<String>>) -> bool {
+    match (job_skills.none_of.as_ref(), actor_skills) {
+        (Some(job_skills), Some(actor_skills)) => job_skills.is_disjoint(actor_skills),
         _ => true,
     }
 }
@@ -0,0 +1,127 @@
+//! Provides a feature to minimize violation of soft time windows.
+//!
+//! A soft time window is a regular place's time window which end is extended by max tolerated lateness, so
+//! the hard time window check done by transport feature still rejects service started too late. Within the
+//! extended part, each second of lateness is penalized. Additionally, each second of waiting before time
+//! window start can be penalized too.
+
+#[cfg(test)]
+#[path = "../../../tests/unit/construction/features/soft_time_windows_test.rs"]
+mod soft_time_windows_test;
+
+use super::*;
+use crate::construction::enablers::calculate_travel;
+use crate::models::problem::{TransportCost, TravelTime};
+use crate::models::solution::Activity;
+use std::sync::Arc;
+
+/// Specifies soft time window penalties of the job.
+#[derive(Clone, Debug)]
+pub struct SoftTimeWindowPenalty {
+    /// Penalty per second of waiting before time window start.
+    pub early_penalty_per_second: Cost,
+    /// Penalty per second of service start after time window end.
+    pub late_penalty_per_second: Cost,
+    /// Max tolerated lateness. Place's time window end is expected to be extended by this value.
+    pub max_lateness: Duration,
+}
+
+impl SoftTimeWindowPenalty {
+    /// Creates a new penalty configuration with penalties specified per minute.
+    pub fn new(early_penalty_per_minute: Cost, late_penalty_per_minute: Cost, max_lateness: Duration) -> Self {
+        Self {
+            early_penalty_per_second: early_penalty_per_minute / 60.,
+            late_penalty_per_second: late_penalty_per_minute / 60.,
+            max_lateness,
+        }
+    }
+
+    /// Calculates the penalty for the given place time window, arrival and service start.
+    fn calculate_penalty(&self, time: &TimeWindow, arrival: Timestamp, service_start: Timestamp) -> Cost {
+        let earliness = (time.start - arrival).max(0.);
+        let lateness = self.get_lateness(time, service_start);
+
+        earliness * self.early_penalty_per_second + lateness * self.late_penalty_per_second
+    }
+
+    /// Returns lateness of the service start regarding the original time window end.
+    fn get_lateness(&self, time: &TimeWindow, service_start: Timestamp) -> Duration {
+        (service_start - (time.end - self.max_lateness)).max(0.)
+    }
+}
+
+custom_dimension!(pub JobSoftTimeWindows typeof SoftTimeWindowPenalty);
+
+/// Returns lateness of the activity with soft time windows if the service starts at given time.
+pub fn get_activity_lateness(activity: &Activity, service_start: Timestamp) -> Option<Duration> {
+    let penalty = activity.job.as_ref()?.dimens.get_job_soft_time_windows()?;
+
+    Some(penalty.get_lateness(&activity.place.time, service_start))
+}
+
+/// Creates a feature that minimizes lateness and earliness of activities with soft time windows
+/// (specified via `JobSoftTimeWindows` dimension).
+pub fn create_minimize_lateness_feature(name: &str, transport: Arc<dyn TransportCost>) -> GenericResult<Feature> {
+    FeatureBuilder::default().with_name(name).with_objective(LatenessObjective { transport }).build()
+}
+
+struct LatenessObjective {
+    transport: Arc<dyn TransportCost>,
+}
+
+impl FeatureObjective for LatenessObjective {
+    fn fitness(&self, solution: &InsertionContext) -> Cost {
+        solution
+            .solution
+            .routes
+            .iter()
+            .flat_map(|route_ctx| {
+                route_ctx
+                    .route()
+                    .tour
+                    .all_activities()
+                    .filter_map(|activity| get_penalty(activity, activity.schedule.arrival))
+            })
+            .sum()
+    }
+
+    fn estimate(&self, move_ctx: &MoveContext<'_>) -> Cost {
+        match move_ctx {
+            MoveContext::Route { .. } => Cost::default(),
+            MoveContext::Activity { route_ctx, activity_ctx, .. } => {
+                let (_, (prev_to_tar_dur, _)) = calculate_travel(route_ctx, activity_ctx, self.transport.as_ref());
+                let target = activity_ctx.target;
+                let arrival = activity_ctx.prev.schedule.departure + prev_to_tar_dur;
+                let mut delta = get_penalty(target, arrival).unwrap_or_default();
+
+                // NOTE insertion delays all activities after the target, so their penalties might change too
+                let route = route_ctx.route();
+                let mut location = target.place.location;
+                let mut departure = arrival.max(target.place.time.start) + target.place.duration;
+                for activity in route.tour.all_activities().skip(activity_ctx.index + 1) {
+                    let travel = TravelTime::Departure(departure);
+                    let arrival = departure + self.transport.duration(route, location, activity.place.location, travel);
+
+                    delta += get_penalty(activity, arrival).unwrap_or_default()
+                        - get_penalty(activity, activity.schedule.arrival).unwrap_or_default();
+
+                    if arrival == activity.schedule.arrival {
+                        break;
+                    }
+
+                    departure = arrival.max(activity.place.time.start) + activity.place.duration;
+                    location = activity.place.location;
+                }
+
+                delta
+            }
+        }
+    }
+}
+
+fn get_penalty(activity: &Activity, arrival: Timestamp) -> Option<Cost> {
+    let penalty = activity.job.as_ref()?.dimens.get_job_soft_time_windows()?;
+    let service_start = arrival.max(activity.place.time.start);
+
+    Some(penalty.calculate_penalty(&activity.place.time, arrival, service_start))
+}
@@ -9,6 +9,8 @@
 //! - While this solo job is onboard, pickups of any other dynamic pickup-delivery jobs are forbidden.
 //! - The same solo job can still have multiple pickups/deliveries (e.g. companions) and those activities
 //!   are allowed.
+//! - Jobs which are already onboard at the shift start (see `JobOnboardSince`) are treated as picked
+//!   up before the first activity of the tour.
 
 #[cfg(test)]
 #[path = "../../../tests/unit/construction/features/solo_riding_test.rs"]
@@ -73,6 +75,30 @@ impl SoloRidingConstraint {
         let mut active_solo_job: Option<Job> = None;
         let tour = &route_ctx.route().tour;
 
+        let initial_jobs = tour
+            .all_activities()
+            .chain(std::iter::once(activity_ctx.target))
+            .filter(|activity| activity.job.as_ref().is_some_and(|single| is_onboard(single)))
+            .filter_map(|activity| activity.retrieve_job());
+
+        for job in initial_jobs {
+            if self.is_solo_job(&job) && active_solo_job.as_ref().is_some_and(|solo| solo != &job) {
+                return true;
+            }
+
+            if self.is_solo_job(&job) {
+                active_solo_job = Some(job.clone());
+            }
+
+            onboard.insert(job);
+        }
+
+        if let Some(solo_job) = active_solo_job.as_ref()
+            && onboard.iter().any(|job| job != solo_job)
+        {
+            return true;
+        }
+
         // `activity_ctx.index` is the leg index, which equals the index of `activity_ctx.prev`.
         // The target is inserted AFTER prev, so prev must be processed BEFORE target.
         for idx in 0..=activity_ctx.index {
@@ -116,8 +142,8 @@ impl SoloRidingConstraint {
             return Ok(());
         };
 
-        let is_pickup = self.is_dynamic_pickup(single);
-        let is_delivery = self.is_dynamic_delivery(single);
+        let is_pickup = is_dynamic_pickup(single);
+        let is_delivery = is_dynamic_delivery(single) || is_onboard(single);
         if !is_pickup && !is_delivery {
             return Ok(());
         }
@@ -149,7 +175,7 @@ impl SoloRidingConstraint {
 
             let completed = completed_deliveries.entry(job.clone()).or_default();
             *completed += 1;
-            let delivery_count = self.get_dynamic_delivery_count(&job);
+            let delivery_count = get_dynamic_delivery_count(&job);
 
             if *completed >= delivery_count {
                 onboard.remove(&job);
@@ -171,35 +197,45 @@ impl SoloRidingConstraint {
     fn is_solo_job(&self, job: &Job) -> bool {
         is_solo_job(job)
     }
+}
 
-    fn is_dynamic_pickup(&self, single: &Single) -> bool {
-        single.dimens.get_job_demand::<SingleDimLoad>().is_some_and(|d| d.pickup.1.value != 0)
-            || single
-                .dimens
-                .get_job_demand::<MultiDimLoad>()
-                .is_some_and(|d| has_non_zero_values(&d.pickup.1.load, d.pickup.1.size))
-            || single
-                .dimens
-                .get_job_demand::<ConfigurableLoad>()
-                .is_some_and(|d| has_non_zero_values(&d.pickup.1.load, d.pickup.1.size))
-    }
+/// Returns true if single is already onboard at the shift start.
+pub(super) fn is_onboard(single: &Single) -> bool {
+    single.dimens.get_job_onboard_since().is_some()
+}
 
-    fn is_dynamic_delivery(&self, single: &Single) -> bool {
-        single.dimens.get_job_demand::<SingleDimLoad>().is_some_and(|d| d.delivery.1.value != 0)
-            || single
-                .dimens
-                .get_job_demand::<MultiDimLoad>()
-                .is_some_and(|d| has_non_zero_values(&d.delivery.1.load, d.delivery.1.size))
-            || single
-                .dimens
-                .get_job_demand::<ConfigurableLoad>()
-                .is_some_and(|d| has_non_zero_values(&d.delivery.1.load, d.delivery.1.size))
-    }
+/// Returns true if single picks up some load which is delivered later in the same tour.
+pub(super) fn is_dynamic_pickup(single: &Single) -> bool {
+    single.dimens.get_job_demand::<SingleDimLoad>().is_some_and(|d| d.pickup.1.value != 0)
+        || single
+            .dimens
+            .get_job_demand::<MultiDimLoad>()
+            .is_some_and(|d| has_non_zero_values(&d.pickup.1.load, d.pickup.1.size))
+        || single
+            .dimens
+            .get_job_demand::<ConfigurableLoad>()
+            .is_some_and(|d| has_non_zero_values(&d.pickup.1.load, d.pickup.1.size))
+}
+
+/// Returns true if single delivers some load which is picked up earlier in the same tour.
+pub(super) fn is_dynamic_delivery(single: &Single) -> bool {
+    single.dimens.get_job_demand::<SingleDimLoad>().is_some_and(|d| d.delivery.1.value != 0)
+        || single
+            .dimens
+            .get_job_demand::<MultiDimLoad>()
+            .is_some_and(|d| has_non_zero_values(&d.delivery.1.load, d.delivery.1.size))
+        || single
+            .dimens
+            .get_job_demand::<ConfigurableLoad>()
+            .is_some_and(|d| has_non_zero_values(&d.delivery.1.load, d.delivery.1.size))
+}
 
-    fn get_dynamic_delivery_count(&self, job: &Job) -> usize {
-        match job {
-            Job::Single(single) => usize::from(self.is_dynamic_delivery(single)),
-            Job::Multi(multi) => multi.jobs.iter().filter(|single| self.is_dynamic_delivery(single)).count(),
+/// Returns amount of activities which have to be completed to drop the job from the vehicle.
+pub(super) fn get_dynamic_delivery_count(job: &Job) -> usize {
+    match job {
+        Job::Single(single) => usize::from(is_dynamic_delivery(single) || is_onboard(single)),
+        Job::Multi(multi) => {
+            multi.jobs.iter().filter(|single| is_dynamic_delivery(single) || is_onboard(single)).count()
         }
     }
 }
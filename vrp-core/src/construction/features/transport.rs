@@ -209,6 +209,16 @@ impl TransportConstraint {
         if arr_time_at_next > latest_arr_time_at_next {
             return ConstraintViolation::fail(self.time_window_code);
         }
+
+        // NOTE latest arrival at next is calculated when it follows the previous activity: if they share
+        // setup or parking time, it has to be charged again after the target is inserted between them
+        let latest_arr_time_at_next = next.map_or(latest_arr_time_at_next, |next| {
+            let get_shared =
+                |prev: &Activity, next: &Activity| get_shared_setup(prev, next) + get_shared_parking(prev, next);
+
+            latest_arr_time_at_next - (get_shared(prev, next) - get_shared(target, next)).max(0.)
+        });
+
         if target.place.time.start > latest_arr_time_at_next {
             return ConstraintViolation::skip(self.time_window_code);
         }
@@ -230,7 +240,7 @@ impl TransportConstraint {
             );
 
         let ControlFlow::Continue(latest_arr_time_at_target) =
-            self.activity.estimate_arrival(route, target, latest_departure_at_target)
+            self.activity.estimate_arrival_after(route, prev, target, latest_departure_at_target)
         else {
             return ConstraintViolation::skip(self.time_window_code);
         };
@@ -245,7 +255,7 @@ impl TransportConstraint {
         }
 
         let ControlFlow::Continue(end_time_at_target) =
-            self.activity.estimate_departure(route, target, arr_time_at_target)
+            self.activity.estimate_departure_after(route, prev, target, arr_time_at_target)
         else {
             return ConstraintViolation::skip(self.time_window_code);
         };
@@ -346,7 +356,8 @@ where
     let (prev_target, dep_time_target) = {
         let time = activity_ctx.prev.schedule.departure;
         let arrival = time + transport.duration(route, prev, target, prev_dep);
-        let departure = activity.estimate_departure(route, activity_ctx.target, arrival).unwrap_value();
+        let departure =
+            activity.estimate_departure_after(route, activity_ctx.prev, activity_ctx.target, arrival).unwrap_value();
 
         (estimate_fn(prev, target, prev_dep), departure)
     };
@@ -440,7 +451,7 @@ impl CostObjective {
 
         let arrival = time
             + self.transport.duration(route, start.place.location, end.place.location, TravelTime::Departure(time));
-        let departure = self.activity.estimate_departure(route, end, arrival).unwrap_value();
+        let departure = self.activity.estimate_departure_after(route, start, end, arrival).unwrap_value();
 
         let transport_cost =
             self.transport.cost(route, start.place.location, end.place.location, TravelTime::Departure(time));
@@ -475,8 +486,9 @@ impl TransportState {
 }
 
 impl FeatureState for TransportState {
-    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, _: &Job) {
+    fn accept_insertion(&self, solution_ctx: &mut SolutionContext, route_index: usize, job: &Job) {
         let route_ctx = solution_ctx.routes.get_mut(route_index).unwrap();
+        shift_queuing_durations(route_ctx, job);
         self.accept_route_state(route_ctx);
     }
 
@@ -372,7 +372,14 @@ impl TryFrom<InsertionResult> for InsertionSuccess {
 }
 
 pub(crate) fn prepare_insertion_ctx(insertion_ctx: &mut InsertionContext) {
-    insertion_ctx.solution.required.extend(insertion_ctx.solution.unassigned.keys().cloned());
+    if insertion_ctx.environment.is_deterministic {
+        // NOTE hash map iteration order is not stable, so use the problem order of jobs instead
+        let unassigned = &insertion_ctx.solution.unassigned;
+        let jobs = insertion_ctx.problem.jobs.all().iter().filter(|job| unassigned.contains_key(*job)).cloned();
+        insertion_ctx.solution.required.extend(jobs);
+    } else {
+        insertion_ctx.solution.required.extend(insertion_ctx.solution.unassigned.keys().cloned());
+    }
     insertion_ctx.problem.goal.accept_solution_state(&mut insertion_ctx.solution);
 }
 
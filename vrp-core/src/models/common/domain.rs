@@ -16,21 +16,41 @@ pub struct Profile {
     pub index: usize,
     /// A duration scale factor.
     pub scale: Float,
+    /// Time-of-day duration scale factors sorted by their start time. Within their time windows,
+    /// they are used instead of `scale`.
+    pub time_scales: Vec<ProfileTimeScale>,
 }
 
 impl Profile {
     /// Creates a new instance of `Profile`.
     pub fn new(index: usize, scale: Option<Float>) -> Profile {
-        Self { index, scale: scale.unwrap_or(1.) }
+        Self { index, scale: scale.unwrap_or(1.), time_scales: vec![] }
+    }
+
+    /// Sets time-of-day duration scale factors.
+    pub fn with_time_scales(mut self, mut time_scales: Vec<ProfileTimeScale>) -> Profile {
+        time_scales.sort_by(|a, b| a.time.start.total_cmp(&b.time.start));
+        self.time_scales = time_scales;
+
+        self
     }
 }
 
 impl Default for Profile {
     fn default() -> Self {
-        Self { index: 0, scale: 1. }
+        Self::new(0, None)
     }
 }
 
+/// Represents a duration scale factor applied within specific time window.
+#[derive(Clone, Debug)]
+pub struct ProfileTimeScale {
+    /// A time window when factor is applied.
+    pub time: TimeWindow,
+    /// A duration scale factor.
+    pub factor: Float,
+}
+
 /// Specifies cost value.
 pub type Cost = Float;
 
@@ -51,6 +51,32 @@ pub trait ActivityCost: Send + Sync {
         activity: &Activity,
         departure: Timestamp,
     ) -> ControlFlow<Timestamp, Timestamp>;
+
+    /// Estimates departure time for activity and actor at given arrival time when activity is performed
+    /// right after `prev` one. By default, the previous activity is ignored, but implementations can use it
+    /// to share service time between consecutive activities at the same stop.
+    fn estimate_departure_after(
+        &self,
+        route: &Route,
+        _prev: &Activity,
+        activity: &Activity,
+        arrival: Timestamp,
+    ) -> ControlFlow<Timestamp, Timestamp> {
+        self.estimate_departure(route, activity, arrival)
+    }
+
+    /// Estimates arrival time for activity and actor at given departure time when activity is performed
+    /// right after `prev` one. By default, the previous activity is ignored, but implementations can use it
+    /// to take into account service time shared between consecutive activities at the same stop.
+    fn estimate_arrival_after(
+        &self,
+        route: &Route,
+        _prev: &Activity,
+        activity: &Activity,
+        departure: Timestamp,
+    ) -> ControlFlow<Timestamp, Timestamp> {
+        self.estimate_arrival(route, activity, departure)
+    }
 }
 
 /// An actor independent activity costs.
@@ -102,6 +128,12 @@ pub trait TransportCost: Send + Sync {
     /// Returns time-dependent travel distance between locations specific for given actor.
     fn distance(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Distance;
 
+    /// Returns sorted timestamps at which time-dependent travel durations of given profile change
+    /// their slope. Empty for time-independent transport costs.
+    fn time_breakpoints(&self, _profile: &Profile) -> &[Timestamp] {
+        &[]
+    }
+
     /// Returns size of known locations
     fn size(&self) -> usize;
 }
@@ -280,8 +312,17 @@ impl<T: TransportFallback> TransportCost for TimeAgnosticMatrixTransportCost<T>
             .unwrap_or_else(|| self.fallback.distance(profile, from, to))
     }
 
-    fn duration(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Duration {
-        self.duration_approx(&route.actor.vehicle.profile, from, to)
+    fn duration(&self, route: &Route, from: Location, to: Location, travel_time: TravelTime) -> Duration {
+        let profile = &route.actor.vehicle.profile;
+        let duration = self
+            .durations
+            .get(profile.index)
+            .unwrap()
+            .get(from * self.size + to)
+            .copied()
+            .unwrap_or_else(|| self.fallback.duration(profile, from, to));
+
+        scale_duration(profile, duration, travel_time)
     }
 
     fn distance(&self, route: &Route, from: Location, to: Location, _: TravelTime) -> Distance {
@@ -293,9 +334,11 @@ impl<T: TransportFallback> TransportCost for TimeAgnosticMatrixTransportCost<T>
     }
 }
 
-/// A time aware matrix costs.
+/// A time aware matrix costs. Travel duration between two locations is modeled as a piecewise-linear
+/// function of departure time with breakpoints at matrix timestamps. Matrices are required to satisfy
+/// FIFO (first-in, first-out) property: departing later never results in earlier arrival.
 struct TimeAwareMatrixTransportCost<T: TransportFallback> {
-    costs: HashMap<usize, (Vec<u64>, Vec<MatrixData>)>,
+    costs: HashMap<usize, (Vec<Timestamp>, Vec<MatrixData>)>,
     size: usize,
     fallback: T,
 }
@@ -316,12 +359,16 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
         let costs = costs
             .into_iter()
             .map(|(profile, mut matrices)| {
-                matrices.sort_by_key(|a| a.timestamp.unwrap() as u64);
-                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap() as u64).collect();
+                matrices.sort_by(|a, b| a.timestamp.unwrap().total_cmp(&b.timestamp.unwrap()));
+                let timestamps = matrices.iter().map(|matrix| matrix.timestamp.unwrap()).collect();
 
                 (profile, (timestamps, matrices))
             })
-            .collect();
+            .collect::<HashMap<_, _>>();
+
+        if costs.values().any(|(_, matrices)| !is_fifo_consistent(matrices.as_slice())) {
+            return Err("time-aware routing requires matrices to satisfy FIFO property".into());
+        }
 
         Ok(Self { costs, size, fallback })
     }
@@ -333,42 +380,30 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
         to: Location,
         travel_time: TravelTime,
     ) -> Duration {
-        let timestamp = match travel_time {
-            TravelTime::Arrival(arrival) => arrival,
-            TravelTime::Departure(departure) => departure,
-        };
-
         let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
         let data_idx = from * self.size + to;
 
-        let duration = match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => matrices.get(matrix_idx).unwrap().durations.get(data_idx).copied(),
-            Err(0) => matrices.first().unwrap().durations.get(data_idx).copied(),
-            Err(matrix_idx) if matrix_idx == matrices.len() => {
-                matrices.last().unwrap().durations.get(data_idx).copied()
-            }
-            Err(matrix_idx) => {
-                let left_matrix = matrices.get(matrix_idx - 1).unwrap();
-                let right_matrix = matrices.get(matrix_idx).unwrap();
-
-                matrices
-                    .get(matrix_idx - 1)
-                    .unwrap()
-                    .durations
-                    .get(data_idx)
-                    .zip(matrices.get(matrix_idx).unwrap().durations.get(data_idx))
-                    .map(|(&left_value, &right_value)| {
-                        // perform linear interpolation
-                        let ratio = (timestamp - left_matrix.timestamp.unwrap())
-                            / (right_matrix.timestamp.unwrap() - left_matrix.timestamp.unwrap());
-
-                        left_value + ratio * (right_value - left_value)
-                    })
+        let get_raw_duration =
+            |matrix_idx: usize| matrices.get(matrix_idx).and_then(|matrix| matrix.durations.get(data_idx)).copied();
+
+        let get_departure_duration = |departure: Timestamp| {
+            interpolate_by_departure(timestamps, departure, get_raw_duration)
+                .map(|duration| scale_duration(profile, duration, TravelTime::Departure(departure)))
+        };
+
+        match travel_time {
+            TravelTime::Departure(departure) => get_departure_duration(departure),
+            // NOTE time-of-day scales make arrival time function non-linear between breakpoints
+            TravelTime::Arrival(arrival) if !profile.time_scales.is_empty() => {
+                invert_by_bisection(arrival, get_departure_duration)
             }
+            TravelTime::Arrival(arrival) => interpolate_by_arrival(timestamps, arrival, |matrix_idx| {
+                // NOTE scale durations at breakpoints to keep arrival time function piecewise-linear
+                get_raw_duration(matrix_idx)
+                    .map(|duration| scale_duration(profile, duration, TravelTime::Departure(timestamps[matrix_idx])))
+            }),
         }
-        .unwrap_or_else(|| self.fallback.duration(profile, from, to));
-
-        duration * profile.scale
+        .unwrap_or_else(|| scale_duration(profile, self.fallback.duration(profile, from, to), travel_time))
     }
 
     fn interpolate_distance(
@@ -385,16 +420,177 @@ impl<T: TransportFallback> TimeAwareMatrixTransportCost<T> {
 
         let (timestamps, matrices) = self.costs.get(&profile.index).unwrap();
         let data_idx = from * self.size + to;
+        let matrix_idx = timestamps.partition_point(|&value| value <= timestamp).max(1) - 1;
+
+        matrices
+            .get(matrix_idx)
+            .and_then(|matrix| matrix.distances.get(data_idx))
+            .copied()
+            .unwrap_or_else(|| self.fallback.distance(profile, from, to))
+    }
+}
+
+/// Scales travel duration taken from routing matrix using profile's scale factors. When profile has
+/// time-of-day scale factors, the duration is distributed over time intervals starting from departure
+/// (walking forward) or arrival (walking backward) time, so that each unit of matrix duration takes
+/// `factor` units of time within the interval.
+pub fn scale_duration(profile: &Profile, duration: Duration, travel_time: TravelTime) -> Duration {
+    // NOTE negative values are used to mark unreachable locations
+    if profile.time_scales.is_empty() || duration <= 0. {
+        return duration * profile.scale;
+    }
+
+    let (mut time, forward) = match travel_time {
+        TravelTime::Departure(departure) => (departure, true),
+        TravelTime::Arrival(arrival) => (arrival, false),
+    };
+    let start_time = time;
+    let mut remaining = duration;
+
+    loop {
+        let (factor, boundary) = if forward {
+            profile
+                .time_scales
+                .iter()
+                .find(|scale| scale.time.start <= time && time < scale.time.end)
+                .map(|scale| (scale.factor, scale.time.end))
+                .unwrap_or_else(|| {
+                    let next_start =
+                        profile.time_scales.iter().map(|scale| scale.time.start).find(|&start| start > time);
+                    (profile.scale, next_start.unwrap_or(Float::INFINITY))
+                })
+        } else {
+            profile
+                .time_scales
+                .iter()
+                .find(|scale| scale.time.start < time && time <= scale.time.end)
+                .map(|scale| (scale.factor, scale.time.start))
+                .unwrap_or_else(|| {
+                    let prev_end = profile
+                        .time_scales
+                        .iter()
+                        .map(|scale| scale.time.end)
+                        .filter(|&end| end < time)
+                        .reduce(Float::max);
+                    (profile.scale, prev_end.unwrap_or(Float::NEG_INFINITY))
+                })
+        };
+
+        let available = (boundary - time).abs() / factor;
+        if remaining <= available {
+            time += if forward { remaining * factor } else { -remaining * factor };
+            break;
+        }
+
+        remaining -= available;
+        time = boundary;
+    }
+
+    (time - start_time).abs()
+}
+
+/// Checks that travel durations between consecutive matrices do not decrease faster than time passes,
+/// which guarantees FIFO property for linear interpolation.
+fn is_fifo_consistent(matrices: &[MatrixData]) -> bool {
+    matrices.windows(2).all(|pair| {
+        let (left, right) = (&pair[0], &pair[1]);
+        let time_delta = right.timestamp.unwrap() - left.timestamp.unwrap();
+
+        // NOTE negative values are used to mark unreachable locations
+        left.durations
+            .iter()
+            .zip(right.durations.iter())
+            .filter(|(left, right)| **left >= 0. && **right >= 0.)
+            .all(|(&left, &right)| left - right <= time_delta)
+    })
+}
+
+/// Returns travel duration for given departure time using linear interpolation between matrix slots.
+fn interpolate_by_departure<F>(timestamps: &[Timestamp], departure: Timestamp, get_duration: F) -> Option<Duration>
+where
+    F: Fn(usize) -> Option<Duration>,
+{
+    match timestamps.partition_point(|&timestamp| timestamp < departure) {
+        0 => get_duration(0),
+        idx if idx == timestamps.len() => get_duration(idx - 1),
+        idx if timestamps[idx] == departure => get_duration(idx),
+        idx => get_duration(idx - 1).zip(get_duration(idx)).map(|(left_value, right_value)| {
+            let (left_time, right_time) = (timestamps[idx - 1], timestamps[idx]);
+            let ratio = (departure - left_time) / (right_time - left_time);
+
+            left_value + ratio * (right_value - left_value)
+        }),
+    }
+}
 
-        match timestamps.binary_search(&(timestamp as u64)) {
-            Ok(matrix_idx) => matrices.get(matrix_idx).unwrap().distances.get(data_idx),
-            Err(0) => matrices.first().unwrap().distances.get(data_idx),
-            Err(matrix_idx) if matrix_idx == matrices.len() => matrices.last().unwrap().distances.get(data_idx),
-            Err(matrix_idx) => matrices.get(matrix_idx - 1).unwrap().distances.get(data_idx),
+/// Returns travel duration for given arrival time by inverting piecewise-linear arrival time function.
+/// Inversion is unambiguous as arrival time function is non-decreasing due to FIFO property.
+fn interpolate_by_arrival<F>(timestamps: &[Timestamp], arrival: Timestamp, get_duration: F) -> Option<Duration>
+where
+    F: Fn(usize) -> Option<Duration>,
+{
+    let get_arrival = |idx: usize| get_duration(idx).map(|duration| timestamps[idx] + duration);
+    let last_idx = timestamps.len() - 1;
+
+    if arrival <= get_arrival(0)? {
+        return get_duration(0);
+    }
+
+    if arrival >= get_arrival(last_idx)? {
+        return get_duration(last_idx);
+    }
+
+    (0..last_idx).find_map(|idx| {
+        let (left_arrival, right_arrival) = (get_arrival(idx)?, get_arrival(idx + 1)?);
+
+        (arrival < right_arrival).then(|| {
+            let (left_time, right_time) = (timestamps[idx], timestamps[idx + 1]);
+            let ratio = (arrival - left_arrival) / (right_arrival - left_arrival);
+
+            arrival - (left_time + ratio * (right_time - left_time))
+        })
+    })
+}
+
+/// Returns travel duration for given arrival time by searching departure time with bisection, so that
+/// the result is consistent with travel duration function given by departure time.
+/// Search is valid as arrival time function is non-decreasing due to FIFO property.
+fn invert_by_bisection<F>(arrival: Timestamp, get_duration: F) -> Option<Duration>
+where
+    F: Fn(Timestamp) -> Option<Duration>,
+{
+    let duration = get_duration(arrival)?;
+
+    // NOTE negative values are used to mark unreachable locations
+    if duration < 0. {
+        return Some(duration);
+    }
+
+    let get_arrival = |departure: Timestamp| get_duration(departure).map(|duration| departure + duration);
+
+    // find departure interval which contains the solution by expanding it backward
+    let (mut left, mut right, mut step) = (arrival - duration, arrival, duration.max(1.));
+    while get_arrival(left)? > arrival {
+        right = left;
+        left -= step;
+        step *= 2.;
+    }
+
+    // NOTE keep the latest departure which does not violate arrival time
+    for _ in 0..64 {
+        if right - left < 1E-9 {
+            break;
+        }
+
+        let middle = (left + right) / 2.;
+        if get_arrival(middle)? > arrival {
+            right = middle;
+        } else {
+            left = middle;
         }
-        .copied()
-        .unwrap_or_else(|| self.fallback.distance(profile, from, to))
     }
+
+    Some(arrival - left)
 }
 
 impl<T: TransportFallback> TransportCost for TimeAwareMatrixTransportCost<T> {
@@ -414,6 +610,10 @@ impl<T: TransportFallback> TransportCost for TimeAwareMatrixTransportCost<T> {
         self.interpolate_distance(&route.actor.vehicle.profile, from, to, travel_time)
     }
 
+    fn time_breakpoints(&self, profile: &Profile) -> &[Timestamp] {
+        self.costs.get(&profile.index).map_or(&[], |(timestamps, _)| timestamps.as_slice())
+    }
+
     fn size(&self) -> usize {
         self.size
     }
@@ -11,6 +11,10 @@ use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 custom_dimension!(pub VehicleId typeof String);
+custom_dimension!(pub DriverId typeof String);
+
+/// A pair of vehicle (driver) index and its detail index.
+type ShiftKey = (usize, usize);
 
 /// Represents operating costs for driver and vehicle.
 #[derive(Clone, Debug)]
@@ -27,12 +31,19 @@ pub struct Costs {
     pub per_service_time: Float,
 }
 
-/// Represents driver detail (reserved for future use).
-#[derive(Clone, Hash, Eq, PartialEq)]
-pub struct DriverDetail {}
+/// Represents driver detail (driver shift): availability time and an optional home depot.
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct DriverDetail {
+    /// A home depot location: the driver can use only vehicles which start at this location.
+    pub depot: Option<Location>,
+
+    /// Time window when the driver is available.
+    pub time: TimeWindow,
+}
 
 /// Represents a driver, person who drives a [`Vehicle`].
-/// Reserved for future usage, e.g., to allow reusing the same vehicle more than once at different times.
+/// A driver without details has no restrictions and can drive any vehicle at any time, including
+/// multiple vehicles simultaneously. A driver with details can drive only one vehicle per detail.
 pub struct Driver {
     /// Specifies operating costs for a driver.
     pub costs: Costs,
@@ -146,6 +157,38 @@ pub struct Fleet {
 
     /// A grouped actors.
     pub groups: HashMap<usize, HashSet<Arc<Actor>>>,
+
+    /// Actors which cannot be used together as they share the same vehicle or driver shift.
+    pub conflicts: ActorConflicts,
+}
+
+/// Keeps actors grouped by vehicle and driver shifts they use, so actors which cannot be used together
+/// are found by shift keys instead of keeping all conflicting pairs.
+#[derive(Clone, Default)]
+pub struct ActorConflicts {
+    keys: HashMap<Arc<Actor>, (ShiftKey, Option<ShiftKey>)>,
+    vehicle_shifts: HashMap<ShiftKey, Vec<Arc<Actor>>>,
+    driver_shifts: HashMap<ShiftKey, Vec<Arc<Actor>>>,
+}
+
+impl ActorConflicts {
+    /// Returns actors which share the same vehicle shift or the same driver shift with the given actor.
+    pub fn get<'a>(&'a self, actor: &'a Actor) -> impl Iterator<Item = &'a Arc<Actor>> + 'a {
+        // NOTE the same vehicle and driver shifts define the same actor, so returned actors are unique
+        self.keys.get(actor).into_iter().flat_map(move |(vehicle_key, driver_key)| {
+            self.vehicle_shifts
+                .get(vehicle_key)
+                .into_iter()
+                .chain(driver_key.and_then(|driver_key| self.driver_shifts.get(&driver_key)))
+                .flatten()
+                .filter(move |other| other.as_ref() != actor)
+        })
+    }
+
+    /// Checks whether the given actor cannot be used together with some other actor.
+    pub fn has_conflicts(&self, actor: &Actor) -> bool {
+        self.get(actor).next().is_some()
+    }
 }
 
 impl Fleet {
@@ -155,8 +198,7 @@ impl Fleet {
         vehicles: Vec<Arc<Vehicle>>,
         group_key: impl Fn(&[Arc<Actor>]) -> R,
     ) -> Fleet {
-        // TODO we should also consider multiple drivers to support smart vehicle-driver assignment.
-        assert_eq!(drivers.len(), 1);
+        assert!(!drivers.is_empty());
         assert!(!vehicles.is_empty());
 
         let profiles: HashMap<usize, Profile> = vehicles.iter().map(|v| (v.profile.index, v.profile.clone())).collect();
@@ -164,34 +206,108 @@ impl Fleet {
         profiles.sort_by_key(|(a, _)| *a);
         let (_, profiles): (Vec<_>, Vec<_>) = profiles.into_iter().unzip();
 
-        let actors = vehicles
+        // NOTE keep vehicle shift and driver shift keys to detect actors which cannot be used together
+        let keyed_actors = vehicles
             .iter()
-            .flat_map(|vehicle| {
-                vehicle.details.iter().map(|detail| {
-                    Arc::new(Actor {
-                        vehicle: vehicle.clone(),
-                        driver: drivers.first().unwrap().clone(),
-                        detail: ActorDetail {
-                            start: detail.start.clone(),
-                            end: detail.end.clone(),
-                            time: TimeWindow {
-                                start: detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
-                                end: detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(Float::MAX),
-                            },
-                        },
+            .enumerate()
+            .flat_map(|(vehicle_idx, vehicle)| {
+                vehicle
+                    .details
+                    .iter()
+                    .enumerate()
+                    .map(move |(vehicle_detail_idx, detail)| ((vehicle_idx, vehicle_detail_idx), vehicle, detail))
+            })
+            .flat_map(|(vehicle_key, vehicle, vehicle_detail)| {
+                drivers.iter().enumerate().flat_map(move |(driver_idx, driver)| {
+                    let driver_details: Vec<_> = if driver.details.is_empty() {
+                        vec![None]
+                    } else {
+                        driver.details.iter().enumerate().map(|(idx, detail)| Some((idx, detail))).collect()
+                    };
+
+                    driver_details.into_iter().filter_map(move |driver_detail| {
+                        create_actor_detail(vehicle_detail, driver_detail.map(|(_, detail)| detail)).map(|detail| {
+                            let actor = Arc::new(Actor { vehicle: vehicle.clone(), driver: driver.clone(), detail });
+                            let driver_key = driver_detail.map(|(idx, _)| (driver_idx, idx));
+
+                            (actor, vehicle_key, driver_key)
+                        })
                     })
                 })
             })
             .collect::<Vec<_>>();
 
+        let actors = keyed_actors.iter().map(|(actor, _, _)| actor.clone()).collect::<Vec<_>>();
+        let conflicts = get_actor_conflicts(keyed_actors.as_slice());
+
         let group_key = (group_key)(&actors);
         let groups: HashMap<_, HashSet<_>> = actors.iter().cloned().fold(HashMap::new(), |mut acc, actor| {
             acc.entry((group_key)(&actor)).or_default().insert(actor.clone());
             acc
         });
 
-        Fleet { drivers, vehicles, profiles, actors, groups }
+        Fleet { drivers, vehicles, profiles, actors, groups, conflicts }
+    }
+}
+
+/// Creates actor detail from vehicle detail restricted by driver detail.
+/// Returns `None` if the driver cannot use the vehicle shift.
+fn create_actor_detail(vehicle_detail: &VehicleDetail, driver_detail: Option<&DriverDetail>) -> Option<ActorDetail> {
+    let vehicle_time = TimeWindow {
+        start: vehicle_detail.start.as_ref().and_then(|s| s.time.earliest).unwrap_or(0.),
+        end: vehicle_detail.end.as_ref().and_then(|e| e.time.latest).unwrap_or(Float::MAX),
+    };
+
+    let Some(driver_detail) = driver_detail else {
+        return Some(ActorDetail {
+            start: vehicle_detail.start.clone(),
+            end: vehicle_detail.end.clone(),
+            time: vehicle_time,
+        });
+    };
+
+    if driver_detail.depot.is_some() && driver_detail.depot != vehicle_detail.start.as_ref().map(|s| s.location) {
+        return None;
     }
+
+    let time = vehicle_time.overlapping(&driver_detail.time)?;
+
+    let start = match vehicle_detail.start.as_ref() {
+        Some(start) => {
+            let earliest = start.time.earliest.map_or(time.start, |earliest| earliest.max(time.start));
+            let latest = start.time.latest.map(|latest| latest.min(time.end));
+
+            if latest.is_some_and(|latest| latest < earliest) {
+                return None;
+            }
+
+            Some(VehiclePlace { location: start.location, time: TimeInterval { earliest: Some(earliest), latest } })
+        }
+        None => None,
+    };
+
+    let end = vehicle_detail.end.as_ref().map(|end| VehiclePlace {
+        location: end.location,
+        time: TimeInterval {
+            earliest: end.time.earliest,
+            latest: Some(end.time.latest.map_or(time.end, |latest| latest.min(time.end))),
+        },
+    });
+
+    Some(ActorDetail { start, end, time })
+}
+
+/// Groups actors by vehicle and driver shifts to detect actors which cannot be used together.
+fn get_actor_conflicts(keyed_actors: &[(Arc<Actor>, ShiftKey, Option<ShiftKey>)]) -> ActorConflicts {
+    keyed_actors.iter().fold(ActorConflicts::default(), |mut acc, (actor, vehicle_key, driver_key)| {
+        acc.keys.insert(actor.clone(), (*vehicle_key, *driver_key));
+        acc.vehicle_shifts.entry(*vehicle_key).or_default().push(actor.clone());
+        if let Some(driver_key) = driver_key {
+            acc.driver_shifts.entry(*driver_key).or_default().push(actor.clone());
+        }
+
+        acc
+    })
 }
 
 impl Debug for Fleet {
@@ -2,41 +2,79 @@
 #[path = "../../../tests/unit/models/solution/actor_test.rs"]
 mod actor_test;
 
-use crate::models::problem::{Actor, Fleet};
+use crate::models::problem::{Actor, ActorConflicts, Fleet};
 use rosomaxa::prelude::Random;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 
 /// Specifies an entity responsible for providing actors and keeping track of their usage.
 pub struct Registry {
-    available: HashMap<usize, HashSet<Arc<Actor>>>,
-    index: HashMap<Arc<Actor>, usize>,
+    // NOTE available actors are kept as their indices in `all` to have stable iteration order
+    available: BTreeMap<usize, BTreeSet<usize>>,
+    index: HashMap<Arc<Actor>, (usize, usize)>,
     all: Vec<Arc<Actor>>,
+    conflicts: Arc<ActorConflicts>,
+    used: HashSet<Arc<Actor>>,
     random: Arc<dyn Random>,
 }
 
 impl Registry {
     /// Creates a new instance of `Registry`
     pub fn new(fleet: &Fleet, random: Arc<dyn Random>) -> Self {
-        let index = fleet
+        let groups = fleet
             .groups
             .iter()
-            .flat_map(|(group_id, actors)| actors.iter().map(|a| (a.clone(), *group_id)).collect::<Vec<_>>())
-            .collect();
+            .flat_map(|(group_id, actors)| actors.iter().map(move |actor| (actor.clone(), *group_id)))
+            .collect::<HashMap<_, _>>();
 
-        Self { available: fleet.groups.clone(), index, all: fleet.actors.to_vec(), random }
+        let all = fleet.actors.to_vec();
+        let index = all
+            .iter()
+            .enumerate()
+            .filter_map(|(actor_idx, actor)| groups.get(actor).map(|group_id| (actor.clone(), (*group_id, actor_idx))))
+            .collect::<HashMap<_, _>>();
+
+        let available = index.values().fold(BTreeMap::<_, BTreeSet<_>>::new(), |mut acc, &(group_id, actor_idx)| {
+            acc.entry(group_id).or_default().insert(actor_idx);
+            acc
+        });
+
+        Self { available, index, all, conflicts: Arc::new(fleet.conflicts.clone()), used: Default::default(), random }
     }
 
-    /// Removes an actor from the list of available actors.
+    /// Removes an actor from the list of available actors together with actors which share the same
+    /// vehicle or driver shift with it.
     /// Returns whether the actor was present in the registry.
     pub fn use_actor(&mut self, actor: &Actor) -> bool {
-        self.index.get(actor).and_then(|idx| self.available.get_mut(idx)).is_some_and(|set| set.remove(actor))
+        let is_removed = self.remove_available(actor);
+
+        if is_removed && self.conflicts.has_conflicts(actor) {
+            self.conflicts.clone().get(actor).for_each(|other| {
+                self.remove_available(other);
+            });
+            if let Some((actor, _)) = self.index.get_key_value(actor) {
+                self.used.insert(actor.clone());
+            }
+        }
+
+        is_removed
     }
 
-    /// Adds actor to the list of available actors.
+    /// Adds actor to the list of available actors if none of its conflicting actors is used.
+    /// Conflicting actors are also returned back when they are not blocked by other used actors.
     /// Returns whether the actor was not present in the registry.
     pub fn free_actor(&mut self, actor: &Arc<Actor>) -> bool {
-        self.index.get(actor).and_then(|idx| self.available.get_mut(idx)).is_some_and(|set| set.insert(actor.clone()))
+        self.used.remove(actor);
+
+        let is_added = self.add_available(actor);
+
+        self.conflicts.clone().get(actor).for_each(|other| {
+            if !self.used.contains(other) {
+                self.add_available(other);
+            }
+        });
+
+        is_added
     }
 
     /// Returns all actors.
@@ -46,7 +84,7 @@ impl Registry {
 
     /// Returns list of all available actors.
     pub fn available(&'_ self) -> impl Iterator<Item = Arc<Actor>> + '_ {
-        self.available.values().flat_map(|set| set.iter().cloned())
+        self.available.values().flat_map(|set| set.iter().map(|&actor_idx| self.all[actor_idx].clone()))
     }
 
     /// Returns next available actors from each different type.
@@ -54,7 +92,7 @@ impl Registry {
         self.available.values().flat_map(move |set| {
             // NOTE pick a random actor from set of available actors.
             let skip_amount = if set.len() < 2 { 0 } else { self.random.uniform_int(0, set.len() as i32 - 1) as usize };
-            set.iter().skip(skip_amount).take(1).cloned()
+            set.iter().skip(skip_amount).take(1).map(|&actor_idx| self.all[actor_idx].clone())
         })
     }
 
@@ -66,7 +104,7 @@ impl Registry {
     /// that need exhaustive coverage so a feasible insertion is guaranteed to be
     /// found if one exists.
     pub fn next_all(&'_ self) -> impl Iterator<Item = Arc<Actor>> + '_ {
-        self.available.values().flat_map(|set| set.iter().cloned())
+        self.available()
     }
 
     /// Creates a deep copy of registry.
@@ -75,29 +113,59 @@ impl Registry {
             available: self.available.clone(),
             index: self.index.clone(),
             all: self.all.clone(),
+            conflicts: self.conflicts.clone(),
+            used: self.used.clone(),
             random: self.random.clone(),
         }
     }
 
     /// Creates a deep sliced copy of registry keeping only specific actors.
     pub fn deep_slice(&self, filter: impl Fn(&Actor) -> bool) -> Self {
+        let all = self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect::<Vec<_>>();
+        let index = all
+            .iter()
+            .enumerate()
+            .filter_map(|(actor_idx, actor)| {
+                self.index.get(actor).map(|(group_id, _)| (actor.clone(), (*group_id, actor_idx)))
+            })
+            .collect::<HashMap<_, _>>();
+
         Self {
             available: self
                 .available
                 .iter()
-                .map(|(idx, actors)| {
-                    let actors = actors.iter().filter(|actor| filter(actor.as_ref())).cloned().collect::<HashSet<_>>();
-                    (*idx, actors)
+                .map(|(group_id, actors)| {
+                    let actors = actors
+                        .iter()
+                        .filter_map(|&actor_idx| index.get(&self.all[actor_idx]).map(|(_, actor_idx)| *actor_idx))
+                        .collect::<BTreeSet<_>>();
+                    (*group_id, actors)
                 })
                 .collect(),
-            index: self
-                .index
-                .iter()
-                .filter(|(actor, _)| filter(actor.as_ref()))
-                .map(|(actor, idx)| (actor.clone(), *idx))
-                .collect(),
-            all: self.all.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
+            index,
+            all,
+            // NOTE conflicting actors which are not in the index are ignored, so conflicts can be shared
+            conflicts: self.conflicts.clone(),
+            used: self.used.iter().filter(|actor| filter(actor.as_ref())).cloned().collect(),
             random: self.random.clone(),
         }
     }
+
+    fn remove_available(&mut self, actor: &Actor) -> bool {
+        self.index
+            .get(actor)
+            .and_then(|&(group_id, actor_idx)| self.available.get_mut(&group_id).map(|set| set.remove(&actor_idx)))
+            .unwrap_or(false)
+    }
+
+    fn add_available(&mut self, actor: &Arc<Actor>) -> bool {
+        let is_blocked = self.conflicts.get(actor).any(|other| self.used.contains(other));
+
+        !is_blocked
+            && self
+                .index
+                .get(actor)
+                .and_then(|&(group_id, actor_idx)| self.available.get_mut(&group_id).map(|set| set.insert(actor_idx)))
+                .unwrap_or(false)
+    }
 }
@@ -25,7 +25,10 @@ pub struct Tour {
     activities: Vec<Activity>,
 
     /// Stores jobs in the order of their activities added.
-    jobs: HashSet<Job, BuildHasherDefault<FxHasher>>,
+    jobs: Vec<Job>,
+
+    /// Keeps jobs for fast lookup.
+    job_lookup: HashSet<Job, BuildHasherDefault<FxHasher>>,
 
     /// Keeps track whether tour is set as closed.
     is_closed: bool,
@@ -71,7 +74,10 @@ impl Tour {
         assert!(activity.job.is_some());
         assert!(!self.activities.is_empty());
 
-        self.jobs.insert(activity.retrieve_job().unwrap());
+        let job = activity.retrieve_job().unwrap();
+        if self.job_lookup.insert(job.clone()) {
+            self.jobs.push(job);
+        }
         self.activities.insert(index, activity);
 
         self
@@ -80,7 +86,13 @@ impl Tour {
     /// Removes job within its activities from the tour.
     pub fn remove(&mut self, job: &Job) -> bool {
         self.activities.retain(|a| !a.has_same_job(job));
-        self.jobs.remove(job)
+
+        let is_removed = self.job_lookup.remove(job);
+        if is_removed {
+            self.jobs.retain(|other| other != job);
+        }
+
+        is_removed
     }
 
     /// Removes activity and its job from the tour.
@@ -168,7 +180,7 @@ impl Tour {
 
     /// Checks whether job is present in tour
     pub fn contains(&self, job: &Job) -> bool {
-        self.jobs.contains(job)
+        self.job_lookup.contains(job)
     }
 
     /// Returns index of first job occurrence in the tour.
@@ -183,7 +195,7 @@ impl Tour {
 
     /// Checks whether job is present in tour.
     pub fn has_job(&self, job: &Job) -> bool {
-        self.jobs.contains(job)
+        self.job_lookup.contains(job)
     }
 
     /// Checks whether tour has jobs.
@@ -211,6 +223,7 @@ impl Tour {
         Tour {
             activities: self.activities.iter().map(|a| a.deep_copy()).collect(),
             jobs: self.jobs.clone(),
+            job_lookup: self.job_lookup.clone(),
             is_closed: self.is_closed,
         }
     }
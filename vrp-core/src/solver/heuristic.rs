@@ -856,6 +856,8 @@ mod statik {
                 (Arc::new(ExchangeSwapStar::new(random)), 200),
                 (Arc::new(ExchangeInterRouteBest::default()), 100),
                 (Arc::new(ExchangeSequence::default()), 100),
+                (Arc::new(ExchangeTails::default()), 50),
+                (Arc::new(RelocateSegment::default()), 50),
                 (Arc::new(ExchangeInterRouteRandom::default()), 30),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 30),
                 (Arc::new(RescheduleDeparture::default()), 20),
@@ -1259,6 +1261,8 @@ mod dynamic {
                 (Arc::new(ExchangeInterRouteRandom::default()), 1),
                 (Arc::new(ExchangeIntraRouteRandom::default()), 1),
                 (Arc::new(ExchangeSequence::default()), 1),
+                (Arc::new(ExchangeTails::default()), 1),
+                (Arc::new(RelocateSegment::default()), 1),
             ],
             1,
             1,
@@ -177,6 +177,10 @@ impl HeuristicContext for RefinementContext {
     fn on_result(self) -> HeuristicResult<Self::Objective, Self::Solution> {
         self.inner_context.on_result()
     }
+
+    fn on_resume(&mut self, checkpoint: &EvolutionCheckpoint) {
+        self.inner_context.on_resume(checkpoint)
+    }
 }
 
 impl Stateful for RefinementContext {
@@ -0,0 +1,150 @@
+#[cfg(test)]
+#[path = "../../../tests/unit/solver/search/crossover_search_test.rs"]
+mod crossover_search_test;
+
+use super::*;
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use rosomaxa::HeuristicContext;
+use rosomaxa::prelude::HeuristicObjective;
+use rosomaxa::utils::SelectionSamplingIterator;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+/// A search operator which recombines two solutions using selective route exchange crossover (SREX):
+/// a few routes of another solution from population are copied into the given one, their jobs are
+/// removed from the rest of routes and jobs left without route are inserted back using recreate method.
+pub struct CrossoverSearch {
+    recreate: Arc<dyn Recreate>,
+}
+
+impl CrossoverSearch {
+    /// Creates a new instance of `CrossoverSearch`.
+    pub fn new(recreate: Arc<dyn Recreate>) -> Self {
+        Self { recreate }
+    }
+}
+
+impl HeuristicSearchOperator for CrossoverSearch {
+    type Context = RefinementContext;
+    type Objective = GoalContext;
+    type Solution = InsertionContext;
+
+    fn search(&self, heuristic_ctx: &Self::Context, solution: &Self::Solution) -> Self::Solution {
+        let refinement_ctx = heuristic_ctx;
+
+        let Some(donor) = select_donor(refinement_ctx, solution) else {
+            return solution.deep_copy();
+        };
+
+        let route_indices = select_donor_routes(solution, donor);
+        if route_indices.is_empty() {
+            return solution.deep_copy();
+        }
+
+        let mut insertion_ctx = solution.deep_copy();
+        exchange_routes(&mut insertion_ctx, donor, route_indices.as_slice());
+
+        let mut insertion_ctx = self.recreate.run(refinement_ctx, insertion_ctx);
+
+        finalize_insertion_ctx(&mut insertion_ctx);
+
+        insertion_ctx
+    }
+}
+
+/// Selects a random solution from population which is different from the given one.
+fn select_donor<'a>(
+    refinement_ctx: &'a RefinementContext,
+    solution: &InsertionContext,
+) -> Option<&'a InsertionContext> {
+    let goal = solution.problem.goal.as_ref();
+    let candidates = refinement_ctx
+        .ranked()
+        .filter(|other| Arc::ptr_eq(&other.problem, &solution.problem))
+        .filter(|other| goal.total_order(other, solution) != Ordering::Equal)
+        .collect::<Vec<_>>();
+
+    if candidates.is_empty() {
+        return None;
+    }
+
+    let idx = solution.environment.random.uniform_int(0, candidates.len() as i32 - 1) as usize;
+
+    candidates.get(idx).copied()
+}
+
+/// Selects indices of donor routes to be copied: up to half of routes without locked jobs.
+fn select_donor_routes(solution: &InsertionContext, donor: &InsertionContext) -> Vec<usize> {
+    let random = solution.environment.random.clone();
+    let locked = &solution.solution.locked;
+
+    let candidates = donor
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| {
+            let tour = &route_ctx.route().tour;
+            tour.has_jobs() && !tour.jobs().any(|job| locked.contains(job))
+        })
+        .map(|(idx, _)| idx)
+        .collect::<Vec<_>>();
+
+    if candidates.is_empty() {
+        return Vec::default();
+    }
+
+    let amount = random.uniform_int(1, (candidates.len() / 2).max(1) as i32) as usize;
+
+    SelectionSamplingIterator::new(candidates.into_iter(), amount, random).collect()
+}
+
+/// Copies donor routes into the solution replacing routes of the same actors. Jobs which are left
+/// without route are marked as required.
+fn exchange_routes(insertion_ctx: &mut InsertionContext, donor: &InsertionContext, route_indices: &[usize]) {
+    let solution = &mut insertion_ctx.solution;
+    let mut removed = Vec::new();
+
+    route_indices.iter().filter_map(|idx| donor.solution.routes.get(*idx)).for_each(|donor_route| {
+        let actor = &donor_route.route().actor;
+
+        if let Some(position) = solution.routes.iter().position(|route_ctx| route_ctx.route().actor == *actor) {
+            let has_locked_jobs =
+                solution.routes[position].route().tour.jobs().any(|job| solution.locked.contains(job));
+            if has_locked_jobs {
+                return;
+            }
+
+            let route_ctx = solution.routes.remove(position);
+            removed.extend(route_ctx.route().tour.jobs().cloned());
+            solution.registry.free_route(route_ctx);
+        }
+
+        if !solution.registry.use_route(donor_route) {
+            return;
+        }
+
+        let donor_jobs = donor_route.route().tour.jobs().cloned().collect::<HashSet<_>>();
+
+        solution.routes.iter_mut().for_each(|route_ctx| {
+            let jobs =
+                route_ctx.route().tour.jobs().filter(|job| donor_jobs.contains(*job)).cloned().collect::<Vec<_>>();
+            jobs.iter().for_each(|job| {
+                route_ctx.route_mut().tour.remove(job);
+            });
+        });
+        solution.required.retain(|job| !donor_jobs.contains(job));
+        solution.ignored.retain(|job| !donor_jobs.contains(job));
+        solution.unassigned.retain(|job, _| !donor_jobs.contains(job));
+
+        solution.routes.push(donor_route.deep_copy());
+    });
+
+    let assigned =
+        solution.routes.iter().flat_map(|route_ctx| route_ctx.route().tour.jobs().cloned()).collect::<HashSet<Job>>();
+    solution.required.extend(removed.into_iter().filter(|job| !assigned.contains(job)));
+
+    solution.remove_empty_routes();
+}
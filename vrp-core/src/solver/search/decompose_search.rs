@@ -178,7 +178,10 @@ fn create_partial_insertion_ctx(
 ) -> (InsertionContext, HashSet<usize>) {
     let solution = &insertion_ctx.solution;
 
-    let routes = route_indices.iter().map(|idx| solution.routes[*idx].deep_copy()).collect::<Vec<_>>();
+    // NOTE keep routes in their original order as hash set iteration order is not stable
+    let mut sorted_indices = route_indices.iter().copied().collect::<Vec<_>>();
+    sorted_indices.sort_unstable();
+    let routes = sorted_indices.into_iter().map(|idx| solution.routes[idx].deep_copy()).collect::<Vec<_>>();
     let actors = routes.iter().map(|route_ctx| route_ctx.route().actor.clone()).collect::<HashSet<_>>();
     let registry = solution.registry.deep_slice(|actor| actors.contains(actor));
 
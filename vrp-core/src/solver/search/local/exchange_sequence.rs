@@ -6,10 +6,9 @@ use crate::construction::heuristics::*;
 use crate::models::problem::Job;
 use crate::solver::RefinementContext;
 use crate::solver::search::LocalOperator;
+use crate::solver::search::local::{get_jobs_in_order, insert_jobs_in_order};
 use rand::prelude::SliceRandom;
 use rosomaxa::prelude::*;
-use std::collections::HashSet;
-use std::ops::ControlFlow;
 
 const MIN_JOBS: usize = 2;
 
@@ -116,18 +115,7 @@ fn extract_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, sequence
 
     assert!(job_count >= sequence_size);
 
-    // get jobs in the exact order as they appear first time in the tour
-    let (_, jobs) = route_ctx.route().tour.all_activities().filter_map(|activity| activity.retrieve_job()).fold(
-        (HashSet::<Job>::default(), Vec::with_capacity(job_count)),
-        |(mut set, mut vec), job| {
-            if !set.contains(&job) && !locked.contains(&job) {
-                vec.push(job.clone());
-                set.insert(job);
-            }
-
-            (set, vec)
-        },
-    );
+    let jobs = get_jobs_in_order(route_ctx, locked);
 
     let sequence_size = sequence_size.min(jobs.len());
     let last_index = jobs.len() - sequence_size;
@@ -155,8 +143,6 @@ fn insert_jobs(
     shuffle_prob: Float,
 ) {
     let random = &insertion_ctx.environment.random;
-    let leg_selection = LegSelection::Stochastic(random.clone());
-    let result_selector = BestResultSelector::default();
 
     let mut jobs = jobs;
     match (random.is_hit(reverse_prob), random.is_hit(shuffle_prob)) {
@@ -173,50 +159,7 @@ fn insert_jobs(
         .uniform_int(0, get_route_ctx(insertion_ctx, route_idx).route().tour.job_activity_count() as i32)
         as usize;
 
-    let (failures, _) = jobs.into_iter().fold((Vec::new(), start_index), |(mut unassigned, start_index), job| {
-        let eval_ctx = EvaluationContext {
-            goal: &insertion_ctx.problem.goal,
-            job: &job,
-            leg_selection: &leg_selection,
-            result_selector: &result_selector,
-        };
-
-        // reevaluate last insertion point
-        let last_index = get_route_ctx(insertion_ctx, route_idx).route().tour.job_activity_count();
-        // try to find success insertion starting from given point
-        let (result, start_index) = (start_index..=last_index)
-            .try_fold((InsertionResult::make_failure(), start_index), |_, insertion_idx| {
-                let insertion = eval_job_insertion_in_route(
-                    insertion_ctx,
-                    &eval_ctx,
-                    get_route_ctx(insertion_ctx, route_idx),
-                    InsertionPosition::Concrete(insertion_idx),
-                    // NOTE we don't try to insert the best, so alternative is a failure
-                    InsertionResult::make_failure(),
-                );
-
-                match &insertion {
-                    InsertionResult::Failure(_) => ControlFlow::Continue((insertion, insertion_idx)),
-                    InsertionResult::Success(_) => ControlFlow::Break((insertion, insertion_idx)),
-                }
-            })
-            .unwrap_value();
-
-        match result {
-            InsertionResult::Success(success) => {
-                apply_insertion_success(insertion_ctx, success);
-            }
-            InsertionResult::Failure(failure) => unassigned.push((job, failure)),
-        }
-
-        (unassigned, start_index + 1)
-    });
-
-    insertion_ctx.solution.unassigned.extend(failures.into_iter().map(|(job, failure)| {
-        let code = UnassignmentInfo::Simple(failure.constraint);
-        let job = failure.job.unwrap_or(job);
-        (job, code)
-    }));
+    insert_jobs_in_order(insertion_ctx, route_idx, jobs, start_index);
 }
 
 fn get_route_ctx(insertion_ctx: &InsertionContext, route_idx: usize) -> &RouteContext {
@@ -0,0 +1,91 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/exchange_tails_test.rs"]
+mod exchange_tails_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::solver::RefinementContext;
+use crate::solver::search::LocalOperator;
+use crate::solver::search::local::{get_jobs_in_order, insert_jobs_in_order};
+use rosomaxa::prelude::*;
+
+/// A local search operator which implements 2-opt* move: it splits two routes at random positions
+/// and exchanges their tails keeping jobs order. Locked jobs stay in their routes.
+#[derive(Default)]
+pub struct ExchangeTails {}
+
+impl LocalOperator for ExchangeTails {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let route_indices = get_route_indices(insertion_ctx);
+
+        if route_indices.len() < 2 {
+            return None;
+        }
+
+        let random = insertion_ctx.environment.random.as_ref();
+        let first_idx = random.uniform_int(0, route_indices.len() as i32 - 1) as usize;
+        let second_idx = random.uniform_int(0, route_indices.len() as i32 - 2) as usize;
+        let second_idx = if second_idx >= first_idx { second_idx + 1 } else { second_idx };
+
+        let get_cut = |route_idx: usize| {
+            let route_ctx = &insertion_ctx.solution.routes[route_idx];
+            let job_count = get_jobs_in_order(route_ctx, &insertion_ctx.solution.locked).len();
+
+            random.uniform_int(0, job_count as i32) as usize
+        };
+
+        let first = (route_indices[first_idx], get_cut(route_indices[first_idx]));
+        let second = (route_indices[second_idx], get_cut(route_indices[second_idx]));
+
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+
+        exchange_tails(&mut insertion_ctx, first, second);
+
+        Some(insertion_ctx)
+    }
+}
+
+fn get_route_indices(insertion_ctx: &InsertionContext) -> Vec<usize> {
+    let locked = &insertion_ctx.solution.locked;
+
+    insertion_ctx
+        .solution
+        .routes
+        .iter()
+        .enumerate()
+        .filter(|(_, route_ctx)| route_ctx.route().tour.jobs().any(|job| !locked.contains(job)))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// Exchanges tails of two routes specified by route index and position of the first tail job.
+fn exchange_tails(insertion_ctx: &mut InsertionContext, first: (usize, usize), second: (usize, usize)) {
+    let first_tail = extract_tail(insertion_ctx, first.0, first.1);
+    let second_tail = extract_tail(insertion_ctx, second.0, second.1);
+
+    append_jobs(insertion_ctx, first.0, second_tail);
+    append_jobs(insertion_ctx, second.0, first_tail);
+
+    insertion_ctx.solution.remove_empty_routes();
+    finalize_insertion_ctx(insertion_ctx);
+}
+
+fn extract_tail(insertion_ctx: &mut InsertionContext, route_idx: usize, cut: usize) -> Vec<Job> {
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).unwrap();
+    let jobs = get_jobs_in_order(route_ctx, &insertion_ctx.solution.locked);
+    let tail = jobs.into_iter().skip(cut).collect::<Vec<_>>();
+
+    tail.iter().for_each(|job| {
+        assert!(route_ctx.route_mut().tour.remove(job));
+    });
+
+    insertion_ctx.problem.goal.accept_route_state(route_ctx);
+
+    tail
+}
+
+fn append_jobs(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: Vec<Job>) {
+    let start_index = insertion_ctx.solution.routes[route_idx].route().tour.job_activity_count();
+
+    insert_jobs_in_order(insertion_ctx, route_idx, jobs, start_index);
+}
@@ -1,9 +1,12 @@
 //! This module contains various Local Search operators.
 
 use crate::construction::heuristics::*;
+use crate::models::problem::Job;
 use crate::solver::RefinementContext;
 use rosomaxa::prelude::*;
 use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 
 mod exchange_inter_route;
@@ -18,6 +21,12 @@ pub use self::exchange_sequence::*;
 mod exchange_swap_star;
 pub use self::exchange_swap_star::*;
 
+mod exchange_tails;
+pub use self::exchange_tails::*;
+
+mod relocate_segment;
+pub use self::relocate_segment::*;
+
 mod reschedule_departure;
 pub use self::reschedule_departure::*;
 
@@ -94,3 +103,72 @@ fn apply_insertion_with_route(insertion_ctx: &mut InsertionContext, result: (Ins
 
     apply_insertion_success(insertion_ctx, success)
 }
+
+/// Returns not locked jobs of the route in the exact order as they appear first time in the tour.
+fn get_jobs_in_order(route_ctx: &RouteContext, locked: &HashSet<Job>) -> Vec<Job> {
+    let (_, jobs) = route_ctx.route().tour.all_activities().filter_map(|activity| activity.retrieve_job()).fold(
+        (HashSet::<Job>::default(), Vec::with_capacity(route_ctx.route().tour.job_count())),
+        |(mut set, mut vec), job| {
+            if !set.contains(&job) && !locked.contains(&job) {
+                vec.push(job.clone());
+                set.insert(job);
+            }
+
+            (set, vec)
+        },
+    );
+
+    jobs
+}
+
+/// Inserts jobs into the route keeping their order: each job is inserted at the first feasible position
+/// starting from the position after previously inserted one. Jobs which cannot be inserted are unassigned.
+fn insert_jobs_in_order(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: Vec<Job>, start_index: usize) {
+    let leg_selection = LegSelection::Stochastic(insertion_ctx.environment.random.clone());
+    let result_selector = BestResultSelector::default();
+
+    let (failures, _) = jobs.into_iter().fold((Vec::new(), start_index), |(mut unassigned, start_index), job| {
+        let eval_ctx = EvaluationContext {
+            goal: &insertion_ctx.problem.goal,
+            job: &job,
+            leg_selection: &leg_selection,
+            result_selector: &result_selector,
+        };
+
+        // reevaluate last insertion point
+        let last_index = insertion_ctx.solution.routes[route_idx].route().tour.job_activity_count();
+        // try to find success insertion starting from given point
+        let (result, start_index) = (start_index..=last_index)
+            .try_fold((InsertionResult::make_failure(), start_index), |_, insertion_idx| {
+                let insertion = eval_job_insertion_in_route(
+                    insertion_ctx,
+                    &eval_ctx,
+                    &insertion_ctx.solution.routes[route_idx],
+                    InsertionPosition::Concrete(insertion_idx),
+                    // NOTE we don't try to insert the best, so alternative is a failure
+                    InsertionResult::make_failure(),
+                );
+
+                match &insertion {
+                    InsertionResult::Failure(_) => ControlFlow::Continue((insertion, insertion_idx)),
+                    InsertionResult::Success(_) => ControlFlow::Break((insertion, insertion_idx)),
+                }
+            })
+            .unwrap_value();
+
+        match result {
+            InsertionResult::Success(success) => {
+                apply_insertion_success(insertion_ctx, success);
+            }
+            InsertionResult::Failure(failure) => unassigned.push((job, failure)),
+        }
+
+        (unassigned, start_index + 1)
+    });
+
+    insertion_ctx.solution.unassigned.extend(failures.into_iter().map(|(job, failure)| {
+        let code = UnassignmentInfo::Simple(failure.constraint);
+        let job = failure.job.unwrap_or(job);
+        (job, code)
+    }));
+}
@@ -0,0 +1,142 @@
+#[cfg(test)]
+#[path = "../../../../tests/unit/solver/search/local/relocate_segment_test.rs"]
+mod relocate_segment_test;
+
+use crate::construction::heuristics::*;
+use crate::models::problem::Job;
+use crate::solver::RefinementContext;
+use crate::solver::search::LocalOperator;
+use crate::solver::search::local::{get_jobs_in_order, insert_jobs_in_order};
+use rosomaxa::prelude::*;
+use std::ops::Range;
+
+/// A local search operator which implements Or-opt move: it removes a short segment of consecutive jobs
+/// from one route and inserts it, optionally reversed, into the best position of the same or another route.
+pub struct RelocateSegment {
+    max_segment_size: usize,
+    reverse_prob: Float,
+}
+
+impl RelocateSegment {
+    /// Creates a new instance of `RelocateSegment`.
+    pub fn new(max_segment_size: usize, reverse_prob: Float) -> Self {
+        assert!(max_segment_size > 0);
+
+        Self { max_segment_size, reverse_prob }
+    }
+}
+
+impl Default for RelocateSegment {
+    fn default() -> Self {
+        Self::new(3, 0.5)
+    }
+}
+
+impl LocalOperator for RelocateSegment {
+    fn explore(&self, _: &RefinementContext, insertion_ctx: &InsertionContext) -> Option<InsertionContext> {
+        let locked = &insertion_ctx.solution.locked;
+        let route_indices = insertion_ctx
+            .solution
+            .routes
+            .iter()
+            .enumerate()
+            .filter(|(_, route_ctx)| route_ctx.route().tour.jobs().any(|job| !locked.contains(job)))
+            .map(|(idx, _)| idx)
+            .collect::<Vec<_>>();
+
+        if route_indices.is_empty() {
+            return None;
+        }
+
+        let random = insertion_ctx.environment.random.as_ref();
+        let source_idx = route_indices[random.uniform_int(0, route_indices.len() as i32 - 1) as usize];
+        let target_idx = random.uniform_int(0, insertion_ctx.solution.routes.len() as i32 - 1) as usize;
+
+        let job_count = get_jobs_in_order(&insertion_ctx.solution.routes[source_idx], locked).len();
+        let segment_size = random.uniform_int(1, job_count.min(self.max_segment_size) as i32) as usize;
+        let segment_start = random.uniform_int(0, (job_count - segment_size) as i32) as usize;
+        let is_reversed = random.is_hit(self.reverse_prob);
+
+        let mut insertion_ctx = insertion_ctx.deep_copy();
+
+        relocate_segment(
+            &mut insertion_ctx,
+            source_idx,
+            target_idx,
+            segment_start..segment_start + segment_size,
+            is_reversed,
+        );
+
+        Some(insertion_ctx)
+    }
+}
+
+/// Moves segment of jobs specified by range of their indices from source route into target route.
+fn relocate_segment(
+    insertion_ctx: &mut InsertionContext,
+    source_idx: usize,
+    target_idx: usize,
+    segment: Range<usize>,
+    is_reversed: bool,
+) {
+    let mut jobs = extract_segment(insertion_ctx, source_idx, segment);
+    if is_reversed {
+        jobs.reverse();
+    }
+
+    // NOTE when the first job cannot be inserted at the best position, try the whole segment from the route start
+    let (jobs, start_index) = match insert_first_job(insertion_ctx, target_idx, &jobs) {
+        Some(start_index) => (jobs.into_iter().skip(1).collect(), start_index),
+        None => (jobs, 0),
+    };
+
+    insert_jobs_in_order(insertion_ctx, target_idx, jobs, start_index);
+
+    insertion_ctx.solution.remove_empty_routes();
+    finalize_insertion_ctx(insertion_ctx);
+}
+
+fn extract_segment(insertion_ctx: &mut InsertionContext, route_idx: usize, segment: Range<usize>) -> Vec<Job> {
+    let route_ctx = insertion_ctx.solution.routes.get_mut(route_idx).unwrap();
+    let jobs = get_jobs_in_order(route_ctx, &insertion_ctx.solution.locked);
+    let segment = jobs[segment].to_vec();
+
+    segment.iter().for_each(|job| {
+        assert!(route_ctx.route_mut().tour.remove(job));
+    });
+
+    insertion_ctx.problem.goal.accept_route_state(route_ctx);
+
+    segment
+}
+
+/// Inserts the first job of segment at the best position and returns the position for the next one.
+fn insert_first_job(insertion_ctx: &mut InsertionContext, route_idx: usize, jobs: &[Job]) -> Option<usize> {
+    let job = jobs.first()?;
+    let leg_selection = LegSelection::Stochastic(insertion_ctx.environment.random.clone());
+    let result_selector = BestResultSelector::default();
+    let eval_ctx = EvaluationContext {
+        goal: &insertion_ctx.problem.goal,
+        job,
+        leg_selection: &leg_selection,
+        result_selector: &result_selector,
+    };
+
+    let result = eval_job_insertion_in_route(
+        insertion_ctx,
+        &eval_ctx,
+        &insertion_ctx.solution.routes[route_idx],
+        InsertionPosition::Any,
+        InsertionResult::make_failure(),
+    );
+
+    match result {
+        InsertionResult::Success(success) => {
+            let start_index = success.activities.iter().map(|(_, idx)| idx + 1).max();
+            apply_insertion_success(insertion_ctx, success);
+
+            start_index
+        }
+        InsertionResult::Failure(_) => None,
+    }
+}
@@ -1,3 +1,4 @@
+use crate::construction::features::has_resource_occupancy;
 use crate::construction::heuristics::InsertionContext;
 use crate::models::GoalContext;
 use crate::solver::RefinementContext;
@@ -27,7 +28,13 @@ impl HeuristicSearchOperator for LocalSearch {
         let insertion_ctx = solution;
 
         match self.operator.explore(refinement_ctx, insertion_ctx) {
-            Some(new_insertion_ctx) => new_insertion_ctx,
+            Some(mut new_insertion_ctx) => {
+                // NOTE operators update only route states, shared resource usage has to be restored on solution level
+                if has_resource_occupancy(&new_insertion_ctx.solution) {
+                    new_insertion_ctx.restore();
+                }
+                new_insertion_ctx
+            }
             _ => insertion_ctx.deep_copy(),
         }
     }
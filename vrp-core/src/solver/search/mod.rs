@@ -25,6 +25,9 @@ pub use self::ruin::*;
 mod utils;
 pub(crate) use self::utils::*;
 
+mod crossover_search;
+pub use self::crossover_search::CrossoverSearch;
+
 mod decompose_search;
 pub use self::decompose_search::DecomposeSearch;
 
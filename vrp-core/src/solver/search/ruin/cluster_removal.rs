@@ -9,6 +9,7 @@ use crate::models::problem::Job;
 use crate::solver::RefinementContext;
 use crate::solver::search::{JobRemovalTracker, TabuList, get_route_jobs};
 use std::cell::RefCell;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 /// A ruin strategy which removes job clusters using DBSCAN algorithm.
@@ -20,12 +21,17 @@ pub struct ClusterRemoval {
 impl ClusterRemoval {
     /// Creates a new instance of `ClusterRemoval`.
     pub fn new(problem: Arc<Problem>, limits: RemovalLimits) -> GenericResult<Self> {
+        // NOTE keep jobs in the problem order as hash set iteration order is not stable
+        let job_order = problem.jobs.all().iter().enumerate().map(|(idx, job)| (job, idx)).collect::<HashMap<_, _>>();
         let clusters = problem
             .jobs
             .clusters()
             .iter()
-            .cloned()
-            .map(|cluster| cluster.into_iter().collect::<Vec<_>>())
+            .map(|cluster| {
+                let mut cluster = cluster.iter().cloned().collect::<Vec<_>>();
+                cluster.sort_by_key(|job| job_order.get(job).copied().unwrap_or(usize::MAX));
+                cluster
+            })
             .collect::<Vec<_>>();
 
         Ok(Self { clusters, limits })
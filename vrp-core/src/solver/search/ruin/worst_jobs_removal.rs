@@ -85,23 +85,26 @@ impl Ruin for WorstJobRemoval {
 fn get_routes_cost_savings(insertion_ctx: &InsertionContext) -> Vec<(Profile, Vec<(Job, Cost)>)> {
     parallel_collect(&insertion_ctx.solution.routes, |route_ctx| {
         let route = route_ctx.route();
-        let mut savings: Vec<(Job, Cost)> = route
-            .tour
-            .all_activities()
-            .as_slice()
-            .windows(3)
-            .fold(HashMap::<Job, Cost>::default(), |mut acc, iter| match iter {
+        // NOTE keep jobs in the order they appear first time in the tour to have stable sorting
+        let (_, mut savings) = route.tour.all_activities().as_slice().windows(3).fold(
+            (HashMap::<Job, usize>::default(), Vec::<(Job, Cost)>::default()),
+            |(mut indices, mut savings), iter| match iter {
                 [start, eval, end] => {
-                    let savings = get_cost_savings(route, start, eval, end, &insertion_ctx.problem.transport);
+                    let cost = get_cost_savings(route, start, eval, end, &insertion_ctx.problem.transport);
                     let job = eval.retrieve_job().unwrap_or_else(|| panic!("Unexpected activity without job"));
-                    *acc.entry(job).or_insert(0.) += savings;
+                    match indices.get(&job) {
+                        Some(&idx) => savings[idx].1 += cost,
+                        None => {
+                            indices.insert(job.clone(), savings.len());
+                            savings.push((job, cost));
+                        }
+                    }
 
-                    acc
+                    (indices, savings)
                 }
                 _ => panic!("Unexpected activity window"),
-            })
-            .drain()
-            .collect();
+            },
+        );
         savings.sort_by(|(_, a), (_, b)| b.total_cmp(a));
 
         (route_ctx.route().actor.vehicle.profile.clone(), savings)
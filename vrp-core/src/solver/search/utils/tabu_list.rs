@@ -11,8 +11,8 @@ custom_solution_state!(TabuList typeof TabuList);
 /// A simple solution's tabu list to keep track of recently affected jobs and actors.
 #[derive(Clone)]
 pub struct TabuList {
-    actors: HashSet<Arc<Actor>>,
-    jobs: HashSet<Job>,
+    actors: TabuItems<Arc<Actor>>,
+    jobs: TabuItems<Job>,
     max_actors: usize,
     max_jobs: usize,
     random: Arc<dyn Random>,
@@ -31,12 +31,12 @@ impl TabuList {
 
     /// Checks whether given an actor is in the tabu list.
     pub fn is_actor_tabu(&self, actor: &Actor) -> bool {
-        self.actors.contains(actor)
+        self.actors.lookup.contains(actor)
     }
 
     /// Checks whether given a job is in the tabu list.
     pub fn is_job_tabu(&self, job: &Job) -> bool {
-        self.jobs.contains(job)
+        self.jobs.lookup.contains(job)
     }
 
     /// Stores tabu list in insertion ctx.
@@ -73,22 +73,37 @@ impl From<&InsertionContext> for TabuList {
     }
 }
 
+/// Keeps tabu items in insertion order to have a stable eviction when the same random is used.
+#[derive(Clone)]
+struct TabuItems<T> {
+    items: Vec<T>,
+    lookup: HashSet<T>,
+}
+
+impl<T> Default for TabuItems<T> {
+    fn default() -> Self {
+        Self { items: Vec::default(), lookup: HashSet::default() }
+    }
+}
+
 fn add_with_limits<T: Clone + Eq + PartialEq + Hash>(
     new_item: T,
-    old_items: &mut HashSet<T>,
+    old_items: &mut TabuItems<T>,
     limits: usize,
     random: &dyn Random,
 ) {
     // NOTE do not use tabu list when limit is zero
-    if limits == 0 {
+    if limits == 0 || old_items.lookup.contains(&new_item) {
         return;
     }
 
-    if old_items.len() == limits
-        && let Some(item) = old_items.iter().choose(&mut random.get_rng()).cloned()
+    if old_items.items.len() == limits
+        && let Some(idx) = (0..limits).choose(&mut random.get_rng())
     {
-        old_items.remove(&item);
+        let item = old_items.items.swap_remove(idx);
+        old_items.lookup.remove(&item);
     }
 
-    old_items.insert(new_item);
+    old_items.lookup.insert(new_item.clone());
+    old_items.items.push(new_item);
 }
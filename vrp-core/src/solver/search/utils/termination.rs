@@ -27,5 +27,6 @@ pub fn create_environment_with_custom_quota(limit: Option<usize>, environment: &
         parallelism: environment.parallelism.clone(),
         logger: environment.logger.clone(),
         is_experimental: environment.is_experimental,
+        is_deterministic: environment.is_deterministic,
     })
 }
@@ -49,7 +49,12 @@ fn can_advance_departure_time_impl(
             )
             .build();
 
-        let departure_time = try_advance_departure_time(&route_ctx, &TestTransportCost::default(), optimize_whole_tour);
+        let departure_time = try_advance_departure_time(
+            &route_ctx,
+            &SimpleActivityCost::default(),
+            &TestTransportCost::default(),
+            optimize_whole_tour,
+        );
 
         assert_eq!(departure_time, expected);
     } else {
@@ -113,3 +118,55 @@ fn can_recede_departure_time_impl(
 
     assert_eq!(departure_time, expected);
 }
+
+parameterized_test! {can_advance_departure_time_with_time_dependent_costs, (optimize_whole_tour, tw, expected), {
+    can_advance_departure_time_with_time_dependent_costs_impl(optimize_whole_tour, TimeWindow::new(tw.0, tw.1), expected);
+}}
+
+can_advance_departure_time_with_time_dependent_costs! {
+    case01_arrive_at_tw_start: (false, (30., 100.), Some(28.)),
+    case02_avoid_traffic_jam: (true, (20., 25.), Some(5.)),
+}
+
+fn can_advance_departure_time_with_time_dependent_costs_impl(
+    optimize_whole_tour: bool,
+    tw: TimeWindow,
+    expected: Option<Float>,
+) {
+    let size = 11;
+    let create_matrix = |timestamp: Timestamp, duration: Duration| MatrixData {
+        index: 0,
+        timestamp: Some(timestamp),
+        durations: vec![duration; size * size],
+        distances: vec![1.; size * size],
+    };
+    let matrices = if optimize_whole_tour {
+        vec![create_matrix(0., 10.), create_matrix(10., 20.)]
+    } else {
+        vec![create_matrix(0., 10.), create_matrix(20., 2.)]
+    };
+    let transport = create_matrix_transport_cost(matrices).unwrap();
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(Vehicle {
+            details: vec![VehicleDetail {
+                start: Some(VehiclePlace { location: 0, time: TimeInterval { earliest: Some(0.), latest: None } }),
+                end: None,
+            }],
+            ..test_vehicle_with_id("v1")
+        })
+        .build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(ActivityBuilder::with_location_and_tw(10, tw).build())
+                .build(),
+        )
+        .build();
+
+    let departure_time =
+        try_advance_departure_time(&route_ctx, &SimpleActivityCost::default(), transport.as_ref(), optimize_whole_tour);
+
+    assert_eq!(departure_time, expected);
+}
@@ -0,0 +1,170 @@
+use super::*;
+use crate::construction::enablers::{LatestArrivalActivityState, update_route_schedule};
+use crate::helpers::models::problem::{TestActivityCost, TestSingleBuilder, TestTransportCost};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+
+fn create_dwell_time(setup: Duration) -> DwellTime {
+    DwellTime { setup, per_unit: vec![2., 1.] }
+}
+
+fn create_activity(location: Location, dwell_time: Option<DwellTime>) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location));
+    if let Some(dwell_time) = dwell_time {
+        builder.dimens_mut().set_job_dwell_time(dwell_time);
+    }
+
+    ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::max(), 15.)
+        .job(Some(builder.build_shared()))
+        .build()
+}
+
+parameterized_test! {can_get_dwell_duration, (demand, expected), {
+    can_get_dwell_duration_impl(demand, expected);
+}}
+
+can_get_dwell_duration! {
+    case01_no_demand: (vec![], 5.),
+    case02_single_dimension: (vec![3], 11.),
+    case03_two_dimensions: (vec![3, 2], 13.),
+    case04_negative_demand: (vec![-3, 0], 11.),
+    case05_more_dimensions: (vec![1, 1, 10], 8.),
+}
+
+fn can_get_dwell_duration_impl(demand: Vec<i32>, expected: Duration) {
+    assert_eq!(create_dwell_time(5.).get_duration(demand.as_slice()), expected);
+}
+
+parameterized_test! {can_get_shared_setup, (prev, activity, expected), {
+    can_get_shared_setup_impl(prev, activity, expected);
+}}
+
+can_get_shared_setup! {
+    case01_same_location: ((10, Some(5.)), (10, Some(5.)), 5.),
+    case02_different_location: ((10, Some(5.)), (11, Some(5.)), 0.),
+    case03_prev_without_dwell: ((10, None), (10, Some(5.)), 0.),
+    case04_target_without_dwell: ((10, Some(5.)), (10, None), 0.),
+    case05_prev_smaller_setup: ((10, Some(3.)), (10, Some(5.)), 3.),
+    case06_prev_bigger_setup: ((10, Some(7.)), (10, Some(5.)), 5.),
+}
+
+fn can_get_shared_setup_impl(
+    prev: (Location, Option<Duration>),
+    activity: (Location, Option<Duration>),
+    expected: Duration,
+) {
+    let prev = create_activity(prev.0, prev.1.map(create_dwell_time));
+    let activity = create_activity(activity.0, activity.1.map(create_dwell_time));
+
+    assert_eq!(get_shared_setup(&prev, &activity), expected);
+}
+
+parameterized_test! {can_charge_setup_once_per_stop_in_schedule, (second, expected), {
+    can_charge_setup_once_per_stop_in_schedule_impl(second, expected);
+}}
+
+can_charge_setup_once_per_stop_in_schedule! {
+    case01_same_stop: ((10, Some(5.)), (25., 35.)),
+    case02_same_stop_no_dwell: ((10, None), (25., 40.)),
+    case03_different_stop: ((15, Some(5.)), (30., 45.)),
+}
+
+fn can_charge_setup_once_per_stop_in_schedule_impl(
+    second: (Location, Option<Duration>),
+    expected: (Timestamp, Timestamp),
+) {
+    let activities = vec![
+        create_activity(10, Some(create_dwell_time(5.))),
+        create_activity(second.0, second.1.map(create_dwell_time)),
+    ];
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build();
+    let activity = DwellActivityCost::new(TestActivityCost::new_shared());
+
+    update_route_schedule(&mut route_ctx, &activity, TestTransportCost::new_shared().as_ref());
+
+    let schedule = &route_ctx.route().tour.get(2).unwrap().schedule;
+    assert_eq!((schedule.arrival, schedule.departure), expected);
+}
+
+fn create_parking_activity(location: Location, parking: Option<Duration>, tw_start: Timestamp) -> Activity {
+    create_parking_activity_with_tw(location, parking, TimeWindow::new(tw_start, 1000.))
+}
+
+fn create_parking_activity_with_tw(location: Location, parking: Option<Duration>, tw: TimeWindow) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(location));
+    if let Some(parking) = parking {
+        builder.dimens_mut().set_job_parking(vec![parking]);
+    }
+
+    ActivityBuilder::with_location_tw_and_duration(location, tw, 15.).job(Some(builder.build_shared())).build()
+}
+
+parameterized_test! {can_charge_parking_once_per_stop_in_schedule, (first_tw_start, second, expected), {
+    can_charge_parking_once_per_stop_in_schedule_impl(first_tw_start, second, expected);
+}}
+
+can_charge_parking_once_per_stop_in_schedule! {
+    case01_same_stop: (0., (10, Some(5.)), [(10., 25.), (25., 35.)]),
+    case02_same_stop_waiting_after_parking: (20., (10, Some(5.)), [(10., 30.), (30., 40.)]),
+    case03_same_stop_waiting_within_parking: (12., (10, Some(5.)), [(10., 25.), (25., 35.)]),
+    case04_same_stop_no_parking: (0., (10, None), [(10., 25.), (25., 40.)]),
+    case05_different_stop: (0., (15, Some(5.)), [(10., 25.), (30., 45.)]),
+}
+
+fn can_charge_parking_once_per_stop_in_schedule_impl(
+    first_tw_start: Timestamp,
+    second: (Location, Option<Duration>),
+    expected: [(Timestamp, Timestamp); 2],
+) {
+    let activities =
+        vec![create_parking_activity(10, Some(5.), first_tw_start), create_parking_activity(second.0, second.1, 0.)];
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build();
+    let activity = DwellActivityCost::new(TestActivityCost::new_shared());
+
+    update_route_schedule(&mut route_ctx, &activity, TestTransportCost::new_shared().as_ref());
+
+    let schedules = route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .skip(1)
+        .take(2)
+        .map(|activity| (activity.schedule.arrival, activity.schedule.departure))
+        .collect::<Vec<_>>();
+    assert_eq!(schedules, expected.to_vec());
+}
+
+parameterized_test! {can_keep_parking_within_time_window_in_latest_arrival, (second, expected), {
+    can_keep_parking_within_time_window_in_latest_arrival_impl(second, expected);
+}}
+
+can_keep_parking_within_time_window_in_latest_arrival! {
+    case01_same_stop: ((10, Some(5.)), [85., 100.]),
+    case02_same_stop_no_parking: ((10, None), [85., 100.]),
+    case03_different_stop: ((15, Some(5.)), [75., 95.]),
+}
+
+fn can_keep_parking_within_time_window_in_latest_arrival_impl(
+    second: (Location, Option<Duration>),
+    expected: [Timestamp; 2],
+) {
+    let activities = vec![
+        create_parking_activity_with_tw(10, Some(5.), TimeWindow::new(0., 100.)),
+        create_parking_activity_with_tw(second.0, second.1, TimeWindow::new(0., 100.)),
+    ];
+    let mut route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activities(activities).build())
+        .build();
+    let activity = DwellActivityCost::new(TestActivityCost::new_shared());
+
+    update_route_schedule(&mut route_ctx, &activity, TestTransportCost::new_shared().as_ref());
+
+    let latest_arrivals =
+        (1..=2).map(|idx| route_ctx.state().get_latest_arrival_at(idx).copied().unwrap()).collect::<Vec<_>>();
+    assert_eq!(latest_arrivals, expected.to_vec());
+}
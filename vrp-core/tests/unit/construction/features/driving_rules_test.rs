@@ -0,0 +1,278 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::solution::{Activity, Tour};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+struct VehicleIdDimenKey;
+struct JobTypeDimenKey;
+
+fn create_driving_rules(max_driving_time: Duration, split_rest: Option<(Duration, Duration)>) -> DrivingRules {
+    DrivingRules { max_driving_time, rest_duration: 5., split_rest, max_daily_driving_time: None }
+}
+
+fn create_driving_rules_feature(rules: DrivingRules) -> Feature {
+    fn is_rest_single(single: &Single) -> bool {
+        single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "rest")
+    }
+
+    DrivingRulesFeatureBuilder::new("driving_rules")
+        .set_transport(TestTransportCost::new_shared())
+        .set_violation_code(VIOLATION_CODE)
+        .set_driving_rules(move |_: &Actor| Some(rules))
+        .set_is_rest_single(is_rest_single)
+        .set_belongs_to_route(|route, job| {
+            job.as_single()
+                .filter(|single| is_rest_single(single))
+                .and_then(|single| single.dimens.get_value::<VehicleIdDimenKey, String>())
+                .zip(route.actor.vehicle.dimens.get_vehicle_id())
+                .is_some_and(|(a, b)| a == b)
+        })
+        .build()
+        .unwrap()
+}
+
+fn rest(location: Location, duration: Duration) -> Activity {
+    ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::max(), duration)
+        .job(Some(
+            TestSingleBuilder::default()
+                .id("rest")
+                .duration(duration)
+                .property::<JobTypeDimenKey, _>("rest".to_string())
+                .property::<VehicleIdDimenKey, _>("v1".to_string())
+                .build_shared(),
+        ))
+        .build()
+}
+
+fn job(location: Location) -> Activity {
+    ActivityBuilder::with_location(location)
+        .schedule(Schedule::new(location as Float, location as Float))
+        .job(Some(TestSingleBuilder::default().build_shared()))
+        .build()
+}
+
+fn create_route(actor: Arc<Actor>, activities: &[Location], rests: Vec<(usize, Location, Duration)>) -> RouteContext {
+    let mut route = Route { actor: actor.clone(), tour: Tour::new(actor.as_ref()) };
+    activities.iter().for_each(|&location| {
+        route.tour.insert_last(job(location));
+    });
+    rests.into_iter().for_each(|(rest_idx, location, duration)| {
+        route.tour.insert_at(rest(location, duration), rest_idx);
+    });
+
+    RouteContextBuilder::default().with_route(route).build()
+}
+
+fn create_route_ctx(activities: &[Location], rests: Vec<(usize, Location, Duration)>) -> RouteContext {
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_ovrp_vehicle("v1")).build();
+    let actor = RouteBuilder::default().with_vehicle(&fleet, "v1").build().actor;
+
+    create_route(actor, activities, rests)
+}
+
+parameterized_test! {can_accumulate_driving_time, (split_rest, rests, activities, expected_counters, expected_segments), {
+    can_accumulate_driving_time_impl(split_rest, rests, activities, expected_counters, expected_segments);
+}}
+
+can_accumulate_driving_time! {
+    case01_single_rest: (None, vec![(3, 10, 5.)], vec![5, 10, 15, 25],
+        vec![0., 5., 10., 0., 5., 15.], vec![10., 10., 10., 10., 15., 15.]
+    ),
+    case02_short_rest: (None, vec![(3, 10, 3.)], vec![5, 10, 15, 25],
+        vec![0., 5., 10., 10., 15., 25.], vec![25., 25., 25., 25., 25., 25.]
+    ),
+    case03_no_rests: (None, vec![], vec![5, 10, 15],
+        vec![0., 5., 10., 15.], vec![15., 15., 15., 15.]
+    ),
+    case04_split_rest: (Some((2., 3.)), vec![(2, 5, 2.), (4, 10, 3.)], vec![5, 10, 15, 25],
+        vec![0., 5., 5., 10., 0., 5., 15.], vec![10., 10., 10., 10., 10., 15., 15.]
+    ),
+    case05_split_rest_wrong_order: (Some((2., 3.)), vec![(2, 5, 3.), (4, 10, 2.)], vec![5, 10, 15, 25],
+        vec![0., 5., 5., 10., 10., 15., 25.], vec![25., 25., 25., 25., 25., 25., 25.]
+    ),
+}
+
+fn can_accumulate_driving_time_impl(
+    split_rest: Option<(Duration, Duration)>,
+    rests: Vec<(usize, Location, Duration)>,
+    activities: Vec<Location>,
+    expected_counters: Vec<Duration>,
+    expected_segments: Vec<Duration>,
+) {
+    let mut route_ctx = create_route_ctx(&activities, rests);
+    let feature = create_driving_rules_feature(create_driving_rules(20., split_rest));
+    let state = feature.state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    let get_values = |get_fn: fn(&RouteState, usize) -> Option<&Duration>| {
+        (0..route_ctx.route().tour.total())
+            .map(|idx| get_fn(route_ctx.state(), idx).copied().unwrap_or_default())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(get_values(|state, idx| state.get_driving_time_at(idx)), expected_counters);
+    assert_eq!(get_values(|state, idx| state.get_driving_segment_time_at(idx)), expected_segments);
+    assert_eq!(route_ctx.state().get_total_driving_time().copied(), Some(*activities.last().unwrap() as Duration));
+}
+
+parameterized_test! {can_evaluate_insertion, (rests, target, insertion_data, activities, expected), {
+    can_evaluate_insertion_impl(rests, target, insertion_data, activities, expected);
+}}
+
+can_evaluate_insertion! {
+    case01_accept_job_after_rest: (vec![(2, 5, 5.)], job(16), (3, (3, 4)), vec![5, 10, 15],
+        None,
+    ),
+    case02_reject_job_before_rest: (vec![(2, 5, 5.)], job(30), (0, (0, 1)), vec![5, 10, 15],
+        ConstraintViolation::skip(VIOLATION_CODE),
+    ),
+    case03_reject_job_with_detour: (vec![(2, 5, 5.)], job(25), (3, (3, 4)), vec![5, 10, 15],
+        ConstraintViolation::skip(VIOLATION_CODE),
+    ),
+    case04_accept_rest_in_the_middle: (vec![], rest(15, 5.), (2, (2, 3)), vec![5, 15, 30],
+        None,
+    ),
+    case05_reject_rest_too_early: (vec![], rest(5, 5.), (1, (1, 2)), vec![5, 15, 30],
+        ConstraintViolation::skip(VIOLATION_CODE),
+    ),
+}
+
+fn can_evaluate_insertion_impl(
+    rests: Vec<(usize, Location, Duration)>,
+    target: Activity,
+    insertion_data: (usize, (usize, usize)),
+    activities: Vec<Location>,
+    expected: Option<ConstraintViolation>,
+) {
+    let (index, (prev, next)) = insertion_data;
+    let mut route_ctx = create_route_ctx(&activities, rests);
+    let feature = create_driving_rules_feature(create_driving_rules(20., None));
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &TestInsertionContextBuilder::default().build().solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index,
+            prev: route_ctx.route().tour.get(prev).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(next),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_handle_obsolete_intervals, (max_driving_time, rests, activities, expected), {
+    can_handle_obsolete_intervals_impl(max_driving_time, rests, activities, expected);
+}}
+
+can_handle_obsolete_intervals! {
+    case01_remove_rest: (20., vec![(2, 5, 5.)], vec![5, 10, 15, 20], vec![0, 5, 10, 15, 20]),
+    case02_keep_rest: (15., vec![(3, 10, 5.)], vec![5, 10, 15, 20], vec![0, 5, 10, 10, 15, 20]),
+}
+
+fn can_handle_obsolete_intervals_impl(
+    max_driving_time: Duration,
+    rests: Vec<(usize, Location, Duration)>,
+    activities: Vec<Location>,
+    expected: Vec<Location>,
+) {
+    let mut solution =
+        TestInsertionContextBuilder::default().with_routes(vec![create_route_ctx(&activities, rests)]).build().solution;
+    let feature = create_driving_rules_feature(create_driving_rules(max_driving_time, None));
+    let state = feature.state.unwrap();
+
+    state.accept_solution_state(&mut solution);
+
+    assert_eq!(
+        expected,
+        solution.routes[0].route().tour.all_activities().map(|a| a.place.location).collect::<Vec<_>>()
+    );
+}
+
+parameterized_test! {can_limit_daily_driving_time, (max_daily_driving_time, expected), {
+    can_limit_daily_driving_time_impl(max_daily_driving_time, expected);
+}}
+
+can_limit_daily_driving_time! {
+    case01_reject_above_limit: (25., ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_accept_within_limit: (30., None),
+}
+
+fn can_limit_daily_driving_time_impl(max_daily_driving_time: Duration, expected: Option<ConstraintViolation>) {
+    // NOTE each vehicle shift is represented by a separate vehicle with the same id
+    let detail = VehicleDetail { end: None, ..test_vehicle_detail() };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(TestVehicleBuilder::default().id("v1").details(vec![detail.clone()]).build())
+        .add_vehicle(TestVehicleBuilder::default().id("v1").details(vec![detail]).build())
+        .build();
+    let feature = create_driving_rules_feature(DrivingRules {
+        max_daily_driving_time: Some(max_daily_driving_time),
+        ..create_driving_rules(20., None)
+    });
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    let mut solution = TestInsertionContextBuilder::default()
+        .with_routes(vec![create_route(fleet.actors[0].clone(), &[10, 20], vec![(2, 10, 5.)])])
+        .build()
+        .solution;
+    state.accept_solution_state(&mut solution);
+    let mut route_ctx = create_route(fleet.actors[1].clone(), &[], vec![]);
+    state.accept_route_state(&mut route_ctx);
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index: 0,
+            prev: route_ctx.route().tour.start().unwrap(),
+            target: &job(10),
+            next: None,
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_remove_jobs_exceeding_daily_driving_time, (max_daily_driving_time, expected), {
+    can_remove_jobs_exceeding_daily_driving_time_impl(max_daily_driving_time, expected);
+}}
+
+can_remove_jobs_exceeding_daily_driving_time! {
+    case01_remove_above_limit: (30., (2, 0, 2)),
+    case02_keep_within_limit: (40., (2, 2, 0)),
+}
+
+fn can_remove_jobs_exceeding_daily_driving_time_impl(
+    max_daily_driving_time: Duration,
+    expected: (usize, usize, usize),
+) {
+    let detail = VehicleDetail { end: None, ..test_vehicle_detail() };
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(TestVehicleBuilder::default().id("v1").details(vec![detail.clone()]).build())
+        .add_vehicle(TestVehicleBuilder::default().id("v1").details(vec![detail]).build())
+        .build();
+    let feature = create_driving_rules_feature(DrivingRules {
+        max_daily_driving_time: Some(max_daily_driving_time),
+        ..create_driving_rules(40., None)
+    });
+    let state = feature.state.unwrap();
+    let mut solution = TestInsertionContextBuilder::default()
+        .with_routes(vec![
+            create_route(fleet.actors[0].clone(), &[10, 20], vec![]),
+            create_route(fleet.actors[1].clone(), &[10, 20], vec![]),
+        ])
+        .build()
+        .solution;
+
+    state.accept_solution_state(&mut solution);
+
+    let job_counts = (solution.routes[0].route().tour.job_count(), solution.routes[1].route().tour.job_count());
+    assert_eq!((job_counts.0, job_counts.1, solution.unassigned.len()), expected);
+}
@@ -486,3 +486,65 @@ fn test_multiple_valid_lifo_sequence() {
 
     assert!(result.is_none(), "Final delivery completing LIFO sequence should be accepted");
 }
+
+/// Creates an activity representing a delivery of the job which is onboard since given time
+fn create_onboard_lifo_delivery(location: usize, tag: &str, group_id: usize, since: Timestamp) -> Activity {
+    let mut single_builder = TestSingleBuilder::default();
+    single_builder.location(Some(location));
+    single_builder.demand(Demand::delivery(1));
+    single_builder.dimens_mut().set_lifo_tag(tag.to_string());
+    single_builder.dimens_mut().set_lifo_group(LifoGroupId(group_id));
+    single_builder.dimens_mut().set_job_onboard_since(since);
+    let single = single_builder.build_shared();
+
+    ActivityBuilder::with_location(location).job(Some(single)).build()
+}
+
+#[test]
+fn test_onboard_jobs_are_delivered_in_reverse_order_of_onboard_time() {
+    // W1 is onboard since 0, W2 is onboard since 10: W2 is on top of the stack
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_lifo_vehicle("v1", &["wheelchair"]))
+        .build();
+    let create_route_ctx = |activity: Activity| {
+        RouteContextBuilder::default()
+            .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activity(activity).build())
+            .build()
+    };
+
+    // Tour: [Start(0), Delivery W2(1)], insert W1 delivery after W2 delivery
+    let route_ctx = create_route_ctx(create_onboard_lifo_delivery(20, "wheelchair", 2, 10.));
+    let w1_delivery = create_onboard_lifo_delivery(10, "wheelchair", 1, 0.);
+    let result = evaluate_insertion(&route_ctx, &w1_delivery, 1, 1, None);
+    assert!(result.is_none(), "onboard job loaded first should be delivered last");
+
+    // Tour: [Start(0), Delivery W1(1)], insert W2 delivery after W1 delivery
+    let route_ctx = create_route_ctx(create_onboard_lifo_delivery(10, "wheelchair", 1, 0.));
+    let w2_delivery = create_onboard_lifo_delivery(20, "wheelchair", 2, 10.);
+    let result = evaluate_insertion(&route_ctx, &w2_delivery, 1, 1, None);
+    assert_eq!(result.map(|violation| violation.code), Some(LIFO_VIOLATION_CODE));
+}
+
+#[test]
+fn test_pickup_is_stacked_on_top_of_onboard_job() {
+    // Tour: [Start(0), Delivery W1(1)] where W1 is onboard, insert W2 pickup at the start
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicle(create_lifo_vehicle("v1", &["wheelchair"]))
+        .build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(create_onboard_lifo_delivery(10, "wheelchair", 1, 0.))
+                .add_activity(create_lifo_delivery(30, "wheelchair", 2))
+                .build(),
+        )
+        .build();
+
+    let w2_pickup = create_lifo_pickup(5, "wheelchair", 2);
+    let result = evaluate_insertion(&route_ctx, &w2_pickup, 0, 0, Some(1));
+
+    assert_eq!(result.map(|violation| violation.code), Some(LIFO_VIOLATION_CODE));
+}
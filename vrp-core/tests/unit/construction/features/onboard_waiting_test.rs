@@ -0,0 +1,154 @@
+use super::*;
+use crate::construction::enablers::update_route_schedule;
+use crate::construction::heuristics::{ActivityContext, MoveContext};
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{
+    FleetBuilder, TestActivityCost, TestSingleBuilder, TestTransportCost, TestVehicleBuilder, test_driver,
+};
+use crate::helpers::models::solution::{ActivityBuilder, RouteBuilder, RouteContextBuilder};
+use crate::models::common::{Demand, TimeWindow};
+use crate::models::problem::Multi;
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+fn create_pudo_activities(
+    pickup_loc: usize,
+    delivery_loc: usize,
+    delivery_tw_start: Float,
+) -> (Arc<Multi>, Activity, Activity) {
+    let pickup = TestSingleBuilder::default().location(Some(pickup_loc)).demand(Demand::pudo_pickup(1)).build_shared();
+    let delivery =
+        TestSingleBuilder::default().location(Some(delivery_loc)).demand(Demand::pudo_delivery(1)).build_shared();
+    let multi = Multi::new_shared(vec![pickup, delivery], Dimensions::default());
+
+    let pickup = ActivityBuilder::with_location(pickup_loc).job(Some(multi.jobs[0].clone())).build();
+    let delivery = ActivityBuilder::with_location_and_tw(delivery_loc, TimeWindow::new(delivery_tw_start, 1000.))
+        .job(Some(multi.jobs[1].clone()))
+        .build();
+
+    (multi, pickup, delivery)
+}
+
+fn create_route_ctx(max_waiting: Option<Duration>, activities: Vec<Activity>) -> RouteContext {
+    let mut vehicle = TestVehicleBuilder::default();
+    vehicle.id("v1");
+    if let Some(max_waiting) = max_waiting {
+        vehicle.dimens_mut().set_vehicle_max_onboard_waiting(max_waiting);
+    }
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(vehicle.build()).build();
+
+    RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activities(activities).build())
+        .build()
+}
+
+fn evaluate_insertion(route_ctx: &RouteContext, target: &Activity, index: usize) -> Option<ConstraintViolation> {
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let activity_ctx = ActivityContext {
+        index,
+        prev: route_ctx.route().tour.get(index).unwrap(),
+        target,
+        next: route_ctx.route().tour.get(index + 1),
+    };
+    let feature =
+        create_max_onboard_waiting_feature("onboard_waiting", VIOLATION_CODE, TestTransportCost::new_shared()).unwrap();
+
+    feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, route_ctx, &activity_ctx))
+}
+
+parameterized_test! {can_limit_waiting_at_delivery, (max_waiting, delivery_tw_start, expected), {
+    can_limit_waiting_at_delivery_impl(max_waiting, delivery_tw_start, expected);
+}}
+
+can_limit_waiting_at_delivery! {
+    case01_no_waiting: (Some(60.), 10., None),
+    case02_waiting_within_limit: (Some(60.), 50., None),
+    case03_waiting_above_limit: (Some(60.), 100., Some(VIOLATION_CODE)),
+    case04_no_limit: (None, 100., None),
+}
+
+fn can_limit_waiting_at_delivery_impl(
+    max_waiting: Option<Duration>,
+    delivery_tw_start: Float,
+    expected: Option<ViolationCode>,
+) {
+    let (_multi, pickup, delivery) = create_pudo_activities(10, 20, delivery_tw_start);
+    let route_ctx = create_route_ctx(max_waiting, vec![pickup]);
+
+    let result = evaluate_insertion(&route_ctx, &delivery, 1);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_limit_waiting_of_other_job_with_passenger_onboard, (index, expected), {
+    can_limit_waiting_of_other_job_with_passenger_onboard_impl(index, expected);
+}}
+
+can_limit_waiting_of_other_job_with_passenger_onboard! {
+    case01_before_pickup: (0, None),
+    case02_between_pickup_and_delivery: (1, Some(VIOLATION_CODE)),
+    case03_after_delivery: (2, None),
+}
+
+fn can_limit_waiting_of_other_job_with_passenger_onboard_impl(index: usize, expected: Option<ViolationCode>) {
+    let (_multi, pickup, delivery) = create_pudo_activities(10, 20, 0.);
+    let route_ctx = create_route_ctx(Some(60.), vec![pickup, delivery]);
+    let target = ActivityBuilder::with_location_and_tw(15, TimeWindow::new(100., 1000.)).build();
+
+    let result = evaluate_insertion(&route_ctx, &target, index);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+#[test]
+fn can_ignore_pickup_without_delivery_in_tour() {
+    let (_multi, pickup, _) = create_pudo_activities(10, 20, 0.);
+    let route_ctx = create_route_ctx(Some(60.), vec![pickup]);
+    let target = ActivityBuilder::with_location_and_tw(15, TimeWindow::new(100., 1000.)).build();
+
+    let result = evaluate_insertion(&route_ctx, &target, 1);
+
+    assert!(result.is_none());
+}
+
+#[test]
+fn can_limit_waiting_with_job_onboard_since_shift_start() {
+    let mut builder = TestSingleBuilder::default();
+    builder.location(Some(20)).demand(Demand::pudo_delivery(1));
+    builder.dimens_mut().set_job_onboard_since(0.);
+    let onboard = ActivityBuilder::with_location(20).job(Some(builder.build_shared())).build();
+    let route_ctx = create_route_ctx(Some(60.), vec![onboard]);
+    let target = ActivityBuilder::with_location_and_tw(15, TimeWindow::new(100., 1000.)).build();
+
+    let result = evaluate_insertion(&route_ctx, &target, 0);
+
+    assert_eq!(result.map(|violation| violation.code), Some(VIOLATION_CODE));
+}
+
+#[test]
+fn can_remove_onboard_job_when_removal_of_other_job_causes_waiting() {
+    let (activity, transport) = (TestActivityCost::default(), TestTransportCost::default());
+    let (multi, pickup, delivery) = create_pudo_activities(5, 15, 20.);
+    let other_single = TestSingleBuilder::default().location(Some(10)).duration(5.).build_shared();
+    let other = ActivityBuilder::with_location_tw_and_duration(10, TimeWindow::max(), 5.)
+        .job(Some(other_single.clone()))
+        .build();
+    let mut route_ctx = create_route_ctx(Some(3.), vec![pickup, other, delivery]);
+    update_route_schedule(&mut route_ctx, &activity, &transport);
+    let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+    let state = create_max_onboard_waiting_feature("onboard_waiting", VIOLATION_CODE, TestTransportCost::new_shared())
+        .unwrap()
+        .state
+        .unwrap();
+    state.accept_solution_state(&mut solution_ctx);
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), 2);
+
+    let route_ctx = &mut solution_ctx.routes[0];
+    assert!(route_ctx.route_mut().tour.remove(&Job::Single(other_single)));
+    update_route_schedule(route_ctx, &activity, &transport);
+    state.accept_solution_state(&mut solution_ctx);
+
+    assert_eq!(solution_ctx.routes[0].route().tour.job_count(), 0);
+    assert_eq!(solution_ctx.unassigned.len(), 1);
+    assert!(solution_ctx.unassigned.contains_key(&Job::Multi(multi)));
+}
@@ -8,6 +8,7 @@ const VIOLATION_CODE: ViolationCode = ViolationCode(1);
 
 struct VehicleIdDimenKey;
 struct JobTypeDimenKey;
+struct JobLoadDimenKey;
 
 fn create_recharge_feature(limit: Distance) -> Feature {
     fn is_recharge_single(single: &Single) -> bool {
@@ -206,3 +207,114 @@ fn can_accept_recharge_in_long_empty_route() {
 
     assert_eq!(result, None);
 }
+
+fn create_battery_feature(battery: BatteryModel) -> Feature {
+    fn is_recharge_single(single: &Single) -> bool {
+        single.dimens.get_value::<JobTypeDimenKey, String>().is_some_and(|job_type| job_type == "recharge")
+    }
+
+    RechargeFeatureBuilder::new("recharge")
+        .set_transport(TestTransportCost::new_shared())
+        .set_activity(TestActivityCost::new_shared())
+        .set_violation_code(VIOLATION_CODE)
+        .set_battery(move |_: &Actor| Some(battery.clone()))
+        .set_consumption_rate(|_, _, extra_load| 1. + extra_load)
+        .set_job_load(|single| {
+            single.dimens.get_value::<JobLoadDimenKey, (Float, Float)>().copied().unwrap_or_default()
+        })
+        .set_charging_power(|_| Some(1.))
+        .set_is_recharge_single(is_recharge_single)
+        .set_belongs_to_route(|_, job| job.as_single().is_some_and(|single| is_recharge_single(single)))
+        .build()
+        .unwrap()
+}
+
+fn create_battery_route_ctx(last_time_end: Timestamp) -> RouteContext {
+    let mut route_ctx = create_route_ctx(&[5, 10, 15], vec![(2, 8)], true);
+    route_ctx.route_mut().tour.get_mut(4).unwrap().place.time = TimeWindow::new(0., last_time_end);
+
+    route_ctx
+}
+
+#[test]
+fn can_calculate_charged_energy() {
+    let mut route_ctx = create_battery_route_ctx(100.);
+    let feature = create_battery_feature(BatteryModel { capacity: 20., initial: 10., minimum: 2. });
+    let state = feature.state.unwrap();
+
+    state.accept_route_state(&mut route_ctx);
+
+    let get_values = |get_fn: fn(&RouteState, usize) -> Option<&Float>| {
+        (0..route_ctx.route().tour.total())
+            .map(|idx| get_fn(route_ctx.state(), idx).copied().unwrap_or_default())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(get_values(|state, idx| state.get_recharge_charged_energy_at(idx)), vec![0., 0., 7., 0., 0.]);
+    let station = route_ctx.route().tour.get(2).unwrap();
+    assert_eq!(station.place.duration, 7.);
+    assert_eq!(get_charging_time(station), 7.);
+    assert_eq!((station.schedule.arrival, station.schedule.departure), (8., 15.));
+}
+
+parameterized_test! {can_evaluate_charging_time, (last_time_end, expected), {
+    can_evaluate_charging_time_impl(last_time_end, expected);
+}}
+
+can_evaluate_charging_time! {
+    case01_reject_when_charging_delays_next: (23., ConstraintViolation::skip(VIOLATION_CODE)),
+    case02_accept_when_charging_fits: (100., None),
+}
+
+fn can_evaluate_charging_time_impl(last_time_end: Timestamp, expected: Option<ConstraintViolation>) {
+    let mut route_ctx = create_battery_route_ctx(last_time_end);
+    let feature = create_battery_feature(BatteryModel { capacity: 20., initial: 10., minimum: 2. });
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+    update_route_schedule(&mut route_ctx, TestActivityCost::new_shared().as_ref(), &TestTransportCost::default());
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &TestInsertionContextBuilder::default().build().solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index: 3,
+            prev: route_ctx.route().tour.get(3).unwrap(),
+            target: &ActivityBuilder::with_location(20).job(Some(TestSingleBuilder::default().build_shared())).build(),
+            next: route_ctx.route().tour.get(4),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
+
+parameterized_test! {can_evaluate_load_change_on_whole_route, (job_load, expected), {
+    can_evaluate_load_change_on_whole_route_impl(job_load, expected);
+}}
+
+can_evaluate_load_change_on_whole_route! {
+    case01_accept_without_load: ((0., 0.), None),
+    case02_reject_delivery_load_before_station: ((1., 0.), ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_accept_pickup_load_after_station: ((0., 1.), None),
+}
+
+fn can_evaluate_load_change_on_whole_route_impl(job_load: (Float, Float), expected: Option<ConstraintViolation>) {
+    let mut route_ctx = create_battery_route_ctx(100.);
+    let feature = create_battery_feature(BatteryModel { capacity: 20., initial: 10., minimum: 2. });
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_route_state(&mut route_ctx);
+
+    // NOTE the job is inserted after the station, but its delivery load is carried through the first interval
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &TestInsertionContextBuilder::default().build().solution,
+        route_ctx: &route_ctx,
+        activity_ctx: &ActivityContext {
+            index: 3,
+            prev: route_ctx.route().tour.get(3).unwrap(),
+            target: &ActivityBuilder::with_location(12)
+                .job(Some(TestSingleBuilder::default().property::<JobLoadDimenKey, _>(job_load).build_shared()))
+                .build(),
+            next: route_ctx.route().tour.get(4),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
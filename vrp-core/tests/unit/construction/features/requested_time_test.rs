@@ -77,6 +77,41 @@ fn can_use_default_penalty() {
     assert!((result - 60.0).abs() < 0.001, "Expected 60.0, got {}", result);
 }
 
+parameterized_test! {can_calculate_penalty_with_shape, (shape, deviation, expected), {
+    can_calculate_penalty_with_shape_impl(shape, deviation, expected);
+}}
+
+can_calculate_penalty_with_shape! {
+    case01_linear: (RequestedTimePenaltyShape::Linear, 600.0, 10.0),
+    case02_quadratic_short: (RequestedTimePenaltyShape::Quadratic, 60.0, 1.0),
+    case03_quadratic_long: (RequestedTimePenaltyShape::Quadratic, 600.0, 100.0),
+    case04_piecewise_first: (RequestedTimePenaltyShape::Piecewise(vec![(300.0, 5.0)]), 240.0, 4.0),
+    case05_piecewise_second: (RequestedTimePenaltyShape::Piecewise(vec![(300.0, 5.0)]), 600.0, 30.0),
+    case06_piecewise_many: (RequestedTimePenaltyShape::Piecewise(vec![(0.0, 0.0), (300.0, 2.0), (600.0, 10.0)]), 660.0, 20.0),
+}
+
+fn can_calculate_penalty_with_shape_impl(shape: RequestedTimePenaltyShape, deviation: Duration, expected: Cost) {
+    let penalty = RequestedTimePenalty::new(1.0, 1.0).with_shape(shape);
+
+    let early = penalty.calculate_penalty(1000.0, 1000.0 + deviation);
+    let late = penalty.calculate_penalty(1000.0 + deviation, 1000.0);
+
+    assert!((early - expected).abs() < 0.001, "expected {expected}, got {early}");
+    assert!((late - expected).abs() < 0.001, "expected {expected}, got {late}");
+}
+
+#[test]
+fn can_use_job_penalty_override() {
+    let activity = requested_activity(1, 160.0, 0.0, 100.0);
+    let mut single = TestSingleBuilder::default().build();
+    single.dimens.set_job_requested_times(HashMap::from([(0, 100.0)]));
+    single.dimens.set_job_requested_time_penalties(HashMap::from([(0, RequestedTimePenalty::new(1.0, 10.0))]));
+    let activity_with_override = Activity { job: Some(Arc::new(single)), ..activity.deep_copy() };
+
+    assert_eq!(objective().calculate_activity_penalty(&activity), Some(1.0));
+    assert_eq!(objective().calculate_activity_penalty(&activity_with_override), Some(10.0));
+}
+
 #[test]
 fn waiting_before_service_is_not_penalized_as_early() {
     let activity = requested_activity(1, 100.0, 200.0, 200.0);
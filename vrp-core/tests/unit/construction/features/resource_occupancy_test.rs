@@ -0,0 +1,226 @@
+use super::*;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::*;
+use crate::helpers::models::solution::{ActivityBuilder, RouteContextBuilder};
+use crate::models::solution::{Route, Tour};
+
+const VIOLATION_CODE: ViolationCode = ViolationCode(1);
+
+struct StationDimenKey;
+
+fn create_occupancy_feature(capacity: usize) -> Feature {
+    ResourceOccupancyFeatureBuilder::new("occupancy")
+        .set_violation_code(VIOLATION_CODE)
+        .set_transport(TestTransportCost::new_shared())
+        .set_activity(TestActivityCost::new_shared())
+        .set_resource(move |activity| {
+            activity
+                .job
+                .as_ref()
+                .and_then(|single| single.dimens.get_value::<StationDimenKey, SharedResourceId>())
+                .map(|resource_id| (*resource_id, capacity))
+        })
+        .build()
+        .unwrap()
+}
+
+fn activity(location: Location, duration: Duration, time: TimeWindow, is_station: bool) -> Activity {
+    let mut single = TestSingleBuilder::default();
+    single.location(Some(location)).duration(duration).times(vec![time.clone()]);
+    if is_station {
+        single.property::<StationDimenKey, SharedResourceId>(0);
+    }
+
+    ActivityBuilder::with_location_tw_and_duration(location, time, duration).job(Some(single.build_shared())).build()
+}
+
+fn station(location: Location, duration: Duration) -> Activity {
+    activity(location, duration, TimeWindow::max(), true)
+}
+
+fn waiting_station() -> Activity {
+    activity(15, 10., TimeWindow::new(20., 1000.), true)
+}
+
+fn job(location: Location, duration: Duration) -> Activity {
+    activity(location, duration, TimeWindow::max(), false)
+}
+
+fn create_route_ctx(actor: Arc<Actor>, activities: Vec<Activity>) -> RouteContext {
+    let mut route = Route { actor: actor.clone(), tour: Tour::new(actor.as_ref()) };
+    activities.into_iter().for_each(|activity| {
+        route.tour.insert_last(activity);
+    });
+
+    let mut route_ctx = RouteContextBuilder::default().with_route(route).build();
+    update_route_schedule(&mut route_ctx, &TestActivityCost::default(), &TestTransportCost::default());
+
+    route_ctx
+}
+
+fn create_solution(routes: Vec<Vec<Activity>>) -> SolutionContext {
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_vehicles(vec![test_ovrp_vehicle("v1"), test_ovrp_vehicle("v2")])
+        .build();
+
+    let routes = routes
+        .into_iter()
+        .zip(fleet.actors.iter())
+        .map(|(activities, actor)| create_route_ctx(actor.clone(), activities))
+        .collect();
+
+    TestInsertionContextBuilder::default().with_routes(routes).build().solution
+}
+
+fn get_station_times(route_ctx: &RouteContext) -> Vec<(Timestamp, Timestamp, Timestamp)> {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .enumerate()
+        .filter(|(_, activity)| {
+            activity
+                .job
+                .as_ref()
+                .is_some_and(|job| job.dimens.get_value::<StationDimenKey, SharedResourceId>().is_some())
+        })
+        .map(|(activity_idx, activity)| {
+            let service_start =
+                activity.schedule.arrival.max(activity.place.time.start) + get_queuing(route_ctx, activity_idx);
+            (service_start, activity.schedule.arrival, activity.schedule.departure)
+        })
+        .collect()
+}
+
+parameterized_test! {can_wait_for_occupied_resource, (capacity, expected), {
+    can_wait_for_occupied_resource_impl(capacity, expected);
+}}
+
+can_wait_for_occupied_resource! {
+    case01_single_slot: (1, vec![(20., 15., 30.)]),
+    case02_two_slots: (2, vec![(15., 15., 25.)]),
+}
+
+fn can_wait_for_occupied_resource_impl(capacity: usize, expected: Vec<(Timestamp, Timestamp, Timestamp)>) {
+    let mut solution = create_solution(vec![vec![station(10, 10.)], vec![station(15, 10.)]]);
+    let state = create_occupancy_feature(capacity).state.unwrap();
+
+    let job = solution.routes[1].route().tour.jobs().next().unwrap().clone();
+
+    state.accept_insertion(&mut solution, 1, &job);
+
+    assert_eq!(get_station_times(&solution.routes[0]), vec![(10., 10., 20.)]);
+    assert_eq!(get_station_times(&solution.routes[1]), expected);
+}
+
+#[test]
+fn can_release_waiting_when_resource_is_free() {
+    let mut solution = create_solution(vec![vec![station(10, 10.)], vec![station(15, 10.)]]);
+    let state = create_occupancy_feature(1).state.unwrap();
+    let job = solution.routes[1].route().tour.jobs().next().unwrap().clone();
+    state.accept_insertion(&mut solution, 1, &job);
+    assert_eq!(get_station_times(&solution.routes[1]), vec![(20., 15., 30.)]);
+
+    let actor = solution.routes[0].route().actor.clone();
+    solution.routes[0] = create_route_ctx(actor, vec![]);
+    state.accept_solution_state(&mut solution);
+
+    assert_eq!(get_station_times(&solution.routes[1]), vec![(15., 15., 25.)]);
+}
+
+#[test]
+fn can_keep_queuing_time_in_route_state() {
+    let mut solution = create_solution(vec![
+        vec![station(10, 10.)],
+        vec![activity(15, 10., TimeWindow::new(12., 1000.), true), job(20, 1.)],
+    ]);
+    let state = create_occupancy_feature(1).state.unwrap();
+    let job = solution.routes[1].route().tour.jobs().next().unwrap().clone();
+    state.accept_insertion(&mut solution, 1, &job);
+
+    let route_ctx = &solution.routes[1];
+    assert_eq!(get_queuing(route_ctx, 1), 5.);
+    assert_eq!(get_queuing(route_ctx, 2), 0.);
+    assert_eq!(route_ctx.route().tour.get(1).unwrap().place.time.start, 12.);
+}
+
+parameterized_test! {can_detect_resource_occupancy, (routes, expected), {
+    can_detect_resource_occupancy_impl(routes, expected);
+}}
+
+can_detect_resource_occupancy! {
+    case01_station: (vec![vec![station(10, 10.)], vec![job(15, 1.)]], true),
+    case02_no_station: (vec![vec![job(10, 1.)], vec![job(15, 1.)]], false),
+}
+
+fn can_detect_resource_occupancy_impl(routes: Vec<Vec<Activity>>, expected: bool) {
+    let mut solution = create_solution(routes);
+    assert!(!has_resource_occupancy(&solution));
+
+    create_occupancy_feature(1).state.unwrap().accept_solution_state(&mut solution);
+
+    assert_eq!(has_resource_occupancy(&solution), expected);
+}
+
+#[test]
+fn can_unassign_job_when_resource_is_occupied_till_time_window_end() {
+    let mut solution = create_solution(vec![
+        vec![station(10, 10.)],
+        vec![activity(15, 10., TimeWindow::new(0., 18.), true), job(20, 1.)],
+    ]);
+    let state = create_occupancy_feature(1).state.unwrap();
+    let job = solution.routes[1].route().tour.jobs().next().unwrap().clone();
+    state.accept_insertion(&mut solution, 1, &job);
+    assert_eq!(solution.routes[1].route().tour.job_count(), 2);
+
+    state.accept_solution_state(&mut solution);
+
+    assert_eq!(solution.routes[1].route().tour.job_count(), 1);
+    assert_eq!(solution.unassigned.len(), 1);
+    assert!(matches!(solution.unassigned.values().next(), Some(UnassignmentInfo::Simple(VIOLATION_CODE))));
+    assert_eq!(get_station_times(&solution.routes[0]), vec![(10., 10., 20.)]);
+}
+
+parameterized_test! {can_evaluate_insertion, (capacity, route, target, insertion_idx, expected), {
+    can_evaluate_insertion_impl(capacity, route, target, insertion_idx, expected);
+}}
+
+can_evaluate_insertion! {
+    case01_accept_station_with_waiting: (1, vec![], station(15, 10.), 0, None),
+    case02_reject_station_with_waiting_after_tw: (1, vec![], activity(15, 10., TimeWindow::new(0., 18.), true), 0,
+        ConstraintViolation::skip(VIOLATION_CODE)),
+    case03_accept_station_without_waiting: (2, vec![], activity(15, 10., TimeWindow::new(0., 18.), true), 0, None),
+    case04_accept_job_absorbed_by_slack: (1, vec![waiting_station()], job(15, 10.), 0, None),
+    case05_reject_job_exceeding_slack: (1, vec![waiting_station()], job(15, 25.), 0,
+        ConstraintViolation::skip(VIOLATION_CODE)),
+    case06_accept_job_after_station: (1, vec![waiting_station()], job(15, 25.), 1, None),
+}
+
+fn can_evaluate_insertion_impl(
+    capacity: usize,
+    route: Vec<Activity>,
+    target: Activity,
+    insertion_idx: usize,
+    expected: Option<ConstraintViolation>,
+) {
+    // NOTE the first route occupies the resource at [10, 20) and [45, 55)
+    let mut solution = create_solution(vec![vec![station(10, 10.), station(35, 10.)], route]);
+    let feature = create_occupancy_feature(capacity);
+    let (constraint, state) = (feature.constraint.unwrap(), feature.state.unwrap());
+    state.accept_solution_state(&mut solution);
+    let route_ctx = &solution.routes[1];
+
+    let result = constraint.evaluate(&MoveContext::Activity {
+        solution_ctx: &solution,
+        route_ctx,
+        activity_ctx: &ActivityContext {
+            index: insertion_idx,
+            prev: route_ctx.route().tour.get(insertion_idx).unwrap(),
+            target: &target,
+            next: route_ctx.route().tour.get(insertion_idx + 1),
+        },
+    });
+
+    assert_eq!(result, expected);
+}
@@ -3,8 +3,10 @@ use crate::construction::heuristics::ActivityContext;
 use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
 use crate::helpers::models::problem::{FleetBuilder, TestSingleBuilder, test_driver, test_vehicle_with_id};
 use crate::helpers::models::solution::{RouteBuilder, RouteContextBuilder};
-use crate::models::common::{ConfigurableLoad, Demand, Distance, Location, MultiDimLoad, Profile, Schedule};
-use crate::models::problem::{Multi, TransportCost, TravelTime};
+use crate::models::common::{
+    ConfigurableLoad, Demand, Distance, Location, MultiDimLoad, Profile, ProfileTimeScale, Schedule,
+};
+use crate::models::problem::{MatrixData, Multi, TransportCost, TravelTime, create_matrix_transport_cost};
 use crate::models::solution::{Activity, Place, Route};
 use std::sync::Arc;
 
@@ -182,6 +184,15 @@ fn create_delivery_activity(location: usize, single: Arc<Single>) -> Activity {
 
 // Helper to create a Multi job with pickup and delivery singles
 fn create_pudo_multi_job(max_ride_duration: Option<Duration>) -> Arc<Multi> {
+    let mut dimens: Dimensions = Default::default();
+    if let Some(duration) = max_ride_duration {
+        dimens.set_job_max_ride_duration(duration);
+    }
+
+    create_pudo_multi_job_with_dimens(dimens)
+}
+
+fn create_pudo_multi_job_with_dimens(dimens: Dimensions) -> Arc<Multi> {
     let mut pickup_builder = TestSingleBuilder::default();
     pickup_builder.demand(Demand::pudo_pickup(1));
     pickup_builder.location(Some(10)); // pickup location
@@ -192,11 +203,6 @@ fn create_pudo_multi_job(max_ride_duration: Option<Duration>) -> Arc<Multi> {
     delivery_builder.location(Some(20)); // delivery location
     let delivery = delivery_builder.build_shared();
 
-    let mut dimens: Dimensions = Default::default();
-    if let Some(duration) = max_ride_duration {
-        dimens.set_job_max_ride_duration(duration);
-    }
-
     Multi::new_shared(vec![pickup, delivery], dimens)
 }
 
@@ -297,7 +303,8 @@ fn invalid_ride_after_route_change_is_returned_to_unassigned() {
         .build();
     let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
 
-    MaxRideDurationState {}.accept_solution_state(&mut insertion_ctx.solution);
+    MaxRideDurationState { transport: ScaledTransportCost::new_shared(1.0) }
+        .accept_solution_state(&mut insertion_ctx.solution);
 
     assert!(!insertion_ctx.solution.routes[0].route().tour.contains(&job));
     assert!(insertion_ctx.solution.unassigned.contains_key(&job));
@@ -347,7 +354,8 @@ fn delivery_waiting_is_included_when_invalid_ride_is_returned_to_unassigned() {
         .build();
     let mut insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
 
-    MaxRideDurationState {}.accept_solution_state(&mut insertion_ctx.solution);
+    MaxRideDurationState { transport: ScaledTransportCost::new_shared(1.0) }
+        .accept_solution_state(&mut insertion_ctx.solution);
 
     assert!(!insertion_ctx.solution.routes[0].route().tour.contains(&job));
     assert!(insertion_ctx.solution.unassigned.contains_key(&job));
@@ -565,3 +573,194 @@ fn test_delivery_insertion_just_over_limit() {
 
     assert!(result.is_some(), "Expected violation when ride duration exceeds limit");
 }
+
+// Helper to create a delivery single of the job which is onboard since given time
+fn create_onboard_delivery_single(since: Timestamp, max_ride_duration: Duration) -> Arc<Single> {
+    let mut delivery_builder = TestSingleBuilder::default();
+    delivery_builder.demand(Demand::delivery(1));
+    delivery_builder.location(Some(20));
+    delivery_builder.dimens_mut().set_job_max_ride_duration(max_ride_duration);
+    delivery_builder.dimens_mut().set_job_onboard_since(since);
+
+    delivery_builder.build_shared()
+}
+
+parameterized_test! {can_check_ride_duration_of_onboard_job, (since, max_ride_duration, expected), {
+    can_check_ride_duration_of_onboard_job_impl(since, max_ride_duration, expected);
+}}
+
+can_check_ride_duration_of_onboard_job! {
+    case01_within_limit: (0., 30., None),
+    case02_exceeds_limit: (0., 10., Some(MAX_RIDE_DURATION_CODE)),
+    case03_exceeds_limit_because_of_onboard_time: (-20., 30., Some(MAX_RIDE_DURATION_CODE)),
+}
+
+fn can_check_ride_duration_of_onboard_job_impl(
+    since: Timestamp,
+    max_ride_duration: Duration,
+    expected: Option<ViolationCode>,
+) {
+    let transport = ScaledTransportCost::new_shared(1.0);
+    let feature = create_max_ride_duration_feature("test", MAX_RIDE_DURATION_CODE, transport).unwrap();
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+    let delivery_activity = create_delivery_activity(20, create_onboard_delivery_single(since, max_ride_duration));
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &delivery_activity,
+        next: route_ctx.route().tour.get(1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let move_ctx = MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx);
+
+    let result = feature.constraint.unwrap().evaluate(&move_ctx);
+
+    assert_eq!(result.map(|violation| violation.code), expected);
+}
+
+parameterized_test! {can_check_relative_ride_duration, (max_ride_duration, relative, delivery_start, expected), {
+    can_check_relative_ride_duration_impl(max_ride_duration, relative, delivery_start, expected);
+}}
+
+can_check_relative_ride_duration! {
+    case01_within_relative_limit: (None, (0., 1.5, 0.), 0., false),
+    case02_exceeds_relative_limit: (None, (0., 1.5, 0.), 300., true),
+    case03_within_min_limit: (None, (0., 1.5, 250.), 300., false),
+    case04_within_offset_limit: (None, (120., 1., 0.), 300., false),
+    case05_exceeds_relative_but_not_absolute_limit: (Some(500.), (0., 1.5, 0.), 300., true),
+    case06_exceeds_absolute_but_not_relative_limit: (Some(180.), (0., 3., 0.), 300., true),
+}
+
+fn can_check_relative_ride_duration_impl(
+    max_ride_duration: Option<Duration>,
+    relative: (Duration, Float, Duration),
+    delivery_start: Timestamp,
+    expected: bool,
+) {
+    // NOTE direct travel duration between pickup and delivery is 100s, pickup departure is at 100s
+    let transport = ScaledTransportCost::new_shared(10.0);
+    let feature = create_max_ride_duration_feature("test", MAX_RIDE_DURATION_CODE, transport).unwrap();
+    let mut dimens: Dimensions = Default::default();
+    if let Some(duration) = max_ride_duration {
+        dimens.set_job_max_ride_duration(duration);
+    }
+    dimens.set_job_relative_max_ride_duration(RelativeRideDuration {
+        offset: relative.0,
+        factor: relative.1,
+        min: relative.2,
+    });
+    let multi = create_pudo_multi_job_with_dimens(dimens);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default()
+                .with_vehicle(&fleet, "v1")
+                .add_activity(create_pickup_activity(10, 100.0, multi.jobs[0].clone()))
+                .build(),
+        )
+        .build();
+    let mut delivery = create_delivery_activity(20, multi.jobs[1].clone());
+    delivery.place.time = TimeWindow::new(delivery_start, 1_000.0);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &delivery,
+        next: route_ctx.route().tour.get(2),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let move_ctx = MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx);
+
+    let result = feature.constraint.unwrap().evaluate(&move_ctx);
+
+    assert_eq!(result.is_some(), expected);
+}
+
+parameterized_test! {can_get_direct_ride_duration_without_profile_scale, (from, to, expected), {
+    can_get_direct_ride_duration_without_profile_scale_impl(from, to, expected);
+}}
+
+can_get_direct_ride_duration_without_profile_scale! {
+    case01_reachable: (0, 1, Some(100.)),
+    case02_unreachable: (1, 0, None),
+}
+
+fn can_get_direct_ride_duration_without_profile_scale_impl(from: Location, to: Location, expected: Option<Duration>) {
+    let transport =
+        create_matrix_transport_cost(vec![MatrixData::new(0, None, vec![0., 100., -1., 0.], vec![0., 1., -1., 0.])])
+            .unwrap();
+    let profile = Profile::new(0, Some(2.))
+        .with_time_scales(vec![ProfileTimeScale { time: TimeWindow::new(0., 1000.), factor: 3. }]);
+
+    let result = get_direct_ride_duration(transport.as_ref(), &profile, from, to);
+
+    assert_eq!(result, expected);
+}
+
+fn create_excess_ride_time_feature(factor: Float) -> Feature {
+    let target = RelativeRideDuration { offset: 0., factor, min: 0. };
+
+    create_minimize_excess_ride_time_feature("excess", target, ScaledTransportCost::new_shared(10.0)).unwrap()
+}
+
+parameterized_test! {can_calculate_excess_ride_time_fitness, (soft_max_ride_duration, factor, expected), {
+    can_calculate_excess_ride_time_fitness_impl(soft_max_ride_duration, factor, expected);
+}}
+
+can_calculate_excess_ride_time_fitness! {
+    case01_relative_target: (None, 1., 100.),
+    case02_relative_target_without_excess: (None, 2., 0.),
+    case03_soft_target: (Some(150.), 1., 50.),
+}
+
+fn can_calculate_excess_ride_time_fitness_impl(
+    soft_max_ride_duration: Option<Duration>,
+    factor: Float,
+    expected: Cost,
+) {
+    let mut dimens: Dimensions = Default::default();
+    if let Some(soft_max_ride_duration) = soft_max_ride_duration {
+        dimens.set_job_soft_max_ride_duration(soft_max_ride_duration);
+    }
+    let multi = create_pudo_multi_job_with_dimens(dimens);
+    let pickup = create_pickup_activity(10, 100.0, multi.jobs[0].clone());
+    let mut delivery = create_delivery_activity(20, multi.jobs[1].clone());
+    delivery.schedule = Schedule::new(300.0, 360.0);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(
+            RouteBuilder::default().with_vehicle(&fleet, "v1").add_activity(pickup).add_activity(delivery).build(),
+        )
+        .build();
+    let insertion_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build();
+
+    let result = create_excess_ride_time_feature(factor).objective.unwrap().fitness(&insertion_ctx);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn can_estimate_excess_ride_time_of_delivery_insertion() {
+    let multi = create_pudo_multi_job(None);
+    let pickup = create_pickup_activity(10, 100.0, multi.jobs[0].clone());
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activity(pickup).build())
+        .build();
+    let mut delivery = create_delivery_activity(20, multi.jobs[1].clone());
+    delivery.place.time = TimeWindow::new(300.0, 1_000.0);
+    let activity_ctx = ActivityContext {
+        index: 1,
+        prev: route_ctx.route().tour.get(1).unwrap(),
+        target: &delivery,
+        next: route_ctx.route().tour.get(2),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let move_ctx = MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx);
+
+    // NOTE direct travel duration is 100s while delivery cannot start before 300s
+    let result = create_excess_ride_time_feature(1.).objective.unwrap().estimate(&move_ctx);
+
+    assert_eq!(result, 100.);
+}
@@ -131,3 +131,40 @@ fn can_create_empty_skills_as_none() {
     assert!(skills.one_of.is_none());
     assert!(skills.none_of.is_none());
 }
+
+parameterized_test! {can_check_skills_of_vehicle_and_driver, (all_of, vehicle_skills, driver_skills, expected), {
+    can_check_skills_of_vehicle_and_driver_impl(all_of, vehicle_skills, driver_skills, expected);
+}}
+
+can_check_skills_of_vehicle_and_driver! {
+    case01: (vec!["s1"], None, Some(vec!["s1"]), None),
+    case02: (vec!["s1", "s2"], Some(vec!["s1"]), Some(vec!["s2"]), None),
+    case03: (vec!["s1", "s2"], Some(vec!["s1"]), Some(vec!["s3"]), failure()),
+    case04: (vec!["s1", "s2"], None, Some(vec!["s1"]), failure()),
+}
+
+fn can_check_skills_of_vehicle_and_driver_impl(
+    all_of: Vec<&str>,
+    vehicle_skills: Option<Vec<&str>>,
+    driver_skills: Option<Vec<&str>>,
+    expected: Option<ConstraintViolation>,
+) {
+    let mut driver = test_driver();
+    if let Some(skills) = driver_skills {
+        driver.dimens.set_driver_skills(skills.iter().map(|s| s.to_string()).collect::<HashSet<_>>());
+    }
+    let fleet =
+        FleetBuilder::default().add_driver(driver).add_vehicle(create_vehicle_with_skills(vehicle_skills)).build();
+    let route_ctx =
+        RouteContextBuilder::default().with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").build()).build();
+
+    let constraint = create_skills_feature("skills", VIOLATION_CODE).unwrap().constraint.unwrap();
+
+    let actual = constraint.evaluate(&MoveContext::route(
+        &TestInsertionContextBuilder::default().build().solution,
+        &route_ctx,
+        &create_job_with_skills(Some(all_of), None, None),
+    ));
+
+    assert_eq!(actual, expected)
+}
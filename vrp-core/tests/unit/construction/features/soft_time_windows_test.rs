@@ -0,0 +1,88 @@
+use super::*;
+use crate::construction::heuristics::ActivityContext;
+use crate::helpers::construction::heuristics::TestInsertionContextBuilder;
+use crate::helpers::models::problem::{
+    FleetBuilder, TestSingleBuilder, TestTransportCost, test_driver, test_vehicle_with_id,
+};
+use crate::helpers::models::solution::{RouteBuilder, RouteContextBuilder};
+use crate::models::common::{Schedule, TimeWindow};
+use crate::models::solution::{Activity, Place};
+
+fn soft_activity(
+    location: usize,
+    arrival: Timestamp,
+    time: (Timestamp, Timestamp),
+    max_lateness: Duration,
+) -> Activity {
+    let mut single = TestSingleBuilder::default().build();
+    single.dimens.set_job_soft_time_windows(SoftTimeWindowPenalty::new(1., 2., max_lateness));
+    let time = TimeWindow::new(time.0, time.1);
+
+    Activity {
+        place: Place { idx: 0, location, duration: 0., time: time.clone() },
+        schedule: Schedule::new(arrival, arrival.max(time.start)),
+        job: Some(Arc::new(single)),
+        commute: None,
+    }
+}
+
+parameterized_test! {can_calculate_penalty, (arrival, time, max_lateness, expected), {
+    can_calculate_penalty_impl(arrival, time, max_lateness, expected);
+}}
+
+can_calculate_penalty! {
+    case01_in_time: (100., (0., 200.), 60., 0.),
+    case02_early: (100., (160., 260.), 60., 1.),
+    case03_late: (170., (0., 200.), 60., 1.),
+    case04_late_and_no_tolerance: (200., (0., 200.), 0., 0.),
+}
+
+fn can_calculate_penalty_impl(
+    arrival: Timestamp,
+    time: (Timestamp, Timestamp),
+    max_lateness: Duration,
+    expected: Cost,
+) {
+    let activity = soft_activity(1, arrival, time, max_lateness);
+
+    let result = get_penalty(&activity, arrival).unwrap();
+
+    assert!((result - expected).abs() < 1E-6, "expected {expected}, got {result}");
+}
+
+#[test]
+fn can_get_activity_lateness() {
+    let activity = soft_activity(1, 170., (0., 200.), 60.);
+
+    assert_eq!(get_activity_lateness(&activity, 170.), Some(30.));
+    assert_eq!(get_activity_lateness(&activity, 100.), Some(0.));
+    assert_eq!(get_activity_lateness(&Activity { job: None, ..activity }, 170.), None);
+}
+
+#[test]
+fn can_estimate_lateness_of_downstream_activity() {
+    let existing = soft_activity(10, 10., (0., 75.), 60.);
+    let fleet = FleetBuilder::default().add_driver(test_driver()).add_vehicle(test_vehicle_with_id("v1")).build();
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::default().with_vehicle(&fleet, "v1").add_activity(existing).build())
+        .build();
+    let target = Activity {
+        place: Place { idx: 0, location: 20, duration: 0., time: TimeWindow::new(0., 1000.) },
+        schedule: Schedule::new(0., 0.),
+        job: Some(TestSingleBuilder::default().build_shared()),
+        commute: None,
+    };
+    let activity_ctx = ActivityContext {
+        index: 0,
+        prev: route_ctx.route().tour.get(0).unwrap(),
+        target: &target,
+        next: route_ctx.route().tour.get(1),
+    };
+    let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+    let feature = create_minimize_lateness_feature("lateness", TestTransportCost::new_shared()).unwrap();
+
+    let result = feature.objective.unwrap().estimate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+    // NOTE existing activity moves from t=10 to t=30 while its time window ends at t=15
+    assert!((result - 15. * 2. / 60.).abs() < 1E-6, "unexpected insertion delta: {result}");
+}
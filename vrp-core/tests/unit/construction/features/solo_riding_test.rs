@@ -304,3 +304,45 @@ fn can_merge_jobs_impl(source_solo: bool, candidate_solo: bool, expected: Result
 
     assert_eq!(result, expected);
 }
+
+fn create_onboard_job_activity(job_id: &str, solo_riding: bool, delivery_loc: usize) -> Activity {
+    let mut builder = TestSingleBuilder::default();
+    builder.id(job_id).location(Some(delivery_loc)).demand(Demand::delivery(1));
+    builder.dimens_mut().set_job_onboard_since(0.);
+    if solo_riding {
+        builder.dimens_mut().set_job_solo_riding(true);
+    }
+
+    ActivityBuilder::with_location(delivery_loc).job(Some(builder.build_shared())).build()
+}
+
+#[test]
+fn rejects_pickup_before_onboard_solo_job_is_delivered() {
+    let onboard_delivery = create_onboard_job_activity("solo", true, 10);
+    let (_other, other_pickup, _) = create_pudo_job_activities("other", false, 2, 11);
+
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activity(onboard_delivery).build())
+        .build();
+
+    // Tour: [start(0), onboard_delivery(1), end(2)].
+    let result = evaluate_insertion(&route_ctx, &other_pickup, 0, 0, Some(1));
+    assert_eq!(result.map(|violation| violation.code), Some(SOLO_RIDING_VIOLATION_CODE));
+
+    let result = evaluate_insertion(&route_ctx, &other_pickup, 2, 1, Some(2));
+    assert!(result.is_none());
+}
+
+#[test]
+fn rejects_solo_pickup_before_onboard_job_is_delivered() {
+    let onboard_delivery = create_onboard_job_activity("other", false, 10);
+    let (_solo, solo_pickup, _) = create_pudo_job_activities("solo", true, 1, 11);
+
+    let route_ctx = RouteContextBuilder::default()
+        .with_route(RouteBuilder::with_default_vehicle().add_activity(onboard_delivery).build())
+        .build();
+
+    // Tour: [start(0), onboard_delivery(1), end(2)].
+    let result = evaluate_insertion(&route_ctx, &solo_pickup, 0, 0, Some(1));
+    assert_eq!(result.map(|violation| violation.code), Some(SOLO_RIDING_VIOLATION_CODE));
+}
@@ -136,6 +136,69 @@ mod timing {
         assert_eq!(result, expected);
     }
 
+    fn create_dwell_activity(location: Location, duration: Duration) -> Activity {
+        let mut builder = TestSingleBuilder::default();
+        builder.location(Some(location));
+        builder.dimens_mut().set_job_dwell_time(DwellTime { setup: 10., per_unit: vec![] });
+
+        ActivityBuilder::with_location_tw_and_duration(location, TimeWindow::max(), duration)
+            .job(Some(builder.build_shared()))
+            .build()
+    }
+
+    parameterized_test! {can_charge_shared_setup_of_next_activity_on_insertion, (target, expected), {
+        can_charge_shared_setup_of_next_activity_on_insertion_impl(target, expected);
+    }}
+
+    can_charge_shared_setup_of_next_activity_on_insertion! {
+        case01_another_location: (
+            ActivityBuilder::with_location_tw_and_duration(11, TimeWindow::max(), 0.).build(),
+            ConstraintViolation::skip(VIOLATION_CODE)
+        ),
+        case02_same_location: (create_dwell_activity(10, 10.), None),
+    }
+
+    fn can_charge_shared_setup_of_next_activity_on_insertion_impl(
+        target: Activity,
+        expected: Option<ConstraintViolation>,
+    ) {
+        // NOTE second job shares setup with the first one and has to be served before the shift end
+        let fleet = FleetBuilder::default()
+            .add_driver(test_driver())
+            .add_vehicles(vec![
+                TestVehicleBuilder::default()
+                    .id("v1")
+                    .details(vec![create_detail((Some(0), Some(0)), Some((0., 52.)))])
+                    .build(),
+            ])
+            .build();
+        let mut route_ctx = RouteContextBuilder::default()
+            .with_route(
+                RouteBuilder::default()
+                    .with_vehicle(&fleet, "v1")
+                    .add_activity(create_dwell_activity(10, 20.))
+                    .add_activity(create_dwell_activity(10, 20.))
+                    .build(),
+            )
+            .build();
+        let feature = TransportFeatureBuilder::new("transport")
+            .set_violation_code(VIOLATION_CODE)
+            .set_transport_cost(TestTransportCost::new_shared())
+            .set_activity_cost(Arc::new(DwellActivityCost::new(TestActivityCost::new_shared())))
+            .build_minimize_cost()
+            .unwrap();
+        feature.state.unwrap().accept_route_state(&mut route_ctx);
+        let solution_ctx = TestInsertionContextBuilder::default().build().solution;
+        let prev = route_ctx.route().tour.get(1).unwrap();
+        let next = route_ctx.route().tour.get(2);
+        let activity_ctx = ActivityContext { index: 1, prev, target: &target, next };
+
+        let result =
+            feature.constraint.unwrap().evaluate(&MoveContext::activity(&solution_ctx, &route_ctx, &activity_ctx));
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn can_update_activity_schedule() {
         let fleet = FleetBuilder::default()
@@ -185,6 +248,34 @@ mod timing {
         assert_eq!(route_ctx.route().tour.get(2).unwrap().schedule, Schedule { arrival: 35., departure: 60. });
     }
 
+    parameterized_test! {can_keep_queuing_duration_with_activity_after_insertion, (insertion_idx, expected), {
+        can_keep_queuing_duration_with_activity_after_insertion_impl(insertion_idx, expected);
+    }}
+
+    can_keep_queuing_duration_with_activity_after_insertion! {
+        case01_before_activity: (1, vec![0., 0., 0., 7., 0., 0.]),
+        case02_after_activity: (3, vec![0., 0., 7., 0., 0., 0.]),
+    }
+
+    fn can_keep_queuing_duration_with_activity_after_insertion_impl(insertion_idx: usize, expected: Vec<Duration>) {
+        let (feature, mut route_ctx) = create_feature_and_route((0, 0, 0., 1000.));
+        route_ctx.state_mut().set_queuing_duration_states(vec![0., 0., 7., 0., 0.]);
+        let job = TestSingleBuilder::default().location(Some(5)).build_shared();
+        let activity = ActivityBuilder::with_location(5).job(Some(job.clone())).build();
+        route_ctx.route_mut().tour.insert_at(activity, insertion_idx);
+        let mut solution_ctx = TestInsertionContextBuilder::default().with_routes(vec![route_ctx]).build().solution;
+
+        feature.state.unwrap().accept_insertion(&mut solution_ctx, 0, &Job::Single(job));
+
+        let route_ctx = solution_ctx.routes.first().unwrap();
+        let durations = (0..route_ctx.route().tour.total())
+            .map(|activity_idx| route_ctx.state().get_queuing_duration_at(activity_idx).copied().unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(durations, expected);
+        let activity = route_ctx.route().tour.get(expected.iter().position(|&d| d > 0.).unwrap()).unwrap();
+        assert_eq!(activity.place.location, 20);
+    }
+
     #[test]
     fn can_calculate_soft_activity_cost_for_empty_tour() {
         let fleet = FleetBuilder::default()
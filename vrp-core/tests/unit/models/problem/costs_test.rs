@@ -114,6 +114,101 @@ fn can_interpolate_durations() {
     assert_eq!(costs.distance_approx(&p1, 0, 1), 5.);
 }
 
+#[test]
+fn can_interpolate_durations_by_arrival() {
+    let route = Route { actor: test_actor_with_profile(0), tour: Default::default() };
+    let p0 = route.actor.vehicle.profile.clone();
+
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(p0.clone(), Some(0.), (10., 2), (1., 2)),
+            create_matrix_data(p0.clone(), Some(20.), (2., 2), (1., 2)),
+            create_matrix_data(p0, Some(40.), (12., 2), (1., 2)),
+        ],
+        2,
+        NoFallback,
+    )
+    .unwrap();
+
+    // NOTE arrival time function breakpoints are (0, 10), (20, 22) and (40, 52)
+    for &(arrival, duration) in &[(5., 10.), (10., 10.), (16., 6.), (22., 2.), (37., 7.), (52., 12.), (60., 12.)] {
+        let actual = costs.duration(&route, 0, 1, TravelTime::Arrival(arrival));
+        let departure = arrival - actual;
+
+        assert!((actual - duration).abs() < 1E-6, "arrival: {arrival}, expected: {duration}, got: {actual}");
+        assert!((costs.duration(&route, 0, 1, TravelTime::Departure(departure)) - actual).abs() < 1E-6);
+    }
+    assert_eq!(costs.time_breakpoints(&route.actor.vehicle.profile), &[0., 20., 40.]);
+}
+
+#[test]
+fn can_interpolate_durations_by_arrival_with_time_scales() {
+    let profile = Profile::new(0, None).with_time_scales(vec![
+        ProfileTimeScale { time: TimeWindow::new(5., 15.), factor: 2. },
+        ProfileTimeScale { time: TimeWindow::new(25., 35.), factor: 0.5 },
+    ]);
+
+    let costs = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(profile.clone(), Some(0.), (10., 2), (1., 2)),
+            create_matrix_data(profile.clone(), Some(20.), (2., 2), (1., 2)),
+            create_matrix_data(profile.clone(), Some(40.), (12., 2), (1., 2)),
+        ],
+        2,
+        NoFallback,
+    )
+    .unwrap();
+
+    for departure in (0..60).map(|value| value as Float) {
+        let duration = costs.interpolate_duration(&profile, 0, 1, TravelTime::Departure(departure));
+        let actual = costs.interpolate_duration(&profile, 0, 1, TravelTime::Arrival(departure + duration));
+
+        assert!((actual - duration).abs() < 1E-6, "departure: {departure}, expected: {duration}, got: {actual}");
+    }
+}
+
+#[test]
+fn can_reject_matrices_without_fifo_property() {
+    let p0 = Profile::default();
+
+    let result = TimeAwareMatrixTransportCost::new(
+        vec![
+            create_matrix_data(p0.clone(), Some(0.), (100., 1), (1., 1)),
+            create_matrix_data(p0, Some(10.), (80., 1), (1., 1)),
+        ],
+        1,
+        NoFallback,
+    );
+
+    assert_eq!(result.err(), Some("time-aware routing requires matrices to satisfy FIFO property".into()));
+}
+
+parameterized_test! {can_scale_duration_with_time_scales, (travel_time, duration, expected), {
+    can_scale_duration_with_time_scales_impl(travel_time, duration, expected);
+}}
+
+can_scale_duration_with_time_scales! {
+    case01_departure_before_scales: (TravelTime::Departure(0.), 10., 10.),
+    case02_departure_into_scale: (TravelTime::Departure(10.), 10., 15.),
+    case03_departure_within_scale: (TravelTime::Departure(20.), 4., 8.),
+    case04_departure_across_scales: (TravelTime::Departure(25.), 30., 22.5),
+    case05_arrival_after_scales: (TravelTime::Arrival(100.), 10., 10.),
+    case06_arrival_from_scale: (TravelTime::Arrival(35.), 15., 15.),
+    case07_arrival_across_scales: (TravelTime::Arrival(42.), 30., 27.5),
+    case08_unreachable: (TravelTime::Departure(20.), -1., -1.),
+}
+
+fn can_scale_duration_with_time_scales_impl(travel_time: TravelTime, duration: Duration, expected: Duration) {
+    let profile = Profile::new(0, None).with_time_scales(vec![
+        ProfileTimeScale { time: TimeWindow::new(30., 40.), factor: 0.5 },
+        ProfileTimeScale { time: TimeWindow::new(15., 30.), factor: 2. },
+    ]);
+
+    let result = scale_duration(&profile, duration, travel_time);
+
+    assert_eq!(result, expected);
+}
+
 mod objective {
     use super::*;
     use crate::construction::heuristics::{InsertionContext, MoveContext};
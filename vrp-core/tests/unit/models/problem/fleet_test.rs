@@ -1,4 +1,6 @@
-use crate::helpers::models::problem::{FleetBuilder, test_driver, test_vehicle};
+use crate::helpers::models::problem::{FleetBuilder, TestVehicleBuilder, test_driver, test_vehicle};
+use crate::models::common::TimeWindow;
+use crate::models::problem::{Driver, DriverDetail};
 
 #[test]
 fn fleet_creates_unique_profiles_from_vehicles() {
@@ -19,3 +21,63 @@ fn fleet_creates_unique_profiles_from_vehicles() {
         vec![profile1, profile2]
     )
 }
+
+parameterized_test! {can_create_actors_restricted_by_driver_details, (depot, time, expected), {
+    can_create_actors_restricted_by_driver_details_impl(depot, time, expected);
+}}
+
+can_create_actors_restricted_by_driver_details! {
+    case01_no_restrictions: (None, (0., 1000.), Some((Some(0.), Some(1000.), (0., 1000.)))),
+    case02_shorter_shift: (None, (100., 500.), Some((Some(100.), Some(500.), (100., 500.)))),
+    case03_matching_depot: (Some(0), (100., 2000.), Some((Some(100.), Some(1000.), (100., 1000.)))),
+    case04_different_depot: (Some(1), (0., 1000.), None),
+    case05_no_time_overlap: (None, (1500., 2000.), None),
+}
+
+#[allow(clippy::type_complexity)]
+fn can_create_actors_restricted_by_driver_details_impl(
+    depot: Option<usize>,
+    time: (f64, f64),
+    expected: Option<(Option<f64>, Option<f64>, (f64, f64))>,
+) {
+    let driver =
+        Driver { details: vec![DriverDetail { depot, time: TimeWindow::new(time.0, time.1) }], ..test_driver() };
+
+    let fleet = FleetBuilder::default().add_driver(driver).add_vehicle(test_vehicle(0)).build();
+
+    let actual = fleet.actors.first().map(|actor| {
+        (
+            actor.detail.start.as_ref().and_then(|start| start.time.earliest),
+            actor.detail.end.as_ref().and_then(|end| end.time.latest),
+            (actor.detail.time.start, actor.detail.time.end),
+        )
+    });
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn can_create_actors_for_each_driver_and_vehicle_pair() {
+    let driver_with_shifts = Driver {
+        details: vec![
+            DriverDetail { depot: None, time: TimeWindow::new(0., 500.) },
+            DriverDetail { depot: None, time: TimeWindow::new(500., 1000.) },
+        ],
+        ..test_driver()
+    };
+
+    let fleet = FleetBuilder::default()
+        .add_driver(test_driver())
+        .add_driver(driver_with_shifts)
+        .add_vehicle(TestVehicleBuilder::default().id("v1").build())
+        .add_vehicle(TestVehicleBuilder::default().id("v2").build())
+        .build();
+
+    assert_eq!(fleet.actors.len(), 6);
+    assert!(fleet.actors.iter().all(|actor| fleet.conflicts.has_conflicts(actor)));
+    fleet.actors.iter().filter(|actor| actor.driver.details.is_empty()).for_each(|actor| {
+        assert_eq!(fleet.conflicts.get(actor).count(), 2);
+    });
+    fleet.actors.iter().filter(|actor| !actor.driver.details.is_empty()).for_each(|actor| {
+        assert_eq!(fleet.conflicts.get(actor).count(), 3);
+    });
+}
@@ -1,7 +1,7 @@
 use crate::helpers::models::domain::test_random;
 use crate::helpers::models::problem::{FleetBuilder, TestVehicleBuilder, test_driver, test_vehicle_detail};
-use crate::models::common::TimeInterval;
-use crate::models::problem::{Actor, VehicleDetail, VehiclePlace};
+use crate::models::common::{TimeInterval, TimeWindow};
+use crate::models::problem::{Actor, Driver, DriverDetail, VehicleDetail, VehicleIdDimension, VehiclePlace};
 use crate::models::solution::Registry;
 use std::sync::Arc;
 
@@ -65,3 +65,63 @@ fn create_two_test_vehicle_details() -> Vec<VehicleDetail> {
         },
     ]
 }
+
+#[test]
+fn can_block_actors_sharing_driver_shift() {
+    let driver =
+        Driver { details: vec![DriverDetail { depot: None, time: TimeWindow::new(0., 1000.) }], ..test_driver() };
+    let fleet = FleetBuilder::default()
+        .add_driver(driver)
+        .add_vehicles(vec![
+            TestVehicleBuilder::default().id("v1").build(),
+            TestVehicleBuilder::default().id("v2").build(),
+        ])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    let actor = registry.available().next().unwrap();
+
+    assert!(registry.use_actor(&actor));
+    assert_eq!(registry.available().count(), 0);
+    assert!(!registry.use_actor(fleet.actors.iter().find(|other| !Arc::ptr_eq(other, &actor)).unwrap()));
+
+    assert!(registry.free_actor(&actor));
+    assert_eq!(registry.available().count(), 2);
+}
+
+#[test]
+fn can_block_actors_sharing_vehicle_shift() {
+    let create_driver = |start: f64, end: f64| Driver {
+        details: vec![DriverDetail { depot: None, time: TimeWindow::new(start, end) }],
+        ..test_driver()
+    };
+    let fleet = FleetBuilder::default()
+        .add_driver(create_driver(0., 500.))
+        .add_driver(create_driver(500., 1000.))
+        .add_driver(create_driver(0., 1000.))
+        .add_vehicles(vec![
+            TestVehicleBuilder::default().id("v1").build(),
+            TestVehicleBuilder::default().id("v2").build(),
+        ])
+        .build();
+    let mut registry = Registry::new(&fleet, test_random());
+    let get_actor = |vehicle_id: &str, driver_time: TimeWindow| {
+        fleet
+            .actors
+            .iter()
+            .find(|actor| {
+                actor.vehicle.dimens.get_vehicle_id().is_some_and(|id| id == vehicle_id)
+                    && actor.driver.details[0].time == driver_time
+            })
+            .cloned()
+            .unwrap()
+    };
+    let first = get_actor("v1", TimeWindow::new(0., 500.));
+    let second = get_actor("v2", TimeWindow::new(500., 1000.));
+
+    assert!(registry.use_actor(&first));
+    assert!(registry.use_actor(&second));
+    assert_eq!(registry.available().count(), 0);
+
+    assert!(registry.free_actor(&first));
+    assert_eq!(registry.available().count(), 2);
+}
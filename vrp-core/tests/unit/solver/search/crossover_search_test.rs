@@ -0,0 +1,84 @@
+use super::*;
+use crate::helpers::models::domain::get_customer_ids_from_routes;
+use crate::helpers::models::problem::get_job_id;
+use crate::helpers::solver::{create_default_refinement_ctx, generate_matrix_routes_with_defaults};
+use rosomaxa::prelude::Environment;
+
+/// Creates a solution with 3 routes of 4 jobs each and a donor where the first job of the first route
+/// is moved into the second route.
+fn create_parents() -> (InsertionContext, InsertionContext) {
+    let environment = Arc::new(Environment::default());
+    let (problem, solution) = generate_matrix_routes_with_defaults(4, 3, false);
+    let parent = InsertionContext::new_from_solution(Arc::new(problem), (solution, None), environment);
+
+    let mut donor = parent.deep_copy();
+    let activity = donor.solution.routes[0].route().tour.get(1).unwrap().deep_copy();
+    let job = activity.retrieve_job().unwrap();
+    donor.solution.routes[0].route_mut().tour.remove(&job);
+    donor.solution.routes[1].route_mut().tour.insert_at(activity, 1);
+    donor.problem.goal.accept_solution_state(&mut donor.solution);
+
+    (parent, donor)
+}
+
+#[test]
+fn can_exchange_route_with_removal_of_donor_jobs() {
+    let (parent, donor) = create_parents();
+    let mut child = parent.deep_copy();
+
+    exchange_routes(&mut child, &donor, &[1]);
+
+    assert_eq!(
+        get_customer_ids_from_routes(&child),
+        vec![vec!["c1", "c2", "c3"], vec!["c8", "c9", "c10", "c11"], vec!["c0", "c4", "c5", "c6", "c7"]]
+    );
+    assert!(child.solution.required.is_empty());
+}
+
+#[test]
+fn can_exchange_route_with_required_jobs() {
+    let (parent, donor) = create_parents();
+    let mut child = parent.deep_copy();
+
+    exchange_routes(&mut child, &donor, &[0]);
+
+    assert_eq!(child.solution.routes.len(), 3);
+    assert_eq!(child.solution.required.iter().map(get_job_id).collect::<Vec<_>>(), vec!["c0"]);
+}
+
+#[test]
+fn can_keep_routes_with_locked_jobs() {
+    let (parent, donor) = create_parents();
+    let mut child = parent.deep_copy();
+    let locked = child.solution.routes[1].route().tour.jobs().next().cloned().unwrap();
+    child.solution.locked.insert(locked);
+
+    exchange_routes(&mut child, &donor, &[1]);
+
+    assert_eq!(get_customer_ids_from_routes(&child), get_customer_ids_from_routes(&parent));
+}
+
+#[test]
+fn can_recombine_solutions() {
+    let (parent, donor) = create_parents();
+    let mut refinement_ctx = create_default_refinement_ctx(parent.problem.clone());
+    refinement_ctx.add_solution(donor);
+    let search = CrossoverSearch::new(Arc::new(RecreateWithCheapest::new(parent.environment.random.clone())));
+
+    let child = search.search(&refinement_ctx, &parent);
+
+    assert!(child.solution.required.is_empty());
+    assert!(child.solution.unassigned.is_empty());
+    assert_eq!(child.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 12);
+}
+
+#[test]
+fn can_return_copy_without_donor() {
+    let (parent, _) = create_parents();
+    let refinement_ctx = create_default_refinement_ctx(parent.problem.clone());
+    let search = CrossoverSearch::new(Arc::new(RecreateWithCheapest::new(parent.environment.random.clone())));
+
+    let child = search.search(&refinement_ctx, &parent);
+
+    assert_eq!(get_customer_ids_from_routes(&child), get_customer_ids_from_routes(&parent));
+}
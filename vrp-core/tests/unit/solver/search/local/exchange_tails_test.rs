@@ -0,0 +1,60 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::*;
+use crate::helpers::utils::create_test_environment_with_random;
+use crate::helpers::utils::random::FakeRandom;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+parameterized_test! { can_exchange_tails, (cuts, locked_ids, expected_route_ids), {
+    can_exchange_tails_impl(cuts, locked_ids, expected_route_ids);
+}}
+
+can_exchange_tails! {
+    case_01: ((2, 3), &[], &[vec!["c0", "c1", "c8", "c9"], vec!["c5", "c6", "c7", "c2", "c3", "c4"]]),
+    case_02: ((5, 0), &[], &[vec!["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9"]]),
+    case_03: ((0, 5), &[], &[vec!["c5", "c6", "c7", "c8", "c9", "c0", "c1", "c2", "c3", "c4"]]),
+    case_04: ((2, 3), &["c3"], &[vec!["c0", "c1", "c3", "c8", "c9"], vec!["c5", "c6", "c7", "c2", "c4"]]),
+}
+
+fn can_exchange_tails_impl(cuts: (usize, usize), locked_ids: &[&str], expected_route_ids: &[Vec<&str>]) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let mut insertion_ctx = promote_to_locked(
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default())),
+        locked_ids,
+    );
+
+    exchange_tails(&mut insertion_ctx, (0, cuts.0), (1, cuts.1));
+
+    assert_eq!(get_customer_ids_from_routes(&insertion_ctx), expected_route_ids);
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_explore_solution() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let problem = Arc::new(problem);
+    let environment = create_test_environment_with_random(Arc::new(FakeRandom::new(vec![1, 0, 4, 1], vec![])));
+    let insertion_ctx = InsertionContext::new_from_solution(problem.clone(), (solution, None), environment);
+
+    let result = ExchangeTails::default()
+        .explore(&create_default_refinement_ctx(problem), &insertion_ctx)
+        .expect("cannot explore solution");
+
+    assert_eq!(
+        get_customer_ids_from_routes(&result),
+        vec![vec!["c0", "c9"], vec!["c5", "c6", "c7", "c8", "c1", "c2", "c3", "c4"]]
+    );
+}
+
+#[test]
+fn can_skip_solution_with_one_route() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 1, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx =
+        InsertionContext::new_from_solution(problem.clone(), (solution, None), Arc::new(Environment::default()));
+
+    let result = ExchangeTails::default().explore(&create_default_refinement_ctx(problem), &insertion_ctx);
+
+    assert!(result.is_none());
+}
@@ -0,0 +1,55 @@
+use super::*;
+use crate::helpers::models::domain::*;
+use crate::helpers::solver::*;
+use rosomaxa::prelude::Environment;
+use std::sync::Arc;
+
+parameterized_test! { can_relocate_segment, (target_idx, segment, is_reversed, expected_source_ids, expected_segment_ids), {
+    can_relocate_segment_impl(target_idx, segment, is_reversed, expected_source_ids, expected_segment_ids);
+}}
+
+can_relocate_segment! {
+    case_01_inter_route: (1, 1..4, false, vec!["c0", "c4"], vec!["c1", "c2", "c3"]),
+    case_02_inter_route_reversed: (1, 1..4, true, vec!["c0", "c4"], vec!["c3", "c2", "c1"]),
+    case_03_inter_route_single: (1, 4..5, false, vec!["c0", "c1", "c2", "c3"], vec!["c4"]),
+    case_04_intra_route: (0, 0..2, false, vec![], vec!["c0", "c1"]),
+}
+
+fn can_relocate_segment_impl(
+    target_idx: usize,
+    segment: Range<usize>,
+    is_reversed: bool,
+    expected_source_ids: Vec<&str>,
+    expected_segment_ids: Vec<&str>,
+) {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let mut insertion_ctx =
+        InsertionContext::new_from_solution(Arc::new(problem), (solution, None), Arc::new(Environment::default()));
+
+    relocate_segment(&mut insertion_ctx, 0, target_idx, segment, is_reversed);
+
+    let route_ids = get_customer_ids_from_routes(&insertion_ctx);
+    let target_ids = &route_ids[target_idx];
+    let start = target_ids.iter().position(|id| id == expected_segment_ids[0]).expect("cannot find segment");
+    assert_eq!(&target_ids[start..start + expected_segment_ids.len()], expected_segment_ids.as_slice());
+    assert_eq!(route_ids.iter().map(|ids| ids.len()).sum::<usize>(), 10);
+    if target_idx != 0 {
+        assert_eq!(route_ids[0], expected_source_ids);
+    }
+    assert!(insertion_ctx.solution.unassigned.is_empty());
+}
+
+#[test]
+fn can_explore_solution() {
+    let (problem, solution) = generate_matrix_routes_with_defaults(5, 2, false);
+    let problem = Arc::new(problem);
+    let insertion_ctx =
+        InsertionContext::new_from_solution(problem.clone(), (solution, None), Arc::new(Environment::default()));
+
+    let result = RelocateSegment::default()
+        .explore(&create_default_refinement_ctx(problem), &insertion_ctx)
+        .expect("cannot explore solution");
+
+    assert!(result.solution.unassigned.is_empty());
+    assert_eq!(result.solution.routes.iter().map(|route_ctx| route_ctx.route().tour.job_count()).sum::<usize>(), 10);
+}
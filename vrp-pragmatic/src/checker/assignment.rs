@@ -3,11 +3,13 @@
 mod assignment_test;
 
 use super::*;
-use crate::format::get_indices;
 use crate::format::solution::activity_matcher::*;
+use crate::format::{ChargingPowerDimension, get_indices};
 use crate::utils::combine_error_results;
 use std::collections::HashSet;
 use vrp_core::construction::clustering::vicinity::ServingPolicy;
+use vrp_core::construction::enablers::JobParkingDimension;
+use vrp_core::construction::features::get_direct_ride_duration;
 use vrp_core::models::solution::Place;
 use vrp_core::prelude::GenericResult;
 use vrp_core::utils::GenericError;
@@ -16,49 +18,71 @@ use vrp_core::utils::GenericError;
 pub fn check_assignment(ctx: &CheckerContext) -> Result<(), Vec<GenericError>> {
     combine_error_results(&[
         check_vehicles(ctx),
+        check_drivers(ctx),
         check_jobs_presence(ctx),
         check_jobs_match(ctx),
         check_fixed_order(ctx),
         check_max_ride_duration(ctx),
         check_solo_riding(ctx),
+        check_max_onboard_waiting(ctx),
         check_lifo(ctx),
         check_groups(ctx),
     ])
 }
 
-/// Checks the final scheduled interval constrained by `maxRideDuration`.
+/// Checks the final scheduled interval constrained by `maxRideDuration` and `relativeMaxRideDuration`.
 ///
 /// For jobs with multiple pickups/deliveries, the earliest pickup departure to
 /// latest delivery arrival is the longest passenger/item ride and therefore the
-/// conservative interval to validate.
+/// conservative interval to validate. A ride of onboard job starts at its onboard time.
+/// Relative limit uses the travel duration between locations of these pickup and delivery
+/// from the vehicle's routing matrix as the direct one.
 fn check_max_ride_duration(ctx: &CheckerContext) -> GenericResult<()> {
     let constrained_jobs = ctx
         .problem
         .plan
         .jobs
         .iter()
-        .filter_map(|job| job.max_ride_duration.map(|limit| (job.id.as_str(), limit)))
+        .filter(|job| job.max_ride_duration.is_some() || job.relative_max_ride_duration.is_some())
+        .map(|job| (job.id.as_str(), job))
         .collect::<HashMap<_, _>>();
     if constrained_jobs.is_empty() {
         return Ok(());
     }
 
+    let onboard_since = ctx
+        .problem
+        .plan
+        .get_onboard_jobs()
+        .map(|(_, onboard)| (onboard.job_id.as_str(), parse_time(&onboard.since)))
+        .collect::<HashMap<_, _>>();
+
+    type RideEvent = (Float, Option<Location>);
+
     ctx.solution.tours.iter().try_for_each(|tour| {
-        let mut intervals = HashMap::<&str, (Vec<Float>, Vec<Float>)>::new();
+        let mut intervals = HashMap::<&str, (Vec<RideEvent>, Vec<RideEvent>)>::new();
         for stop in &tour.stops {
             let schedule = stop.schedule();
             for activity in stop.activities() {
                 if !constrained_jobs.contains_key(activity.job_id.as_str()) {
                     continue;
                 }
-                let entry = intervals.entry(activity.job_id.as_str()).or_default();
+                let entry = intervals.entry(activity.job_id.as_str()).or_insert_with(|| {
+                    (
+                        onboard_since.get(activity.job_id.as_str()).map(|since| (*since, None)).into_iter().collect(),
+                        vec![],
+                    )
+                });
+                let location = ctx.get_activity_location(stop, activity);
                 match activity.activity_type.as_str() {
-                    "pickup" => {
-                        entry.0.push(parse_time(activity.time.as_ref().map_or(&schedule.departure, |time| &time.end)))
-                    }
-                    "delivery" => {
-                        entry.1.push(parse_time(activity.time.as_ref().map_or(&schedule.arrival, |time| &time.start)))
-                    }
+                    "pickup" => entry.0.push((
+                        parse_time(activity.time.as_ref().map_or(&schedule.departure, |time| &time.end)),
+                        location,
+                    )),
+                    "delivery" => entry.1.push((
+                        parse_time(activity.time.as_ref().map_or(&schedule.arrival, |time| &time.start)),
+                        location,
+                    )),
                     _ => {}
                 }
             }
@@ -68,10 +92,36 @@ fn check_max_ride_duration(ctx: &CheckerContext) -> GenericResult<()> {
             if pickups.is_empty() || deliveries.is_empty() {
                 return Ok(());
             }
-            let pickup_departure = pickups.into_iter().min_by(Float::total_cmp).unwrap();
-            let delivery_arrival = deliveries.into_iter().max_by(Float::total_cmp).unwrap();
+            let (pickup_departure, pickup_location) =
+                pickups.into_iter().min_by(|(a, _), (b, _)| a.total_cmp(b)).unwrap();
+            let (delivery_arrival, delivery_location) =
+                deliveries.into_iter().max_by(|(a, _), (b, _)| a.total_cmp(b)).unwrap();
             let ride_duration = delivery_arrival - pickup_departure;
-            let limit = constrained_jobs[job_id];
+
+            let job = constrained_jobs[job_id];
+            let relative_limit = match (&job.relative_max_ride_duration, pickup_location, delivery_location) {
+                (Some(relative), Some(pickup_location), Some(delivery_location)) => {
+                    let profile = ctx.get_vehicle_profile(&tour.vehicle_id)?;
+                    let direct = get_direct_ride_duration(
+                        ctx.core_problem.transport.as_ref(),
+                        &profile,
+                        ctx.get_location_index(&pickup_location)?,
+                        ctx.get_location_index(&delivery_location)?,
+                    )
+                    .ok_or_else(|| format!("cannot get direct ride duration for job '{job_id}'"))?;
+
+                    Some(
+                        (relative.offset.unwrap_or_default() + relative.factor * direct)
+                            .max(relative.min.unwrap_or_default()),
+                    )
+                }
+                _ => None,
+            };
+            let limit = match (job.max_ride_duration, relative_limit) {
+                (Some(limit), Some(relative_limit)) => limit.min(relative_limit),
+                (limit, relative_limit) => limit.or(relative_limit).unwrap_or(Float::MAX),
+            };
+
             if ride_duration > limit + 1. {
                 Err(format!(
                     "max ride duration is not respected for job '{}': duration {:.0}s exceeds {:.0}s",
@@ -105,6 +155,53 @@ fn check_vehicles(ctx: &CheckerContext) -> GenericResult<()> {
     Ok(())
 }
 
+/// Checks that drivers are known in problem and each tour fits into a driver shift which is used once.
+fn check_drivers(ctx: &CheckerContext) -> GenericResult<()> {
+    let drivers = ctx.problem.fleet.drivers.as_ref().filter(|drivers| !drivers.is_empty());
+    let mut used_shifts = HashSet::<(&str, usize)>::new();
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let Some(drivers) = drivers else {
+            return match tour.driver_id.as_ref() {
+                Some(driver_id) => Err(format!("used driver with unknown id: '{driver_id}'").into()),
+                None => Ok(()),
+            };
+        };
+
+        let driver_id =
+            tour.driver_id.as_ref().ok_or_else(|| format!("tour of vehicle '{}' has no driver", tour.vehicle_id))?;
+        let driver = drivers
+            .iter()
+            .find(|driver| driver.id == *driver_id)
+            .ok_or_else(|| format!("used driver with unknown id: '{driver_id}'"))?;
+
+        let (Some(first), Some(last)) = (tour.stops.first(), tour.stops.last()) else { return Ok(()) };
+        let tour_time = TimeWindow::new(parse_time(&first.schedule().departure), parse_time(&last.schedule().arrival));
+        let start_location = first.location().map(|location| ctx.get_location_index(location)).transpose()?;
+
+        let shift_index = driver
+            .shifts
+            .iter()
+            .position(|shift| {
+                let shift_time = TimeWindow::new(parse_time(&shift.earliest), parse_time(&shift.latest));
+                let is_depot_match = shift.depot.as_ref().is_none_or(|depot| {
+                    ctx.get_location_index(depot).ok().zip(start_location).is_some_and(|(depot, start)| depot == start)
+                });
+
+                is_depot_match && shift_time.start <= tour_time.start && tour_time.end <= shift_time.end
+            })
+            .ok_or_else(|| {
+                format!("tour of vehicle '{}' does not fit into any shift of driver '{}'", tour.vehicle_id, driver_id)
+            })?;
+
+        if used_shifts.insert((driver_id.as_str(), shift_index)) {
+            Ok(())
+        } else {
+            Err(format!("driver '{driver_id}' is used more than once for shift {shift_index}").into())
+        }
+    })
+}
+
 /// Checks job task rules.
 fn check_jobs_presence(ctx: &CheckerContext) -> GenericResult<()> {
     struct JobAssignment {
@@ -244,8 +341,17 @@ fn check_jobs_match(ctx: &CheckerContext) -> GenericResult<()> {
                                                 true
                                             }
                                         }
-                                        Ok(Some(JobInfo(_, _, place, time))) => {
-                                            is_valid_job_info(ctx, stop, activity, *idx, place, time)
+                                        Ok(Some(JobInfo(_, single, place, time))) => {
+                                            let has_charging = single.dimens.get_charging_power().is_some();
+                                            // NOTE parking is done before service start
+                                            let parking = single
+                                                .dimens
+                                                .get_job_parking()
+                                                .and_then(|parking| parking.get(place.idx))
+                                                .copied()
+                                                .unwrap_or_default();
+                                            let place = Place { duration: place.duration - parking, ..place };
+                                            is_valid_job_info(ctx, stop, activity, *idx, place, time, has_charging)
                                         }
                                         _ => false,
                                     }
@@ -335,7 +441,8 @@ fn check_fixed_order(ctx: &CheckerContext) -> GenericResult<()> {
 
 /// Checks that a solo-riding job does not overlap another dynamic pickup-delivery job while onboard.
 /// A parent job becomes active on its first dynamic pickup and completes on its final dynamic delivery,
-/// which also handles companion jobs with unequal pickup and delivery activity counts.
+/// which also handles companion jobs with unequal pickup and delivery activity counts. Onboard jobs are
+/// active from the tour start.
 fn check_solo_riding(ctx: &CheckerContext) -> GenericResult<()> {
     let jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
     let solo_jobs = jobs
@@ -350,15 +457,25 @@ fn check_solo_riding(ctx: &CheckerContext) -> GenericResult<()> {
     let dynamic_delivery_counts = jobs
         .iter()
         .map(|(job_id, job)| {
-            let count = job.deliveries.iter().flatten().filter(|task| is_dynamic_task(job, "delivery", task)).count();
+            let count = job.deliveries.iter().flatten().filter(|task| is_ride_task(ctx, job, "delivery", task)).count();
             (*job_id, count)
         })
         .collect::<HashMap<_, _>>();
 
     ctx.solution.tours.iter().try_for_each(|tour| {
-        let mut active_jobs = HashSet::<&str>::new();
+        let mut active_jobs = get_onboard_job_ids(ctx, tour).into_iter().collect::<HashSet<_>>();
         let mut completed_deliveries = HashMap::<&str, usize>::new();
-        let mut active_solo: Option<&str> = None;
+        let mut active_solo = active_jobs.iter().copied().find(|job_id| solo_jobs.contains(job_id));
+
+        if let Some(solo_job_id) = active_solo
+            && active_jobs.len() > 1
+        {
+            return Err(format!(
+                "solo riding is not respected in tour '{}'/{}: solo job '{}' is onboard with another job",
+                tour.vehicle_id, tour.shift_index, solo_job_id
+            )
+            .into());
+        }
 
         tour.stops
             .iter()
@@ -372,7 +489,7 @@ fn check_solo_riding(ctx: &CheckerContext) -> GenericResult<()> {
                     return Ok(());
                 };
 
-                if !is_dynamic_task(job, activity.activity_type.as_str(), task) {
+                if !is_ride_task(ctx, job, activity.activity_type.as_str(), task) {
                     return Ok(());
                 }
 
@@ -446,7 +563,7 @@ fn check_solo_riding(ctx: &CheckerContext) -> GenericResult<()> {
 
 /// Checks that dynamic pickup-delivery jobs follow LIFO ordering when their `lifoTag` is enforced
 /// by the concrete vehicle. Each enforced tag has its own independent stack, matching the solver's
-/// LIFO feature semantics.
+/// LIFO feature semantics. Onboard jobs are put on their stacks in order of their onboard time.
 fn check_lifo(ctx: &CheckerContext) -> GenericResult<()> {
     let jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
 
@@ -474,6 +591,13 @@ fn check_lifo(ctx: &CheckerContext) -> GenericResult<()> {
         }
 
         let mut stacks = HashMap::<&str, Vec<&str>>::new();
+        get_onboard_job_ids(ctx, tour).into_iter().for_each(|job_id| {
+            if let Some(lifo_tag) =
+                jobs.get(job_id).and_then(|job| job.lifo_tag.as_deref()).filter(|tag| enforced_tags.contains(tag))
+            {
+                stacks.entry(lifo_tag).or_default().push(job_id);
+            }
+        });
 
         tour.stops
             .iter()
@@ -490,7 +614,7 @@ fn check_lifo(ctx: &CheckerContext) -> GenericResult<()> {
                     return Ok(());
                 };
 
-                if !is_dynamic_task(job, activity.activity_type.as_str(), task) {
+                if !is_ride_task(ctx, job, activity.activity_type.as_str(), task) {
                     return Ok(());
                 }
 
@@ -570,6 +694,120 @@ fn get_activity_task<'a>(job: &'a Job, activity: &Activity) -> GenericResult<Opt
     Ok(tasks.iter().find(|task| task.places.iter().any(|place| place.tag.as_ref() == Some(tag))))
 }
 
+/// Checks that waiting at activities does not exceed vehicle's `maxOnboardWaiting` limit while some
+/// pickup-delivery job is onboard on arrival. Waiting is the time between arrival at the activity and
+/// its service start. Onboard jobs are onboard from the tour start.
+fn check_max_onboard_waiting(ctx: &CheckerContext) -> GenericResult<()> {
+    let jobs = ctx.problem.plan.jobs.iter().map(|job| (job.id.as_str(), job)).collect::<HashMap<_, _>>();
+
+    ctx.solution.tours.iter().try_for_each(|tour| {
+        let vehicle = ctx.get_vehicle(&tour.vehicle_id)?;
+        let Some(max_waiting) = vehicle.limits.as_ref().and_then(|limits| limits.max_onboard_waiting) else {
+            return Ok(());
+        };
+
+        let mut onboard = get_onboard_job_ids(ctx, tour).into_iter().collect::<HashSet<_>>();
+        let mut completed_deliveries = HashMap::<&str, usize>::new();
+
+        tour.stops.iter().flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity))).try_fold(
+            None,
+            |prev_departure: Option<Float>, (stop, activity)| -> GenericResult<_> {
+                let time = ctx.get_activity_time(stop, activity);
+                let arrival = get_activity_arrival(stop, activity, prev_departure);
+                let waiting = (time.start - arrival).max(0.);
+
+                if !onboard.is_empty() && waiting > max_waiting {
+                    return Err(format!(
+                        "max onboard waiting is not respected in tour '{}'/{} at activity '{}' of job '{}': \
+                         {}s exceeds {}s",
+                        tour.vehicle_id,
+                        tour.shift_index,
+                        activity.activity_type,
+                        activity.job_id,
+                        waiting,
+                        max_waiting
+                    )
+                    .into());
+                }
+
+                if let Some(job) = jobs.get(activity.job_id.as_str())
+                    && let Some(task) = get_activity_task(job, activity)?
+                    && is_ride_task(ctx, job, activity.activity_type.as_str(), task)
+                {
+                    match activity.activity_type.as_str() {
+                        "pickup" => {
+                            onboard.insert(job.id.as_str());
+                        }
+                        "delivery" => {
+                            let completed = completed_deliveries.entry(job.id.as_str()).or_default();
+                            *completed += 1;
+
+                            let total = job
+                                .deliveries
+                                .iter()
+                                .flatten()
+                                .filter(|task| is_ride_task(ctx, job, "delivery", task))
+                                .count();
+                            if *completed >= total {
+                                onboard.remove(job.id.as_str());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+
+                Ok(Some(time.end))
+            },
+        )?;
+
+        Ok(())
+    })
+}
+
+/// Returns arrival time at the activity: the first activity of the stop is reached after parking,
+/// other activities start after the previous one is finished. Commute to the activity is respected.
+fn get_activity_arrival(stop: &Stop, activity: &Activity, prev_departure: Option<Float>) -> Float {
+    if let Some(forward) = activity.commute.as_ref().and_then(|commute| commute.forward.as_ref()) {
+        return parse_time(&forward.time.end);
+    }
+
+    let is_first = stop.activities().first().is_some_and(|first| std::ptr::eq(first, activity));
+
+    match (stop, prev_departure) {
+        (Stop::Point(PointStop { parking: Some(parking), .. }), _) if is_first => parse_time(&parking.end),
+        (_, Some(departure)) if !is_first => departure,
+        _ => parse_time(&stop.schedule().arrival),
+    }
+}
+
+/// Returns ids of onboard jobs served in the tour ordered by their onboard time.
+fn get_onboard_job_ids<'a>(ctx: &'a CheckerContext, tour: &Tour) -> Vec<&'a str> {
+    let tour_job_ids = tour.stops.iter().flat_map(|stop| stop.activities()).map(|activity| activity.job_id.as_str());
+    let tour_job_ids = tour_job_ids.collect::<HashSet<_>>();
+
+    let mut onboard = ctx
+        .problem
+        .plan
+        .get_onboard_jobs()
+        .filter(|(vehicle, onboard)| {
+            vehicle.vehicle_id == tour.vehicle_id
+                && vehicle.shift_index.unwrap_or_default() == tour.shift_index
+                && tour_job_ids.contains(onboard.job_id.as_str())
+        })
+        .map(|(_, onboard)| (onboard.job_id.as_str(), parse_time(&onboard.since)))
+        .collect::<Vec<_>>();
+    onboard.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+    onboard.into_iter().map(|(job_id, _)| job_id).collect()
+}
+
+/// Checks whether the task is a part of the ride: a dynamic pickup or delivery, or a delivery of onboard job.
+fn is_ride_task(ctx: &CheckerContext, job: &Job, activity_type: &str, task: &JobTask) -> bool {
+    is_dynamic_task(job, activity_type, task)
+        || (activity_type == "delivery"
+            && ctx.problem.plan.get_onboard_jobs().any(|(_, onboard)| onboard.job_id == job.id))
+}
+
 fn is_dynamic_task(job: &Job, activity_type: &str, task: &JobTask) -> bool {
     let has_pickups = job.pickups.as_ref().is_some_and(|tasks| !tasks.is_empty());
     let has_deliveries = job.deliveries.as_ref().is_some_and(|tasks| !tasks.is_empty());
@@ -586,6 +824,7 @@ fn is_valid_job_info(
     activity_idx: usize,
     place: Place,
     time: TimeWindow,
+    has_charging: bool,
 ) -> bool {
     let not_equal = |left: Float, right: Float| left != right;
     let parking = ctx.clustering.as_ref().map(|config| config.serving.get_parking()).unwrap_or(0.);
@@ -596,8 +835,10 @@ fn is_valid_job_info(
     match (&ctx.clustering, &activity.commute, domain_commute) {
         (_, _, Err(_)) | (_, None, Ok(Some(_))) | (_, Some(_), Ok(None)) | (&None, &Some(_), Ok(Some(_))) => true,
         (_, None, Ok(None)) => {
-            let expected_departure = time.start.max(place.time.start) + place.duration + extra_time;
-            not_equal(time.end, expected_departure)
+            let shared_setup = get_shared_dwell_setup(ctx, stop, activity, activity_idx);
+            let expected_departure = time.start.max(place.time.start) + place.duration - shared_setup + extra_time;
+            // NOTE charging time is added to recharge station's duration, its energy is checked separately
+            if has_charging { time.end < expected_departure } else { not_equal(time.end, expected_departure) }
         }
         (Some(config), Some(commute), Ok(Some(d_commute))) => {
             let (service_time, parking) = match config.serving {
@@ -628,6 +869,22 @@ fn is_valid_job_info(
     }
 }
 
+/// Returns dwell setup time of the activity which is already charged by the previous activity at the same stop.
+fn get_shared_dwell_setup(ctx: &CheckerContext, stop: &PointStop, activity: &Activity, activity_idx: usize) -> Float {
+    let get_setup = |activity: &Activity| {
+        ctx.get_job_by_id(&activity.job_id)
+            .and_then(|job| job.dwell_time.as_ref())
+            .map(|dwell_time| dwell_time.setup.unwrap_or_default())
+    };
+
+    activity_idx
+        .checked_sub(1)
+        .and_then(|prev_idx| stop.activities.get(prev_idx))
+        .and_then(get_setup)
+        .zip(get_setup(activity))
+        .map_or(0., |(prev, setup)| prev.min(setup))
+}
+
 fn check_groups(ctx: &CheckerContext) -> GenericResult<()> {
     let violations = ctx
         .solution
@@ -10,7 +10,7 @@ use vrp_core::utils::GenericError;
 
 /// Checks that breaks are properly assigned.
 pub fn check_breaks(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_break_assignment(context)])
+    combine_error_results(&[check_break_assignment(context), check_driving_rules(context)])
 }
 
 fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
@@ -126,6 +126,87 @@ fn check_break_assignment(context: &CheckerContext) -> GenericResult<()> {
     })
 }
 
+fn check_driving_rules(context: &CheckerContext) -> GenericResult<()> {
+    const DAY_DURATION: Float = 86400.;
+
+    let daily_driving = context.solution.tours.iter().try_fold(
+        HashMap::<(String, i64), (Float, Float)>::default(),
+        |mut acc, tour| {
+            let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+            let Some(rules) = vehicle.driving_rules.as_ref() else { return Ok(acc) };
+
+            let (max_driving, total_driving) = get_tour_driving_time(tour, rules);
+            if max_driving > rules.max_driving_time {
+                return Err(format!(
+                    "driving time rules violation: expected limit is {}, got {}, vehicle id '{}', shift index: {}",
+                    rules.max_driving_time, max_driving, tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+
+            if let Some(max_daily_driving_time) = rules.max_daily_driving_time {
+                let departure = tour.stops.first().map_or(0., |stop| parse_time(&stop.schedule().departure));
+                let worker_id = tour.driver_id.clone().unwrap_or_else(|| tour.vehicle_id.clone());
+                let day = (departure / DAY_DURATION).floor() as i64;
+
+                acc.entry((worker_id, day)).or_insert((max_daily_driving_time, 0.)).1 += total_driving;
+            }
+
+            Ok::<_, GenericError>(acc)
+        },
+    )?;
+
+    daily_driving.into_iter().try_for_each(|((worker_id, day), (limit, total))| {
+        if total > limit {
+            Err(format!(
+                "daily driving time rules violation: expected limit is {limit}, got {total}, worker id '{worker_id}', day {day}"
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    })
+}
+
+/// Returns max cumulative driving time between rests and total driving time of the tour.
+fn get_tour_driving_time(tour: &Tour, rules: &VehicleDrivingRules) -> (Float, Float) {
+    let split_rest = rules.split_rest.as_ref().and_then(|split| split.first().copied().zip(split.last().copied()));
+    let take_rest = |is_split: bool, rest: Float| match split_rest {
+        _ if rest >= rules.rest_duration => None,
+        Some((_, second)) if is_split && rest >= second => None,
+        Some((first, _)) if rest >= first => Some(true),
+        _ => Some(is_split),
+    };
+
+    let (max_driving, total_driving, ..) = tour.stops.iter().fold(
+        (Float::default(), Float::default(), Float::default(), false, None::<Float>),
+        |(max_driving, total_driving, driving, is_split, prev_departure), stop| {
+            let (arrival, departure) = (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure));
+            let leg = prev_departure.map_or(0., |prev_departure| arrival - prev_departure);
+            let arrival_driving = driving + leg;
+
+            let (driving, is_split) = stop
+                .activities()
+                .iter()
+                .filter(|activity| activity.activity_type == "rest" || activity.activity_type == "break")
+                .map(|activity| {
+                    activity
+                        .time
+                        .as_ref()
+                        .map_or(departure - arrival, |time| parse_time(&time.end) - parse_time(&time.start))
+                })
+                .fold((arrival_driving, is_split), |(driving, is_split), rest| match take_rest(is_split, rest) {
+                    Some(is_split) => (driving, is_split),
+                    None => (0., false),
+                });
+
+            (max_driving.max(arrival_driving), total_driving + leg, driving, is_split, Some(departure))
+        },
+    );
+
+    (max_driving, total_driving)
+}
+
 /// Represents information about break and neighbour activity.
 type LegBreakInfo<'a> = (Option<Location>, (Option<&'a Activity>, &'a Activity), (&'a Activity, VehicleBreak));
 
@@ -100,8 +100,9 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
         .resources
         .iter()
         .flat_map(|resources| resources.iter().cloned())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id, MultiDimLoad::new(capacity)),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id, MultiDimLoad::new(capacity))),
+            VehicleResource::Charging { .. } | VehicleResource::Docking { .. } => None,
         })
         .collect::<HashMap<_, _>>();
 
@@ -121,7 +122,7 @@ fn check_resource_consumption(context: &CheckerContext) -> GenericResult<()> {
                             _ => None,
                         })
                         .filter_map(|reload| reload.resource_id.as_ref().cloned())
-                        .next()
+                        .find(|resource_id| resources.contains_key(resource_id))
                 });
 
                 if let Some(resource_id) = resource_id {
@@ -9,7 +9,12 @@ use vrp_core::prelude::GenericResult;
 
 /// NOTE to ensure distance/duration correctness, routing check should be performed first.
 pub fn check_limits(context: &CheckerContext) -> Result<(), Vec<GenericError>> {
-    combine_error_results(&[check_shift_limits(context), check_shift_time(context), check_recharge_limits(context)])
+    combine_error_results(&[
+        check_shift_limits(context),
+        check_shift_time(context),
+        check_recharge_limits(context),
+        check_resource_occupancy(context),
+    ])
 }
 
 /// Check that shift limits are not violated:
@@ -94,6 +99,13 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
 
         let Some(recharge) = shift.recharges.as_ref() else { return Ok(()) };
 
+        if let Some(energy) = recharge.energy.as_ref() {
+            let vehicle = context.get_vehicle(&tour.vehicle_id)?;
+            return check_state_of_charge(context, tour, &energy.with_profile(&vehicle.profile.matrix));
+        }
+
+        let Some(max_distance) = recharge.max_distance else { return Ok(()) };
+
         let stops = tour.stops.iter().filter_map(|stop| stop.as_point()).collect::<Vec<_>>();
         if stops.len() < 2 {
             return Ok(());
@@ -110,10 +122,10 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
                 let delta = (next.distance - prev.distance) as Distance;
                 let total_distance = acc + delta;
 
-                if total_distance > recharge.max_distance {
+                if total_distance > max_distance {
                     return Err(format!(
                         "recharge distance violation: expected limit is {}, got {}, vehicle id '{}', shift index: {}",
-                        recharge.max_distance, total_distance, tour.vehicle_id, tour.shift_index
+                        max_distance, total_distance, tour.vehicle_id, tour.shift_index
                     )
                     .into());
                 }
@@ -125,3 +137,151 @@ fn check_recharge_limits(context: &CheckerContext) -> GenericResult<()> {
             .map(|_| ())
     })
 }
+
+fn check_state_of_charge(context: &CheckerContext, tour: &Tour, energy: &VehicleEnergy) -> GenericResult<()> {
+    let capacity = energy.battery_capacity;
+    let minimum = capacity * energy.min_state_of_charge.unwrap_or_default();
+    let initial = capacity * energy.initial_state_of_charge.unwrap_or(1.);
+    // NOTE stop distances are rounded, so some tolerance is needed
+    let tolerance = (capacity * 0.01).max(1E-6);
+
+    let check_reported = |stop: &PointStop, expected: Float| match stop.state_of_charge {
+        Some(actual) if (actual - expected).abs() > tolerance => Err(format!(
+            "state of charge mismatch: expected {expected}, got {actual}, vehicle id '{}', shift index: {}",
+            tour.vehicle_id, tour.shift_index
+        )),
+        None => Err(format!(
+            "state of charge is not reported, vehicle id '{}', shift index: {}",
+            tour.vehicle_id, tour.shift_index
+        )),
+        _ => Ok(()),
+    };
+
+    let stops = tour.stops.iter().filter(|stop| stop.as_point().is_some()).collect::<Vec<_>>();
+    let Some(first) = stops.first().and_then(|stop| stop.as_point()) else { return Ok(()) };
+    check_reported(first, initial)?;
+
+    stops
+        .windows(2)
+        .try_fold(initial, |state_of_charge, stops| {
+            let (prev, next) = match stops {
+                [prev, next] => (prev.as_point().unwrap(), *next),
+                _ => unreachable!(),
+            };
+            let next_point = next.as_point().unwrap();
+
+            let distance = (next_point.distance - prev.distance) as Float;
+            let load = prev.load.first().copied().unwrap_or_default() as Float;
+            let arrival_charge = state_of_charge - energy.get_consumption(distance, load);
+
+            if arrival_charge < minimum - tolerance {
+                return Err(format!(
+                    "state of charge violation: expected minimum is {minimum}, got {arrival_charge}, vehicle id '{}', \
+                     shift index: {}",
+                    tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+
+            let charged = next
+                .activities()
+                .iter()
+                .filter(|activity| activity.activity_type == "recharge")
+                .map(|activity| match context.get_activity_type(tour, next, activity)? {
+                    ActivityType::Recharge(station) => {
+                        let charging = context.get_activity_time(next, activity).duration() - station.duration;
+                        Ok(charging.max(0.) * station.power.unwrap_or_default() / 3600.)
+                    }
+                    _ => Err(GenericError::from("unexpected activity type")),
+                })
+                .sum::<GenericResult<Float>>()?;
+
+            let departure_charge = arrival_charge + charged;
+            if departure_charge > capacity + tolerance {
+                return Err(format!(
+                    "state of charge violation: expected maximum is {capacity}, got {departure_charge}, \
+                     vehicle id '{}', shift index: {}",
+                    tour.vehicle_id, tour.shift_index
+                )
+                .into());
+            }
+
+            check_reported(next_point, departure_charge)?;
+
+            Ok(departure_charge)
+        })
+        .map(|_| ())
+}
+
+/// Checks that shared resources are not used by more vehicles than allowed at the same time.
+fn check_resource_occupancy(context: &CheckerContext) -> GenericResult<()> {
+    let capacities = context
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Charging { id, connectors: capacity } | VehicleResource::Docking { id, capacity } => {
+                Some((id.clone(), *capacity))
+            }
+            VehicleResource::Reload { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    if capacities.is_empty() {
+        return Ok(());
+    }
+
+    let usage =
+        context.solution.tours.iter().try_fold(HashMap::<String, Vec<TimeWindow>>::default(), |acc, tour| {
+            tour.stops.iter().try_fold(acc, |acc, stop| {
+                stop.activities()
+                    .iter()
+                    .filter(|activity| matches!(activity.activity_type.as_str(), "recharge" | "reload"))
+                    .try_fold(acc, |mut acc, activity| {
+                        let resource_id = match context.get_activity_type(tour, stop, activity)? {
+                            ActivityType::Recharge(station) => station.resource_id,
+                            ActivityType::Reload(reload) => reload.resource_id,
+                            _ => None,
+                        };
+
+                        if let Some(resource_id) = resource_id.filter(|id| capacities.contains_key(id)) {
+                            acc.entry(resource_id).or_default().push(context.get_activity_time(stop, activity));
+                        }
+
+                        Ok::<_, GenericError>(acc)
+                    })
+            })
+        })?;
+
+    usage.into_iter().try_for_each(|(resource_id, times)| {
+        let capacity = capacities.get(&resource_id).copied().unwrap_or_default();
+
+        let mut events = times
+            .iter()
+            .filter(|time| time.end > time.start)
+            .flat_map(|time| [(time.start, 1_i64), (time.end, -1_i64)])
+            .collect::<Vec<_>>();
+        events.sort_by(|(a_time, a_delta), (b_time, b_delta)| a_time.total_cmp(b_time).then(a_delta.cmp(b_delta)));
+
+        let max_usage = events
+            .into_iter()
+            .scan(0_i64, |used, (_, delta)| {
+                *used += delta;
+                Some(*used)
+            })
+            .max()
+            .unwrap_or_default();
+
+        if max_usage > capacity as i64 {
+            Err(format!(
+                "resource occupancy violation: resource '{resource_id}' is used by {max_usage} vehicles at the same \
+                 time, but only {capacity} are allowed"
+            )
+            .into())
+        } else {
+            Ok(())
+        }
+    })
+}
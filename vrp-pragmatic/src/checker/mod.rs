@@ -15,6 +15,7 @@ use vrp_core::construction::clustering::vicinity::ClusterConfig;
 use vrp_core::construction::clustering::vicinity::VisitPolicy;
 use vrp_core::models::Problem as CoreProblem;
 use vrp_core::models::common::{Duration, Profile, TimeWindow};
+use vrp_core::models::problem::{TravelTime, scale_duration};
 use vrp_core::models::solution::{Commute as DomainCommute, CommuteInfo as DomainCommuteInfo};
 use vrp_core::prelude::{GenericError, GenericResult};
 use vrp_core::solver::processing::ClusterConfigExtraProperty;
@@ -111,7 +112,7 @@ impl CheckerContext {
             .cloned()
             .ok_or(format!("cannot get matrix for '{}' profile", profile.matrix))?;
 
-        Ok(Profile { index, scale: profile.scale.unwrap_or(1.) })
+        Ok(create_profile(index, profile))
     }
 
     /// Gets activity operation time range in seconds since Unix epoch.
@@ -225,6 +226,13 @@ impl CheckerContext {
                 .map(|_| ActivityType::Terminal) // Treat required stops as terminal activities for checking
                 .ok_or_else(|| format!("cannot find required stop for tour '{}'", tour.vehicle_id).into()),
 
+            "rest" => self
+                .get_vehicle(&tour.vehicle_id)?
+                .driving_rules
+                .as_ref()
+                .map(|_| ActivityType::Terminal) // Treat rests as terminal activities for checking
+                .ok_or_else(|| format!("cannot find driving rules for tour '{}'", tour.vehicle_id).into()),
+
             "via" => shift
                 .via
                 .as_ref()
@@ -283,7 +291,7 @@ impl CheckerContext {
                         match (curr_location, prev_location) {
                             (Some(curr_location), Some(prev_location)) => {
                                 let (f_distance, f_duration) =
-                                    self.get_matrix_data(profile, prev_location, curr_location)?;
+                                    self.get_matrix_data(profile, prev_location, curr_location, None)?;
 
                                 let has_next_commute = get_activity_location_by_idx(idx + 1)
                                     .zip(get_activity_commute_by_idx(idx + 1))
@@ -292,7 +300,7 @@ impl CheckerContext {
                                     (VisitPolicy::Return, _) | (VisitPolicy::ClosedContinuation, false) => {
                                         let stop_location = stop_location.ok_or("no location for clustered stop")?;
                                         let (b_distance, b_duration) =
-                                            self.get_matrix_data(profile, curr_location, stop_location)?;
+                                            self.get_matrix_data(profile, curr_location, stop_location, None)?;
 
                                         (stop_location, b_distance, b_duration)
                                     }
@@ -370,7 +378,15 @@ impl CheckerContext {
             .ok_or_else(|| format!("cannot find coordinate in coord index: {location:?}").into())
     }
 
-    fn get_matrix_data(&self, profile: &Profile, from_idx: usize, to_idx: usize) -> GenericResult<(i64, i64)> {
+    /// Gets distance and scaled duration between two locations. When departure time is specified,
+    /// time-of-day scale factors are taken into account.
+    fn get_matrix_data(
+        &self,
+        profile: &Profile,
+        from_idx: usize,
+        to_idx: usize,
+        departure: Option<Float>,
+    ) -> GenericResult<(i64, i64)> {
         let matrices = get_matrices(&self.matrices)?;
         let matrix =
             matrices.get(profile.index).ok_or_else(|| format!("cannot find matrix with index {}", profile.index))?;
@@ -388,7 +404,10 @@ impl CheckerContext {
 
         let distance = get_matrix_value(matrix_idx, &matrix.distances)?;
         let duration = get_matrix_value(matrix_idx, &matrix.travel_times)?;
-        let duration = (duration as Float * profile.scale) as i64;
+        let duration = match departure {
+            Some(departure) => scale_duration(profile, duration as Float, TravelTime::Departure(departure)),
+            None => duration as Float * profile.scale,
+        } as i64;
 
         Ok((distance, duration))
     }
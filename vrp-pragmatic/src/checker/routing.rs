@@ -21,10 +21,10 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
     context.solution.tours.iter().try_for_each::<_, GenericResult<_>>(|tour| {
         let profile = context.get_vehicle_profile(&tour.vehicle_id)?;
 
-        let get_matrix_data = |from: &PointStop, to: &PointStop| -> GenericResult<(i64, i64)> {
+        let get_matrix_data = |from: &PointStop, to: &PointStop, departure: i64| -> GenericResult<(i64, i64)> {
             let from_idx = context.get_location_index(&from.location)?;
             let to_idx = context.get_location_index(&to.location)?;
-            context.get_matrix_data(&profile, from_idx, to_idx)
+            context.get_matrix_data(&profile, from_idx, to_idx, Some(departure as Float))
         };
 
         let first_stop = tour.stops.first().ok_or_else(|| "empty tour".to_string())?;
@@ -48,7 +48,7 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
 
                 let (distance, duration, to_distance) = match (from, to) {
                     (Stop::Point(from), Stop::Point(to)) => {
-                        let (distance, duration) = get_matrix_data(from, to)?;
+                        let (distance, duration) = get_matrix_data(from, to, arrival_time)?;
                         (distance, duration, to.distance)
                     }
                     (prev, Stop::Transit(transit)) => {
@@ -70,7 +70,7 @@ fn check_routing_rules(context: &CheckerContext) -> GenericResult<()> {
                             .unwrap()
                             .as_point()
                             .expect("two consistent transit stops are not supported");
-                        let (distance, duration) = get_matrix_data(from, to)?;
+                        let (distance, duration) = get_matrix_data(from, to, arrival_time)?;
                         (distance, duration, to.distance)
                     }
                 };
@@ -96,6 +96,15 @@ impl CoordIndex {
             });
         });
 
+        // process replanning
+        problem
+            .plan
+            .replanning
+            .iter()
+            .flat_map(|replanning| replanning.vehicles.iter())
+            .filter_map(|vehicle| vehicle.location.as_ref())
+            .for_each(|location| index.add(location));
+
         // Append extra (candidate) locations after all problem coordinates so
         // they receive the highest matrix indices. `add` deduplicates, so a
         // location already present in the problem keeps its existing index.
@@ -1,5 +1,6 @@
 //! Specifies different properties as extension points on Dimensions type.
 
+use crate::format::problem::VehicleEnergy;
 use vrp_core::construction::features::BreakPolicy;
 use vrp_core::custom_dimension;
 use vrp_core::models::common::Dimensions;
@@ -22,3 +23,7 @@ custom_dimension!(pub JobType typeof String);
 custom_dimension!(pub ViaOrder typeof i32);
 
 custom_dimension!(pub BreakPolicy typeof BreakPolicy);
+
+custom_dimension!(pub VehicleEnergy typeof VehicleEnergy);
+
+custom_dimension!(pub ChargingPower typeof Float);
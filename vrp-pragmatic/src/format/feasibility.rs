@@ -13,18 +13,29 @@ use crate::format::problem::{
     deserialize_matrix, deserialize_problem, get_problem_properties, map_to_problem_with_props, ApiProblem, Matrix,
     ProblemProperties,
 };
-use crate::format::solution::map_code_reason;
+use crate::format::solution::{get_capacity, map_code_reason};
 use crate::format::solution::read_init_solution;
-use crate::format::{CoordIndexExtraProperty, CoreProblem, Location, ShiftIndexDimension, VehicleTypeDimension};
+use crate::format::{
+    CoordIndexExtraProperty, CoreProblem, JobTypeDimension, Location, ShiftIndexDimension, VehicleTypeDimension,
+};
 use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::HashSet;
 use std::io::BufReader;
 use std::sync::Arc;
 use vrp_core::construction::heuristics::{
-    apply_insertion_success, eval_job_insertion_in_route, BestResultSelector, EvaluationContext, InsertionPosition,
+    apply_insertion_success, RouteContext, SolutionContext, eval_job_insertion_in_route, BestResultSelector, EvaluationContext, InsertionPosition,
     InsertionResult, LegSelection, ResultSelector, UnassignmentInfo,
 };
-use vrp_core::models::problem::VehicleIdDimension;
+use vrp_core::construction::enablers::TotalDurationTourState;
+use vrp_core::models::common::{Duration, MultiDimLoad, Timestamp};
+use vrp_core::models::problem::{Actor, JobIdDimension, Single, VehicleIdDimension};
+use vrp_core::models::solution::Activity;
 use vrp_core::prelude::*;
+use vrp_core::rosomaxa::prelude::{HeuristicObjective, TelemetryMode};
+use vrp_core::solver::{ElitismPopulation, RefinementContext};
+use vrp_core::solver::search::{CompositeLocalOperator, ExchangeIntraRouteRandom, ExchangeSequence, LocalOperator};
+use vrp_core::utils::Timer;
 
 type ApiJob = crate::format::problem::Job;
 
@@ -81,6 +92,49 @@ pub struct FeasibilityResult {
     pub vehicles: Vec<VehicleFeasibility>,
 }
 
+/// Job cancellation result.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CancellationResult {
+    /// The id of the cancelled job.
+    pub job_id: String,
+    /// The vehicle instance id which served the job.
+    pub vehicle_id: String,
+    /// The vehicle type id.
+    pub type_id: String,
+    /// The shift index of the vehicle.
+    pub shift_index: usize,
+    /// Capacity occupied by the job, per capacity dimension.
+    pub freed_capacity: Vec<i32>,
+    /// Reduction of the route duration.
+    pub freed_duration: Float,
+    /// Time left between the route end and the latest allowed shift end after cancellation.
+    /// Omitted when the shift end is unbounded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_slack: Option<Float>,
+}
+
+/// Specifies bounded local re-optimization of the route touched by [`FeasibilityContext::accept_job`].
+///
+/// Only the route which received the accepted job is improved, locked jobs stay in place and
+/// pickups are served not further than `max_pickup_shift` from the time they were scheduled at
+/// before the re-optimization.
+#[derive(Clone, Debug)]
+pub struct ReoptimizationOptions {
+    /// A time limit in milliseconds.
+    pub time_limit: usize,
+    /// A max amount of local search iterations.
+    pub max_iterations: usize,
+    /// A max deviation of pickup service start from its scheduled time in seconds.
+    pub max_pickup_shift: Duration,
+}
+
+impl Default for ReoptimizationOptions {
+    fn default() -> Self {
+        Self { time_limit: 100, max_iterations: 1000, max_pickup_shift: 300. }
+    }
+}
+
 /// Pre-built context for performing repeated feasibility checks.
 ///
 /// Reconstructs route state from a problem + solution pair once, then allows
@@ -90,6 +144,7 @@ pub struct FeasibilityContext {
     insertion_ctx: InsertionContext,
     api_problem: ApiProblem,
     properties: ProblemProperties,
+    reoptimization: Option<ReoptimizationOptions>,
 }
 
 impl FeasibilityContext {
@@ -132,7 +187,13 @@ impl FeasibilityContext {
         let insertion_ctx =
             InsertionContext::new_from_solution(core_problem.clone(), (solution, None), environment);
 
-        Ok(Self { problem: core_problem, insertion_ctx, api_problem, properties })
+        Ok(Self { problem: core_problem, insertion_ctx, api_problem, properties, reoptimization: None })
+    }
+
+    /// Enables bounded local re-optimization of the touched route after each accepted job.
+    pub fn with_reoptimization(mut self, options: ReoptimizationOptions) -> Self {
+        self.reoptimization = Some(options);
+        self
     }
 
     /// Checks whether the given candidate API job can be feasibly inserted.
@@ -239,7 +300,8 @@ impl FeasibilityContext {
     /// Finds the best feasible insertion for the candidate job and applies it to the internal state.
     ///
     /// After this call the insertion context is updated as if the job had been assigned,
-    /// so subsequent `check_job` / `accept_job` calls see the new state.
+    /// so subsequent `check_job` / `accept_job` calls see the new state. When re-optimization is
+    /// enabled, the touched route is improved before returning (see [`ReoptimizationOptions`]).
     pub fn accept_job(&mut self, candidate: &ApiJob) -> Result<VehicleFeasibility, GenericError> {
         let coord_index = self
             .problem
@@ -310,10 +372,15 @@ impl FeasibilityContext {
                     .copied()
                     .unwrap_or_default();
                 let cost_delta: Float = success.cost.iter().sum();
+                let actor = actor.clone();
 
                 apply_insertion_success(&mut self.insertion_ctx, success);
                 self.problem.goal.accept_solution_state(&mut self.insertion_ctx.solution);
 
+                if let Some(options) = self.reoptimization.as_ref() {
+                    reoptimize_route(&mut self.insertion_ctx, &actor, options);
+                }
+
                 Ok(VehicleFeasibility {
                     vehicle_id,
                     type_id,
@@ -329,6 +396,62 @@ impl FeasibilityContext {
         }
     }
 
+    /// Removes a job with given id from the internal state.
+    ///
+    /// All activities of the job (e.g. both pickup and delivery legs) are removed from its route
+    /// and the route schedule is recalculated, so subsequent `check_job` / `accept_job` calls see
+    /// the freed capacity and time. A route left without jobs releases its vehicle.
+    pub fn cancel_job(&mut self, job_id: &str) -> Result<CancellationResult, GenericError> {
+        let has_job_id = |job: &Job| job.dimens().get_job_id().is_some_and(|id| id == job_id);
+        let solution = &mut self.insertion_ctx.solution;
+
+        let route_ctx = solution
+            .routes
+            .iter_mut()
+            .find(|route_ctx| route_ctx.route().tour.jobs().any(has_job_id))
+            .ok_or_else(|| GenericError::from(format!("cannot find assigned job with id '{job_id}'")))?;
+
+        let job = route_ctx.route().tour.jobs().find(|job| has_job_id(job)).cloned().expect("job must be present");
+
+        // NOTE freed capacity is a sum of static and dynamic loads occupied by the job
+        let freed_capacity = route_ctx
+            .route()
+            .tour
+            .job_activities(&job)
+            .filter_map(|activity| activity.job.as_ref())
+            .filter_map(|single| get_capacity(&single.dimens))
+            .fold(MultiDimLoad::default(), |acc, demand| acc + demand.pickup.0 + demand.pickup.1 + demand.delivery.0);
+        let duration_before = route_ctx.state().get_total_duration().copied().unwrap_or_default();
+
+        route_ctx.route_mut().tour.remove(&job);
+        solution.locked.remove(&job);
+        self.problem.goal.accept_route_state(route_ctx);
+
+        let route = route_ctx.route();
+        let duration_after = if route.tour.has_jobs() {
+            route_ctx.state().get_total_duration().copied().unwrap_or_default()
+        } else {
+            Duration::default()
+        };
+        let time_slack = Some(route.actor.detail.time.end)
+            .filter(|&shift_end| shift_end < Float::MAX)
+            .map(|shift_end| route.tour.end().map_or(0., |end| (shift_end - end.schedule.departure).max(0.)));
+
+        let result = CancellationResult {
+            job_id: job_id.to_string(),
+            vehicle_id: route.actor.vehicle.dimens.get_vehicle_id().cloned().unwrap_or_default(),
+            type_id: route.actor.vehicle.dimens.get_vehicle_type().cloned().unwrap_or_default(),
+            shift_index: route.actor.vehicle.dimens.get_shift_index().copied().unwrap_or_default(),
+            freed_capacity: freed_capacity.as_vec(),
+            freed_duration: duration_before - duration_after,
+            time_slack,
+        };
+
+        self.insertion_ctx.restore();
+
+        Ok(result)
+    }
+
     /// Serializes the current solution state to a JSON string.
     ///
     /// Builds a domain `Solution` from the internal `InsertionContext` and converts it
@@ -415,3 +538,99 @@ pub fn check_insertion_feasibility(
 
     serde_json::to_string_pretty(&result).map_err(|e: serde_json::Error| e.to_string().into())
 }
+
+/// Improves the route of given actor using intra-route local search operators within the time and
+/// iteration budget. A new route is accepted only when it is better, keeps all jobs and serves every
+/// pickup close to the time it was scheduled at before re-optimization.
+fn reoptimize_route(insertion_ctx: &mut InsertionContext, actor: &Arc<Actor>, options: &ReoptimizationOptions) {
+    let Some(route_idx) = insertion_ctx.solution.routes.iter().position(|route_ctx| route_ctx.route().actor == *actor)
+    else {
+        return;
+    };
+
+    let problem = insertion_ctx.problem.clone();
+    let environment = insertion_ctx.environment.clone();
+    let solution = &insertion_ctx.solution;
+    let route_ctx = &solution.routes[route_idx];
+    let jobs = route_ctx.route().tour.jobs().cloned().collect::<HashSet<_>>();
+    let promised = get_pickup_times(route_ctx);
+
+    let mut best = InsertionContext {
+        problem: problem.clone(),
+        solution: SolutionContext {
+            required: Default::default(),
+            ignored: Default::default(),
+            unassigned: Default::default(),
+            locked: solution.locked.iter().filter(|job| jobs.contains(*job)).cloned().collect(),
+            routes: vec![route_ctx.deep_copy()],
+            registry: solution.registry.deep_slice(|other| other == actor.as_ref()),
+            state: Default::default(),
+        },
+        environment: environment.clone(),
+    };
+    problem.goal.accept_solution_state(&mut best.solution);
+
+    let population = Box::new(ElitismPopulation::new(problem.goal.clone(), environment.random.clone(), 1, 1));
+    let refinement_ctx = RefinementContext::new(problem.clone(), population, TelemetryMode::None, environment);
+    let operator = CompositeLocalOperator::new(
+        vec![(Arc::new(ExchangeIntraRouteRandom::default()), 2), (Arc::new(ExchangeSequence::default()), 1)],
+        1,
+        1,
+    );
+
+    let timer = Timer::start();
+    let mut is_improved = false;
+
+    for _ in 0..options.max_iterations {
+        if timer.elapsed_millis() >= options.time_limit as u128 {
+            break;
+        }
+
+        let Some(candidate) = operator.explore(&refinement_ctx, &best) else { continue };
+
+        let is_complete = candidate.solution.unassigned.is_empty()
+            && candidate.solution.required.is_empty()
+            && candidate.solution.routes.len() == 1
+            && candidate.solution.routes[0].route().tour.job_count() == jobs.len();
+
+        if is_complete
+            && keeps_pickup_times(&candidate.solution.routes[0], promised.as_slice(), options.max_pickup_shift)
+            && problem.goal.total_order(&candidate, &best) == Ordering::Less
+        {
+            best = candidate;
+            is_improved = true;
+        }
+    }
+
+    if is_improved {
+        insertion_ctx.solution.routes[route_idx] = best.solution.routes.swap_remove(0);
+        problem.goal.accept_solution_state(&mut insertion_ctx.solution);
+    }
+}
+
+/// Returns service start times at which pickups of the route are currently scheduled.
+fn get_pickup_times(route_ctx: &RouteContext) -> Vec<(Arc<Single>, Timestamp)> {
+    route_ctx
+        .route()
+        .tour
+        .all_activities()
+        .filter_map(|activity| activity.job.as_ref().map(|single| (single, activity)))
+        .filter(|(single, _)| single.dimens.get_job_type().is_some_and(|job_type| job_type == "pickup"))
+        .map(|(single, activity)| (single.clone(), get_service_start(activity)))
+        .collect()
+}
+
+fn keeps_pickup_times(route_ctx: &RouteContext, promised: &[(Arc<Single>, Timestamp)], max_shift: Duration) -> bool {
+    route_ctx.route().tour.all_activities().all(|activity| {
+        let Some(single) = activity.job.as_ref() else { return true };
+
+        promised
+            .iter()
+            .filter(|(other, _)| Arc::ptr_eq(single, other))
+            .all(|(_, time)| (get_service_start(activity) - time).abs() <= max_shift)
+    })
+}
+
+fn get_service_start(activity: &Activity) -> Timestamp {
+    activity.schedule.arrival.max(activity.place.time.start)
+}
@@ -199,6 +199,9 @@ const RECHARGE_CONSTRAINT_CODE: ViolationCode = ViolationCode(15);
 const LIFO_CONSTRAINT_CODE: ViolationCode = ViolationCode(16);
 const MAX_RIDE_DURATION_CONSTRAINT_CODE: ViolationCode = ViolationCode(17);
 const SOLO_RIDING_CONSTRAINT_CODE: ViolationCode = ViolationCode(18);
+const DRIVING_RULES_CONSTRAINT_CODE: ViolationCode = ViolationCode(19);
+const RESOURCE_OCCUPANCY_CONSTRAINT_CODE: ViolationCode = ViolationCode(20);
+const MAX_ONBOARD_WAITING_CONSTRAINT_CODE: ViolationCode = ViolationCode(21);
 
 /// An job id to job index.
 pub type JobIndex = HashMap<String, CoreJob>;
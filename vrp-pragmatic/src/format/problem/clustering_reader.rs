@@ -1,5 +1,5 @@
 use super::*;
-use crate::format::problem::fleet_reader::get_profile_index_map;
+use crate::format::problem::fleet_reader::{create_profile, get_profile_index_map};
 use std::cmp::Ordering;
 use std::collections::HashSet;
 use vrp_core::construction::clustering::vicinity::*;
@@ -46,7 +46,7 @@ fn get_profile(api_problem: &ApiProblem, profile: &VehicleProfile) -> Result<Pro
         .cloned()
         .ok_or_else(|| format!("cannot find matrix profile: {}", profile.matrix))?;
 
-    Ok(Profile { index: profile_index, scale: profile.scale.unwrap_or(1.) })
+    Ok(create_profile(profile_index, profile))
 }
 
 fn get_builder_policy() -> BuilderPolicy {
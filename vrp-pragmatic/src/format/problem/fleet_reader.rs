@@ -10,9 +10,11 @@ use crate::utils::get_approx_transportation;
 use std::collections::HashSet;
 use vrp_core::construction::enablers::create_typed_actor_groups;
 use vrp_core::construction::features::{
-    VehicleAttributesDimension, VehicleCapacityDimension, VehicleLifoTagsDimension, VehicleSkillsDimension,
+    DriverSkillsDimension, VehicleAttributesDimension, VehicleCapacityDimension, VehicleLifoTagsDimension,
+    VehicleMaxOnboardWaitingDimension, VehicleSkillsDimension,
 };
 use vrp_core::models::common::*;
+use vrp_core::models::problem::Driver as CoreDriver;
 use vrp_core::models::problem::*;
 
 pub(super) fn get_profile_index_map(api_problem: &ApiProblem) -> HashMap<String, usize> {
@@ -111,9 +113,10 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
         };
 
         let index = *profile_indices.get(&vehicle.profile.matrix).unwrap();
-        let profile = Profile::new(index, vehicle.profile.scale);
+        let profile = create_profile(index, &vehicle.profile);
 
         let tour_size = vehicle.limits.as_ref().and_then(|l| l.tour_size);
+        let max_onboard_waiting = vehicle.limits.as_ref().and_then(|l| l.max_onboard_waiting);
 
         for (shift_index, shift) in vehicle.shifts.iter().enumerate() {
             let start = {
@@ -141,6 +144,7 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
             }];
 
             vehicle.vehicle_ids.iter().for_each(|vehicle_id| {
+                let details = get_replanning_details(api_problem, coord_index, vehicle_id, shift_index, &details);
                 let mut dimens: Dimensions = Default::default();
 
                 dimens
@@ -152,6 +156,10 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_tour_size(tour_size);
                 }
 
+                if let Some(max_onboard_waiting) = max_onboard_waiting {
+                    dimens.set_vehicle_max_onboard_waiting(max_onboard_waiting);
+                }
+
                 if props.has_configurable_capacity {
                     if let Some(configs) = &vehicle.capacity_configurations {
                         let configurations: Vec<Vec<i32>> = configs.iter().map(|c| c.capacities.clone()).collect();
@@ -177,35 +185,136 @@ pub(super) fn read_fleet(api_problem: &ApiProblem, props: &ProblemProperties, co
                     dimens.set_vehicle_lifo_tags(lifo_tags_set);
                 }
 
-                vehicles.push(Arc::new(Vehicle {
-                    profile: profile.clone(),
-                    costs: costs.clone(),
-                    dimens,
-                    details: details.clone(),
-                }));
+                if let Some(energy) = shift.recharges.as_ref().and_then(|recharges| recharges.energy.as_ref()) {
+                    dimens.set_vehicle_energy(energy.with_profile(&vehicle.profile.matrix));
+                }
+
+                vehicles.push(Arc::new(Vehicle { profile: profile.clone(), costs: costs.clone(), dimens, details }));
             });
         }
     });
 
-    let drivers = vec![Arc::new(Driver {
-        costs: Costs {
-            fixed: 0.0,
-            per_distance: 0.0,
-            per_driving_time: 0.0,
-            per_waiting_time: 0.0,
-            per_service_time: 0.0,
-        },
-        dimens: Default::default(),
-        details: vec![],
-    })];
+    let drivers = read_drivers(api_problem, coord_index);
 
     CoreFleet::new(drivers, vehicles, |actors| {
         create_typed_actor_groups(actors, |a| {
-            a.vehicle.dimens.get_vehicle_type().cloned().expect("vehicle has no type defined")
+            let vehicle_type = a.vehicle.dimens.get_vehicle_type().cloned().expect("vehicle has no type defined");
+
+            match a.driver.dimens.get_driver_id() {
+                Some(driver_id) => format!("{vehicle_type}:{driver_id}"),
+                None => vehicle_type,
+            }
         })
     })
 }
 
+/// Creates a routing profile with given index using vehicle profile definition.
+pub(crate) fn create_profile(index: usize, profile: &VehicleProfile) -> Profile {
+    match &profile.scale {
+        Some(VehicleProfileScale::Constant(scale)) => Profile::new(index, Some(*scale)),
+        Some(VehicleProfileScale::TimeOfDay(scales)) => Profile::new(index, None).with_time_scales(
+            scales
+                .iter()
+                .map(|scale| ProfileTimeScale {
+                    time: TimeWindow::new(parse_time(&scale.from), parse_time(&scale.to)),
+                    factor: scale.factor,
+                })
+                .collect(),
+        ),
+        None => Profile::new(index, None),
+    }
+}
+
+/// Reads drivers from the fleet. When no drivers are specified, a single driver without costs and
+/// restrictions is used for all vehicles.
+fn read_drivers(api_problem: &ApiProblem, coord_index: &CoordIndex) -> Vec<Arc<CoreDriver>> {
+    let drivers = api_problem.fleet.drivers.as_ref().filter(|drivers| !drivers.is_empty());
+
+    let Some(drivers) = drivers else {
+        return vec![Arc::new(CoreDriver {
+            costs: get_driver_costs(None),
+            dimens: Default::default(),
+            details: vec![],
+        })];
+    };
+
+    drivers
+        .iter()
+        .map(|driver| {
+            let costs = get_driver_costs(driver.costs.as_ref());
+            let mut dimens: Dimensions = Default::default();
+            dimens.set_driver_id(driver.id.clone());
+
+            if let Some(skills) = driver.skills.as_ref() {
+                dimens.set_driver_skills(skills.iter().cloned().collect::<HashSet<_>>());
+            }
+
+            let details = driver
+                .shifts
+                .iter()
+                .map(|shift| DriverDetail {
+                    depot: shift.depot.as_ref().map(|depot| coord_index.get_by_loc(depot).unwrap()),
+                    time: TimeWindow::new(parse_time(&shift.earliest), parse_time(&shift.latest)),
+                })
+                .collect();
+
+            Arc::new(CoreDriver { costs, dimens, details })
+        })
+        .collect()
+}
+
+fn get_driver_costs(costs: Option<&VehicleCosts>) -> Costs {
+    Costs {
+        fixed: costs.and_then(|costs| costs.fixed).unwrap_or(0.),
+        per_distance: costs.map_or(0., |costs| costs.distance),
+        per_driving_time: costs.map_or(0., |costs| costs.time),
+        per_waiting_time: costs.map_or(0., |costs| costs.time),
+        per_service_time: costs.map_or(0., |costs| costs.time),
+    }
+}
+
+/// Adjusts vehicle details for rolling horizon replanning: a vehicle without executed activities
+/// cannot depart earlier than `now` and departs from its current position when it is specified.
+/// Details of the vehicle with executed activities are kept as the executed part of its tour is locked,
+/// its remaining activities are kept after `now` by job time windows.
+fn get_replanning_details(
+    api_problem: &ApiProblem,
+    coord_index: &CoordIndex,
+    vehicle_id: &str,
+    shift_index: usize,
+    details: &[VehicleDetail],
+) -> Vec<VehicleDetail> {
+    let Some(replanning) = api_problem.plan.replanning.as_ref() else { return details.to_vec() };
+
+    let state = replanning
+        .vehicles
+        .iter()
+        .find(|state| state.vehicle_id == vehicle_id && state.shift_index.unwrap_or_default() == shift_index);
+
+    if state.and_then(|state| state.executed.as_ref()).is_some_and(|executed| !executed.is_empty()) {
+        return details.to_vec();
+    }
+
+    let now = parse_time(&replanning.now);
+    let location =
+        state.and_then(|state| state.location.as_ref()).and_then(|location| coord_index.get_by_loc(location));
+
+    details
+        .iter()
+        .cloned()
+        .map(|mut detail| {
+            if let Some(start) = detail.start.as_mut() {
+                let earliest = start.time.earliest.map_or(now, |earliest| earliest.max(now));
+
+                start.location = location.unwrap_or(start.location);
+                start.time = TimeInterval { earliest: Some(earliest), latest: start.time.latest };
+            }
+
+            detail
+        })
+        .collect()
+}
+
 /// Creates a matrices using approximation.
 pub fn create_approx_matrices(problem: &ApiProblem) -> Vec<Matrix> {
     const DEFAULT_SPEED: Float = 10.;
@@ -4,6 +4,7 @@ use vrp_core::algorithms::clustering::kmedoids::create_hierarchical_kmedoids;
 use vrp_core::construction::clustering::vicinity::ClusterInfoDimension;
 use vrp_core::construction::enablers::FeatureCombinator;
 use vrp_core::construction::features::*;
+use vrp_core::construction::heuristics::RouteContext;
 use vrp_core::models::common::{ConfigurableLoad, Demand, LoadOps, MultiDimLoad, SingleDimLoad};
 use vrp_core::models::problem::{Actor, Single, TransportCost};
 use vrp_core::models::solution::Route;
@@ -27,6 +28,16 @@ pub(super) fn create_goal_context(
 
     features.push(get_capacity_feature("capacity", api_problem, blocks, props)?);
 
+    // NOTE charging and queuing times change schedule, so their states should be updated before any other
+    // state which depends on it
+    if props.has_recharges {
+        features.push(get_recharge_feature("recharge", api_problem, blocks)?);
+    }
+
+    if props.has_occupancy_resources {
+        features.push(get_resource_occupancy_feature("resource_occupancy", api_problem, blocks)?);
+    }
+
     if props.has_tour_travel_limits {
         features.push(get_tour_limit_feature(
             "tour_limit",
@@ -40,8 +51,8 @@ pub(super) fn create_goal_context(
         features.push(create_optional_break_feature("break")?)
     }
 
-    if props.has_recharges {
-        features.push(get_recharge_feature("recharge", api_problem, blocks.transport.clone())?);
+    if props.has_driving_rules {
+        features.push(get_driving_rules_feature("driving_rules", api_problem, blocks.transport.clone())?);
     }
 
     if props.has_order && !features.iter().any(|f| f.name == "tour_order") {
@@ -80,6 +91,14 @@ pub(super) fn create_goal_context(
         )?)
     }
 
+    if props.has_max_onboard_waiting {
+        features.push(create_max_onboard_waiting_feature(
+            "max_onboard_waiting",
+            MAX_ONBOARD_WAITING_CONSTRAINT_CODE,
+            blocks.transport.clone(),
+        )?)
+    }
+
     if !blocks.locks.is_empty() {
         features.push(create_locked_jobs_feature(
             "locked_jobs",
@@ -228,10 +247,20 @@ fn get_objective_feature_layer(
         }
         Objective::TourOrder => create_tour_order_soft_feature("tour_order", get_tour_order_fn()),
         Objective::FastService => get_fast_service_feature("fast_service", blocks),
-        Objective::MatchRequestedTime { early_penalty, late_penalty } => {
-            let penalty = RequestedTimePenalty::new(early_penalty.unwrap_or(1.0), late_penalty.unwrap_or(1.0));
+        Objective::MatchRequestedTime { early_penalty, late_penalty, shape, .. } => {
+            let penalty = job_reader::get_requested_time_penalty(*early_penalty, *late_penalty, shape.as_ref());
             create_requested_time_feature("match_requested_time", penalty, blocks.transport.clone())
         }
+        Objective::MinimizeLateness => create_minimize_lateness_feature("min_lateness", blocks.transport.clone()),
+        Objective::MinimizeExcessRideTime { offset, factor, min } => create_minimize_excess_ride_time_feature(
+            "min_excess_ride_time",
+            RelativeRideDuration {
+                offset: offset.unwrap_or_default(),
+                factor: factor.unwrap_or(1.),
+                min: min.unwrap_or_default(),
+            },
+            blocks.transport.clone(),
+        ),
         Objective::HierarchicalAreas { levels } => get_hierarchical_areas_feature(blocks, *levels),
         Objective::MultiObjective { objectives, strategy: composition_type } => {
             let features = objectives
@@ -283,6 +312,14 @@ fn get_objectives(api_problem: &ApiProblem, props: &ProblemProperties) -> Vec<Ob
         let mut objectives =
             vec![Objective::MinimizeUnassigned { breaks: Some(1.) }, Objective::MinimizeTours, Objective::MinimizeCost];
 
+        if props.has_soft_time_windows {
+            objectives.insert(2, Objective::MinimizeLateness)
+        }
+
+        if props.has_soft_max_ride_duration {
+            objectives.insert(2, Objective::MinimizeExcessRideTime { offset: None, factor: None, min: None })
+        }
+
         if props.has_value {
             objectives.insert(0, Objective::MaximizeValue { breaks: None })
         }
@@ -493,11 +530,7 @@ fn get_tour_limit_feature(
     )
 }
 
-fn get_recharge_feature(
-    name: &str,
-    api_problem: &ApiProblem,
-    transport: Arc<dyn TransportCost>,
-) -> GenericResult<Feature> {
+fn get_recharge_feature(name: &str, api_problem: &ApiProblem, blocks: &ProblemBlocks) -> GenericResult<Feature> {
     fn is_recharge_single(single: &Single) -> bool {
         single.dimens.get_job_type().is_some_and(|job_type| job_type == "recharge")
     }
@@ -509,7 +542,10 @@ fn get_recharge_feature(
                 .iter()
                 .enumerate()
                 .flat_map(|(shift_idx, shift)| {
-                    shift.recharges.as_ref().map(|recharges| (shift_idx, recharges.max_distance))
+                    shift
+                        .recharges
+                        .as_ref()
+                        .and_then(|recharges| recharges.max_distance.map(|limit| (shift_idx, limit)))
                 })
                 .for_each(|(shift_idx, max_distance)| {
                     acc.entry(vehicle_type.type_id.clone()).or_default().insert(shift_idx, max_distance);
@@ -518,9 +554,24 @@ fn get_recharge_feature(
             acc
         });
 
-    RechargeFeatureBuilder::new(name)
+    // NOTE battery and consumption are specified in kWh, distance in meters and charging power in kW
+    let get_battery = |actor: &Actor| {
+        actor.vehicle.dimens.get_vehicle_energy().map(|energy| {
+            let min_soc = energy.min_state_of_charge.unwrap_or_default();
+            let initial_soc = energy.initial_state_of_charge.unwrap_or(1.);
+
+            BatteryModel {
+                capacity: energy.battery_capacity,
+                initial: energy.battery_capacity * initial_soc,
+                minimum: energy.battery_capacity * min_soc,
+            }
+        })
+    };
+
+    let builder = RechargeFeatureBuilder::new(name)
         .set_violation_code(RECHARGE_CONSTRAINT_CODE)
-        .set_transport(transport)
+        .set_transport(blocks.transport.clone())
+        .set_activity(blocks.activity.clone())
         .set_is_recharge_single(is_recharge_single)
         .set_belongs_to_route(|route, job| {
             job.as_single()
@@ -530,6 +581,108 @@ fn get_recharge_feature(
             actor.vehicle.dimens.get_vehicle_type().zip(actor.vehicle.dimens.get_shift_index().copied()).and_then(
                 |(type_id, shift_idx)| distance_limit_index.get(type_id).and_then(|idx| idx.get(&shift_idx).copied()),
             )
+        });
+
+    let has_energy = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .any(|shift| shift.recharges.as_ref().is_some_and(|recharges| recharges.energy.is_some()));
+
+    let mut builder = if has_energy {
+        builder
+            .set_battery(get_battery)
+            .set_consumption_rate(|route_ctx, activity_idx, extra_load| {
+                let load = get_current_load(route_ctx, activity_idx) + extra_load;
+                route_ctx
+                    .route()
+                    .actor
+                    .vehicle
+                    .dimens
+                    .get_vehicle_energy()
+                    .map_or(0., |energy| energy.get_consumption(1., load))
+            })
+            .set_job_load(get_job_load)
+            .set_charging_power(|single| single.dimens.get_charging_power().map(|power| power / 3600.))
+    } else {
+        builder
+    };
+
+    builder.build()
+}
+
+/// Returns current load (first dimension) after activity departure.
+fn get_current_load(route_ctx: &RouteContext, activity_idx: usize) -> Float {
+    let state = route_ctx.state();
+
+    state
+        .get_current_capacity_at::<SingleDimLoad>(activity_idx)
+        .map(|load| load.value)
+        .or_else(|| state.get_current_capacity_at::<MultiDimLoad>(activity_idx).map(|load| load.load[0]))
+        .or_else(|| state.get_current_capacity_at::<ConfigurableLoad>(activity_idx).map(|load| load.load[0]))
+        .unwrap_or_default() as Float
+}
+
+/// Returns job's load (first dimension) carried before and after its activity.
+fn get_job_load(single: &Single) -> (Float, Float) {
+    let demand_single: Option<&Demand<SingleDimLoad>> = single.dimens.get_job_demand();
+    let demand_multi: Option<&Demand<MultiDimLoad>> = single.dimens.get_job_demand();
+    let demand_configurable: Option<&Demand<ConfigurableLoad>> = single.dimens.get_job_demand();
+
+    let (delivery, pickup) = demand_single
+        .map(|d| (d.delivery.0.value + d.delivery.1.value, d.pickup.0.value + d.pickup.1.value))
+        .or_else(|| {
+            demand_multi.map(|d| (d.delivery.0.load[0] + d.delivery.1.load[0], d.pickup.0.load[0] + d.pickup.1.load[0]))
+        })
+        .or_else(|| {
+            demand_configurable
+                .map(|d| (d.delivery.0.load[0] + d.delivery.1.load[0], d.pickup.0.load[0] + d.pickup.1.load[0]))
+        })
+        .unwrap_or_default();
+
+    (delivery as Float, pickup as Float)
+}
+
+fn get_driving_rules_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    transport: Arc<dyn TransportCost>,
+) -> GenericResult<Feature> {
+    fn is_rest_single(single: &Single) -> bool {
+        single.dimens.get_job_type().is_some_and(|job_type| job_type == "rest")
+    }
+
+    let driving_rules_index: HashMap<_, _> = api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .filter_map(|vehicle_type| {
+            vehicle_type.driving_rules.as_ref().map(|rules| {
+                let split_rest =
+                    rules.split_rest.as_ref().and_then(|split| split.first().copied().zip(split.last().copied()));
+                let rules = DrivingRules {
+                    max_driving_time: rules.max_driving_time,
+                    rest_duration: rules.rest_duration,
+                    split_rest,
+                    max_daily_driving_time: rules.max_daily_driving_time,
+                };
+
+                (vehicle_type.type_id.clone(), rules)
+            })
+        })
+        .collect();
+
+    DrivingRulesFeatureBuilder::new(name)
+        .set_violation_code(DRIVING_RULES_CONSTRAINT_CODE)
+        .set_transport(transport)
+        .set_is_rest_single(is_rest_single)
+        .set_is_break_single(|single| single.dimens.get_job_type().is_some_and(|job_type| job_type == "break"))
+        .set_belongs_to_route(|route, job| {
+            job.as_single().is_some_and(|single| is_rest_single(single.as_ref()) && is_correct_vehicle(route, single))
+        })
+        .set_driving_rules(move |actor| {
+            actor.vehicle.dimens.get_vehicle_type().and_then(|type_id| driving_rules_index.get(type_id).copied())
         })
         .build()
 }
@@ -549,8 +702,9 @@ where
         .as_ref()
         .iter()
         .flat_map(|resources| resources.iter())
-        .map(|resource| match resource {
-            VehicleResource::Reload { id, capacity } => (id.clone(), capacity.clone()),
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, capacity } => Some((id.clone(), capacity.clone())),
+            VehicleResource::Charging { .. } | VehicleResource::Docking { .. } => None,
         })
         .collect::<Vec<_>>();
     let total_resources_specified = available_resources.len();
@@ -597,6 +751,84 @@ where
         .collect()
 }
 
+fn get_resource_occupancy_feature(
+    name: &str,
+    api_problem: &ApiProblem,
+    blocks: &ProblemBlocks,
+) -> GenericResult<Feature> {
+    let total_jobs = blocks.jobs.size();
+    let job_index = blocks.job_index.as_ref().ok_or("misconfiguration in goal reader: job index is not set")?;
+    let occupancy_resources = get_occupancy_resources(api_problem, job_index);
+
+    ResourceOccupancyFeatureBuilder::new(name)
+        .set_violation_code(RESOURCE_OCCUPANCY_CONSTRAINT_CODE)
+        .set_transport(blocks.transport.clone())
+        .set_activity(blocks.activity.clone())
+        .set_resource(move |activity| {
+            activity.job.as_ref().and_then(|single| occupancy_resources.get(&CoreJob::Single(single.clone())).copied())
+        })
+        .set_is_partial_solution(move |solution_ctx| solution_ctx.get_jobs_amount() != total_jobs)
+        .build()
+}
+
+fn get_occupancy_resources(
+    api_problem: &ApiProblem,
+    job_index: &JobIndex,
+) -> HashMap<CoreJob, (SharedResourceId, usize)> {
+    let available_resources = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Charging { id, connectors: capacity } | VehicleResource::Docking { id, capacity } => {
+                Some((id.clone(), *capacity))
+            }
+            VehicleResource::Reload { .. } => None,
+        })
+        .enumerate()
+        .map(|(idx, (id, capacity))| (id, (idx, capacity)))
+        .collect::<HashMap<_, _>>();
+
+    api_problem
+        .fleet
+        .vehicles
+        .iter()
+        .flat_map(|vehicle| {
+            vehicle
+                .shifts
+                .iter()
+                .enumerate()
+                .flat_map(|(shift_idx, shift)| {
+                    let stations =
+                        shift.recharges.iter().flat_map(|recharges| recharges.stations.iter()).enumerate().map(
+                            move |(station_idx, station)| {
+                                ("recharge", shift_idx, station_idx + 1, &station.resource_id)
+                            },
+                        );
+                    let reloads =
+                        shift.reloads.iter().flatten().enumerate().map(move |(reload_idx, reload)| {
+                            ("reload", shift_idx, reload_idx + 1, &reload.resource_id)
+                        });
+
+                    stations.chain(reloads)
+                })
+                .filter_map(|(job_type, shift_idx, place_idx, resource_id)| {
+                    resource_id
+                        .as_ref()
+                        .and_then(|resource_id| available_resources.get(resource_id))
+                        .map(|&resource| (job_type, shift_idx, place_idx, resource))
+                })
+                .flat_map(move |(job_type, shift_idx, place_idx, resource)| {
+                    vehicle.vehicle_ids.iter().filter_map(move |vehicle_id| {
+                        let job_id = format!("{vehicle_id}_{job_type}_{shift_idx}_{place_idx}");
+                        job_index.get(&job_id).map(|job| (job.clone(), resource))
+                    })
+                })
+        })
+        .collect()
+}
+
 fn create_optional_break_feature(name: &str) -> GenericResult<Feature> {
     fn is_break_job(single: &Single) -> bool {
         single.dimens.get_job_type().is_some_and(|job_type| job_type == "break")
@@ -8,11 +8,15 @@ use crate::utils::VariableJobPermutation;
 use std::collections::HashMap;
 use std::sync::Arc;
 use vrp_core::{
+    construction::enablers::{DwellTime, JobDwellTimeDimension, JobParkingDimension},
     construction::features::{
         BreakPolicy, JobCompatibilityDimension, JobDemandDimension, JobGroupDimension, JobMaxRideDurationDimension,
-        JobPreferences as FeatureJobPreferences, JobPreferencesDimension, JobRequestedTimesDimension,
-        JobSkills as FeatureJobSkills, JobSkillsDimension, JobSoloRidingDimension, LifoGroupDimension, LifoGroupId,
-        LifoTagDimension,
+        JobOnboardSinceDimension, JobPreferences as FeatureJobPreferences, JobPreferencesDimension,
+        JobRelativeMaxRideDurationDimension, JobRequestedTimePenaltiesDimension, JobRequestedTimesDimension,
+        JobSkills as FeatureJobSkills, JobSkillsDimension, JobSoftMaxRideDurationDimension,
+        JobSoftTimeWindowsDimension, JobSoloRidingDimension, LifoGroupDimension, LifoGroupId, LifoTagDimension,
+        RelativeRideDuration, RequestedTimePenalty as FeatureRequestedTimePenalty,
+        RequestedTimePenaltyShape as FeatureRequestedTimePenaltyShape, SoftTimeWindowPenalty,
     },
     models::common::*,
     models::problem::{
@@ -23,6 +27,7 @@ use vrp_core::{
 
 // TODO configure sample size
 const MULTI_JOB_SAMPLE_SIZE: usize = 3;
+const DAY_DURATION: Float = 86400.;
 
 type PlaceData = (Option<Location>, Duration, Vec<TimeSpan>, Option<String>, Option<Timestamp>);
 type ApiJob = crate::format::problem::Job;
@@ -51,17 +56,17 @@ pub(super) fn read_jobs_with_extra_locks(
 }
 
 pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<Arc<Lock>> {
-    if api_problem.plan.relations.as_ref().is_none_or(|r| r.is_empty()) {
+    let relations = api_problem.plan.get_relations();
+    if relations.is_empty() {
         return vec![];
     }
 
-    let relations: HashMap<_, Vec<_>> =
-        api_problem.plan.relations.as_ref().unwrap().iter().fold(HashMap::new(), |mut acc, r| {
-            let shift_index = r.shift_index.unwrap_or_default();
-            acc.entry((r.vehicle_id.clone(), shift_index)).or_default().push(r.clone());
+    let relations: HashMap<_, Vec<_>> = relations.into_iter().fold(HashMap::new(), |mut acc, r| {
+        let shift_index = r.shift_index.unwrap_or_default();
+        acc.entry((r.vehicle_id.clone(), shift_index)).or_default().push(r);
 
-            acc
-        });
+        acc
+    });
 
     relations.into_iter().fold(vec![], |mut acc, ((vehicle_id, shift_index), rels)| {
         let condition = create_condition(vehicle_id.clone(), shift_index);
@@ -85,7 +90,7 @@ pub(super) fn read_locks(api_problem: &ApiProblem, job_index: &JobIndex) -> Vec<
                 .filter(|job| job.as_str() != "departure" && job.as_str() != "arrival")
                 .fold((HashMap::<String, _>::default(), vec![]), |(mut indexer, mut jobs), job| {
                     let job_id = match job.as_str() {
-                        "break" | "reload" | "recharge" => {
+                        "break" | "reload" | "recharge" | "rest" => {
                             let entry = indexer.entry(job.clone()).or_insert(1_usize);
                             let job_index = *entry;
                             *entry += 1;
@@ -192,12 +197,18 @@ fn read_required_jobs(
         )
     };
 
+    let onboard_jobs = api_problem
+        .plan
+        .get_onboard_jobs()
+        .map(|(_, onboard)| (onboard.job_id.as_str(), parse_time(&onboard.since)))
+        .collect::<HashMap<_, _>>();
+
     api_problem.plan.jobs.iter().for_each(|job| {
         let pickups = job.pickups.as_ref().map_or(0, |p| p.len());
         let deliveries = job.deliveries.as_ref().map_or(0, |p| p.len());
         let is_static_demand = pickups == 0 || deliveries == 0;
 
-        let singles =
+        let mut singles =
             job.pickups
                 .iter()
                 .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
@@ -218,9 +229,24 @@ fn read_required_jobs(
 
         assert!(!singles.is_empty());
 
+        add_soft_time_windows(job, &mut singles);
+        add_requested_time_penalties(job, api_problem, &mut singles);
+        add_dwell_time(job, api_problem, &mut singles);
+        add_parking(job, &mut singles);
+        add_replanning_time(job, api_problem, &mut singles);
+
+        let onboard_since = onboard_jobs.get(job.id.as_str()).copied();
+        if let Some(onboard_since) = onboard_since {
+            singles.iter_mut().for_each(|single| {
+                single.dimens.set_job_onboard_since(onboard_since);
+            });
+        }
+
         let problem_job = if singles.len() > 1 {
             let deliveries_start_index = job.pickups.as_ref().map_or(0, |p| p.len());
             get_multi_job(job, singles, deliveries_start_index, random)
+        } else if onboard_since.is_some() {
+            get_onboard_single_job(job, singles.into_iter().next().unwrap())
         } else {
             get_single_job(job, singles.into_iter().next().unwrap())
         };
@@ -287,7 +313,7 @@ pub(crate) fn convert_api_job_to_core(
     let deliveries = api_job.deliveries.as_ref().map_or(0, |p| p.len());
     let is_static_demand = pickups == 0 || deliveries == 0;
 
-    let singles = api_job
+    let mut singles = api_job
         .pickups
         .iter()
         .flat_map(|tasks| tasks.iter().map(|task| get_single_from_task(task, "pickup", is_static_demand)))
@@ -313,6 +339,12 @@ pub(crate) fn convert_api_job_to_core(
 
     assert!(!singles.is_empty());
 
+    add_soft_time_windows(api_job, &mut singles);
+    add_requested_time_penalties(api_job, api_problem, &mut singles);
+    add_dwell_time(api_job, api_problem, &mut singles);
+    add_parking(api_job, &mut singles);
+    add_replanning_time(api_job, api_problem, &mut singles);
+
     if singles.len() > 1 {
         let deliveries_start_index = api_job.pickups.as_ref().map_or(0, |p| p.len());
         let random: Arc<dyn Random> = Arc::new(DefaultRandom::default());
@@ -346,6 +378,11 @@ fn read_conditional_jobs(api_problem: &ApiProblem, coord_index: &CoordIndex, job
             if let Some(recharges) = &shift.recharges {
                 read_recharges(coord_index, job_index, &mut jobs, vehicle, shift_index, recharges);
             }
+
+            if let Some(driving_rules) = &vehicle.driving_rules {
+                let rests = get_max_rests(api_problem, shift, driving_rules);
+                read_rests(coord_index, job_index, &mut jobs, vehicle, shift_index, driving_rules, rests);
+            }
         }
     });
 
@@ -368,6 +405,7 @@ fn read_required_stops(
         vehicle,
         shift_index,
         required_stops.iter().cloned(),
+        |_, _| {},
     )
 }
 
@@ -483,7 +521,9 @@ fn read_reloads(
             times: reload.times.clone(),
             tag: reload.tag.clone(),
             requested_time: None,
+            parking: None,
         }),
+        |_, _| {},
     )
 }
 
@@ -502,10 +542,79 @@ fn read_recharges(
         jobs,
         vehicle,
         shift_index,
-        recharges.stations.iter().cloned(),
+        recharges.stations.iter().map(|station| JobPlace {
+            location: station.location.clone(),
+            duration: station.duration,
+            times: station.times.clone(),
+            tag: station.tag.clone(),
+            requested_time: None,
+            parking: None,
+        }),
+        |place_idx, single| {
+            if let Some(power) = recharges.stations.get(place_idx).and_then(|station| station.power) {
+                single.dimens.set_charging_power(power);
+            }
+        },
     )
 }
 
+fn read_rests(
+    coord_index: &CoordIndex,
+    job_index: &mut JobIndex,
+    jobs: &mut Vec<Job>,
+    vehicle: &VehicleType,
+    shift_index: usize,
+    driving_rules: &VehicleDrivingRules,
+    rests: usize,
+) {
+    // NOTE each rest is accompanied by both parts of split rest, if it is allowed
+    let durations = std::iter::once(driving_rules.rest_duration)
+        .chain(
+            driving_rules
+                .split_rest
+                .as_ref()
+                .and_then(|split| split.first().copied().zip(split.last().copied()))
+                .into_iter()
+                .flat_map(|(first, second)| [first, second]),
+        )
+        .collect::<Vec<_>>();
+
+    (0..rests)
+        .flat_map(|_| durations.iter().copied())
+        .enumerate()
+        .flat_map(|(idx, duration)| {
+            let rest_idx = idx + 1;
+            vehicle
+                .vehicle_ids
+                .iter()
+                .map(|vehicle_id| {
+                    let job_id = format!("{vehicle_id}_rest_{shift_index}_{rest_idx}");
+                    let places = vec![(None, duration, parse_times(&None), None, None)];
+                    let job =
+                        get_conditional_job(coord_index, vehicle_id.clone(), &job_id, "rest", shift_index, places);
+
+                    (job_id, job)
+                })
+                .collect::<Vec<_>>()
+        })
+        .for_each(|(job_id, single)| add_conditional_job(job_index, jobs, job_id, single));
+}
+
+/// Returns max amount of rests which can be useful within the vehicle shift.
+fn get_max_rests(api_problem: &ApiProblem, shift: &VehicleShift, driving_rules: &VehicleDrivingRules) -> usize {
+    // NOTE the amount of rests is limited by max possible driving time within the shift
+    let max_driving_time = driving_rules.max_daily_driving_time.unwrap_or_else(|| {
+        shift.end.as_ref().map_or(DAY_DURATION, |end| parse_time(&end.latest) - parse_time(&shift.start.earliest))
+    });
+    let time_rests = (max_driving_time / driving_rules.max_driving_time.max(1.)).ceil() as usize;
+
+    // NOTE more than one rest (or two parts of split rest) between stops is not needed
+    let stop_rests = api_problem.plan.jobs.iter().map(|job| job.all_tasks_iter().count()).sum::<usize>() + 1;
+
+    time_rests.min(stop_rests)
+}
+
+#[allow(clippy::too_many_arguments)]
 fn read_specific_job_places(
     job_type: &str,
     coord_index: &CoordIndex,
@@ -514,6 +623,7 @@ fn read_specific_job_places(
     vehicle: &VehicleType,
     shift_index: usize,
     get_places: impl Iterator<Item = JobPlace>,
+    update_single: impl Fn(usize, &mut Single),
 ) {
     (1..)
         .zip(get_places)
@@ -526,7 +636,7 @@ fn read_specific_job_places(
                     let times = parse_times(&place.times);
 
                     let requested_time = place.requested_time.as_ref().map(|t| parse_time(t));
-                    let job = get_conditional_job(
+                    let mut job = get_conditional_job(
                         coord_index,
                         vehicle_id.clone(),
                         &job_id,
@@ -534,6 +644,7 @@ fn read_specific_job_places(
                         shift_index,
                         vec![(Some(place.location.clone()), place.duration, times, place.tag.clone(), requested_time)],
                     );
+                    update_single(place_idx - 1, &mut job);
 
                     (job_id, job)
                 })
@@ -671,6 +782,178 @@ fn fill_dimens(job: &ApiJob, dimens: &mut Dimensions) {
     }
 }
 
+/// Extends time windows of job places by max tolerated lateness and keeps soft time windows penalty
+/// in job dimensions, so lateness can be penalized by the corresponding objective.
+fn add_soft_time_windows(job: &ApiJob, singles: &mut [Single]) {
+    let Some(soft_time_windows) = job.soft_time_windows.as_ref() else { return };
+
+    let max_lateness = soft_time_windows.max_lateness;
+    let penalty = SoftTimeWindowPenalty::new(
+        soft_time_windows.early_penalty.unwrap_or(0.),
+        soft_time_windows.late_penalty.unwrap_or(1.),
+        max_lateness,
+    );
+
+    singles.iter_mut().for_each(|single| {
+        single.places.iter_mut().flat_map(|place| place.times.iter_mut()).for_each(|time| {
+            if let TimeSpan::Window(time) = time {
+                time.end += max_lateness;
+            }
+        });
+        single.dimens.set_job_soft_time_windows(penalty.clone());
+    });
+}
+
+/// Extends durations of job places by dwell time which depends on task demand and keeps dwell time
+/// model in job dimensions, so its setup time can be charged once per stop.
+fn add_dwell_time(job: &ApiJob, api_problem: &ApiProblem, singles: &mut [Single]) {
+    let Some(dwell_time) = job.dwell_time.as_ref() else { return };
+
+    let dwell_time = DwellTime {
+        setup: dwell_time.setup.unwrap_or_default(),
+        per_unit: dwell_time.per_unit.clone().unwrap_or_default(),
+    };
+    let dimension_mapping =
+        api_problem.fleet.capacity_dimensions.as_ref().map(|names| CapacityDimensionMapping::from_names(names));
+
+    get_job_tasks(job).zip(singles.iter_mut()).for_each(|(task, single)| {
+        let demand = match (&task.demand, &task.named_demand, &dimension_mapping) {
+            (Some(demand), None, _) => demand.clone(),
+            (None, Some(named), Some(mapping)) => mapping.resolve_demand(named),
+            _ => vec![],
+        };
+        let duration = dwell_time.get_duration(demand.as_slice());
+
+        single.places.iter_mut().for_each(|place| place.duration += duration);
+        single.dimens.set_job_dwell_time(dwell_time.clone());
+    });
+}
+
+/// Extends durations of job places by their parking time and keeps it in job dimensions, so it can be
+/// charged once per stop.
+fn add_parking(job: &ApiJob, singles: &mut [Single]) {
+    get_job_tasks(job).zip(singles.iter_mut()).for_each(|(task, single)| {
+        let parking = task.places.iter().map(|place| place.parking.unwrap_or_default()).collect::<Vec<_>>();
+        if parking.iter().all(|&parking| parking == 0.) {
+            return;
+        }
+
+        single.places.iter_mut().zip(parking.iter()).for_each(|(place, &parking)| place.duration += parking);
+        single.dimens.set_job_parking(parking);
+    });
+}
+
+/// Restricts time windows of a job which is not executed yet to start not earlier than replanning time,
+/// so its activities cannot be scheduled in the past, even after the executed part of the tour.
+/// Time windows which end before replanning time are removed, so a job without any time window left
+/// cannot be assigned. Time windows specified as offsets from the shift start are kept as is.
+fn add_replanning_time(job: &ApiJob, api_problem: &ApiProblem, singles: &mut [Single]) {
+    let Some(replanning) = api_problem.plan.replanning.as_ref() else { return };
+
+    let is_executed =
+        replanning.vehicles.iter().flat_map(|vehicle| vehicle.executed.iter().flatten()).any(|id| *id == job.id);
+    if is_executed {
+        return;
+    }
+
+    let now = parse_time(&replanning.now);
+
+    singles.iter_mut().flat_map(|single| single.places.iter_mut()).for_each(|place| {
+        place.times.retain(|time| !matches!(time, TimeSpan::Window(time) if time.end < now));
+        place.times.iter_mut().for_each(|time| {
+            if let TimeSpan::Window(time) = time {
+                time.start = time.start.max(now);
+            }
+        });
+    });
+}
+
+/// Returns job tasks in the same order as singles are created from them.
+fn get_job_tasks(job: &ApiJob) -> impl Iterator<Item = &JobTask> {
+    job.pickups.iter().chain(job.deliveries.iter()).chain(job.replacements.iter()).chain(job.services.iter()).flatten()
+}
+
+/// Creates requested time penalty using default values for omitted properties.
+pub(super) fn get_requested_time_penalty(
+    early_penalty: Option<Float>,
+    late_penalty: Option<Float>,
+    shape: Option<&RequestedTimePenaltyShape>,
+) -> FeatureRequestedTimePenalty {
+    let shape = match shape {
+        None | Some(RequestedTimePenaltyShape::Linear) => FeatureRequestedTimePenaltyShape::Linear,
+        Some(RequestedTimePenaltyShape::Quadratic) => FeatureRequestedTimePenaltyShape::Quadratic,
+        Some(RequestedTimePenaltyShape::Piecewise { segments }) => {
+            let mut segments = segments.iter().map(|segment| (segment.from, segment.multiplier)).collect::<Vec<_>>();
+            segments.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            FeatureRequestedTimePenaltyShape::Piecewise(segments)
+        }
+    };
+
+    FeatureRequestedTimePenalty::new(early_penalty.unwrap_or(1.), late_penalty.unwrap_or(1.)).with_shape(shape)
+}
+
+/// Keeps requested time penalties for job places which have job or tag specific penalty, so they
+/// are used instead of the one defined on `match-requested-time` objective.
+fn add_requested_time_penalties(job: &ApiJob, api_problem: &ApiProblem, singles: &mut [Single]) {
+    let Some(Objective::MatchRequestedTime { early_penalty, late_penalty, shape, tag_penalties }) = api_problem
+        .objectives
+        .iter()
+        .flatten()
+        .flat_map(|objective| match objective {
+            Objective::MultiObjective { objectives, .. } => objectives.as_slice(),
+            _ => std::slice::from_ref(objective),
+        })
+        .find(|objective| matches!(objective, Objective::MatchRequestedTime { .. }))
+    else {
+        return;
+    };
+
+    let job_penalty = job.requested_time_penalty.as_ref();
+    let tag_penalties = tag_penalties.as_deref().unwrap_or_default();
+    if job_penalty.is_none() && tag_penalties.is_empty() {
+        return;
+    }
+
+    singles.iter_mut().for_each(|single| {
+        let Some(requested_times) = single.dimens.get_job_requested_times() else { return };
+        let place_tags = single.dimens.get_place_tags().map(|tags| tags.as_slice()).unwrap_or_default();
+
+        let penalties = requested_times
+            .keys()
+            .filter_map(|&place_idx| {
+                let tag_penalty = place_tags
+                    .iter()
+                    .find(|(idx, _)| *idx == place_idx)
+                    .and_then(|(_, tag)| tag_penalties.iter().find(|penalty| penalty.tag == *tag));
+
+                if job_penalty.is_none() && tag_penalty.is_none() {
+                    return None;
+                }
+
+                // NOTE job specific penalty has precedence over tag one which has precedence over objective one
+                let early = job_penalty.and_then(|p| p.early_penalty).or(tag_penalty.and_then(|p| p.early_penalty));
+                let late = job_penalty.and_then(|p| p.late_penalty).or(tag_penalty.and_then(|p| p.late_penalty));
+                let place_shape =
+                    job_penalty.and_then(|p| p.shape.as_ref()).or(tag_penalty.and_then(|p| p.shape.as_ref()));
+
+                Some((
+                    place_idx,
+                    get_requested_time_penalty(
+                        early.or(*early_penalty),
+                        late.or(*late_penalty),
+                        place_shape.or(shape.as_ref()),
+                    ),
+                ))
+            })
+            .collect::<HashMap<_, _>>();
+
+        if !penalties.is_empty() {
+            single.dimens.set_job_requested_time_penalties(penalties);
+        }
+    });
+}
+
 fn get_single_job(job: &ApiJob, single: Single) -> Job {
     let mut single = single;
     fill_dimens(job, &mut single.dimens);
@@ -678,6 +961,27 @@ fn get_single_job(job: &ApiJob, single: Single) -> Job {
     Job::Single(Arc::new(single))
 }
 
+/// Creates a single job which is already onboard: in contrast to other single jobs, it keeps
+/// pickup-delivery specific properties as its pickup is done before the shift start.
+fn get_onboard_single_job(job: &ApiJob, single: Single) -> Job {
+    let mut single = single;
+
+    if let Some(max_ride_duration) = job.max_ride_duration {
+        single.dimens.set_job_max_ride_duration(max_ride_duration);
+    }
+
+    if let Some(soft_max_ride_duration) = job.soft_max_ride_duration {
+        single.dimens.set_job_soft_max_ride_duration(soft_max_ride_duration);
+    }
+
+    if let Some(lifo_tag) = &job.lifo_tag {
+        single.dimens.set_lifo_tag(lifo_tag.clone());
+        single.dimens.set_lifo_group(get_lifo_group_id(job));
+    }
+
+    get_single_job(job, single)
+}
+
 fn get_multi_job(
     job: &ApiJob,
     mut singles: Vec<Single>,
@@ -692,15 +996,21 @@ fn get_multi_job(
         dimens.set_job_max_ride_duration(max_ride_duration);
     }
 
+    if let Some(soft_max_ride_duration) = job.soft_max_ride_duration {
+        dimens.set_job_soft_max_ride_duration(soft_max_ride_duration);
+    }
+
+    if let Some(relative) = &job.relative_max_ride_duration {
+        dimens.set_job_relative_max_ride_duration(RelativeRideDuration {
+            offset: relative.offset.unwrap_or_default(),
+            factor: relative.factor,
+            min: relative.min.unwrap_or_default(),
+        });
+    }
+
     // If this job has a LIFO tag, set it on all singles and derive group ID from job ID
     if let Some(lifo_tag) = &job.lifo_tag {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-
-        // Derive LIFO group ID from job ID
-        let mut hasher = DefaultHasher::new();
-        job.id.hash(&mut hasher);
-        let lifo_id = LifoGroupId(hasher.finish() as usize);
+        let lifo_id = get_lifo_group_id(job);
 
         for single in &mut singles {
             single.dimens.set_lifo_tag(lifo_tag.clone());
@@ -734,6 +1044,17 @@ fn get_multi_job(
     Job::Multi(multi)
 }
 
+fn get_lifo_group_id(job: &ApiJob) -> LifoGroupId {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    // Derive LIFO group ID from job ID
+    let mut hasher = DefaultHasher::new();
+    job.id.hash(&mut hasher);
+
+    LifoGroupId(hasher.finish() as usize)
+}
+
 fn create_condition(vehicle_id: String, shift_index: usize) -> Arc<dyn Fn(&Actor) -> bool + Sync + Send> {
     Arc::new(move |actor: &Actor| {
         *actor.vehicle.dimens.get_vehicle_id().unwrap() == vehicle_id
@@ -22,6 +22,7 @@ mod clustering_reader;
 
 mod fleet_reader;
 pub use self::fleet_reader::create_approx_matrices;
+pub(crate) use self::fleet_reader::create_profile;
 
 mod goal_reader;
 pub(crate) mod job_reader;
@@ -107,6 +108,8 @@ pub(crate) struct ProblemProperties {
     pub(crate) has_unreachable_locations: bool,
     pub(crate) has_reloads: bool,
     pub(crate) has_recharges: bool,
+    pub(crate) has_driving_rules: bool,
+    pub(crate) has_occupancy_resources: bool,
     pub(crate) has_order: bool,
     pub(crate) has_group: bool,
     pub(crate) has_value: bool,
@@ -114,8 +117,12 @@ pub(crate) struct ProblemProperties {
     pub(crate) has_solo_riding: bool,
     pub(crate) has_tour_size_limits: bool,
     pub(crate) has_tour_travel_limits: bool,
+    pub(crate) has_max_onboard_waiting: bool,
     pub(crate) has_lifo: bool,
     pub(crate) has_max_ride_duration: bool,
+    pub(crate) has_soft_time_windows: bool,
+    pub(crate) has_soft_max_ride_duration: bool,
+    pub(crate) has_dwell_time: bool,
     pub(crate) has_via: bool,
 }
 
@@ -137,6 +144,7 @@ impl ProblemProperties {
         self.has_solo_riding = true;
         self.has_tour_size_limits = true;
         self.has_tour_travel_limits = true;
+        self.has_max_onboard_waiting = true;
         self.has_lifo = true;
         self.has_max_ride_duration = true;
         self
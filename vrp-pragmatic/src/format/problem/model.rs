@@ -5,6 +5,7 @@ mod model_test;
 extern crate serde_json;
 
 use crate::format::{FormatError, Location, MultiFormatError};
+use crate::parse_time_safe;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::io::{BufReader, BufWriter, Error, Read, Write};
@@ -102,6 +103,10 @@ pub struct JobPlace {
     /// deviation from this time rather than only serving within the time window.
     #[serde(skip_serializing_if = "Option::is_none", rename = "requestedTime")]
     pub requested_time: Option<String>,
+    /// A parking time at the stop in seconds which is added to the duration. It is charged once
+    /// for consecutive activities at the same location, e.g. multiple drops in the same building.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parking: Option<Float>,
 }
 
 /// Specifies a job task.
@@ -194,6 +199,143 @@ pub struct Job {
     /// Only applies to jobs with both pickups and deliveries.
     #[serde(skip_serializing_if = "Option::is_none", rename = "maxRideDuration")]
     pub max_ride_duration: Option<Float>,
+
+    /// Maximum ride duration relative to the direct travel duration between pickup and delivery.
+    /// When specified together with `maxRideDuration`, the smaller limit is used.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "relativeMaxRideDuration")]
+    pub relative_max_ride_duration: Option<RelativeMaxRideDuration>,
+
+    /// Soft maximum ride duration in seconds for pickup/delivery jobs. In contrast to `maxRideDuration`,
+    /// the ride duration above this value is penalized by `minimize-excess-ride-time` objective.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "softMaxRideDuration")]
+    pub soft_max_ride_duration: Option<Float>,
+
+    /// Makes time windows of all job places soft: service can start after time window end,
+    /// but not later than max tolerated lateness. Violations are penalized by `minimize-lateness` objective.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "softTimeWindows")]
+    pub soft_time_windows: Option<JobSoftTimeWindows>,
+
+    /// Overrides requested time penalty of `match-requested-time` objective for all job places.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "requestedTimePenalty")]
+    pub requested_time_penalty: Option<RequestedTimePenalty>,
+
+    /// Specifies dwell time model which depends on task demand: it is added to duration of each job place.
+    /// Setup time is charged once for consecutive activities with dwell time at the same location.
+    #[serde(skip_serializing_if = "Option::is_none", rename = "dwellTime")]
+    pub dwell_time: Option<JobDwellTime>,
+}
+
+/// Specifies max ride duration as `max(offset + factor * direct, min)`, where `direct` is the travel
+/// duration between pickup and delivery locations taken from the vehicle's routing matrix.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct RelativeMaxRideDuration {
+    /// A constant part of the limit in seconds. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<Float>,
+
+    /// A factor applied to the direct travel duration.
+    pub factor: Float,
+
+    /// A minimum limit in seconds. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min: Option<Float>,
+}
+
+/// Specifies soft time windows penalties of the job.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobSoftTimeWindows {
+    /// Penalty per minute of waiting before time window start.
+    /// Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub early_penalty: Option<Float>,
+
+    /// Penalty per minute of service start after time window end.
+    /// Default is 1.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub late_penalty: Option<Float>,
+
+    /// Max tolerated lateness in seconds.
+    pub max_lateness: Float,
+}
+
+/// Specifies dwell time of the job as `setup + sum(perUnit[i] * |demand[i]|)`.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JobDwellTime {
+    /// A fixed setup time in seconds charged once per stop.
+    /// Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setup: Option<Float>,
+
+    /// A time in seconds per unit of task demand for each demand dimension.
+    /// Default is empty.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub per_unit: Option<Vec<Float>>,
+}
+
+/// Specifies requested time penalty. Omitted properties are taken from `match-requested-time` objective.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestedTimePenalty {
+    /// Penalty per minute for starting service early (before requested time).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub early_penalty: Option<Float>,
+
+    /// Penalty per minute for starting service late (after requested time).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub late_penalty: Option<Float>,
+
+    /// Specifies how penalty grows with deviation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shape: Option<RequestedTimePenaltyShape>,
+}
+
+/// Specifies requested time penalty applied to job places with given tag.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TagRequestedTimePenalty {
+    /// A job place tag.
+    pub tag: String,
+
+    /// Penalty per minute for starting service early (before requested time).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub early_penalty: Option<Float>,
+
+    /// Penalty per minute for starting service late (after requested time).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub late_penalty: Option<Float>,
+
+    /// Specifies how penalty grows with deviation.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shape: Option<RequestedTimePenaltyShape>,
+}
+
+/// Specifies how requested time penalty grows with deviation.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum RequestedTimePenaltyShape {
+    /// Penalty grows linearly with deviation.
+    Linear,
+
+    /// Penalty grows with squared deviation in minutes.
+    Quadratic,
+
+    /// Penalty grows linearly within segments with different multipliers.
+    Piecewise {
+        /// Segments sorted by their start.
+        segments: Vec<RequestedTimePenaltySegment>,
+    },
+}
+
+/// Specifies a segment of piecewise requested time penalty.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct RequestedTimePenaltySegment {
+    /// A deviation in seconds from which the segment starts.
+    pub from: Float,
+
+    /// A multiplier applied to the penalty within the segment.
+    pub multiplier: Float,
 }
 
 // region Clustering
@@ -284,6 +426,52 @@ pub struct VicinityFilteringPolicy {
 
 // endregion
 
+// region Replanning
+
+/// Specifies a job which is already onboard of the vehicle at the moment of replanning.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OnboardJob {
+    /// Job id. The job has to be defined in the plan with deliveries only.
+    pub job_id: String,
+    /// A time when the job was picked up in RFC3339 format.
+    pub since: String,
+}
+
+/// Specifies a state of the vehicle at the moment of replanning.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleReplanning {
+    /// Vehicle id.
+    pub vehicle_id: String,
+    /// Vehicle shift index. If not specified, a first, zero indexed, shift assumed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shift_index: Option<usize>,
+    /// A current position of the vehicle which has not executed any activity yet.
+    /// It is used as a new shift start location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<Location>,
+    /// Job ids of executed activities in order of their execution, including reserved ids like `break`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub executed: Option<Vec<String>>,
+    /// Jobs which are picked up, but not yet delivered by the vehicle.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub onboard: Option<Vec<OnboardJob>>,
+}
+
+/// Specifies rolling horizon replanning: activities executed before `now` are kept and the rest
+/// of the plan is re-optimized.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Replanning {
+    /// A current time in RFC3339 format.
+    pub now: String,
+    /// States of vehicles.
+    pub vehicles: Vec<VehicleReplanning>,
+}
+
+// endregion
+
 /// A plan specifies work which has to be done.
 #[derive(Clone, Deserialize, Debug, Serialize)]
 pub struct Plan {
@@ -297,6 +485,10 @@ pub struct Plan {
     /// Specifies clustering parameters.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub clustering: Option<Clustering>,
+
+    /// Specifies rolling horizon replanning.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replanning: Option<Replanning>,
 }
 
 // endregion
@@ -398,7 +590,7 @@ pub struct VehicleReload {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
 
-    /// A shared reload resource id.
+    /// A shared reload or docking resource id.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub resource_id: Option<String>,
 }
@@ -408,14 +600,94 @@ pub struct VehicleReload {
 #[serde(rename_all = "camelCase")]
 pub struct VehicleRecharges {
     /// Maximum traveled distance before recharge station has to be visited.
-    pub max_distance: Float,
+    /// Ignored when energy model is specified.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_distance: Option<Float>,
+
+    /// Vehicle energy model. When specified, vehicle's state of charge is tracked and recharge
+    /// duration depends on how much energy is added at the station.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub energy: Option<VehicleEnergy>,
 
     /// Specifies list of recharge station. Each can be visited only once.
     pub stations: Vec<VehicleRechargeStation>,
 }
 
-/// Specifies type alias for vehicle recharge station.
-pub type VehicleRechargeStation = JobPlace;
+/// Specifies vehicle energy (battery) model.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleEnergy {
+    /// Battery capacity in kWh.
+    pub battery_capacity: Float,
+
+    /// Energy consumption of empty vehicle in kWh per km.
+    pub consumption: Float,
+
+    /// Energy consumption of empty vehicle in kWh per km for specific routing profiles.
+    /// When vehicle's profile is listed, it overrides `consumption`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile_consumption: Option<HashMap<String, Float>>,
+
+    /// Extra energy consumption in kWh per km for each unit of load (first capacity dimension).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub load_consumption: Option<Float>,
+
+    /// Initial state of charge as a fraction of battery capacity. Default is 1 (fully charged).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initial_state_of_charge: Option<Float>,
+
+    /// Minimum state of charge as a fraction of battery capacity. Default is 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_state_of_charge: Option<Float>,
+}
+
+impl VehicleEnergy {
+    /// Returns energy model of the vehicle which uses given routing profile.
+    pub fn with_profile(&self, profile: &str) -> Self {
+        let consumption = self
+            .profile_consumption
+            .as_ref()
+            .and_then(|values| values.get(profile))
+            .copied()
+            .unwrap_or(self.consumption);
+
+        Self { consumption, profile_consumption: None, ..self.clone() }
+    }
+
+    /// Returns energy (kWh) consumed on traveling given distance (meters) with given load.
+    pub fn get_consumption(&self, distance: Float, load: Float) -> Float {
+        distance / 1000. * (self.consumption + self.load_consumption.unwrap_or_default() * load)
+    }
+}
+
+/// Specifies vehicle recharge station.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleRechargeStation {
+    /// A station location.
+    pub location: Location,
+
+    /// A fixed recharge duration (service time).
+    pub duration: Float,
+
+    /// A list of time windows with time specified in RFC3339 format.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub times: Option<Vec<Vec<String>>>,
+
+    /// A tag which will be propagated back within corresponding activity in solution.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+
+    /// Charging power in kW. When specified together with vehicle energy model, recharge takes
+    /// extra time needed to charge the energy.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub power: Option<Float>,
+
+    /// A shared charging resource id which limits an amount of vehicles charging at the same time.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub resource_id: Option<String>,
+}
 
 /// Vehicle limits.
 #[derive(Clone, Deserialize, Debug, Serialize)]
@@ -436,6 +708,33 @@ pub struct VehicleLimits {
     /// No job activities restrictions when omitted.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tour_size: Option<usize>,
+
+    /// Max waiting time at a stop while some pickup-delivery job is onboard.
+    /// No onboard waiting restrictions when omitted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_onboard_waiting: Option<Float>,
+}
+
+/// Specifies statutory driving time rules: a rest has to be taken after the given amount of
+/// cumulative driving time.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VehicleDrivingRules {
+    /// Max cumulative driving time before rest has to be taken.
+    pub max_driving_time: Float,
+
+    /// Rest duration which resets cumulative driving time.
+    pub rest_duration: Float,
+
+    /// Allows rest to be split in two parts: the first part has to be at least of the first
+    /// duration, the second one - at least of the second duration.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub split_rest: Option<Vec<Float>>,
+
+    /// Max driving time per day. It applies to all shifts of the same driver or, when drivers are
+    /// not specified, of the same vehicle started at the same day.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_daily_driving_time: Option<Float>,
 }
 
 /// Vehicle optional break time variant.
@@ -571,6 +870,44 @@ pub struct VehicleType {
     /// Useful for vehicles with limited maneuvering space (e.g., wheelchair minibuses, narrow cargo holds).
     #[serde(skip_serializing_if = "Option::is_none", rename = "lifoTags")]
     pub lifo_tags: Option<Vec<String>>,
+
+    /// Driving time rules. Rests are inserted by the solver when specified.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub driving_rules: Option<VehicleDrivingRules>,
+}
+
+/// Specifies a driver who can be paired with a vehicle.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Driver {
+    /// Driver id.
+    pub id: String,
+
+    /// Driver costs which are added to the costs of the vehicle driven. Default is zero.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub costs: Option<VehicleCosts>,
+
+    /// Driver availability shifts.
+    pub shifts: Vec<DriverShift>,
+
+    /// Driver skills. They are combined with skills of the vehicle driven.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skills: Option<Vec<String>>,
+}
+
+/// Specifies a driver availability shift.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriverShift {
+    /// Earliest time when the driver is available.
+    pub earliest: String,
+
+    /// Latest time when the driver is available.
+    pub latest: String,
+
+    /// A home depot: the driver can use only vehicles which start their shift at this location.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub depot: Option<Location>,
 }
 
 /// Specifies a vehicle profile.
@@ -579,10 +916,31 @@ pub struct VehicleProfile {
     /// Routing matrix profile name.
     pub matrix: String,
 
-    /// Traveling duration scale factor.
+    /// Traveling duration scale factor: either a constant or a list of time-of-day rules.
     /// Default value is 1.
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub scale: Option<Float>,
+    pub scale: Option<VehicleProfileScale>,
+}
+
+/// Specifies traveling duration scale of vehicle profile.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+#[serde(untagged)]
+pub enum VehicleProfileScale {
+    /// A constant scale factor applied to all durations.
+    Constant(Float),
+    /// Scale factors applied within specific time intervals. Outside of them, durations are not scaled.
+    TimeOfDay(Vec<VehicleProfileTimeScale>),
+}
+
+/// Specifies traveling duration scale factor applied within specific time interval.
+#[derive(Clone, Deserialize, Debug, Serialize)]
+pub struct VehicleProfileTimeScale {
+    /// Interval start time in RFC3339 format.
+    pub from: String,
+    /// Interval end time in RFC3339 format.
+    pub to: String,
+    /// Scale factor.
+    pub factor: Float,
 }
 
 /// Specifies routing matrix profile.
@@ -609,6 +967,24 @@ pub enum VehicleResource {
         /// A total resource capacity.
         capacity: Vec<i32>,
     },
+
+    /// A shared charging resource: a station with limited amount of connectors.
+    #[serde(rename(deserialize = "charging", serialize = "charging"))]
+    Charging {
+        /// Resource id.
+        id: String,
+        /// An amount of connectors which can be used simultaneously.
+        connectors: usize,
+    },
+
+    /// A shared docking resource: a depot with limited amount of docking doors.
+    #[serde(rename(deserialize = "docking", serialize = "docking"))]
+    Docking {
+        /// Resource id.
+        id: String,
+        /// An amount of vehicles which can be served simultaneously.
+        capacity: usize,
+    },
 }
 
 /// Specifies fleet.
@@ -618,6 +994,10 @@ pub struct Fleet {
     /// Vehicle types.
     pub vehicles: Vec<VehicleType>,
 
+    /// Drivers. When specified, each used vehicle shift is paired with one of the drivers.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub drivers: Option<Vec<Driver>>,
+
     /// Routing profiles.
     pub profiles: Vec<MatrixProfile>,
 
@@ -709,6 +1089,31 @@ pub enum Objective {
         /// Default is 1.0.
         #[serde(skip_serializing_if = "Option::is_none", rename = "latePenalty")]
         late_penalty: Option<Float>,
+        /// Specifies how penalty grows with deviation. Default is linear.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        shape: Option<RequestedTimePenaltyShape>,
+        /// Penalties which override the objective's one for job places with given tags.
+        #[serde(skip_serializing_if = "Option::is_none", rename = "tagPenalties")]
+        tag_penalties: Option<Vec<TagRequestedTimePenalty>>,
+    },
+
+    /// An objective to minimize violation of soft time windows specified on jobs
+    /// with `softTimeWindows` property.
+    MinimizeLateness,
+
+    /// An objective to minimize ride duration of pickup-delivery jobs above the target (excess ride time).
+    /// The target is job's `softMaxRideDuration` or, if it is not specified, `max(offset + factor * direct, min)`,
+    /// where `direct` is the travel duration between pickup and delivery.
+    MinimizeExcessRideTime {
+        /// A constant part of the target in seconds. Default is 0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        offset: Option<Float>,
+        /// A factor applied to the direct travel duration. Default is 1.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        factor: Option<Float>,
+        /// A minimum target in seconds. Default is 0.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        min: Option<Float>,
     },
 
     /// An objective to consider hierarchy of areas while serving jobs.
@@ -783,6 +1188,60 @@ pub struct Matrix {
 
 // endregion
 
+impl Plan {
+    /// Returns relations defined in the plan together with relations derived from replanning:
+    /// a strict relation which locks executed activities at tour departure and a relation which
+    /// keeps onboard jobs on their vehicle.
+    pub fn get_relations(&self) -> Vec<Relation> {
+        let vehicles = || self.replanning.iter().flat_map(|replanning| replanning.vehicles.iter());
+
+        let executed = vehicles().filter_map(|vehicle| {
+            vehicle.executed.as_ref().filter(|executed| !executed.is_empty()).map(|executed| Relation {
+                type_field: RelationType::Strict,
+                jobs: std::iter::once("departure".to_string()).chain(executed.iter().cloned()).collect(),
+                vehicle_id: vehicle.vehicle_id.clone(),
+                shift_index: vehicle.shift_index,
+            })
+        });
+
+        // NOTE jobs loaded last are listed first as they are used as initial order in the tour
+        let onboard = vehicles().filter_map(|vehicle| {
+            vehicle.onboard.as_ref().filter(|onboard| !onboard.is_empty()).map(|onboard| {
+                let mut onboard = onboard.iter().collect::<Vec<_>>();
+                onboard.sort_by(|a, b| {
+                    let since = |job: &OnboardJob| parse_time_safe(&job.since).unwrap_or_default();
+                    since(b).total_cmp(&since(a))
+                });
+
+                Relation {
+                    type_field: RelationType::Any,
+                    jobs: onboard
+                        .into_iter()
+                        .flat_map(|onboard| {
+                            let job = self.jobs.iter().find(|job| job.id == onboard.job_id);
+                            let deliveries = job.and_then(|job| job.deliveries.as_ref()).map_or(1, |tasks| tasks.len());
+
+                            std::iter::repeat_n(onboard.job_id.clone(), deliveries.max(1))
+                        })
+                        .collect(),
+                    vehicle_id: vehicle.vehicle_id.clone(),
+                    shift_index: vehicle.shift_index,
+                }
+            })
+        });
+
+        self.relations.iter().flatten().cloned().chain(executed).chain(onboard).collect()
+    }
+
+    /// Returns onboard jobs of replanning vehicles together with their vehicle states.
+    pub fn get_onboard_jobs(&self) -> impl Iterator<Item = (&VehicleReplanning, &OnboardJob)> + '_ {
+        self.replanning
+            .iter()
+            .flat_map(|replanning| replanning.vehicles.iter())
+            .flat_map(|vehicle| vehicle.onboard.iter().flatten().map(move |job| (vehicle, job)))
+    }
+}
+
 impl Job {
     /// Returns iterator over all tasks.
     pub fn all_tasks_iter(&self) -> impl Iterator<Item = &JobTask> {
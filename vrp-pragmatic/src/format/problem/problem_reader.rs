@@ -9,6 +9,7 @@ use crate::{CoordIndex, parse_time};
 use vrp_core::construction::enablers::*;
 use vrp_core::models::Extras;
 use vrp_core::models::common::{TimeOffset, TimeSpan, TimeWindow};
+use vrp_core::models::problem::SimpleActivityCost;
 use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
 
 pub(super) fn map_to_problem_with_approx(problem: ApiProblem) -> Result<CoreProblem, MultiFormatError> {
@@ -155,6 +156,17 @@ pub(crate) fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matri
     let has_breaks = shift_has_fn(|s| s.breaks.as_ref().is_some_and(|b| !b.is_empty()));
     let has_reloads = shift_has_fn(|s| s.reloads.as_ref().is_some_and(|r| !r.is_empty()));
     let has_recharges = shift_has_fn(|s| s.recharges.as_ref().is_some());
+    let has_driving_rules = api_problem.fleet.vehicles.iter().any(|v| v.driving_rules.is_some());
+    let has_docking_resources = api_problem
+        .fleet
+        .resources
+        .iter()
+        .flatten()
+        .any(|resource| matches!(resource, VehicleResource::Docking { .. }));
+    let has_occupancy_resources = shift_has_fn(|s| {
+        s.recharges.as_ref().is_some_and(|r| r.stations.iter().any(|station| station.resource_id.is_some()))
+    }) || (has_docking_resources
+        && shift_has_fn(|s| s.reloads.iter().flatten().any(|reload| reload.resource_id.is_some())));
     let has_via = shift_has_fn(|s| s.via.as_ref().is_some_and(|v| !v.is_empty()));
 
     let has_order = api_problem
@@ -172,6 +184,8 @@ pub(crate) fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matri
     let has_tour_size_limits =
         api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().is_some_and(|l| l.tour_size.is_some()));
 
+    let has_max_onboard_waiting =
+        api_problem.fleet.vehicles.iter().any(|v| v.limits.as_ref().is_some_and(|l| l.max_onboard_waiting.is_some()));
     let has_tour_travel_limits = api_problem
         .fleet
         .vehicles
@@ -181,7 +195,18 @@ pub(crate) fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matri
     let has_lifo = api_problem.plan.jobs.iter().any(|job| job.lifo_tag.is_some())
         || api_problem.fleet.vehicles.iter().any(|v| v.lifo_tags.as_ref().is_some_and(|t| !t.is_empty()));
 
-    let has_max_ride_duration = api_problem.plan.jobs.iter().any(|job| job.max_ride_duration.is_some());
+    let has_max_ride_duration = api_problem
+        .plan
+        .jobs
+        .iter()
+        .any(|job| job.max_ride_duration.is_some() || job.relative_max_ride_duration.is_some());
+    let has_soft_time_windows = api_problem.plan.jobs.iter().any(|job| job.soft_time_windows.is_some());
+    let has_soft_max_ride_duration = api_problem.plan.jobs.iter().any(|job| job.soft_max_ride_duration.is_some());
+    // NOTE parking time is charged once per stop in the same way as dwell time setup
+    let has_dwell_time = api_problem.plan.jobs.iter().any(|job| {
+        job.dwell_time.is_some()
+            || job.all_tasks_iter().flat_map(|task| task.places.iter()).any(|place| place.parking.is_some())
+    });
 
     ProblemProperties {
         has_multi_dimen_capacity,
@@ -192,6 +217,8 @@ pub(crate) fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matri
         has_unreachable_locations,
         has_reloads,
         has_recharges,
+        has_driving_rules,
+        has_occupancy_resources,
         has_order,
         has_group,
         has_value,
@@ -199,8 +226,12 @@ pub(crate) fn get_problem_properties(api_problem: &ApiProblem, matrices: &[Matri
         has_solo_riding,
         has_tour_size_limits,
         has_tour_travel_limits,
+        has_max_onboard_waiting,
         has_lifo,
         has_max_ride_duration,
+        has_soft_time_windows,
+        has_soft_max_ride_duration,
+        has_dwell_time,
         has_via,
     }
 }
@@ -232,7 +263,8 @@ fn get_problem_blocks(
             (environment.logger)(format!("fleet index created in {}ms", duration.as_millis()).as_str());
         },
     )?;
-    let activity: Arc<dyn ActivityCost> = Arc::new(OnlyVehicleActivityCost::default());
+    // NOTE driver costs are zero unless drivers are specified explicitly
+    let activity: Arc<dyn ActivityCost> = Arc::new(SimpleActivityCost::default());
 
     let (transport, activity) = if reserved_times_index.is_empty() {
         (transport, activity)
@@ -253,6 +285,9 @@ fn get_problem_blocks(
             })?
     };
 
+    let activity: Arc<dyn ActivityCost> =
+        if problem_props.has_dwell_time { Arc::new(DwellActivityCost::new(activity)) } else { activity };
+
     let (jobs, locks) = read_jobs_with_extra_locks(
         api_problem,
         problem_props,
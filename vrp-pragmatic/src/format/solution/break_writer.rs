@@ -133,6 +133,7 @@ fn insert_break(
             time: Some(Interval { start: format_time(activity_time.start), end: format_time(activity_time.end) }),
             job_tag: None,
             commute: None,
+            lateness: None,
         },
     );
 
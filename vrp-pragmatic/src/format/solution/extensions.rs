@@ -16,6 +16,7 @@ impl Add for Statistic {
                 break_time: self.times.break_time + rhs.times.break_time,
                 commuting: self.times.commuting + rhs.times.commuting,
                 parking: self.times.parking + rhs.times.parking,
+                queuing: self.times.queuing + rhs.times.queuing,
             },
         }
     }
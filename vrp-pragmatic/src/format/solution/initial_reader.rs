@@ -3,23 +3,24 @@
 mod initial_reader_test;
 
 use crate::format::solution::Activity as FormatActivity;
+use crate::format::solution::Solution as FormatSolution;
 use crate::format::solution::Stop as FormatStop;
 use crate::format::solution::Tour as FormatTour;
 use crate::format::solution::activity_matcher::{JobInfo, try_match_point_job};
 use crate::format::solution::{deserialize_solution, map_reason_code};
 use crate::format::{CoordIndex, JobIndex, ShiftIndexDimension, VehicleTypeDimension, get_indices};
-use crate::parse_time;
+use crate::{parse_time, parse_time_safe};
 use std::collections::{HashMap, HashSet};
 use std::io::{BufReader, Read};
 use std::sync::Arc;
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{Actor, Job, JobIdDimension, VehicleIdDimension};
+use vrp_core::models::problem::{Actor, DriverIdDimension, Job, JobIdDimension, VehicleIdDimension};
 use vrp_core::models::solution::Tour as CoreTour;
 use vrp_core::models::solution::{Activity, Registry, Route};
 use vrp_core::prelude::*;
 
-type ActorKey = (String, String, usize);
+type ActorKey = (String, String, usize, Option<String>);
 
 /// Reads initial solution from buffer.
 /// NOTE: Solution feasibility is not checked.
@@ -30,18 +31,40 @@ pub fn read_init_solution<R: Read>(
 ) -> Result<Solution, GenericError> {
     let solution = deserialize_solution(solution).map_err(|err| format!("cannot deserialize solution: {err}"))?;
 
+    create_init_solution(&solution, problem, random)
+}
+
+/// Creates initial solution from already deserialized pragmatic solution.
+/// NOTE: Solution feasibility is not checked.
+pub fn create_init_solution(
+    solution: &FormatSolution,
+    problem: Arc<Problem>,
+    random: Arc<dyn Random>,
+) -> Result<Solution, GenericError> {
     let mut registry = Registry::new(&problem.fleet, random);
     let mut added_jobs = HashSet::default();
 
-    let actor_index = registry.all().map(|actor| (get_actor_key(actor.as_ref()), actor)).collect::<HashMap<_, _>>();
+    let actor_index = registry.all().fold(HashMap::<_, Vec<_>>::new(), |mut acc, actor| {
+        acc.entry(get_actor_key(actor.as_ref())).or_default().push(actor);
+        acc
+    });
     let (job_index, coord_index) = get_indices(&problem.extras)?;
 
     let routes =
         solution.tours.iter().try_fold::<_, _, Result<_, GenericError>>(Vec::<_>::default(), |mut routes, tour| {
-            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index);
-            let actor =
-                actor_index.get(&actor_key).ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?.clone();
-            registry.use_actor(&actor);
+            let actor_key = (tour.vehicle_id.clone(), tour.type_id.clone(), tour.shift_index, tour.driver_id.clone());
+            // NOTE the same driver can have multiple shifts, so take the available actor which covers tour start
+            let departure = tour.stops.first().and_then(|stop| parse_time_safe(&stop.schedule().departure).ok());
+            let actor = actor_index
+                .get(&actor_key)
+                .and_then(|actors| {
+                    actors
+                        .iter()
+                        .filter(|actor| departure.is_none_or(|departure| actor.detail.time.contains(departure)))
+                        .find(|actor| registry.use_actor(actor))
+                })
+                .ok_or_else(|| format!("cannot find vehicle for {actor_key:?}"))?
+                .clone();
 
             let mut core_route = create_core_route(actor, tour)?;
 
@@ -64,11 +87,9 @@ pub fn read_init_solution<R: Read>(
             Ok(routes)
         })?;
 
-    let mut unassigned = solution
-        .unassigned
-        .unwrap_or_default()
-        .iter()
-        .try_fold::<Vec<_>, _, Result<_, GenericError>>(Default::default(), |mut acc, unassigned_job| {
+    let mut unassigned = solution.unassigned.iter().flatten().try_fold::<Vec<_>, _, Result<_, GenericError>>(
+        Default::default(),
+        |mut acc, unassigned_job| {
             let job = job_index
                 .get(&unassigned_job.job_id)
                 .cloned()
@@ -84,7 +105,8 @@ pub fn read_init_solution<R: Read>(
             acc.push((job, code));
 
             Ok(acc)
-        })?;
+        },
+    )?;
 
     unassigned.extend(
         problem
@@ -155,8 +177,9 @@ fn get_actor_key(actor: &Actor) -> ActorKey {
     let vehicle_id = dimens.get_vehicle_id().cloned().expect("cannot get vehicle id!");
     let type_id = dimens.get_vehicle_type().cloned().expect("cannot get type id!");
     let shift_index = dimens.get_shift_index().copied().expect("cannot get shift index!");
+    let driver_id = actor.driver.dimens.get_driver_id().cloned();
 
-    (vehicle_id, type_id, shift_index)
+    (vehicle_id, type_id, shift_index, driver_id)
 }
 
 fn create_core_route(actor: Arc<Actor>, format_tour: &FormatTour) -> Result<Route, GenericError> {
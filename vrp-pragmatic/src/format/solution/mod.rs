@@ -11,13 +11,13 @@ mod geo_serializer;
 pub use self::geo_serializer::*;
 
 mod initial_reader;
-pub use self::initial_reader::read_init_solution;
+pub use self::initial_reader::{create_init_solution, read_init_solution};
 
 mod model;
 pub use self::model::*;
 
 mod solution_writer;
-pub(crate) use self::solution_writer::create_solution;
+pub(crate) use self::solution_writer::{create_solution, get_capacity};
 
 use super::*;
 use crate::{format_time, parse_time};
@@ -49,6 +49,11 @@ pub enum PragmaticOutputType {
     Combined,
 }
 
+/// Creates solution in pragmatic format from the domain one.
+pub fn create_pragmatic_solution(problem: &DomainProblem, solution: &DomainSolution) -> ApiSolution {
+    create_solution(problem, solution, &PragmaticOutputType::OnlyPragmatic)
+}
+
 /// Writes solution in pragmatic format variation defined by output type argument.
 pub fn write_pragmatic<W: Write>(
     problem: &DomainProblem,
@@ -102,6 +107,15 @@ pub(crate) fn map_code_reason(code: ViolationCode) -> (&'static str, &'static st
             ("MAX_RIDE_DURATION_CONSTRAINT", "cannot be assigned due to max ride duration constraint")
         }
         SOLO_RIDING_CONSTRAINT_CODE => ("SOLO_RIDING_CONSTRAINT", "cannot be assigned due to solo riding constraint"),
+        DRIVING_RULES_CONSTRAINT_CODE => {
+            ("DRIVING_RULES_CONSTRAINT", "cannot be assigned due to driving time rules of vehicle")
+        }
+        RESOURCE_OCCUPANCY_CONSTRAINT_CODE => {
+            ("RESOURCE_OCCUPANCY_CONSTRAINT", "cannot be assigned due to shared resource occupancy")
+        }
+        MAX_ONBOARD_WAITING_CONSTRAINT_CODE => {
+            ("MAX_ONBOARD_WAITING_CONSTRAINT", "cannot be assigned due to max onboard waiting constraint of vehicle")
+        }
         _ => ("NO_REASON_FOUND", "unknown"),
     }
 }
@@ -126,6 +140,9 @@ fn map_reason_code(reason: &str) -> ViolationCode {
         "LIFO_CONSTRAINT" => LIFO_CONSTRAINT_CODE,
         "MAX_RIDE_DURATION_CONSTRAINT" => MAX_RIDE_DURATION_CONSTRAINT_CODE,
         "SOLO_RIDING_CONSTRAINT" => SOLO_RIDING_CONSTRAINT_CODE,
+        "DRIVING_RULES_CONSTRAINT" => DRIVING_RULES_CONSTRAINT_CODE,
+        "RESOURCE_OCCUPANCY_CONSTRAINT" => RESOURCE_OCCUPANCY_CONSTRAINT_CODE,
+        "MAX_ONBOARD_WAITING_CONSTRAINT" => MAX_ONBOARD_WAITING_CONSTRAINT_CODE,
         _ => ViolationCode::unknown(),
     }
 }
@@ -26,6 +26,9 @@ pub struct Timing {
     /// Parking time.
     #[serde(default = "i64::default")]
     pub parking: i64,
+    /// Waiting time in the queue for a shared resource.
+    #[serde(default = "i64::default")]
+    pub queuing: i64,
 }
 
 /// Represents statistic.
@@ -102,6 +105,9 @@ pub struct Activity {
     /// Commute information.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub commute: Option<Commute>,
+    /// Lateness regarding soft time window end of the job.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lateness: Option<i64>,
 }
 
 /// A stop is a place where vehicle is supposed to do some work.
@@ -213,6 +219,10 @@ pub struct PointStop {
     /// Parking time.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub parking: Option<Interval>,
+    /// Vehicle's battery state of charge (kWh) after departure from this stop, present only when
+    /// vehicle has energy model.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub state_of_charge: Option<Float>,
     /// Activities performed at the stop.
     pub activities: Vec<Activity>,
 }
@@ -228,6 +238,9 @@ pub struct Tour {
     /// Shift index.
     #[serde(default)]
     pub shift_index: usize,
+    /// Id of the driver assigned to the vehicle, present only when fleet has drivers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub driver_id: Option<String>,
     /// List of stops.
     pub stops: Vec<Stop>,
     /// Tour statistic.
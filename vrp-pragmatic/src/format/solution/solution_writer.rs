@@ -6,30 +6,39 @@ use crate::format::CoordIndex;
 use crate::format::solution::activity_matcher::get_job_tag;
 use crate::format::solution::model::Timing;
 use crate::format::solution::*;
-use vrp_core::construction::enablers::{ReservedTimesIndex, get_route_intervals};
-use vrp_core::construction::features::JobDemandDimension;
+use vrp_core::construction::enablers::{
+    ReservedTimesIndex, get_parking, get_route_intervals, get_shared_parking, get_shared_setup,
+};
+use vrp_core::construction::features::{JobDemandDimension, get_activity_lateness, get_charging_time};
 use vrp_core::construction::heuristics::UnassignmentInfo;
 use vrp_core::models::common::*;
-use vrp_core::models::problem::{JobIdDimension, Multi, TravelTime, VehicleIdDimension};
+use vrp_core::models::problem::{DriverIdDimension, JobIdDimension, Multi, TravelTime, VehicleIdDimension};
 use vrp_core::models::solution::{Activity, Route};
 use vrp_core::prelude::Float;
 use vrp_core::rosomaxa::evolution::TelemetryMetrics;
+use vrp_core::rosomaxa::utils::UnwrapValue;
 use vrp_core::solver::processing::{ClusterConfigExtraProperty, ReservedTimesExtraProperty};
 use vrp_core::utils::CollectGroupBy;
 
 struct Leg {
     pub last_detail: Option<(DomainLocation, Timestamp)>,
     pub load: Option<MultiDimLoad>,
+    pub state_of_charge: Option<Float>,
     pub statistic: Statistic,
 }
 
 impl Leg {
-    fn new(last_detail: Option<(DomainLocation, Timestamp)>, load: Option<MultiDimLoad>, statistic: Statistic) -> Self {
-        Self { last_detail, load, statistic }
+    fn new(
+        last_detail: Option<(DomainLocation, Timestamp)>,
+        load: Option<MultiDimLoad>,
+        state_of_charge: Option<Float>,
+        statistic: Statistic,
+    ) -> Self {
+        Self { last_detail, load, state_of_charge, statistic }
     }
 
     fn empty() -> Self {
-        Self { last_detail: None, load: None, statistic: Statistic::default() }
+        Self { last_detail: None, load: None, state_of_charge: None, statistic: Statistic::default() }
     }
 }
 
@@ -75,11 +84,13 @@ fn create_tour(
     let actor = route.actor.as_ref();
     let vehicle = actor.vehicle.as_ref();
     let transport = problem.transport.as_ref();
+    let energy = vehicle.dimens.get_vehicle_energy();
 
     let mut tour = Tour {
         vehicle_id: vehicle.dimens.get_vehicle_id().unwrap().clone(),
         type_id: vehicle.dimens.get_vehicle_type().unwrap().clone(),
         shift_index: vehicle.dimens.get_shift_index().copied().unwrap(),
+        driver_id: actor.driver.dimens.get_driver_id().cloned(),
         stops: vec![],
         statistic: Statistic::default(),
     };
@@ -99,6 +110,12 @@ fn create_tour(
             },
         );
 
+        let state_of_charge = if start_idx == 0 {
+            energy.map(|energy| energy.battery_capacity * energy.initial_state_of_charge.unwrap_or(1.))
+        } else {
+            leg.state_of_charge
+        };
+
         let (start_idx, start) = if start_idx == 0 {
             let start = route.tour.start().unwrap();
             let is_same_location =
@@ -123,17 +140,24 @@ fn create_tour(
                     },
                     job_tag: None,
                     commute: None,
+                    lateness: None,
                 }],
                 parking: None,
+                state_of_charge,
             }));
             (start_idx + 1, start)
         } else {
             (start_idx, route.tour.get(start_idx - 1).unwrap())
         };
 
-        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().fold(
-            Leg::new(Some((start.place.location, start.schedule.departure)), Some(start_delivery), leg.statistic),
-            |leg, act| {
+        let mut leg = route.tour.activities_slice(start_idx, end_idx).iter().enumerate().fold(
+            Leg::new(
+                Some((start.place.location, start.schedule.departure)),
+                Some(start_delivery),
+                state_of_charge,
+                leg.statistic,
+            ),
+            |leg, (idx, act)| {
                 let activity_type = get_activity_type(act).cloned();
                 let (prev_location, prev_departure) = leg.last_detail.unwrap();
                 let prev_load = if activity_type.is_some() {
@@ -145,7 +169,7 @@ fn create_tour(
                 };
 
                 let activity_type = activity_type.unwrap_or_else(|| "arrival".to_string());
-                let is_break = activity_type == "break";
+                let is_break = activity_type == "break" || activity_type == "rest";
 
                 let job_tag = act.job.as_ref().and_then(|single| {
                     get_job_tag(single, (act.place.location, (act.place.time.clone(), start.schedule.departure)))
@@ -171,32 +195,55 @@ fn create_tour(
                     (duration, transport_cost)
                 } else {
                     // NOTE: no need to drive in case of non-zero commute, this goes to commuting time
-                    (0., commuting * vehicle.costs.per_service_time)
+                    (0., commuting * (actor.driver.costs.per_service_time + vehicle.costs.per_service_time))
                 };
 
                 // NOTE two clusters at the same stop location
-                let parking =
+                let cluster_parking =
                     match (prev_location == act.place.location, act.commute.is_some(), commute.is_zero_distance()) {
                         (false, true, true) => parking,
                         _ => 0.,
                     };
 
+                // NOTE job place parking is charged once per stop, shared part is not a part of service
+                let prev_act = route.tour.get(start_idx + idx - 1).unwrap();
+                let job_parking = get_parking(act);
+                let parking = cluster_parking + job_parking - get_shared_parking(prev_act, act);
+
                 let activity_arrival = parking + act.schedule.arrival + commute.forward.duration;
-                let service_start = activity_arrival.max(act.place.time.start);
+                // NOTE charging time is kept as a part of station's service duration
+                let charging = if activity_type == "recharge" { get_charging_time(act) } else { 0. };
+                // NOTE only these activities can wait in the queue for a shared resource
+                let queuing = if matches!(activity_type.as_str(), "recharge" | "reload") {
+                    let expected_departure = problem
+                        .activity
+                        .estimate_departure_after(route, prev_act, act, act.schedule.arrival)
+                        .unwrap_value();
+                    (act.schedule.departure - expected_departure).max(0.)
+                } else {
+                    0.
+                };
+                let service_start = activity_arrival.max(act.place.time.start) + queuing;
                 let waiting = service_start - activity_arrival;
-                let serving = act.place.duration - parking;
+                let serving = act.place.duration - cluster_parking - job_parking - get_shared_setup(prev_act, act);
                 let service_end = service_start + serving;
                 let activity_departure = service_end;
 
                 // TODO: add better support of time based activity costs
                 let serving_cost = problem.activity.cost(route, act, service_start);
-                let total_cost = serving_cost + transport_cost + waiting * vehicle.costs.per_waiting_time;
+                let waiting_cost = waiting * (actor.driver.costs.per_waiting_time + vehicle.costs.per_waiting_time);
+                let total_cost = serving_cost + transport_cost + waiting_cost;
 
-                let location_distance =
-                    transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure))
-                        as i64;
+                let travel_distance =
+                    transport.distance(route, prev_location, act.place.location, TravelTime::Departure(prev_departure));
+                let location_distance = travel_distance as i64;
                 let distance = leg.statistic.distance + location_distance - commute.forward.distance as i64;
 
+                let state_of_charge = leg.state_of_charge.zip(energy).map(|(state_of_charge, energy)| {
+                    let load = leg.load.map_or(0., |load| load.load[0] as Float);
+                    state_of_charge - energy.get_consumption(travel_distance, load) + get_charged_energy(act, charging)
+                });
+
                 let is_new_stop = match (act.commute.as_ref(), prev_location == act.place.location) {
                     (Some(commute), false) if commute.is_zero_distance() => true,
                     (Some(_), _) => false,
@@ -217,6 +264,7 @@ fn create_tour(
                         } else {
                             None
                         },
+                        state_of_charge,
                         activities: vec![],
                     }));
                 }
@@ -231,19 +279,20 @@ fn create_tour(
 
                 last.time.departure = format_time(act.schedule.departure);
                 last.load = load.as_vec();
+                last.state_of_charge = state_of_charge;
                 last.activities.push(ApiActivity {
                     job_id,
                     activity_type: activity_type.clone(),
                     location: Some(coord_index.get_by_idx(act.place.location).unwrap()),
-                    time: Some(Interval {
-                        start: format_time(activity_arrival.max(act.place.time.start)),
-                        end: format_time(activity_departure),
-                    }),
+                    time: Some(Interval { start: format_time(service_start), end: format_time(activity_departure) }),
                     job_tag,
                     commute: act
                         .commute
                         .as_ref()
                         .map(|commute| Commute::new(commute, act.schedule.arrival, activity_departure, coord_index)),
+                    lateness: get_activity_lateness(act, service_start)
+                        .filter(|&lateness| lateness > 0.)
+                        .map(|lateness| lateness as i64),
                 });
 
                 // NOTE detect when vehicle returns after activity to stop point
@@ -266,13 +315,15 @@ fn create_tour(
                         times: Timing {
                             driving: leg.statistic.times.driving + driving as i64,
                             serving: leg.statistic.times.serving + (if is_break { 0 } else { serving as i64 }),
-                            waiting: leg.statistic.times.waiting + waiting as i64,
+                            waiting: leg.statistic.times.waiting + (waiting - queuing) as i64,
                             break_time: leg.statistic.times.break_time + (if is_break { serving as i64 } else { 0 }),
                             commuting: leg.statistic.times.commuting + commuting as i64,
                             parking: leg.statistic.times.parking + parking as i64,
+                            queuing: leg.statistic.times.queuing + queuing as i64,
                         },
                     },
                     load: Some(load),
+                    state_of_charge,
                 }
             },
         );
@@ -282,7 +333,7 @@ fn create_tour(
         leg
     });
 
-    leg.statistic.cost += vehicle.costs.fixed;
+    leg.statistic.cost += actor.driver.costs.fixed + vehicle.costs.fixed;
     tour.statistic = leg.statistic;
 
     insert_reserved_times_as_breaks(route, &mut tour, reserved_times_index);
@@ -395,11 +446,24 @@ fn create_violations(solution: &DomainSolution) -> Option<Vec<Violation>> {
     if violations.is_empty() { None } else { Some(violations) }
 }
 
+/// Returns energy (kWh) charged at the recharge station during given charging time.
+fn get_charged_energy(activity: &Activity, charging: Duration) -> Float {
+    get_charging_power(activity).map_or(0., |power| charging * power / 3600.)
+}
+
+fn get_charging_power(activity: &Activity) -> Option<Float> {
+    activity
+        .job
+        .as_ref()
+        .filter(|single| single.dimens.get_job_type().is_some_and(|job_type| job_type == "recharge"))
+        .and_then(|single| single.dimens.get_charging_power().copied())
+}
+
 fn get_activity_type(activity: &Activity) -> Option<&String> {
     activity.job.as_ref().and_then(|single| single.dimens.get_job_type())
 }
 
-fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
+pub(crate) fn get_capacity(dimens: &Dimensions) -> Option<Demand<MultiDimLoad>> {
     // NOTE: try to detect whether dimensions stores multidimensional demand
     let demand: Option<Demand<MultiDimLoad>> = dimens.get_job_demand().cloned();
     if let Some(demand) = demand {
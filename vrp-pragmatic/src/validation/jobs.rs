@@ -4,6 +4,7 @@ mod jobs_test;
 
 use super::*;
 use crate::utils::combine_error_results;
+use std::iter::once;
 use vrp_core::models::common::MultiDimLoad;
 
 /// Checks that plan has no jobs with duplicate ids.
@@ -156,14 +157,15 @@ fn check_e1105_empty_jobs(ctx: &ValidationContext) -> Result<(), FormatError> {
     }
 }
 
-/// Checks that job has no negative duration aka service time.
+/// Checks that job has no negative duration aka service time or parking time.
 fn check_e1106_negative_duration(ctx: &ValidationContext) -> Result<(), FormatError> {
     let ids = ctx
         .jobs()
         .filter(|job| {
             ctx.tasks(job)
                 .iter()
-                .flat_map(|task| task.places.iter().map(|place| place.duration))
+                .flat_map(|task| task.places.iter())
+                .flat_map(|place| once(place.duration).chain(place.parking))
                 .any(|duration| duration.is_sign_negative())
         })
         .map(|job| job.id.clone())
@@ -268,6 +270,98 @@ fn check_e1109_named_demand_dimensions_exist(ctx: &ValidationContext) -> Result<
     Ok(())
 }
 
+/// Checks that soft time windows have non-negative penalties and max lateness.
+fn check_e1110_soft_time_windows_are_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.soft_time_windows.as_ref().is_some_and(|soft| {
+                [Some(soft.max_lateness), soft.early_penalty, soft.late_penalty].into_iter().flatten().any(|v| v < 0.)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1110".to_string(),
+            "invalid job soft time windows".to_string(),
+            format!("make sure that penalties and max lateness are not negative for jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that relative max ride duration has non-negative parameters.
+fn check_e1111_relative_max_ride_duration_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.relative_max_ride_duration.as_ref().is_some_and(|relative| {
+                [relative.offset, Some(relative.factor), relative.min].into_iter().flatten().any(|v| v < 0.)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1111".to_string(),
+            "invalid relative max ride duration".to_string(),
+            format!("make sure that offset, factor and min are not negative for jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that soft max ride duration is not negative.
+fn check_e1112_soft_max_ride_duration_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| job.soft_max_ride_duration.is_some_and(|duration| duration < 0.))
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1112".to_string(),
+            "invalid soft max ride duration".to_string(),
+            format!("make sure that soft max ride duration is not negative for jobs: '{}'", ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that dwell time setup and per unit values are not negative.
+fn check_e1113_dwell_time_is_correct(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let ids = ctx
+        .jobs()
+        .filter(|job| {
+            job.dwell_time.as_ref().is_some_and(|dwell_time| {
+                dwell_time.setup.is_some_and(|setup| setup < 0.)
+                    || dwell_time.per_unit.iter().flatten().any(|&per_unit| per_unit < 0.)
+            })
+        })
+        .map(|job| job.id.clone())
+        .collect::<Vec<_>>();
+
+    if ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1113".to_string(),
+            "invalid dwell time".to_string(),
+            format!(
+                "make sure that dwell time setup and per unit values are not negative for jobs: '{}'",
+                ids.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates jobs from the plan.
 pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -281,6 +375,10 @@ pub fn validate_jobs(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
         check_e1107_negative_demand(ctx),
         check_e1108_demand_named_demand_mutual_exclusion(ctx),
         check_e1109_named_demand_dimensions_exist(ctx),
+        check_e1110_soft_time_windows_are_correct(ctx),
+        check_e1111_relative_max_ride_duration_is_correct(ctx),
+        check_e1112_soft_max_ride_duration_is_correct(ctx),
+        check_e1113_dwell_time_is_correct(ctx),
     ])
     .map_err(From::from)
 }
@@ -29,6 +29,9 @@ use self::vehicles::validate_vehicles;
 mod relations;
 use self::relations::validate_relations;
 
+mod replanning;
+use self::replanning::validate_replanning;
+
 mod routing;
 use self::routing::validate_routing;
 use std::collections::HashMap;
@@ -53,6 +56,7 @@ impl<'a> ValidationContext<'a> {
             .chain(validate_objectives(self).err())
             .chain(validate_routing(self).err())
             .chain(validate_relations(self).err())
+            .chain(validate_replanning(self).err())
             .flatten()
             .collect::<Vec<_>>()
             .into();
@@ -70,6 +74,11 @@ impl<'a> ValidationContext<'a> {
         self.problem.fleet.vehicles.iter()
     }
 
+    /// Gets list of drivers.
+    fn drivers(&self) -> impl Iterator<Item = &Driver> {
+        self.problem.fleet.drivers.iter().flatten()
+    }
+
     /// Gets a flat list of job tasks from the job.
     fn tasks(&self, job: &'a Job) -> Vec<&'a JobTask> {
         job.pickups
@@ -166,6 +166,106 @@ fn check_e1607_jobs_with_value_but_no_objective(
     }
 }
 
+/// Checks that lateness objective is specified when some jobs have soft time windows.
+fn check_e1608_jobs_with_soft_time_windows_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_lateness_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeLateness));
+    let has_soft_time_windows = ctx.problem.plan.jobs.iter().any(|job| job.soft_time_windows.is_some());
+
+    if has_no_lateness_objective && has_soft_time_windows {
+        Err(FormatError::new(
+            "E1608".to_string(),
+            "missing lateness objective".to_string(),
+            "specify 'minimize-lateness' objective, remove objectives property or remove softTimeWindows property \
+             from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that requested time penalties are not negative.
+fn check_e1609_requested_time_penalties_are_correct(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    let objective_penalties = get_objectives_flattened(objectives).flat_map(|objective| match objective {
+        MatchRequestedTime { early_penalty, late_penalty, shape, tag_penalties } => Either::Left(
+            std::iter::once((*early_penalty, *late_penalty, shape.as_ref())).chain(
+                tag_penalties
+                    .iter()
+                    .flatten()
+                    .map(|penalty| (penalty.early_penalty, penalty.late_penalty, penalty.shape.as_ref())),
+            ),
+        ),
+        _ => Either::Right(std::iter::empty()),
+    });
+    let job_penalties = ctx
+        .problem
+        .plan
+        .jobs
+        .iter()
+        .filter_map(|job| job.requested_time_penalty.as_ref())
+        .map(|penalty| (penalty.early_penalty, penalty.late_penalty, penalty.shape.as_ref()));
+
+    let has_invalid_penalty = objective_penalties.chain(job_penalties).any(|(early_penalty, late_penalty, shape)| {
+        let has_negative_penalty = early_penalty.into_iter().chain(late_penalty).any(|penalty| penalty < 0.);
+        let has_invalid_segment = match shape {
+            Some(RequestedTimePenaltyShape::Piecewise { segments }) => {
+                segments.iter().any(|segment| segment.from < 0. || segment.multiplier < 0.)
+            }
+            _ => false,
+        };
+
+        has_negative_penalty || has_invalid_segment
+    });
+
+    if has_invalid_penalty {
+        Err(FormatError::new(
+            "E1609".to_string(),
+            "invalid requested time penalty".to_string(),
+            "ensure that penalties, segment starts and multipliers of requested time penalties are not negative"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks that excess ride time objective is specified when some jobs have soft max ride duration.
+fn check_e1610_jobs_with_soft_max_ride_duration_but_no_objective(
+    ctx: &ValidationContext,
+    objectives: &[&Objective],
+) -> Result<(), FormatError> {
+    if objectives.is_empty() {
+        return Ok(());
+    }
+
+    let has_no_excess_ride_time_objective =
+        !get_objectives_flattened(objectives).any(|objective| matches!(objective, MinimizeExcessRideTime { .. }));
+    let has_soft_max_ride_duration = ctx.problem.plan.jobs.iter().any(|job| job.soft_max_ride_duration.is_some());
+
+    if has_no_excess_ride_time_objective && has_soft_max_ride_duration {
+        Err(FormatError::new(
+            "E1610".to_string(),
+            "missing excess ride time objective".to_string(),
+            "specify 'minimize-excess-ride-time' objective, remove objectives property or remove softMaxRideDuration \
+             property from jobs"
+                .to_string(),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn get_objectives<'a>(ctx: &'a ValidationContext) -> Option<Vec<&'a Objective>> {
     ctx.problem.objectives.as_ref().map(|objectives| objectives.iter().collect())
 }
@@ -188,6 +288,9 @@ pub fn validate_objectives(ctx: &ValidationContext) -> Result<(), MultiFormatErr
             check_e1605_check_positive_value_and_order(ctx),
             check_e1606_check_multiple_cost_objectives(&objectives),
             check_e1607_jobs_with_value_but_no_objective(ctx, &objectives),
+            check_e1608_jobs_with_soft_time_windows_but_no_objective(ctx, &objectives),
+            check_e1609_requested_time_penalties_are_correct(ctx, &objectives),
+            check_e1610_jobs_with_soft_max_ride_duration_but_no_objective(ctx, &objectives),
         ])
         .map_err(From::from)
     } else {
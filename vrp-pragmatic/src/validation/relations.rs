@@ -251,7 +251,10 @@ pub fn validate_relations(ctx: &ValidationContext) -> Result<(), MultiFormatErro
         .flat_map(|v_type| v_type.vehicle_ids.iter().map(move |id| (id.clone(), v_type)))
         .collect::<HashMap<_, _>>();
 
-    if let Some(relations) = ctx.problem.plan.relations.as_ref() {
+    let relations = ctx.problem.plan.get_relations();
+    let relations = relations.as_slice();
+
+    if !relations.is_empty() {
         combine_error_results(&[
             check_e1200_job_existence(ctx, relations),
             check_e1201_vehicle_existence(relations, &vehicle_map),
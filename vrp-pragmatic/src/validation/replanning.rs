@@ -0,0 +1,205 @@
+#[cfg(test)]
+#[path = "../../tests/unit/validation/replanning_test.rs"]
+mod replanning_test;
+
+use super::*;
+use crate::parse_time_safe;
+use crate::utils::combine_error_results;
+
+/// Checks that replanning time is valid.
+fn check_e1700_valid_now_time(replanning: &Replanning) -> Result<(), FormatError> {
+    parse_time_safe(&replanning.now).map(|_| ()).map_err(|_| {
+        FormatError::new(
+            "E1700".to_string(),
+            "invalid replanning time".to_string(),
+            format!("change replanning time to RFC3339 format, current value: '{}'", replanning.now),
+        )
+    })
+}
+
+/// Checks that replanning vehicle ids and shift indices are defined in fleet.
+fn check_e1701_vehicle_existence(ctx: &ValidationContext, replanning: &Replanning) -> Result<(), FormatError> {
+    let vehicle_ids = replanning
+        .vehicles
+        .iter()
+        .filter(|state| {
+            let shift_index = state.shift_index.unwrap_or_default();
+            !ctx.vehicles().any(|vehicle| {
+                vehicle.vehicle_ids.contains(&state.vehicle_id) && vehicle.shifts.get(shift_index).is_some()
+            })
+        })
+        .map(|state| state.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1701".to_string(),
+            "replanning has vehicle id or shift index which does not present in the fleet".to_string(),
+            format!(
+                "remove vehicle state from replanning or fix vehicle id or shift index, ids: '{}'",
+                vehicle_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that replanning has no duplicated vehicle states.
+fn check_e1702_no_duplicated_vehicle_states(replanning: &Replanning) -> Result<(), FormatError> {
+    let keys = replanning
+        .vehicles
+        .iter()
+        .map(|state| format!("{}:{}", state.vehicle_id, state.shift_index.unwrap_or_default()))
+        .collect::<Vec<_>>();
+
+    get_duplicates(keys.iter()).map_or(Ok(()), |ids| {
+        Err(FormatError::new(
+            "E1702".to_string(),
+            "duplicated vehicle states in replanning".to_string(),
+            format!("remove duplicated vehicle states, vehicle id and shift index pairs: {}", ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that current location is not specified for a vehicle with executed activities.
+fn check_e1703_no_location_with_executed_activities(replanning: &Replanning) -> Result<(), FormatError> {
+    let vehicle_ids = replanning
+        .vehicles
+        .iter()
+        .filter(|state| {
+            state.location.is_some() && state.executed.as_ref().is_some_and(|executed| !executed.is_empty())
+        })
+        .map(|state| state.vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1703".to_string(),
+            "replanning vehicle state has both location and executed activities".to_string(),
+            format!("remove location or executed activities from vehicle state, ids: '{}'", vehicle_ids.join(", ")),
+        ))
+    }
+}
+
+/// Checks that onboard jobs are defined in the plan and have deliveries only.
+fn check_e1704_onboard_jobs_are_deliveries(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let has_tasks = |tasks: &Option<Vec<JobTask>>| tasks.as_ref().is_some_and(|tasks| !tasks.is_empty());
+    let job_ids = ctx
+        .problem
+        .plan
+        .get_onboard_jobs()
+        .filter(|(_, onboard)| {
+            ctx.jobs().find(|job| job.id == onboard.job_id).is_none_or(|job| {
+                has_tasks(&job.pickups)
+                    || has_tasks(&job.replacements)
+                    || has_tasks(&job.services)
+                    || !has_tasks(&job.deliveries)
+            })
+        })
+        .map(|(_, onboard)| onboard.job_id.clone())
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1704".to_string(),
+            "onboard job is not defined in the plan as a job with deliveries only".to_string(),
+            format!(
+                "define remaining part of onboard jobs in the plan using deliveries only, ids: '{}'",
+                job_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that onboard time is valid and is not after replanning time.
+fn check_e1705_valid_onboard_time(ctx: &ValidationContext, replanning: &Replanning) -> Result<(), FormatError> {
+    let now = parse_time_safe(&replanning.now).ok();
+    let job_ids = ctx
+        .problem
+        .plan
+        .get_onboard_jobs()
+        .filter(|(_, onboard)| parse_time_safe(&onboard.since).map_or(true, |since| now.is_some_and(|now| since > now)))
+        .map(|(_, onboard)| onboard.job_id.clone())
+        .collect::<Vec<_>>();
+
+    if job_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1705".to_string(),
+            "invalid onboard time".to_string(),
+            format!(
+                "change onboard time to RFC3339 format which is not after replanning time, ids: '{}'",
+                job_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicles without executed activities can depart not earlier than replanning time.
+fn check_e1706_shift_start_after_now(ctx: &ValidationContext, replanning: &Replanning) -> Result<(), FormatError> {
+    let Ok(now) = parse_time_safe(&replanning.now) else { return Ok(()) };
+
+    let has_executed = |vehicle_id: &str, shift_index: usize| {
+        replanning.vehicles.iter().any(|state| {
+            state.vehicle_id == vehicle_id
+                && state.shift_index.unwrap_or_default() == shift_index
+                && state.executed.as_ref().is_some_and(|executed| !executed.is_empty())
+        })
+    };
+
+    let vehicle_ids = ctx
+        .vehicles()
+        .flat_map(|vehicle| {
+            vehicle.shifts.iter().enumerate().flat_map(move |(shift_index, shift)| {
+                vehicle.vehicle_ids.iter().map(move |vehicle_id| (vehicle_id, shift_index, shift))
+            })
+        })
+        .filter(|(vehicle_id, shift_index, shift)| {
+            !has_executed(vehicle_id, *shift_index)
+                && shift
+                    .start
+                    .latest
+                    .as_ref()
+                    .and_then(|latest| parse_time_safe(latest).ok())
+                    .is_some_and(|latest| latest < now)
+        })
+        .map(|(vehicle_id, _, _)| vehicle_id.clone())
+        .collect::<Vec<_>>();
+
+    if vehicle_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1706".to_string(),
+            "vehicle shift latest start is before replanning time".to_string(),
+            format!(
+                "remove vehicle shift or change its latest start to be not before replanning time, ids: '{}'",
+                vehicle_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Validates replanning in the plan.
+pub fn validate_replanning(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
+    if let Some(replanning) = ctx.problem.plan.replanning.as_ref() {
+        combine_error_results(&[
+            check_e1700_valid_now_time(replanning),
+            check_e1701_vehicle_existence(ctx, replanning),
+            check_e1702_no_duplicated_vehicle_states(replanning),
+            check_e1703_no_location_with_executed_activities(replanning),
+            check_e1704_onboard_jobs_are_deliveries(ctx),
+            check_e1705_valid_onboard_time(ctx, replanning),
+            check_e1706_shift_start_after_now(ctx, replanning),
+        ])
+        .map_err(From::from)
+    } else {
+        Ok(())
+    }
+}
@@ -3,8 +3,9 @@
 mod routing_test;
 
 use super::*;
+use crate::parse_time_safe;
 use crate::utils::combine_error_results;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use vrp_core::prelude::Float;
 
 /// Checks that no duplicated profile names specified.
@@ -115,6 +116,55 @@ fn check_e1505_profiles_exist(ctx: &ValidationContext) -> Result<(), FormatError
     }
 }
 
+/// Checks that time dependent routing matrices satisfy FIFO property: departing later never results
+/// in earlier arrival.
+fn check_e1506_time_dependent_matrices_fifo(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let matrices_by_profile = ctx.matrices.iter().flat_map(|matrices| matrices.iter()).fold(
+        HashMap::<_, Vec<_>>::default(),
+        |mut acc, matrix| {
+            if let Some(timestamp) = matrix.timestamp.as_ref().and_then(|time| parse_time_safe(time).ok()) {
+                acc.entry(matrix.profile.clone().unwrap_or_default()).or_default().push((timestamp, matrix));
+            }
+            acc
+        },
+    );
+
+    let mut profiles = matrices_by_profile
+        .into_iter()
+        .filter(|(_, matrices)| {
+            let mut matrices = matrices.clone();
+            matrices.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+            matrices.windows(2).any(|pair| {
+                let ((left_time, left), (right_time, right)) = (pair[0], pair[1]);
+                let is_reachable = |matrix: &Matrix, idx: usize| {
+                    matrix.error_codes.as_ref().and_then(|codes| codes.get(idx)).is_none_or(|&code| code == 0)
+                };
+
+                (0..left.travel_times.len().min(right.travel_times.len()))
+                    .filter(|&idx| is_reachable(left, idx) && is_reachable(right, idx))
+                    .any(|idx| (left.travel_times[idx] - right.travel_times[idx]) as Float > right_time - left_time)
+            })
+        })
+        .map(|(profile, _)| profile)
+        .collect::<Vec<_>>();
+
+    if profiles.is_empty() {
+        Ok(())
+    } else {
+        profiles.sort();
+        Err(FormatError::new(
+            "E1506".to_string(),
+            "time dependent routing matrices violate FIFO property".to_string(),
+            format!(
+                "ensure that travel time does not decrease between consecutive matrices more than time difference \
+                 between their timestamps, profiles: '{}'",
+                profiles.join(", ")
+            ),
+        ))
+    }
+}
+
 /// Validates routing rules.
 pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     let location_types = (ctx.coord_index.has_coordinates(), ctx.coord_index.has_indices());
@@ -126,6 +176,7 @@ pub fn validate_routing(ctx: &ValidationContext) -> Result<(), MultiFormatError>
         check_e1503_no_matrix_when_indices_used(ctx, location_types),
         check_e1504_index_size_mismatch(ctx),
         check_e1505_profiles_exist(ctx),
+        check_e1506_time_dependent_matrices_fifo(ctx),
     ])
     .map_err(From::from)
 }
@@ -6,8 +6,9 @@ use super::*;
 use crate::utils::combine_error_results;
 use crate::validation::common::get_time_windows;
 use crate::{parse_time, parse_time_safe};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use vrp_core::models::common::TimeWindow;
+use vrp_core::prelude::Float;
 
 /// Checks that fleet has no vehicle with duplicate type ids.
 fn check_e1300_no_vehicle_types_with_duplicate_type_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
@@ -210,27 +211,40 @@ fn check_e1307_vehicle_offset_break_rescheduling(ctx: &ValidationContext) -> Res
 }
 
 fn check_e1308_vehicle_reload_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
-    let reload_resource_ids = ctx
+    let resource_ids = ctx
         .problem
         .fleet
         .resources
         .iter()
         .flat_map(|resources| resources.iter())
         .map(|resource| match resource {
-            VehicleResource::Reload { id, .. } => id.to_string(),
+            VehicleResource::Reload { id, .. }
+            | VehicleResource::Charging { id, .. }
+            | VehicleResource::Docking { id, .. } => id.to_string(),
         })
         .collect::<Vec<_>>();
 
-    let unique_resource_ids = reload_resource_ids.iter().cloned().collect::<HashSet<_>>();
-
-    if reload_resource_ids.len() != unique_resource_ids.len() {
+    if resource_ids.len() != resource_ids.iter().collect::<HashSet<_>>().len() {
         return Err(FormatError::new(
             "E1308".to_string(),
             "invalid vehicle reload resource".to_string(),
-            "make sure that fleet reload resource ids are unique".to_string(),
+            "make sure that fleet resource ids are unique".to_string(),
         ));
     }
 
+    let unique_resource_ids = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Reload { id, .. } => Some(id.to_string()),
+            VehicleResource::Docking { id, capacity } if *capacity > 0 => Some(id.to_string()),
+            VehicleResource::Charging { .. } | VehicleResource::Docking { .. } => None,
+        })
+        .collect::<HashSet<_>>();
+
     let type_ids = get_invalid_type_ids(
         ctx,
         Box::new(move |_, shift, _| {
@@ -388,6 +402,257 @@ fn check_e1312_vehicle_has_capacity(ctx: &ValidationContext) -> Result<(), Forma
     }
 }
 
+/// Checks that fleet has no drivers with duplicate ids.
+fn check_e1313_no_drivers_with_duplicate_ids(ctx: &ValidationContext) -> Result<(), FormatError> {
+    get_duplicates(ctx.drivers().map(|driver| &driver.id)).map_or(Ok(()), |ids| {
+        Err(FormatError::new(
+            "E1313".to_string(),
+            "duplicated driver ids".to_string(),
+            format!("remove duplicated driver ids: {}", ids.join(", ")),
+        ))
+    })
+}
+
+/// Checks that driver shifts are specified and their time is correct.
+fn check_e1314_driver_shift_time(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let driver_ids = ctx
+        .drivers()
+        .filter(|driver| {
+            let tws = driver
+                .shifts
+                .iter()
+                .map(|shift| vec![shift.earliest.clone(), shift.latest.clone()])
+                .collect::<Vec<_>>();
+
+            !check_raw_time_windows(&tws, false)
+        })
+        .map(|driver| driver.id.clone())
+        .collect::<Vec<_>>();
+
+    if driver_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1314".to_string(),
+            "invalid driver shifts".to_string(),
+            format!(
+                "ensure that driver has at least one shift and shift times conform time window rules, driver ids: '{}'",
+                driver_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that driver depot is a start location of some vehicle shift.
+fn check_e1315_driver_depot_is_vehicle_start(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let start_locations = ctx
+        .vehicles()
+        .flat_map(|vehicle| vehicle.shifts.iter())
+        .filter_map(|shift| ctx.coord_index.get_by_loc(&shift.start.location))
+        .collect::<HashSet<_>>();
+
+    let driver_ids =
+        ctx.drivers()
+            .filter(|driver| {
+                driver.shifts.iter().filter_map(|shift| shift.depot.as_ref()).any(|depot| {
+                    ctx.coord_index.get_by_loc(depot).is_none_or(|depot| !start_locations.contains(&depot))
+                })
+            })
+            .map(|driver| driver.id.clone())
+            .collect::<Vec<_>>();
+
+    if driver_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1315".to_string(),
+            "driver depot is not a vehicle start location".to_string(),
+            format!(
+                "ensure that driver depot matches start location of some vehicle shift, driver ids: '{}'",
+                driver_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle driving rules are valid.
+fn check_e1316_vehicle_driving_rules(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.driving_rules.as_ref().is_some_and(|rules| {
+                let is_invalid_split = rules.split_rest.as_ref().is_some_and(|split| {
+                    split.len() != 2 || split.iter().any(|&part| part <= 0. || part > rules.rest_duration)
+                });
+
+                rules.max_driving_time <= 0.
+                    || rules.rest_duration <= 0.
+                    || rules.max_daily_driving_time.is_some_and(|max| max < rules.max_driving_time)
+                    || is_invalid_split
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1316".to_string(),
+            "invalid vehicle driving rules".to_string(),
+            format!(
+                "ensure that driving rules have positive durations, split rest has two parts which are not longer \
+                 than rest duration and max daily driving time is not less than max driving time, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle profile scale is correct.
+fn check_e1317_vehicle_profile_scale(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| match &vehicle.profile.scale {
+            Some(VehicleProfileScale::Constant(scale)) => *scale <= 0.,
+            Some(VehicleProfileScale::TimeOfDay(scales)) => {
+                let intervals = scales
+                    .iter()
+                    .map(|scale| parse_time_safe(&scale.from).ok().zip(parse_time_safe(&scale.to).ok()))
+                    .collect::<Option<Vec<_>>>();
+
+                intervals.is_none_or(|mut intervals| {
+                    intervals.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+                    scales.iter().any(|scale| scale.factor <= 0.)
+                        || intervals.iter().any(|(from, to)| from >= to)
+                        || intervals.windows(2).any(|pair| pair[0].1 > pair[1].0)
+                })
+            }
+            None => false,
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1317".to_string(),
+            "invalid vehicle profile scale".to_string(),
+            format!(
+                "ensure that scale factors are positive and time intervals of time-of-day scale are valid and \
+                 do not overlap, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1318_vehicle_recharges_energy(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let is_fraction = |value: Option<Float>| value.is_none_or(|value| (0. ..=1.).contains(&value));
+
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| {
+            vehicle.shifts.iter().filter_map(|shift| shift.recharges.as_ref()).any(|recharges| {
+                match (recharges.max_distance, recharges.energy.as_ref()) {
+                    (_, Some(energy)) => {
+                        energy.battery_capacity <= 0.
+                            || energy.consumption < 0.
+                            || energy
+                                .profile_consumption
+                                .as_ref()
+                                .is_some_and(|values| values.values().any(|value| *value < 0.))
+                            || energy.load_consumption.is_some_and(|value| value < 0.)
+                            || !is_fraction(energy.initial_state_of_charge)
+                            || !is_fraction(energy.min_state_of_charge)
+                            || energy.min_state_of_charge.unwrap_or_default()
+                                > energy.initial_state_of_charge.unwrap_or(1.)
+                            || recharges.stations.iter().any(|station| station.power.is_none_or(|power| power <= 0.))
+                    }
+                    (Some(max_distance), None) => max_distance <= 0.,
+                    (None, None) => true,
+                }
+            })
+        })
+        .map(|vehicle| vehicle.type_id.clone())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1318".to_string(),
+            "invalid vehicle recharges".to_string(),
+            format!(
+                "ensure that either positive max distance or valid energy model is specified and all recharge \
+                 stations have positive power when energy model is used, vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+fn check_e1319_vehicle_recharge_resources(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let charging_resources = ctx
+        .problem
+        .fleet
+        .resources
+        .iter()
+        .flat_map(|resources| resources.iter())
+        .filter_map(|resource| match resource {
+            VehicleResource::Charging { id, connectors } => Some((id.to_string(), *connectors)),
+            VehicleResource::Reload { .. } | VehicleResource::Docking { .. } => None,
+        })
+        .collect::<HashMap<_, _>>();
+
+    let type_ids = get_invalid_type_ids(
+        ctx,
+        Box::new(move |_, shift, _| {
+            shift
+                .recharges
+                .iter()
+                .flat_map(|recharges| recharges.stations.iter())
+                .filter_map(|station| station.resource_id.as_ref())
+                .all(|resource_id| charging_resources.get(resource_id).is_some_and(|&connectors| connectors > 0))
+        }),
+    );
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1319".to_string(),
+            "invalid vehicle recharge resource".to_string(),
+            format!(
+                "make sure that fleet has all charging resources used by recharge stations defined with positive \
+                 amount of connectors, check vehicle type ids: '{}'",
+                type_ids.join(", ")
+            ),
+        ))
+    }
+}
+
+/// Checks that vehicle max onboard waiting is not negative.
+fn check_e1320_vehicle_max_onboard_waiting(ctx: &ValidationContext) -> Result<(), FormatError> {
+    let type_ids = ctx
+        .vehicles()
+        .filter(|vehicle| vehicle.limits.as_ref().and_then(|l| l.max_onboard_waiting).is_some_and(|value| value < 0.))
+        .map(|vehicle| vehicle.type_id.to_string())
+        .collect::<Vec<_>>();
+
+    if type_ids.is_empty() {
+        Ok(())
+    } else {
+        Err(FormatError::new(
+            "E1320".to_string(),
+            "invalid vehicle max onboard waiting".to_string(),
+            format!("make sure that max onboard waiting is not negative, vehicle type ids: '{}'", type_ids.join(", ")),
+        ))
+    }
+}
+
 /// Validates vehicles from the fleet.
 pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError> {
     combine_error_results(&[
@@ -403,6 +668,14 @@ pub fn validate_vehicles(ctx: &ValidationContext) -> Result<(), MultiFormatError
         check_e1310_capacity_configurations_dimensions(ctx),
         check_e1311_capacity_dimensions_count(ctx),
         check_e1312_vehicle_has_capacity(ctx),
+        check_e1313_no_drivers_with_duplicate_ids(ctx),
+        check_e1314_driver_shift_time(ctx),
+        check_e1315_driver_depot_is_vehicle_start(ctx),
+        check_e1316_vehicle_driving_rules(ctx),
+        check_e1317_vehicle_profile_scale(ctx),
+        check_e1318_vehicle_recharges_energy(ctx),
+        check_e1319_vehicle_recharge_resources(ctx),
+        check_e1320_vehicle_max_onboard_waiting(ctx),
     ])
     .map_err(From::from)
 }
@@ -263,6 +263,7 @@ fn can_handle_required_break_with_infeasible_sequence_relation() {
                 times: Some(vec![vec![times.0, times.1]]),
                 tag: None,
                 requested_time: None,
+                parking: None,
             }],
             demand: None,
             named_demand: None,
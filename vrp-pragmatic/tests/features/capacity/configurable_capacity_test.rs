@@ -27,6 +27,7 @@ fn can_serve_jobs_with_configurable_capacity() {
                             times: None,
                             tag: Some("p1".to_string()),
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![2, 0]),
                         named_demand: None,
@@ -39,6 +40,7 @@ fn can_serve_jobs_with_configurable_capacity() {
                             times: None,
                             tag: Some("d1".to_string()),
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![2, 0]),
                         named_demand: None,
@@ -55,6 +57,7 @@ fn can_serve_jobs_with_configurable_capacity() {
                             times: None,
                             tag: Some("p1".to_string()),
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![0, 1]),
                         named_demand: None,
@@ -67,6 +70,7 @@ fn can_serve_jobs_with_configurable_capacity() {
                             times: None,
                             tag: Some("d1".to_string()),
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![0, 1]),
                         named_demand: None,
@@ -92,7 +96,9 @@ fn can_serve_jobs_with_configurable_capacity() {
                 skills: None,
                 limits: None,
                 lifo_tags: None,
+                driving_rules: None,
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
             resources: None,
             capacity_dimensions: Some(vec!["seated".to_string(), "wheelchair".to_string()]),
@@ -130,6 +136,7 @@ fn can_reject_jobs_exceeding_all_configurations() {
                             times: None,
                             tag: Some("p1".to_string()),
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![3, 1]),
                         named_demand: None,
@@ -142,6 +149,7 @@ fn can_reject_jobs_exceeding_all_configurations() {
                             times: None,
                             tag: Some("d1".to_string()),
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![3, 1]),
                         named_demand: None,
@@ -167,7 +175,9 @@ fn can_reject_jobs_exceeding_all_configurations() {
                 skills: None,
                 limits: None,
                 lifo_tags: None,
+                driving_rules: None,
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
             resources: None,
             capacity_dimensions: Some(vec!["seated".to_string(), "wheelchair".to_string()]),
@@ -204,6 +214,7 @@ fn can_use_named_demand_with_capacity_dimensions() {
                         times: None,
                         tag: Some("p1".to_string()),
                         requested_time: None,
+                        parking: None,
                     }],
                     demand: None,
                     named_demand: Some(named_demand_pickup),
@@ -216,6 +227,7 @@ fn can_use_named_demand_with_capacity_dimensions() {
                         times: None,
                         tag: Some("d1".to_string()),
                         requested_time: None,
+                        parking: None,
                     }],
                     demand: None,
                     named_demand: Some(named_demand_delivery),
@@ -240,7 +252,9 @@ fn can_use_named_demand_with_capacity_dimensions() {
                 skills: None,
                 limits: None,
                 lifo_tags: None,
+                driving_rules: None,
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
             resources: None,
             capacity_dimensions: Some(vec!["seated".to_string(), "wheelchair".to_string()]),
@@ -285,6 +299,7 @@ fn can_handle_multiple_accessibility_features() {
                             times: None,
                             tag: None,
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![2, 0, 0]),
                         named_demand: None,
@@ -301,6 +316,7 @@ fn can_handle_multiple_accessibility_features() {
                             times: None,
                             tag: None,
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![0, 1, 0]),
                         named_demand: None,
@@ -317,6 +333,7 @@ fn can_handle_multiple_accessibility_features() {
                             times: None,
                             tag: None,
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![0, 0, 1]),
                         named_demand: None,
@@ -344,7 +361,9 @@ fn can_handle_multiple_accessibility_features() {
                 skills: None,
                 limits: None,
                 lifo_tags: None,
+                driving_rules: None,
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
             resources: None,
             capacity_dimensions: Some(vec!["seated".to_string(), "wheelchair".to_string(), "stroller".to_string()]),
@@ -45,6 +45,7 @@ impl From<ActivityData> for Activity {
                 forward: convert_expected_commute_info(fwd),
                 backward: convert_expected_commute_info(bak),
             }),
+            lateness: None,
         }
     }
 }
@@ -86,6 +87,7 @@ impl From<StopData> for Stop {
                 None
             },
             load: vec![stop.load],
+            state_of_charge: None,
             activities: stop.activities.into_iter().map(ActivityData::into).collect(),
         })
     }
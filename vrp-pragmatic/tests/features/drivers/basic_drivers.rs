@@ -0,0 +1,176 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_driver(id: &str, skills: Option<Vec<&str>>) -> Driver {
+    Driver {
+        id: id.to_string(),
+        costs: None,
+        shifts: vec![DriverShift { earliest: format_time(0.), latest: format_time(1000.), depot: None }],
+        skills: skills.map(to_strings),
+    }
+}
+
+#[test]
+fn can_pair_driver_and_vehicle_with_combined_skills() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_skills(
+                "job1",
+                (1., 0.),
+                all_of_skills(to_strings(vec!["wheelchair", "ramp"])),
+            )],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("vehicle_without_ramp"),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    skills: Some(to_strings(vec!["ramp"])),
+                    ..create_default_vehicle("vehicle_with_ramp")
+                },
+            ],
+            drivers: Some(vec![
+                create_driver("driver", None),
+                create_driver("certified_driver", Some(vec!["wheelchair"])),
+            ]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 100);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].vehicle_id, "vehicle_with_ramp_1");
+    assert_eq!(solution.tours[0].driver_id.as_deref(), Some("certified_driver"));
+}
+
+#[test]
+fn can_use_vehicles_not_more_than_available_drivers() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: to_strings(vec!["v1", "v2"]),
+                ..create_vehicle_with_capacity("my_vehicle", vec![1])
+            }],
+            drivers: Some(vec![create_driver("driver", None)]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 100);
+
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(solution.tours[0].driver_id.as_deref(), Some("driver"));
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
+
+#[test]
+fn can_use_vehicle_from_driver_depot() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![
+                create_default_vehicle("near_vehicle"),
+                VehicleType {
+                    shifts: vec![create_default_vehicle_shift_with_locations((10., 0.), (10., 0.))],
+                    ..create_default_vehicle("far_vehicle")
+                },
+            ],
+            drivers: Some(vec![Driver {
+                shifts: vec![DriverShift {
+                    earliest: format_time(0.),
+                    latest: format_time(1000.),
+                    depot: Some((10., 0.).to_loc()),
+                }],
+                ..create_driver("driver", None)
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 100);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"], vec!["arrival"]]);
+    assert_eq!(solution.tours[0].vehicle_id, "far_vehicle_1");
+}
+
+#[test]
+fn can_respect_driver_availability() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(0, 60)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            drivers: Some(vec![Driver {
+                shifts: vec![DriverShift { earliest: format_time(50.), latest: format_time(200.), depot: None }],
+                ..create_driver("driver", None)
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 100);
+
+    assert!(solution.unassigned.is_none());
+    let departure = solution.tours[0].stops.first().unwrap().schedule().departure.clone();
+    let arrival = solution.tours[0].stops.last().unwrap().schedule().arrival.clone();
+    assert!(departure >= format_time(50.));
+    assert!(arrival <= format_time(200.));
+}
+
+#[test]
+fn can_include_driver_costs_in_statistic() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job_with_times("job1", (5., 0.), vec![(20, 100)], 1.)],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    start: ShiftStart {
+                        earliest: format_time(0.),
+                        latest: Some(format_time(0.)),
+                        location: (0., 0.).to_loc(),
+                    },
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            drivers: Some(vec![Driver {
+                costs: Some(VehicleCosts { fixed: Some(50.), distance: 0., time: 2. }),
+                ..create_driver("driver", None)
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 100);
+
+    assert!(solution.unassigned.is_none());
+    let statistic = &solution.tours[0].statistic;
+    assert_eq!(statistic.times.waiting, 15);
+    assert_eq!(statistic.duration, 26);
+    // NOTE vehicle: fixed, distance and duration, driver: fixed and duration with own time cost
+    assert_eq!(statistic.cost, 10. + 10. + 26. + 50. + 26. * 2.);
+    assert_eq!(solution.statistic.cost, statistic.cost);
+}
@@ -0,0 +1,120 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_problem_with_driving_rules(jobs: Vec<Job>, driving_rules: VehicleDrivingRules) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                driving_rules: Some(driving_rules),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_rest_count(solution: &Solution) -> usize {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter(|activity| activity.activity_type == "rest")
+        .count()
+}
+
+#[test]
+fn can_insert_rests_after_max_driving_time() {
+    let problem = create_problem_with_driving_rules(
+        (1..=10).map(|idx| create_delivery_job(&format!("job{idx}"), (idx as f64 * 10., 0.))).collect(),
+        VehicleDrivingRules {
+            max_driving_time: 35.,
+            rest_duration: 10.,
+            split_rest: None,
+            max_daily_driving_time: None,
+        },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    assert_eq!(get_rest_count(&solution), 3);
+}
+
+#[test]
+fn can_skip_rests_when_driving_time_is_short() {
+    let problem = create_problem_with_driving_rules(
+        vec![create_delivery_job("job1", (10., 0.)), create_delivery_job("job2", (20., 0.))],
+        VehicleDrivingRules {
+            max_driving_time: 35.,
+            rest_duration: 10.,
+            split_rest: None,
+            max_daily_driving_time: None,
+        },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_rest_count(&solution), 0);
+}
+
+#[test]
+fn can_skip_job_due_to_daily_driving_limit() {
+    let problem = create_problem_with_driving_rules(
+        vec![create_delivery_job("job1", (30., 0.)), create_delivery_job("job2", (70., 0.))],
+        VehicleDrivingRules {
+            max_driving_time: 35.,
+            rest_duration: 10.,
+            split_rest: None,
+            max_daily_driving_time: Some(50.),
+        },
+    );
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(get_ids_from_tour(&solution.tours[0]), vec![vec!["departure"], vec!["job1"]]);
+    assert_eq!(
+        solution.unassigned,
+        Some(vec![UnassignedJob {
+            job_id: "job2".to_string(),
+            reasons: vec![UnassignedJobReason {
+                code: "DRIVING_RULES_CONSTRAINT".to_string(),
+                description: "cannot be assigned due to driving time rules of vehicle".to_string(),
+                details: Some(vec![UnassignedJobDetail { vehicle_id: "my_vehicle_1".to_string(), shift_index: 0 }]),
+            }],
+        }])
+    );
+}
+
+#[test]
+fn can_limit_daily_driving_time_across_vehicle_shifts() {
+    let mut problem = create_problem_with_driving_rules(
+        vec![create_delivery_job("job1", (30., 0.)), create_delivery_job("job2", (-30., 0.))],
+        VehicleDrivingRules {
+            max_driving_time: 35.,
+            rest_duration: 10.,
+            split_rest: None,
+            max_daily_driving_time: Some(50.),
+        },
+    );
+    problem.fleet.vehicles[0].shifts.push(VehicleShift {
+        start: ShiftStart { earliest: format_time(100.), latest: None, location: (0., 0.).to_loc() },
+        ..create_default_open_vehicle_shift()
+    });
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.tours.iter().map(|tour| tour.stops.len() - 1).sum::<usize>(), 1);
+    assert_eq!(solution.unassigned.map(|unassigned| unassigned.len()), Some(1));
+}
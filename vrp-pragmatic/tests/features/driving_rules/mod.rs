@@ -0,0 +1 @@
+mod basic_driving_rules;
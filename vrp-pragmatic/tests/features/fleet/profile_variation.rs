@@ -1,11 +1,12 @@
 use crate::format::problem::*;
+use crate::format_time;
 use crate::helpers::*;
 use vrp_core::prelude::Float;
 
 fn create_vehicle_type(type_id: &str, scale: Option<Float>) -> VehicleType {
     VehicleType {
         type_id: type_id.to_string(),
-        profile: VehicleProfile { matrix: "car".to_string(), scale },
+        profile: VehicleProfile { matrix: "car".to_string(), scale: scale.map(VehicleProfileScale::Constant) },
         vehicle_ids: vec![format!("{type_id}_1")],
         ..create_default_vehicle_type()
     }
@@ -31,3 +32,35 @@ fn can_use_scale() {
     assert_eq!(tour.statistic.distance, 20);
     assert_eq!(tour.statistic.duration, 11)
 }
+
+#[test]
+fn can_use_time_of_day_scale() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (10., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile {
+                    matrix: "car".to_string(),
+                    scale: Some(VehicleProfileScale::TimeOfDay(vec![VehicleProfileTimeScale {
+                        from: format_time(0.),
+                        to: format_time(10.),
+                        factor: 2.,
+                    }])),
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let tour = solution.tours.first().unwrap();
+    assert_eq!(tour.statistic.distance, 20);
+    assert_eq!(tour.statistic.duration, 26);
+    assert_eq!(tour.stops[1].schedule().arrival, format_time(15.));
+}
@@ -25,6 +25,7 @@ fn can_combine_required_and_via_stops() {
                             times: None,
                             tag: Some("req1".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                         JobPlace {
                             location: (8., 0.).to_loc(),
@@ -32,6 +33,7 @@ fn can_combine_required_and_via_stops() {
                             times: None,
                             tag: Some("req2".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                     ]),
                     via: Some(vec![
@@ -41,6 +43,7 @@ fn can_combine_required_and_via_stops() {
                             times: None,
                             tag: Some("via1".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                         JobPlace {
                             location: (10., 0.).to_loc(),
@@ -48,6 +51,7 @@ fn can_combine_required_and_via_stops() {
                             times: None,
                             tag: Some("via2".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                     ]),
                 }],
@@ -131,6 +135,7 @@ fn required_stops_take_precedence_over_via_stops() {
                             times: None,
                             tag: Some("req_first".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                         JobPlace {
                             location: (7., 0.).to_loc(),
@@ -138,6 +143,7 @@ fn required_stops_take_precedence_over_via_stops() {
                             times: None,
                             tag: Some("req_second".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                     ]),
                     via: Some(vec![JobPlace {
@@ -146,6 +152,7 @@ fn required_stops_take_precedence_over_via_stops() {
                         times: None,
                         tag: Some("via_between".to_string()),
                         requested_time: None,
+                        parking: None,
                     }]),
                 }],
                 ..create_default_vehicle_type()
@@ -214,6 +221,7 @@ fn complex_mixed_route_with_jobs_required_and_via() {
                             times: None,
                             tag: Some("checkpoint1".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                         JobPlace {
                             location: (20., 0.).to_loc(),
@@ -221,6 +229,7 @@ fn complex_mixed_route_with_jobs_required_and_via() {
                             times: None,
                             tag: Some("checkpoint2".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                     ]),
                     via: Some(vec![
@@ -230,6 +239,7 @@ fn complex_mixed_route_with_jobs_required_and_via() {
                             times: None,
                             tag: Some("optional_waypoint1".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                         JobPlace {
                             location: (13., 0.).to_loc(),
@@ -237,6 +247,7 @@ fn complex_mixed_route_with_jobs_required_and_via() {
                             times: None,
                             tag: Some("optional_waypoint2".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                         JobPlace {
                             location: (22., 0.).to_loc(),
@@ -244,6 +255,7 @@ fn complex_mixed_route_with_jobs_required_and_via() {
                             times: None,
                             tag: Some("optional_waypoint3".to_string()),
                             requested_time: None,
+                            parking: None,
                         },
                     ]),
                 }],
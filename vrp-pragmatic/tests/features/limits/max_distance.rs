@@ -9,7 +9,12 @@ fn can_limit_by_max_distance() {
         plan: Plan { jobs: vec![create_delivery_job("job1", (100., 0.))], ..create_empty_plan() },
         fleet: Fleet {
             vehicles: vec![VehicleType {
-                limits: Some(VehicleLimits { max_distance: Some(99.), max_duration: None, tour_size: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(99.),
+                    max_duration: None,
+                    tour_size: None,
+                    max_onboard_waiting: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -52,7 +57,12 @@ fn can_handle_empty_route() {
                     end: Some(ShiftEnd { earliest: None, latest: format_time(100.), location: (10., 0.).to_loc() }),
                     ..create_default_open_vehicle_shift()
                 }],
-                limits: Some(VehicleLimits { max_distance: Some(9.), max_duration: None, tour_size: None }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(9.),
+                    max_duration: None,
+                    tour_size: None,
+                    max_onboard_waiting: None,
+                }),
                 ..create_default_vehicle_type()
             }],
             ..create_default_fleet()
@@ -0,0 +1,61 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use vrp_core::prelude::Float;
+
+fn create_problem_with_max_onboard_waiting(max_onboard_waiting: Float, delivery_tw_start: i32) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_pickup_delivery_job_with_params(
+                "job1",
+                vec![1],
+                ((10., 0.), 1., vec![(0, 10)]),
+                ((20., 0.), 1., vec![(delivery_tw_start, 200)]),
+            )],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_onboard_waiting: Some(max_onboard_waiting),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+parameterized_test! {can_limit_waiting_with_passenger_onboard, (max_onboard_waiting, delivery_tw_start, expected), {
+    can_limit_waiting_with_passenger_onboard_impl(max_onboard_waiting, delivery_tw_start, expected);
+}}
+
+can_limit_waiting_with_passenger_onboard! {
+    case01_no_waiting: (10., 20, None),
+    case02_waiting_within_limit: (100., 100, None),
+    case03_waiting_above_limit: (10., 100, Some("MAX_ONBOARD_WAITING_CONSTRAINT")),
+}
+
+fn can_limit_waiting_with_passenger_onboard_impl(
+    max_onboard_waiting: Float,
+    delivery_tw_start: i32,
+    expected: Option<&str>,
+) {
+    let problem = create_problem_with_max_onboard_waiting(max_onboard_waiting, delivery_tw_start);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(
+        solution
+            .unassigned
+            .iter()
+            .flatten()
+            .flat_map(|job| job.reasons.iter().map(|reason| reason.code.as_str()))
+            .next(),
+        expected
+    );
+}
@@ -1,3 +1,4 @@
 mod max_distance;
 mod max_duration;
+mod max_onboard_waiting;
 mod tour_size;
@@ -5,6 +5,8 @@ mod breaks;
 mod capacity;
 mod clustering;
 mod compatibility;
+mod drivers;
+mod driving_rules;
 mod fleet;
 mod flexible_routes;
 mod format;
@@ -17,6 +19,7 @@ mod priorities;
 mod recharge;
 mod relations;
 mod reload;
+mod replanning;
 mod skills;
 mod solo_riding;
 mod timing;
@@ -1,3 +1,5 @@
 mod basic_pick_dev;
 mod mixed_pick_dev_simple_jobs;
 mod relation_pick_dev;
+mod relative_ride_duration;
+mod soft_ride_duration;
@@ -0,0 +1,67 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+fn create_relative_max_ride_duration(offset: Option<f64>, factor: f64) -> Option<RelativeMaxRideDuration> {
+    Some(RelativeMaxRideDuration { offset, factor, min: None })
+}
+
+#[test]
+fn can_avoid_detour_exceeding_relative_max_ride_duration() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job {
+                    relative_max_ride_duration: create_relative_max_ride_duration(Some(0.5), 1.),
+                    ..create_pickup_delivery_job("job1", (10., 0.), (20., 0.))
+                },
+                create_pickup_delivery_job("job2", (12., 0.), (14., 0.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let ids = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    let job1_activities =
+        ids.iter().enumerate().filter(|(_, id)| *id == "job1").map(|(idx, _)| idx).collect::<Vec<_>>();
+    assert_eq!(job1_activities.len(), 2);
+    assert_eq!(job1_activities[0] + 1, job1_activities[1]);
+}
+
+parameterized_test! {can_limit_waiting_with_relative_max_ride_duration, (factor, is_assigned), {
+    can_limit_waiting_with_relative_max_ride_duration_impl(factor, is_assigned);
+}}
+
+can_limit_waiting_with_relative_max_ride_duration! {
+    case01_assigned: (10., true),
+    case02_unassigned: (1.5, false),
+}
+
+fn can_limit_waiting_with_relative_max_ride_duration_impl(factor: f64, is_assigned: bool) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                relative_max_ride_duration: create_relative_max_ride_duration(None, factor),
+                ..create_pickup_delivery_job_with_params(
+                    "job1",
+                    vec![1],
+                    ((10., 0.), 1., vec![(0, 10)]),
+                    ((20., 0.), 1., vec![(100, 200)]),
+                )
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.is_none(), is_assigned);
+}
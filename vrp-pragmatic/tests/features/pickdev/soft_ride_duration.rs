@@ -0,0 +1,37 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+
+parameterized_test! {can_minimize_excess_ride_time, (soft_max_ride_duration, expected), {
+    can_minimize_excess_ride_time_impl(soft_max_ride_duration, expected);
+}}
+
+can_minimize_excess_ride_time! {
+    case01_no_detour: (Some(10.), vec!["departure", "job1", "job1", "job2"]),
+    case02_detour: (None, vec!["departure", "job1", "job2", "job1"]),
+}
+
+fn can_minimize_excess_ride_time_impl(soft_max_ride_duration: Option<f64>, expected: Vec<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { soft_max_ride_duration, ..create_pickup_delivery_job("job1", (10., 0.), (20., 0.)) },
+                create_delivery_job("job2", (15., 5.)),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![create_default_open_vehicle_shift()],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>(), expected);
+}
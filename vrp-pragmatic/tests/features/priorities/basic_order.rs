@@ -15,7 +15,7 @@ fn create_test_plan_with_three_jobs() -> Plan {
 }
 
 fn create_test_limit() -> Option<VehicleLimits> {
-    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None })
+    Some(VehicleLimits { max_distance: Some(15.), max_duration: None, tour_size: None, max_onboard_waiting: None })
 }
 
 fn create_order_objective(is_constrained: bool) -> Vec<Objective> {
@@ -149,6 +149,7 @@ fn can_handle_order_between_special_activities() {
                 duration: 100.,
                 tag: None,
                 requested_time: None,
+                parking: None,
             }],
             demand: Some(vec![1]),
             named_demand: None,
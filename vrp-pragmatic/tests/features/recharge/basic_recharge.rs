@@ -13,13 +13,15 @@ fn can_use_recharge_trivial_case() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     recharges: Some(VehicleRecharges {
-                        max_distance: 55.,
-                        stations: vec![JobPlace {
+                        max_distance: Some(55.),
+                        energy: None,
+                        stations: vec![VehicleRechargeStation {
                             location: (50., 0.).to_loc(),
                             duration: 0.0,
                             times: None,
                             tag: None,
-                            requested_time: None,
+                            power: None,
+                            resource_id: None,
                         }],
                     }),
                     ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
@@ -61,13 +63,15 @@ fn can_still_skip_jobs_with_recharge() {
                         location: (52.5189, 13.4011).to_loc(),
                     }),
                     recharges: Some(VehicleRecharges {
-                        max_distance: 10000.,
-                        stations: vec![JobPlace {
+                        max_distance: Some(10000.),
+                        energy: None,
+                        stations: vec![VehicleRechargeStation {
                             location: (52.5459, 13.5058).to_loc(),
                             duration: 900.,
                             times: None,
                             tag: None,
-                            requested_time: None,
+                            power: None,
+                            resource_id: None,
                         }],
                     }),
                     ..create_default_vehicle_shift_with_locations((52.5189, 13.4011), (52.5189, 13.4011))
@@ -96,19 +100,22 @@ fn can_use_recharge_with_ten_jobs() {
             vehicles: vec![VehicleType {
                 shifts: vec![VehicleShift {
                     recharges: Some(VehicleRecharges {
-                        max_distance: 55.,
+                        max_distance: Some(55.),
+                        energy: None,
                         stations: vec![VehicleRechargeStation {
                             location: (50., 0.).to_loc(),
                             duration: 0.0,
                             times: None,
                             tag: None,
-                            requested_time: None,
+                            power: None,
+                            resource_id: None,
                         }],
                     }),
                     ..create_default_open_vehicle_shift()
                 }],
                 ..create_default_vehicle_type()
             }],
+            drivers: None,
             profiles: create_default_matrix_profiles(),
             resources: None,
             capacity_dimensions: None,
@@ -0,0 +1,106 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::format_time;
+use crate::helpers::*;
+use std::collections::HashMap;
+use vrp_core::prelude::Float;
+
+fn create_problem_with_energy(
+    consumption: Float,
+    profile_consumption: Option<HashMap<String, Float>>,
+    load_consumption: Option<Float>,
+) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (30., 0.)), create_delivery_job("job2", (70., 0.))],
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: None,
+                        energy: Some(VehicleEnergy {
+                            battery_capacity: 60.,
+                            consumption,
+                            profile_consumption,
+                            load_consumption,
+                            initial_state_of_charge: None,
+                            min_state_of_charge: None,
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (50., 0.).to_loc(),
+                            duration: 0.,
+                            times: None,
+                            tag: None,
+                            // NOTE 1 kWh per second
+                            power: Some(3600.),
+                            resource_id: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift_with_locations((0., 0.), (100., 0.))
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_states_of_charge(tour: &Tour) -> Vec<Float> {
+    tour.stops.iter().filter_map(|stop| stop.as_point()).filter_map(|stop| stop.state_of_charge).collect()
+}
+
+#[test]
+fn can_charge_only_needed_energy() {
+    // NOTE 1 kWh per distance unit
+    let problem = create_problem_with_energy(1000., None, None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    assert_eq!(
+        get_ids_from_tour(tour),
+        vec![vec!["departure"], vec!["job1"], vec!["recharge"], vec!["job2"], vec!["arrival"]]
+    );
+    assert_eq!(get_states_of_charge(tour), vec![60., 30., 50., 30., 0.]);
+    assert_eq!(tour.stops[2].schedule().arrival, format_time(51.));
+    assert_eq!(tour.stops[2].schedule().departure, format_time(91.));
+}
+
+#[test]
+fn can_use_load_dependent_consumption() {
+    // NOTE 0.5 kWh per distance unit when empty and 0.25 kWh more per each load unit
+    let problem = create_problem_with_energy(500., None, Some(250.));
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    assert_eq!(
+        get_ids_from_tour(tour),
+        vec![vec!["departure"], vec!["job1"], vec!["recharge"], vec!["job2"], vec!["arrival"]]
+    );
+    assert_eq!(get_states_of_charge(tour), vec![60., 30., 30., 15., 0.]);
+    assert_eq!(tour.stops[2].schedule().arrival, format_time(51.));
+    assert_eq!(tour.stops[2].schedule().departure, format_time(66.));
+}
+
+#[test]
+fn can_use_profile_dependent_consumption() {
+    // NOTE 1 kWh per distance unit for vehicle's profile, 2 kWh for any other
+    let profile_consumption = HashMap::from([("car".to_string(), 1000.), ("truck".to_string(), 3000.)]);
+    let problem = create_problem_with_energy(2000., Some(profile_consumption), None);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    assert_eq!(get_states_of_charge(tour), vec![60., 30., 50., 30., 0.]);
+    assert_eq!(tour.stops[2].schedule().departure, format_time(91.));
+}
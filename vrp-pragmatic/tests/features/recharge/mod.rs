@@ -1 +1,3 @@
 mod basic_recharge;
+mod energy_recharge;
+mod shared_recharge;
@@ -0,0 +1,96 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_shared_recharge_problem(vehicle_ids: &[&str], connectors: usize) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vehicle_ids
+                .iter()
+                .enumerate()
+                .map(|(idx, _)| create_delivery_job(&format!("job{}", idx + 1), (30., 0.)))
+                .collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vehicle_ids.iter().map(|id| id.to_string()).collect(),
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: Some(60.),
+                        energy: None,
+                        stations: vec![VehicleRechargeStation {
+                            location: (40., 0.).to_loc(),
+                            duration: 10.,
+                            times: None,
+                            tag: None,
+                            power: None,
+                            resource_id: Some("charger".to_string()),
+                        }],
+                    }),
+                    ..create_default_vehicle_shift_with_locations((0., 0.), (80., 0.))
+                }],
+                ..create_vehicle_with_capacity("my_vehicle", vec![1])
+            }],
+            resources: Some(vec![VehicleResource::Charging { id: "charger".to_string(), connectors }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_recharge_schedules(solution: &Solution) -> Vec<(f64, f64)> {
+    let mut schedules = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .filter(|stop| stop.activities().iter().any(|activity| activity.activity_type == "recharge"))
+        .map(|stop| (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)))
+        .collect::<Vec<_>>();
+    schedules.sort_by(|(a_arr, a_dep), (b_arr, b_dep)| a_arr.total_cmp(b_arr).then(a_dep.total_cmp(b_dep)));
+
+    schedules
+}
+
+#[test]
+fn can_wait_for_busy_charging_connector() {
+    let problem = create_shared_recharge_problem(&["v1", "v2"], 1);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    let mut departures =
+        get_recharge_schedules(&solution).into_iter().map(|(_, departure)| departure).collect::<Vec<_>>();
+    departures.sort_by(|a, b| a.total_cmp(b));
+    assert_eq!(departures, vec![51., 61.]);
+}
+
+#[test]
+fn can_charge_in_parallel_when_connectors_are_available() {
+    let problem = create_shared_recharge_problem(&["v1", "v2"], 2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_recharge_schedules(&solution), vec![(41., 51.), (41., 51.)]);
+}
+
+#[test]
+fn can_solve_shared_recharge_with_metaheuristic() {
+    let problem = create_shared_recharge_problem(&["v1", "v2", "v3"], 1);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let schedules = get_recharge_schedules(&solution);
+    assert_eq!(schedules.len(), 3);
+    schedules.windows(2).for_each(|pair| {
+        let ((_, departure), (_, next_departure)) = (pair[0], pair[1]);
+        assert!(next_departure - departure >= 10.);
+    });
+}
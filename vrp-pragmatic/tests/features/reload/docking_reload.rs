@@ -0,0 +1,85 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+use crate::parse_time;
+
+fn create_docking_problem(capacity: usize) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: (0..4).map(|idx| create_delivery_job(&format!("job{}", idx + 1), (10., 0.))).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: vec!["v1".to_string(), "v2".to_string()],
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        duration: 10.,
+                        resource_id: Some("dock".to_string()),
+                        ..create_default_reload()
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_vehicle_with_capacity("my_vehicle", vec![1])
+            }],
+            resources: Some(vec![VehicleResource::Docking { id: "dock".to_string(), capacity }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    }
+}
+
+fn get_reload_times(solution: &Solution) -> Vec<(f64, f64)> {
+    let mut times = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == "reload")
+        .map(|(stop, activity)| match activity.time.as_ref() {
+            Some(time) => (parse_time(&time.start), parse_time(&time.end)),
+            None => (parse_time(&stop.schedule().arrival), parse_time(&stop.schedule().departure)),
+        })
+        .collect::<Vec<_>>();
+    times.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    times
+}
+
+#[test]
+fn can_wait_for_busy_docking_door() {
+    let problem = create_docking_problem(1);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 2);
+    assert_eq!(get_reload_times(&solution), vec![(21., 31.), (31., 41.)]);
+    assert_eq!(solution.statistic.times.queuing, 10);
+    assert_eq!(solution.tours.iter().map(|tour| tour.statistic.times.queuing).sum::<i64>(), 10);
+}
+
+#[test]
+fn can_dock_in_parallel_when_doors_are_available() {
+    let problem = create_docking_problem(2);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_cheapest_insertion(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(get_reload_times(&solution), vec![(21., 31.), (21., 31.)]);
+    assert_eq!(solution.statistic.times.queuing, 0);
+}
+
+#[test]
+fn can_solve_docking_with_metaheuristic() {
+    let problem = create_docking_problem(1);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let times = get_reload_times(&solution);
+    times.windows(2).for_each(|pair| assert!(pair[0].1 <= pair[1].0));
+}
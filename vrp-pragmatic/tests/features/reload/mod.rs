@@ -1,6 +1,7 @@
 mod avoid_reload;
 mod basic_reload;
 mod diff_reload_places;
+mod docking_reload;
 mod multi_dim_reload;
 mod multi_job_reload;
 mod multi_vehicle_reload;
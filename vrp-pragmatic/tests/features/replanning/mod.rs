@@ -0,0 +1,2 @@
+mod replanning_basic;
+mod replanning_onboard;
@@ -0,0 +1,124 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+fn create_vehicle_state(vehicle_id: &str, location: Option<(f64, f64)>, executed: Vec<&str>) -> VehicleReplanning {
+    VehicleReplanning {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index: None,
+        location: location.map(|location| location.to_loc()),
+        executed: if executed.is_empty() { None } else { Some(to_strings(executed)) },
+        onboard: None,
+    }
+}
+
+#[test]
+fn can_keep_executed_activities_at_tour_start() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job("job1", (1., 0.)),
+                create_delivery_job("job2", (2., 0.)),
+                create_delivery_job("job3", (3., 0.)),
+                create_delivery_job("job4", (4., 0.)),
+            ],
+            replanning: Some(Replanning {
+                now: format_time(5.),
+                vehicles: vec![create_vehicle_state("my_vehicle_1", None, vec!["job3", "job1"])],
+            }),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+    let ids = get_ids_from_tour(&solution.tours[0]).into_iter().flatten().collect::<Vec<_>>();
+    assert_eq!(&ids[..3], &["departure".to_string(), "job3".to_string(), "job1".to_string()]);
+}
+
+#[test]
+fn can_start_not_departed_vehicle_from_current_position_after_now() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (6., 0.))],
+            replanning: Some(Replanning {
+                now: format_time(10.),
+                vehicles: vec![create_vehicle_state("my_vehicle_1", Some((5., 0.)), vec![])],
+            }),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let departure = solution.tours[0].stops.first().expect("no departure stop");
+    assert_eq!(departure.location(), Some(&(5., 0.).to_loc()));
+    assert_eq!(departure.schedule().departure, format_time(10.));
+}
+
+#[test]
+fn can_serve_remaining_activities_of_departed_vehicle_after_now() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_delivery_job("job2", (2., 0.))],
+            replanning: Some(Replanning {
+                now: format_time(100.),
+                vehicles: vec![create_vehicle_state("my_vehicle_1", None, vec!["job1"])],
+            }),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let job2_stop = solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.job_id == "job2"))
+        .expect("no job2 stop");
+    assert!(parse_time(&job2_stop.schedule().departure) >= 101.);
+}
+
+#[test]
+fn can_skip_time_windows_ending_before_now() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (1., 0.), vec![(0, 50), (200, 300)], 1.),
+                create_delivery_job_with_times("job2", (2., 0.), vec![(0, 50)], 1.),
+            ],
+            replanning: Some(Replanning {
+                now: format_time(100.),
+                vehicles: vec![create_vehicle_state("my_vehicle_1", None, vec![])],
+            }),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let unassigned = solution.unassigned.as_ref().expect("no unassigned jobs");
+    assert_eq!(unassigned.iter().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job2"]);
+    let job1_stop = solution.tours[0]
+        .stops
+        .iter()
+        .find(|stop| stop.activities().iter().any(|activity| activity.job_id == "job1"))
+        .expect("no job1 stop");
+    assert!(parse_time(&job1_stop.schedule().departure) >= 201.);
+}
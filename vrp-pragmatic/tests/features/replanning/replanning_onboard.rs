@@ -0,0 +1,136 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_onboard_vehicle_state(onboard: Vec<(&str, f64)>) -> VehicleReplanning {
+    VehicleReplanning {
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: None,
+        location: None,
+        executed: None,
+        onboard: Some(
+            onboard
+                .into_iter()
+                .map(|(job_id, since)| OnboardJob { job_id: job_id.to_string(), since: format_time(since) })
+                .collect(),
+        ),
+    }
+}
+
+fn create_onboard_problem(jobs: Vec<Job>, vehicle: VehicleType, onboard: Vec<(&str, f64)>, now: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs,
+            replanning: Some(Replanning {
+                now: format_time(now),
+                vehicles: vec![create_onboard_vehicle_state(onboard)],
+            }),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], ..create_default_fleet() },
+        ..create_empty_problem()
+    }
+}
+
+fn solve_and_get_job_ids(problem: Problem) -> Vec<String> {
+    let matrix = create_matrix_from_problem(&problem);
+    let solution = solve_with_metaheuristic_and_iterations(problem, Some(vec![matrix]), 200);
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(solution.tours.len(), 1);
+
+    get_ids_from_tour(&solution.tours[0])
+        .into_iter()
+        .flatten()
+        .filter(|id| id != "departure" && id != "arrival")
+        .collect()
+}
+
+#[test]
+fn can_account_onboard_job_in_vehicle_capacity() {
+    let problem = create_onboard_problem(
+        vec![create_delivery_job("onboard", (5., 0.)), create_pickup_delivery_job("job1", (1., 0.), (2., 0.))],
+        create_vehicle_with_capacity("my_vehicle", vec![1]),
+        vec![("onboard", 0.)],
+        0.,
+    );
+
+    let ids = solve_and_get_job_ids(problem);
+
+    assert_eq!(ids, to_strings(vec!["onboard", "job1", "job1"]));
+}
+
+#[test]
+fn can_account_onboard_time_in_max_ride_duration() {
+    let mut onboard_job = create_delivery_job("onboard", (3., 0.));
+    onboard_job.max_ride_duration = Some(13.5);
+    let problem = create_onboard_problem(
+        vec![onboard_job, create_pickup_delivery_job("job1", (1., 0.), (2., 0.))],
+        create_vehicle_with_capacity("my_vehicle", vec![2]),
+        vec![("onboard", 0.)],
+        10.,
+    );
+
+    let ids = solve_and_get_job_ids(problem);
+
+    assert_eq!(ids[0], "onboard");
+}
+
+#[test]
+fn can_deliver_onboard_jobs_in_lifo_order() {
+    let create_lifo_job = |id: &str, location: (f64, f64)| Job {
+        lifo_tag: Some("wheelchair".to_string()),
+        ..create_delivery_job(id, location)
+    };
+    let problem = create_onboard_problem(
+        vec![create_lifo_job("job1", (1., 0.)), create_lifo_job("job2", (2., 0.))],
+        VehicleType {
+            lifo_tags: Some(vec!["wheelchair".to_string()]),
+            driving_rules: None,
+            ..create_vehicle_with_capacity("my_vehicle", vec![2])
+        },
+        vec![("job1", 0.), ("job2", 5.)],
+        10.,
+    );
+
+    let ids = solve_and_get_job_ids(problem);
+
+    assert_eq!(ids, to_strings(vec!["job2", "job1"]));
+}
+
+#[test]
+fn can_keep_onboard_solo_job_alone() {
+    let problem = create_onboard_problem(
+        vec![
+            Job { solo_riding: Some(true), ..create_delivery_job("onboard", (5., 0.)) },
+            create_pickup_delivery_job("job1", (1., 0.), (2., 0.)),
+        ],
+        create_vehicle_with_capacity("my_vehicle", vec![2]),
+        vec![("onboard", 0.)],
+        0.,
+    );
+
+    let ids = solve_and_get_job_ids(problem);
+
+    assert_eq!(ids, to_strings(vec!["onboard", "job1", "job1"]));
+}
+
+#[test]
+fn can_keep_onboard_solo_job_with_multiple_deliveries_alone() {
+    let problem = create_onboard_problem(
+        vec![
+            Job {
+                solo_riding: Some(true),
+                ..create_multi_job("onboard", vec![], vec![((5., 0.), 1., vec![1]), ((6., 0.), 1., vec![1])])
+            },
+            create_pickup_delivery_job("job1", (1., 0.), (2., 0.)),
+        ],
+        create_vehicle_with_capacity("my_vehicle", vec![2]),
+        vec![("onboard", 0.)],
+        0.,
+    );
+
+    let ids = solve_and_get_job_ids(problem);
+
+    assert_eq!(ids, to_strings(vec!["onboard", "onboard", "job1", "job1"]));
+}
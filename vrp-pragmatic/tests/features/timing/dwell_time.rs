@@ -0,0 +1,39 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+parameterized_test! {can_charge_dwell_time_setup_once_per_stop, (job2_location, expected_serving, expected_arrival), {
+    can_charge_dwell_time_setup_once_per_stop_impl(job2_location, expected_serving, expected_arrival);
+}}
+
+can_charge_dwell_time_setup_once_per_stop! {
+    case01_same_stop: ((1., 0.), 22, 24.),
+    case02_different_stops: ((2., 0.), 32, 36.),
+}
+
+fn can_charge_dwell_time_setup_once_per_stop_impl(
+    job2_location: (f64, f64),
+    expected_serving: i64,
+    expected_arrival: f64,
+) {
+    let dwell_time = || Some(JobDwellTime { setup: Some(10.), per_unit: Some(vec![5.]) });
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                Job { dwell_time: dwell_time(), ..create_delivery_job("job1", (1., 0.)) },
+                Job { dwell_time: dwell_time(), ..create_delivery_job("job2", job2_location) },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    assert_eq!(tour.stops.last().unwrap().schedule().arrival, format_time(expected_arrival));
+    assert_eq!(tour.statistic.times.serving, expected_serving);
+}
@@ -1,4 +1,8 @@
 mod basic_multiple_times;
 mod basic_waiting_time;
+mod dwell_time;
+mod parking;
+mod requested_time_penalties;
+mod soft_time_windows;
 mod strict_leads_to_unassigned;
 mod strict_split_into_two_tours;
@@ -0,0 +1,144 @@
+use crate::format::problem::*;
+use crate::helpers::*;
+use crate::{format_time, parse_time};
+
+fn create_delivery_job_with_parking(id: &str, location: (f64, f64), parking: f64) -> Job {
+    let mut job = create_delivery_job(id, location);
+    job.deliveries.as_mut().unwrap()[0].places[0].parking = Some(parking);
+
+    job
+}
+
+parameterized_test! {can_charge_parking_once_per_stop, (job2_location, expected_parking, expected_arrival), {
+    can_charge_parking_once_per_stop_impl(job2_location, expected_parking, expected_arrival);
+}}
+
+can_charge_parking_once_per_stop! {
+    case01_same_stop: ((1., 0.), 5, 9.),
+    case02_different_stops: ((2., 0.), 10, 16.),
+}
+
+fn can_charge_parking_once_per_stop_impl(job2_location: (f64, f64), expected_parking: i64, expected_arrival: f64) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_parking("job1", (1., 0.), 5.),
+                create_delivery_job_with_parking("job2", job2_location, 5.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    let tour = &solution.tours[0];
+    assert_eq!(tour.stops.last().unwrap().schedule().arrival, format_time(expected_arrival));
+    assert_eq!(tour.statistic.times.parking, expected_parking);
+    assert_eq!(tour.statistic.times.serving, 2);
+    assert!(
+        tour.stops.iter().skip(1).take(tour.stops.len() - 2).all(|stop| stop.as_point().unwrap().parking.is_some())
+    );
+}
+
+#[test]
+fn can_report_parking_before_service_start() {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![JobTask {
+                    places: vec![JobPlace {
+                        times: Some(vec![vec![format_time(3.), format_time(100.)]]),
+                        parking: Some(5.),
+                        ..create_job_place((1., 0.), None)
+                    }],
+                    ..create_task((1., 0.), None)
+                }]),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    let stop = solution.tours[0].stops[1].as_point().unwrap();
+    let parking = stop.parking.as_ref().unwrap();
+    let arrival = parse_time(&stop.time.arrival);
+    assert_eq!((parse_time(&parking.start), parse_time(&parking.end)), (arrival, arrival + 5.));
+    assert_eq!(parse_time(&stop.time.departure), arrival + 6.);
+    assert_eq!(solution.tours[0].statistic.times.waiting, 0);
+}
+
+parameterized_test! {can_keep_parking_within_time_window, (tw_end, is_assigned), {
+    can_keep_parking_within_time_window_impl(tw_end, is_assigned);
+}}
+
+can_keep_parking_within_time_window! {
+    case01_parking_within_window: (15., true),
+    case02_parking_exceeds_window: (12., false),
+}
+
+fn can_keep_parking_within_time_window_impl(tw_end: f64, is_assigned: bool) {
+    let mut job = create_delivery_job_with_parking("job1", (10., 0.), 5.);
+    job.deliveries.as_mut().unwrap()[0].places[0].times = Some(vec![vec![format_time(0.), format_time(tw_end)]]);
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.is_none(), is_assigned);
+    if is_assigned {
+        let stop = solution.tours[0].stops[1].as_point().unwrap();
+        let parking = stop.parking.as_ref().unwrap();
+        assert!(parse_time(&parking.end) <= tw_end);
+    }
+}
+
+parameterized_test! {can_charge_shared_parking_again_when_job_is_inserted_between, (job2_parking, expected_unassigned), {
+    can_charge_shared_parking_again_when_job_is_inserted_between_impl(job2_parking, expected_unassigned);
+}}
+
+can_charge_shared_parking_again_when_job_is_inserted_between! {
+    case01_shared_parking: (Some(5.), 1),
+    case02_no_parking: (None, 0),
+}
+
+fn can_charge_shared_parking_again_when_job_is_inserted_between_impl(
+    job2_parking: Option<f64>,
+    expected_unassigned: usize,
+) {
+    let set_times = |mut job: Job, start: f64, end: f64| {
+        job.deliveries.as_mut().unwrap()[0].places[0].times = Some(vec![vec![format_time(start), format_time(end)]]);
+        job
+    };
+    let mut job2 = set_times(create_delivery_job("job2", (10., 0.)), 0., 20.);
+    job2.deliveries.as_mut().unwrap()[0].places[0].parking = job2_parking;
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![
+                set_times(create_delivery_job_with_parking("job1", (10., 0.), 5.), 0., 20.),
+                job2,
+                set_times(create_delivery_job("job3", (11., 0.)), 17., 17.),
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.map_or(0, |unassigned| unassigned.len()), expected_unassigned);
+}
@@ -0,0 +1,75 @@
+use crate::format::problem::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn create_requested_job(id: &str, location: (f64, f64), tag: &str) -> Job {
+    Job {
+        deliveries: Some(vec![JobTask {
+            places: vec![JobPlace {
+                requested_time: Some(format_time(10.)),
+                ..create_job_place(location, Some(tag.to_string()))
+            }],
+            ..create_task(location, None)
+        }]),
+        ..create_job(id)
+    }
+}
+
+fn create_problem(jobs: Vec<Job>, tag_penalties: Option<Vec<TagRequestedTimePenalty>>) -> Problem {
+    Problem {
+        plan: Plan { jobs, ..create_empty_plan() },
+        fleet: create_default_fleet(),
+        objectives: Some(vec![
+            Objective::MinimizeUnassigned { breaks: None },
+            Objective::MatchRequestedTime { early_penalty: None, late_penalty: None, shape: None, tag_penalties },
+            Objective::MinimizeCost,
+        ]),
+        ..create_empty_problem()
+    }
+}
+
+fn get_first_job_id(problem: Problem) -> String {
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    get_ids_from_tour(&solution.tours[0])[1][0].clone()
+}
+
+#[test]
+fn can_prefer_job_with_higher_tag_penalty() {
+    let problem = create_problem(
+        vec![create_requested_job("job1", (10., 0.), "shopping"), create_requested_job("job2", (-10., 0.), "dialysis")],
+        Some(vec![TagRequestedTimePenalty {
+            tag: "dialysis".to_string(),
+            early_penalty: None,
+            late_penalty: Some(10.),
+            shape: None,
+        }]),
+    );
+
+    assert_eq!(get_first_job_id(problem), "job2");
+}
+
+#[test]
+fn can_prefer_job_with_higher_job_penalty() {
+    let problem = create_problem(
+        vec![
+            create_requested_job("job1", (10., 0.), "shopping"),
+            Job {
+                requested_time_penalty: Some(RequestedTimePenalty {
+                    early_penalty: None,
+                    late_penalty: None,
+                    shape: Some(RequestedTimePenaltyShape::Piecewise {
+                        segments: vec![RequestedTimePenaltySegment { from: 5., multiplier: 100. }],
+                    }),
+                }),
+                ..create_requested_job("job2", (-10., 0.), "shopping")
+            },
+        ],
+        None,
+    );
+
+    assert_eq!(get_first_job_id(problem), "job2");
+}
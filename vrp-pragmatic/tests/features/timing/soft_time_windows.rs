@@ -0,0 +1,63 @@
+use crate::format::problem::*;
+use crate::format::solution::*;
+use crate::helpers::*;
+
+fn create_problem_with_soft_job(max_lateness: f64) -> Problem {
+    Problem {
+        plan: Plan {
+            jobs: vec![
+                create_delivery_job_with_times("job1", (10., 0.), vec![(0, 10)], 0.),
+                create_delivery_job_with_times("job2", (20., 0.), vec![(10, 20)], 0.),
+                create_delivery_job_with_times("job3", (30., 0.), vec![(20, 30)], 0.),
+                create_delivery_job_with_times("job4", (40., 0.), vec![(30, 40)], 0.),
+                Job {
+                    soft_time_windows: Some(JobSoftTimeWindows {
+                        early_penalty: None,
+                        late_penalty: Some(2.),
+                        max_lateness,
+                    }),
+                    ..create_delivery_job_with_times("job5", (50., 0.), vec![(0, 10)], 0.)
+                },
+            ],
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    }
+}
+
+fn get_lateness(solution: &Solution) -> Vec<(String, i64)> {
+    solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter())
+        .filter_map(|activity| activity.lateness.map(|lateness| (activity.job_id.clone(), lateness)))
+        .collect()
+}
+
+#[test]
+fn can_serve_job_late_within_max_lateness() {
+    let problem = create_problem_with_soft_job(100.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert!(solution.unassigned.is_none());
+    assert_eq!(
+        get_ids_from_tour(&solution.tours[0]),
+        vec![vec!["departure"], vec!["job1"], vec!["job2"], vec!["job3"], vec!["job4"], vec!["job5"], vec!["arrival"]]
+    );
+    assert_eq!(get_lateness(&solution), vec![("job5".to_string(), 40)]);
+}
+
+#[test]
+fn can_have_unassigned_job_when_max_lateness_is_exceeded() {
+    let problem = create_problem_with_soft_job(30.);
+    let matrix = create_matrix_from_problem(&problem);
+
+    let solution = solve_with_metaheuristic(problem, Some(vec![matrix]));
+
+    assert_eq!(solution.unassigned.iter().flatten().map(|job| job.job_id.as_str()).collect::<Vec<_>>(), vec!["job5"]);
+    assert!(get_lateness(&solution).is_empty());
+}
@@ -97,6 +97,11 @@ prop_compose! {
             lifo_tag: None,
             fixed_order: None,
             max_ride_duration: None,
+            relative_max_ride_duration: None,
+            soft_max_ride_duration: None,
+            soft_time_windows: None,
+            requested_time_penalty: None,
+            dwell_time: None,
         }
     }
 }
@@ -148,6 +153,11 @@ prop_compose! {
             lifo_tag: None,
             fixed_order: None,
             max_ride_duration: None,
+            relative_max_ride_duration: None,
+            soft_max_ride_duration: None,
+            soft_time_windows: None,
+            requested_time_penalty: None,
+            dwell_time: None,
         }
     }
 }
@@ -180,7 +190,7 @@ prop_compose! {
      times in time_windows,
      tag in tags
     ) -> JobPlace {
-      JobPlace { times, location, duration, tag, requested_time: None }
+      JobPlace { times, location, duration, tag, requested_time: None, parking: None }
     }
 }
 
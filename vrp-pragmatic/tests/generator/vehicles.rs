@@ -36,6 +36,7 @@ prop_compose! {
             skills,
             limits,
             lifo_tags: None,
+            driving_rules: None,
         }
     }
 }
@@ -81,7 +82,8 @@ prop_compose! {
           location,
           duration,
           tag,
-          requested_time: None,
+          power: None,
+          resource_id: None,
         }
     }
 }
@@ -135,7 +137,7 @@ prop_compose! {
      vehicles in vehicles_proto,
      profiles in profiles_proto
     ) -> Fleet {
-        Fleet { vehicles, profiles, resources: None, capacity_dimensions: None }
+        Fleet { vehicles, drivers: None, profiles, resources: None, capacity_dimensions: None }
     }
 }
 
@@ -7,7 +7,7 @@ use vrp_core::models::common::Duration;
 use vrp_core::prelude::Float;
 
 pub fn create_job_place(location: (f64, f64), tag: Option<String>) -> JobPlace {
-    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, requested_time: None }
+    JobPlace { times: None, location: location.to_loc(), duration: 1., tag, requested_time: None, parking: None }
 }
 
 pub fn create_task(location: (f64, f64), tag: Option<String>) -> JobTask {
@@ -30,6 +30,11 @@ pub fn create_job(id: &str) -> Job {
         lifo_tag: None,
         fixed_order: None,
         max_ride_duration: None,
+        relative_max_ride_duration: None,
+        soft_max_ride_duration: None,
+        soft_time_windows: None,
+        requested_time_penalty: None,
+        dwell_time: None,
     }
 }
 
@@ -181,6 +186,7 @@ pub fn create_delivery_job_with_index(id: &str, index: usize) -> Job {
                 duration: 1.,
                 tag: None,
                 requested_time: None,
+                parking: None,
             }],
             demand: Some(vec![1]),
             named_demand: None,
@@ -280,12 +286,14 @@ pub fn create_vehicle_with_capacity(id: &str, capacity: Vec<i32>) -> VehicleType
         skills: None,
         limits: None,
         lifo_tags: None,
+        driving_rules: None,
     }
 }
 
 pub fn create_default_fleet() -> Fleet {
     Fleet {
         vehicles: vec![create_default_vehicle_type()],
+        drivers: None,
         profiles: create_default_matrix_profiles(),
         resources: None,
         capacity_dimensions: None,
@@ -301,13 +309,13 @@ pub fn create_min_jobs_cost_objective() -> Option<Vec<Objective>> {
 }
 
 pub fn create_empty_plan() -> Plan {
-    Plan { jobs: vec![], relations: None, clustering: None }
+    Plan { jobs: vec![], relations: None, clustering: None, replanning: None }
 }
 
 pub fn create_empty_problem() -> Problem {
     Problem {
         plan: create_empty_plan(),
-        fleet: Fleet { vehicles: vec![], profiles: vec![], resources: None, capacity_dimensions: None },
+        fleet: Fleet { vehicles: vec![], drivers: None, profiles: vec![], resources: None, capacity_dimensions: None },
         objectives: None,
     }
 }
@@ -170,6 +170,7 @@ impl Default for StopBuilder {
                 distance: 0,
                 load: vec![],
                 parking: None,
+                state_of_charge: None,
                 activities: vec![],
             }),
         }
@@ -262,6 +263,7 @@ impl Default for ActivityBuilder {
                 time: None,
                 job_tag: None,
                 commute: None,
+                lateness: None,
             },
         }
     }
@@ -342,6 +344,12 @@ impl TourBuilder {
         self
     }
 
+    pub fn driver_id(mut self, id: &str) -> Self {
+        self.tour.driver_id = Some(id.to_string());
+
+        self
+    }
+
     pub fn stops(mut self, stops: Vec<Stop>) -> Self {
         self.tour.stops = stops;
 
@@ -370,6 +378,7 @@ impl Default for TourBuilder {
                 vehicle_id: "my_vehicle_1".to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index: 0,
+                driver_id: None,
                 stops: vec![],
                 statistic: Default::default(),
             },
@@ -23,6 +23,7 @@ fn can_handle_properly_invalid_break_removal() {
                                 ]]),
                                 tag: None,
                                 requested_time: None,
+                                parking: None,
                             }],
                             demand: Some(vec![1]),
                             named_demand: None,
@@ -41,6 +42,7 @@ fn can_handle_properly_invalid_break_removal() {
                                 ]]),
                                 tag: None,
                                 requested_time: None,
+                                parking: None,
                             }],
                             demand: Some(vec![1]),
                             named_demand: None,
@@ -59,6 +61,7 @@ fn can_handle_properly_invalid_break_removal() {
                                 ]),
                                 tag: None,
                                 requested_time: None,
+                                parking: None,
                             }],
                             demand: Some(vec![1]),
                             named_demand: None,
@@ -77,6 +80,7 @@ fn can_handle_properly_invalid_break_removal() {
                                 ]]),
                                 tag: None,
                                 requested_time: None,
+                                parking: None,
                             }],
                             demand: Some(vec![2]),
                             named_demand: None,
@@ -95,6 +99,7 @@ fn can_handle_properly_invalid_break_removal() {
                                 ]),
                                 tag: None,
                                 requested_time: None,
+                                parking: None,
                             }],
                             demand: Some(vec![3]),
                             named_demand: None,
@@ -113,6 +118,7 @@ fn can_handle_properly_invalid_break_removal() {
                                 ]]),
                                 tag: None,
                                 requested_time: None,
+                                parking: None,
                             }],
                             demand: Some(vec![1]),
                             named_demand: None,
@@ -158,6 +164,7 @@ fn can_handle_properly_invalid_break_removal() {
                     skills: None,
                     limits: None,
                     lifo_tags: None,
+                    driving_rules: None,
                 }],
                 ..create_default_fleet()
             },
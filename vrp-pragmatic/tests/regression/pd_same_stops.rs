@@ -14,6 +14,7 @@ fn can_handle_two_pd_jobs_with_same_locations_and_unusual_routing() {
             times: None,
             tag: Some(tag.to_string()),
             requested_time: None,
+            parking: None,
         }],
         demand: Some(vec![1]),
         named_demand: None,
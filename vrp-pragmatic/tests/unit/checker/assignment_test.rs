@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 use vrp_core::models::examples::create_example_problem;
 
@@ -31,6 +32,7 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
                 vehicle_id: id.to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index,
+                driver_id: None,
                 stops: vec![],
                 statistic: Statistic::default(),
             })
@@ -44,6 +46,59 @@ fn check_vehicles_impl(known_ids: Vec<&str>, tours: Vec<(&str, usize)>, expected
     assert_eq!(result.map_err(|_| ()), expected_result);
 }
 
+parameterized_test! {can_check_drivers, (tours, expected_result), {
+    can_check_drivers_impl(tours, expected_result);
+}}
+
+can_check_drivers! {
+    case_01: (vec![("my_vehicle_1", Some("driver"), (10., 50.))], Ok(())),
+    case_02: (vec![("my_vehicle_1", None, (10., 50.))], Err(())),
+    case_03: (vec![("my_vehicle_1", Some("unknown"), (10., 50.))], Err(())),
+    case_04: (vec![("my_vehicle_1", Some("driver"), (10., 150.))], Err(())),
+    case_05: (vec![("my_vehicle_1", Some("driver"), (10., 50.)), ("my_vehicle_2", Some("driver"), (10., 50.))], Err(())),
+}
+
+fn can_check_drivers_impl(tours: Vec<(&str, Option<&str>, (Float, Float))>, expected_result: Result<(), ()>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                vehicle_ids: to_strings(vec!["my_vehicle_1", "my_vehicle_2"]),
+                ..create_default_vehicle_type()
+            }],
+            drivers: Some(vec![Driver {
+                id: "driver".to_string(),
+                costs: None,
+                shifts: vec![DriverShift { earliest: format_time(0.), latest: format_time(100.), depot: None }],
+                skills: None,
+            }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let solution = Solution {
+        tours: tours
+            .into_iter()
+            .map(|(vehicle_id, driver_id, (departure, arrival))| {
+                let builder = TourBuilder::default().vehicle_id(vehicle_id).stops(vec![
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(departure, departure).build_departure(),
+                    StopBuilder::default().coordinate((0., 0.)).schedule_stamp(arrival, arrival).build_arrival(),
+                ]);
+
+                match driver_id {
+                    Some(driver_id) => builder.driver_id(driver_id).build(),
+                    None => builder.build(),
+                }
+            })
+            .collect(),
+        ..SolutionBuilder::default().build()
+    };
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_drivers(&ctx);
+
+    assert_eq!(result.map_err(|_| ()), expected_result);
+}
+
 parameterized_test! {check_jobs, (jobs, tours, unassigned, expected_result), {
     check_jobs_impl(jobs, tours, unassigned, expected_result);
 }}
@@ -132,6 +187,7 @@ fn check_jobs_impl(
                     times: None,
                     tag: Some(format!("{tgt}{idx}")),
                     requested_time: None,
+                    parking: None,
                 }],
                 demand: if tgt != "service" { Some(vec![1]) } else { None },
                 named_demand: None,
@@ -166,6 +222,7 @@ fn check_jobs_impl(
                 vehicle_id: id.to_string(),
                 type_id: "my_vehicle".to_string(),
                 shift_index,
+                driver_id: None,
                 stops: stops.into_iter().map(create_stop).collect(),
                 statistic: Statistic::default(),
             })
@@ -306,6 +363,109 @@ fn can_detect_max_ride_duration_violation() {
     assert!(error.contains("102s exceeds 100s"));
 }
 
+parameterized_test! {can_check_relative_max_ride_duration, (relative, scale, delivery_start, expected), {
+    can_check_relative_max_ride_duration_impl(relative, scale, delivery_start, expected);
+}}
+
+can_check_relative_max_ride_duration! {
+    case01_within_limit: ((None, 2., None), None, 70., None),
+    case02_exceeds_limit: ((None, 2., None), None, 72., Some("22s exceeds 20s")),
+    case03_within_offset: ((Some(5.), 2., None), None, 72., None),
+    case04_within_min: ((None, 2., Some(30.)), None, 72., None),
+    case05_ignores_profile_scale: ((None, 2., None), Some(2.), 72., Some("22s exceeds 20s")),
+}
+
+fn can_check_relative_max_ride_duration_impl(
+    relative: (Option<Float>, Float, Option<Float>),
+    scale: Option<Float>,
+    delivery_start: Float,
+    expected: Option<&str>,
+) {
+    let job = Job {
+        pickups: Some(vec![create_task((0., 0.), Some("p".to_string()))]),
+        deliveries: Some(vec![create_task((10., 0.), Some("d".to_string()))]),
+        relative_max_ride_duration: Some(RelativeMaxRideDuration {
+            offset: relative.0,
+            factor: relative.1,
+            min: relative.2,
+        }),
+        ..create_job("job1")
+    };
+    let problem = Problem {
+        plan: Plan { jobs: vec![job], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile {
+                    scale: scale.map(VehicleProfileScale::Constant),
+                    ..create_default_vehicle_profile()
+                },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+    let pickup = StopBuilder::default().coordinate((0., 0.)).build_single_time("job1", "pickup", (40., 50.));
+    let delivery = StopBuilder::default().coordinate((10., 0.)).build_single_time(
+        "job1",
+        "delivery",
+        (delivery_start, delivery_start + 10.),
+    );
+    let solution =
+        SolutionBuilder::default().tour(TourBuilder::default().stops(vec![pickup, delivery]).build()).build();
+    let core_problem = Arc::new((problem.clone(), vec![matrix.clone()]).read_pragmatic().unwrap());
+    let ctx = CheckerContext::new(core_problem, problem, Some(vec![matrix]), solution).unwrap();
+
+    let result = check_max_ride_duration(&ctx);
+
+    match expected {
+        Some(expected) => assert!(result.unwrap_err().to_string().contains(expected)),
+        None => assert_eq!(result, Ok(())),
+    }
+}
+
+fn create_onboard_replanning(onboard: Vec<(&str, Float)>) -> Option<Replanning> {
+    Some(Replanning {
+        now: format_time(200.),
+        vehicles: vec![VehicleReplanning {
+            vehicle_id: "my_vehicle_1".to_string(),
+            shift_index: None,
+            location: None,
+            executed: None,
+            onboard: Some(
+                onboard
+                    .into_iter()
+                    .map(|(job_id, since)| OnboardJob { job_id: job_id.to_string(), since: format_time(since) })
+                    .collect(),
+            ),
+        }],
+    })
+}
+
+#[test]
+fn can_detect_max_ride_duration_violation_of_onboard_job() {
+    let job = Job {
+        pickups: None,
+        max_ride_duration: Some(100.),
+        ..create_constraint_job("job1", &[], &["d"], false, false)
+    };
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![job],
+            replanning: create_onboard_replanning(vec![("job1", 50.)]),
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+    let delivery = StopBuilder::default().build_single_time("job1", "delivery", (152., 162.));
+    let solution = SolutionBuilder::default().tour(TourBuilder::default().stops(vec![delivery]).build()).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let error = check_max_ride_duration(&ctx).unwrap_err().to_string();
+    assert!(error.contains("102s exceeds 100s"));
+}
+
 #[test]
 fn can_check_non_overlapping_solo_riding() {
     let ctx = create_constraint_context(
@@ -689,3 +849,64 @@ fn can_detect_group_violations() {
 
     assert_eq!(result, Err("job groups are not respected: 'group1'".into()));
 }
+
+#[test]
+fn can_detect_lifo_order_violation_of_onboard_jobs() {
+    let create_onboard_lifo_job =
+        |id: &str, tag: &str| Job { pickups: None, ..create_lifo_job(id, "wheelchair", &[], &[tag]) };
+    let mut ctx = create_constraint_context_with_lifo_tags(
+        vec![create_onboard_lifo_job("job1", "d1"), create_onboard_lifo_job("job2", "d2")],
+        vec![("job1", "delivery", "d1"), ("job2", "delivery", "d2")],
+        Some(&["wheelchair"]),
+    );
+    ctx.problem.plan.replanning = create_onboard_replanning(vec![("job1", 0.), ("job2", 10.)]);
+
+    let error = check_lifo(&ctx).unwrap_err().to_string();
+
+    assert!(error.contains("delivery job 'job1' expected job 'job2'"));
+}
+
+parameterized_test! {can_check_max_onboard_waiting, (delivery_start, expected), {
+    can_check_max_onboard_waiting_impl(delivery_start, expected);
+}}
+
+can_check_max_onboard_waiting! {
+    case01_within_limit: (25., None),
+    case02_at_limit: (30., None),
+    case03_exceeds_limit: (50., Some("at activity 'delivery' of job 'job1': 30s exceeds 10s")),
+}
+
+fn can_check_max_onboard_waiting_impl(delivery_start: Float, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_constraint_job("job1", &["p"], &["d"], false, false)], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_onboard_waiting: Some(10.),
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let pickup = StopBuilder::default().schedule_stamp(0., 10.).build_single_time("job1", "pickup", (0., 10.));
+    let delivery = StopBuilder::default().schedule_stamp(20., delivery_start + 10.).build_single_time(
+        "job1",
+        "delivery",
+        (delivery_start, delivery_start + 10.),
+    );
+    let solution =
+        SolutionBuilder::default().tour(TourBuilder::default().stops(vec![pickup, delivery]).build()).build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_max_onboard_waiting(&ctx);
+
+    match expected {
+        Some(expected) => assert!(result.unwrap_err().to_string().contains(expected)),
+        None => assert_eq!(result, Ok(())),
+    }
+}
@@ -104,6 +104,7 @@ fn can_check_breaks_impl(
         time: Some(Interval { start: "1970-01-01T00:00:03Z".to_string(), end: "1970-01-01T00:00:04Z".to_string() }),
         job_tag: None,
         commute: None,
+        lateness: None,
     }];
     if has_break {
         activities.push(Activity {
@@ -113,6 +114,7 @@ fn can_check_breaks_impl(
             time: Some(Interval { start: "1970-01-01T00:00:04Z".to_string(), end: "1970-01-01T00:00:06Z".to_string() }),
             job_tag: None,
             commute: None,
+            lateness: None,
         });
     }
 
@@ -152,3 +154,96 @@ fn can_check_breaks_impl(
 
     assert_eq!(result, expected_result);
 }
+
+fn get_driving_error_msg(actual: Float) -> Result<(), Vec<GenericError>> {
+    Err(vec![
+        format!(
+            "driving time rules violation: expected limit is 25, got {actual}, vehicle id 'my_vehicle_1', shift index: 0"
+        )
+        .into(),
+    ])
+}
+
+parameterized_test! {can_check_driving_rules, (split_rest, max_daily_driving_time, rests, expected_result), {
+    can_check_driving_rules_impl(split_rest, max_daily_driving_time, rests, expected_result);
+}}
+
+can_check_driving_rules! {
+    case01_no_rests: (None, None, vec![], get_driving_error_msg(30.)),
+    case02_single_rest: (None, None, vec![(2, 5.)], Ok(())),
+    case03_short_rest: (None, None, vec![(2, 3.)], get_driving_error_msg(30.)),
+    case04_split_rest: (Some(vec![2., 3.]), None, vec![(1, 2.), (2, 3.)], Ok(())),
+    case05_split_rest_wrong_order: (Some(vec![2., 3.]), None, vec![(1, 3.), (2, 2.)], get_driving_error_msg(30.)),
+    case06_split_rest_too_short: (Some(vec![2., 3.]), None, vec![(1, 1.), (2, 3.)], get_driving_error_msg(30.)),
+    case07_daily_limit: (None, Some(25.), vec![(2, 5.)], Err(vec![
+        "daily driving time rules violation: expected limit is 25, got 30, worker id 'my_vehicle_1', day 0".into()
+    ])),
+}
+
+fn can_check_driving_rules_impl(
+    split_rest: Option<Vec<Float>>,
+    max_daily_driving_time: Option<Float>,
+    rests: Vec<(usize, Float)>,
+    expected_result: Result<(), Vec<GenericError>>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: (1..=3).map(|idx| create_delivery_job(&format!("job{idx}"), (idx as Float * 10., 0.))).collect(),
+            ..create_empty_plan()
+        },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                driving_rules: Some(VehicleDrivingRules {
+                    max_driving_time: 25.,
+                    rest_duration: 5.,
+                    split_rest,
+                    max_daily_driving_time,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let departure = StopBuilder::default().coordinate((0., 0.)).schedule_stamp(0., 0.).load(vec![3]).build_departure();
+    let (stops, _) = (1..=3).fold((vec![departure], 0.), |(mut stops, prev_departure), idx| {
+        let arrival = prev_departure + 10.;
+        let job_id = format!("job{idx}");
+        let activity = ActivityBuilder::delivery().job_id(&job_id).time_stamp(arrival, arrival + 1.).build();
+        let (activities, departure) = rests.iter().filter(|(stop_idx, _)| *stop_idx == idx).fold(
+            (vec![activity], arrival + 1.),
+            |(mut activities, start), (_, duration)| {
+                activities.push(
+                    ActivityBuilder::default()
+                        .job_id("rest")
+                        .activity_type("rest")
+                        .time_stamp(start, start + duration)
+                        .build(),
+                );
+                (activities, start + duration)
+            },
+        );
+
+        stops.push(
+            StopBuilder::default()
+                .coordinate((idx as Float * 10., 0.))
+                .schedule_stamp(arrival, departure)
+                .load(vec![3 - idx as i32])
+                .distance(idx as i64 * 10)
+                .activities(activities)
+                .build(),
+        );
+
+        (stops, departure)
+    });
+
+    let solution = SolutionBuilder::default()
+        .tour(TourBuilder::default().stops(stops).statistic(StatisticBuilder::default().driving(30).build()).build())
+        .build();
+    let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
+
+    let result = check_driving_rules(&ctx).map_err(|err| vec![err]);
+
+    assert_eq!(result, expected_result);
+}
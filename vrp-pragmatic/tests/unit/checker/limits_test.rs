@@ -23,6 +23,7 @@ fn create_test_solution(statistic: Statistic, stops: Vec<Stop>) -> Solution {
             vehicle_id: "some_real_vehicle".to_string(),
             type_id: "my_vehicle".to_string(),
             shift_index: 0,
+            driver_id: None,
             stops,
             statistic,
         })
@@ -59,7 +60,12 @@ pub fn can_check_shift_and_distance_limit_impl(
     actual: i64,
     expected: Result<(), GenericError>,
 ) {
-    let problem = create_test_problem(Some(VehicleLimits { max_distance, max_duration, tour_size: None }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance,
+        max_duration,
+        tour_size: None,
+        max_onboard_waiting: None,
+    }));
     let solution =
         create_test_solution(Statistic { distance: actual, duration: actual, ..Statistic::default() }, vec![]);
     let ctx = CheckerContext::new(create_example_problem(), problem, None, solution).unwrap();
@@ -71,8 +77,12 @@ pub fn can_check_shift_and_distance_limit_impl(
 
 #[test]
 pub fn can_check_tour_size_limit() {
-    let problem =
-        create_test_problem(Some(VehicleLimits { max_distance: None, max_duration: None, tour_size: Some(2) }));
+    let problem = create_test_problem(Some(VehicleLimits {
+        max_distance: None,
+        max_duration: None,
+        tour_size: Some(2),
+        max_onboard_waiting: None,
+    }));
     let solution = create_test_solution(
         Statistic::default(),
         vec![
@@ -178,13 +188,15 @@ fn can_check_recharge_distance() {
                     start: ShiftStart { earliest: format_time(0.), latest: None, location: (0., 0.).to_loc() },
                     end: None,
                     recharges: Some(VehicleRecharges {
-                        max_distance: 8.,
+                        max_distance: Some(8.),
+                        energy: None,
                         stations: vec![VehicleRechargeStation {
                             location: (8., 0.).to_loc(),
                             duration: 0.,
                             times: None,
                             tag: None,
-                            requested_time: None,
+                            power: None,
+                            resource_id: None,
                         }],
                     }),
                     ..create_default_vehicle_shift()
@@ -1,4 +1,4 @@
-use crate::format::feasibility::{job_locations, FeasibilityContext};
+use crate::format::feasibility::{job_locations, FeasibilityContext, ReoptimizationOptions};
 use crate::format::problem::*;
 use crate::format::{CoordIndex, Location};
 use crate::helpers::*;
@@ -563,6 +563,7 @@ fn can_check_feasibility_at_scale_500_jobs_50_vehicles() {
                 skills: None,
                 limits: None,
                 lifo_tags: None,
+                driving_rules: None,
             }
         })
         .collect();
@@ -830,6 +831,7 @@ fn compare_feasibility_vs_solver_500_jobs_50_vehicles() {
                 skills: None,
                 limits: None,
                 lifo_tags: None,
+                driving_rules: None,
             }
         })
         .collect();
@@ -1287,3 +1289,221 @@ fn cannot_insert_unreachable_candidate_on_open_route() {
         result.vehicles
     );
 }
+
+#[test]
+fn can_cancel_pickup_delivery_job_with_both_legs() {
+    let (problem, matrix) = build_problem_and_matrix(
+        vec![create_vehicle_with_capacity("my_vehicle", vec![10])],
+        vec![create_delivery_job("job1", (1.0, 0.0)), create_pickup_delivery_job("pd", (2.0, 0.0), (3.0, 0.0))],
+    );
+    let solution_json = build_solution_json(
+        "my_vehicle_1",
+        "my_vehicle",
+        (0.0, 0.0),
+        vec![("job1", "delivery", (1.0, 0.0)), ("pd", "pickup", (2.0, 0.0)), ("pd", "delivery", (3.0, 0.0))],
+        10,
+    )
+    .replace(r#""jobId": "pd", "type": "pickup""#, r#""jobId": "pd", "type": "pickup", "jobTag": "p1""#)
+    .replace(r#""jobId": "pd", "type": "delivery""#, r#""jobId": "pd", "type": "delivery", "jobTag": "d1""#);
+    let mut ctx = FeasibilityContext::new(problem, vec![matrix], &solution_json, &[]).expect("cannot build context");
+
+    let result = ctx.cancel_job("pd").expect("cancel_job failed");
+
+    assert_eq!(result.job_id, "pd");
+    assert_eq!(result.vehicle_id, "my_vehicle_1");
+    assert_eq!(result.type_id, "my_vehicle");
+    assert_eq!(result.shift_index, 0);
+    assert_eq!(result.freed_capacity, vec![1]);
+    assert!(result.freed_duration > 0.);
+    assert!(result.time_slack.is_some_and(|time_slack| time_slack > 0.));
+    let json = ctx.to_solution_json().expect("to_solution_json failed");
+    assert!(!json.contains(r#""pd""#), "both legs of cancelled job must be removed");
+    assert!(json.contains("job1"));
+}
+
+#[test]
+fn can_cancel_job_on_open_route_without_time_slack() {
+    let jobs = vec![
+        create_delivery_job_with_times("job1", (1.0, 0.0), vec![(100, 110)], 1.),
+        create_delivery_job_with_times("job2", (2.0, 0.0), vec![(200, 210)], 1.),
+    ];
+    let (problem, matrix) = build_problem_and_matrix_with_extra(vec![open_windowed_vehicle()], jobs, &[]);
+    let solution_json = open_windowed_solution_json();
+    let mut ctx = FeasibilityContext::new(problem, vec![matrix], &solution_json, &[]).expect("cannot build context");
+
+    let result = ctx.cancel_job("job2").expect("cancel_job failed");
+
+    assert!(result.freed_duration > 0.);
+    assert_eq!(result.time_slack, None);
+}
+
+#[test]
+fn can_accept_job_after_cancel_frees_capacity() {
+    let (problem, matrix) = build_problem_and_matrix(
+        vec![create_vehicle_with_capacity("my_vehicle", vec![1])],
+        vec![create_delivery_job("job1", (1.0, 0.0))],
+    );
+    let solution_json =
+        build_solution_json("my_vehicle_1", "my_vehicle", (0.0, 0.0), vec![("job1", "delivery", (1.0, 0.0))], 1);
+    let mut ctx = FeasibilityContext::new(problem, vec![matrix], &solution_json, &[]).expect("cannot build context");
+    let candidate = create_delivery_job("candidate1", (2.0, 0.0));
+    assert!(!ctx.check_job(&candidate).expect("check_job failed").is_feasible);
+
+    let result = ctx.cancel_job("job1").expect("cancel_job failed");
+
+    assert_eq!(result.freed_capacity, vec![1]);
+    assert!(ctx.check_job(&candidate).expect("check_job failed").is_feasible);
+    assert!(ctx.accept_job(&candidate).expect("accept_job failed").is_feasible);
+}
+
+#[test]
+fn cannot_cancel_unknown_job() {
+    let (problem, matrix) = build_problem_and_matrix(
+        vec![create_vehicle_with_capacity("my_vehicle", vec![10])],
+        vec![create_delivery_job("job1", (1.0, 0.0))],
+    );
+    let solution_json =
+        build_solution_json("my_vehicle_1", "my_vehicle", (0.0, 0.0), vec![("job1", "delivery", (1.0, 0.0))], 10);
+    let mut ctx = FeasibilityContext::new(problem, vec![matrix], &solution_json, &[]).expect("cannot build context");
+
+    let result = ctx.cancel_job("unknown");
+
+    assert_eq!(result.err().map(|err| err.to_string()), Some("cannot find assigned job with id 'unknown'".to_string()));
+}
+
+fn get_solution_cost(ctx: &FeasibilityContext) -> f64 {
+    let json = ctx.to_solution_json().expect("to_solution_json failed");
+    let solution = crate::format::solution::deserialize_solution(std::io::BufReader::new(json.as_bytes()))
+        .expect("cannot deserialize solution");
+
+    solution.statistic.cost
+}
+
+#[test]
+fn can_reoptimize_touched_route_after_accept() {
+    let create_ctx = || {
+        let jobs = (1..=4).map(|idx| create_delivery_job(&format!("job{idx}"), (idx as f64, 0.0))).collect();
+        let (problem, matrix) =
+            build_problem_and_matrix(vec![create_vehicle_with_capacity("my_vehicle", vec![10])], jobs);
+        let solution_json = build_solution_json(
+            "my_vehicle_1",
+            "my_vehicle",
+            (0.0, 0.0),
+            vec![
+                ("job3", "delivery", (3.0, 0.0)),
+                ("job1", "delivery", (1.0, 0.0)),
+                ("job4", "delivery", (4.0, 0.0)),
+                ("job2", "delivery", (2.0, 0.0)),
+            ],
+            10,
+        );
+        FeasibilityContext::new(problem, vec![matrix], &solution_json, &[]).expect("cannot build context")
+    };
+    let candidate = create_delivery_job("candidate", (5.0, 0.0));
+    let mut greedy_ctx = create_ctx();
+    let mut improved_ctx =
+        create_ctx().with_reoptimization(ReoptimizationOptions {
+            time_limit: 5000,
+            max_iterations: 200,
+            ..ReoptimizationOptions::default()
+        });
+
+    greedy_ctx.accept_job(&candidate).expect("accept_job failed");
+    let result = improved_ctx.accept_job(&candidate).expect("accept_job failed");
+
+    assert!(result.is_feasible);
+    assert!(get_solution_cost(&improved_ctx) < get_solution_cost(&greedy_ctx));
+    let json = improved_ctx.to_solution_json().expect("to_solution_json failed");
+    assert!(["job1", "job2", "job3", "job4", "candidate"].iter().all(|id| json.contains(id)));
+}
+
+fn get_pickup_times(ctx: &FeasibilityContext) -> Vec<(String, String)> {
+    let json = ctx.to_solution_json().expect("to_solution_json failed");
+    let solution = crate::format::solution::deserialize_solution(std::io::BufReader::new(json.as_bytes()))
+        .expect("cannot deserialize solution");
+
+    let mut times = solution
+        .tours
+        .iter()
+        .flat_map(|tour| tour.stops.iter())
+        .flat_map(|stop| stop.activities().iter().map(move |activity| (stop, activity)))
+        .filter(|(_, activity)| activity.activity_type == "pickup")
+        .map(|(stop, activity)| {
+            let start = activity.time.as_ref().map_or(&stop.schedule().arrival, |time| &time.start);
+            (activity.job_id.clone(), start.clone())
+        })
+        .collect::<Vec<_>>();
+    times.sort();
+
+    times
+}
+
+#[test]
+fn can_keep_scheduled_pickup_times_when_reoptimizing() {
+    let create_ctx = || {
+        let jobs = (1..=4).map(|idx| create_pickup_job(&format!("job{idx}"), (idx as f64, 0.0))).collect();
+        let (problem, matrix) =
+            build_problem_and_matrix(vec![create_vehicle_with_capacity("my_vehicle", vec![10])], jobs);
+        let solution_json = build_solution_json(
+            "my_vehicle_1",
+            "my_vehicle",
+            (0.0, 0.0),
+            vec![
+                ("job3", "pickup", (3.0, 0.0)),
+                ("job1", "pickup", (1.0, 0.0)),
+                ("job4", "pickup", (4.0, 0.0)),
+                ("job2", "pickup", (2.0, 0.0)),
+            ],
+            0,
+        );
+        FeasibilityContext::new(problem, vec![matrix], &solution_json, &[]).expect("cannot build context")
+    };
+    let create_options =
+        |max_pickup_shift| ReoptimizationOptions { time_limit: 5000, max_iterations: 200, max_pickup_shift };
+    let candidate = create_delivery_job("candidate", (5.0, 0.0));
+    let mut greedy_ctx = create_ctx();
+    let mut strict_ctx = create_ctx().with_reoptimization(create_options(0.));
+    let mut relaxed_ctx = create_ctx().with_reoptimization(create_options(f64::MAX));
+
+    greedy_ctx.accept_job(&candidate).expect("accept_job failed");
+    strict_ctx.accept_job(&candidate).expect("accept_job failed");
+    relaxed_ctx.accept_job(&candidate).expect("accept_job failed");
+
+    assert!(get_solution_cost(&relaxed_ctx) < get_solution_cost(&greedy_ctx));
+    assert_ne!(get_pickup_times(&relaxed_ctx), get_pickup_times(&greedy_ctx));
+    assert_eq!(get_pickup_times(&strict_ctx), get_pickup_times(&greedy_ctx));
+}
+
+#[test]
+fn can_keep_locked_jobs_when_reoptimizing() {
+    let jobs = (1..=3).map(|idx| create_delivery_job(&format!("job{idx}"), (idx as f64, 0.0))).collect();
+    let (mut problem, matrix) =
+        build_problem_and_matrix(vec![create_vehicle_with_capacity("my_vehicle", vec![10])], jobs);
+    problem.plan.relations = Some(vec![Relation {
+        type_field: RelationType::Strict,
+        jobs: vec!["departure".to_string(), "job3".to_string(), "job1".to_string()],
+        vehicle_id: "my_vehicle_1".to_string(),
+        shift_index: None,
+    }]);
+    let solution_json = build_solution_json(
+        "my_vehicle_1",
+        "my_vehicle",
+        (0.0, 0.0),
+        vec![("job3", "delivery", (3.0, 0.0)), ("job1", "delivery", (1.0, 0.0)), ("job2", "delivery", (2.0, 0.0))],
+        10,
+    );
+    let mut ctx = FeasibilityContext::new(problem, vec![matrix], &solution_json, &[])
+        .expect("cannot build context")
+        .with_reoptimization(ReoptimizationOptions {
+            time_limit: 5000,
+            max_iterations: 200,
+            ..ReoptimizationOptions::default()
+        });
+
+    ctx.accept_job(&create_delivery_job("candidate", (4.0, 0.0))).expect("accept_job failed");
+
+    let json = ctx.to_solution_json().expect("to_solution_json failed");
+    let (job3, job1) = (json.find(r#""job3""#).unwrap(), json.find(r#""job1""#).unwrap());
+    assert!(job3 < job1, "locked sequence must be kept");
+    assert!(json.contains("candidate"));
+}
@@ -1,3 +1,4 @@
+use crate::format::JobTypeDimension;
 use crate::format::problem::*;
 use crate::helpers::*;
 use std::collections::HashSet;
@@ -80,6 +81,7 @@ fn can_read_complex_problem() {
                             duration: 100.0,
                             tag: Some("my_delivery".to_string()),
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![0, 1]),
                         named_demand: None,
@@ -99,6 +101,7 @@ fn can_read_complex_problem() {
                             duration: 110.0,
                             tag: None,
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![2]),
                         named_demand: None,
@@ -114,6 +117,7 @@ fn can_read_complex_problem() {
                             duration: 120.0,
                             tag: None,
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![2]),
                         named_demand: None,
@@ -132,6 +136,7 @@ fn can_read_complex_problem() {
                             duration: 90.0,
                             tag: None,
                             requested_time: None,
+                            parking: None,
                         }],
                         demand: Some(vec![3]),
                         named_demand: None,
@@ -180,8 +185,14 @@ fn can_read_complex_problem() {
                 capacity: Some(vec![10, 1]),
                 capacity_configurations: None,
                 skills: Some(vec!["unique1".to_string(), "unique2".to_string()]),
-                limits: Some(VehicleLimits { max_distance: Some(123.1), max_duration: Some(100.), tour_size: Some(3) }),
+                limits: Some(VehicleLimits {
+                    max_distance: Some(123.1),
+                    max_duration: Some(100.),
+                    tour_size: Some(3),
+                    max_onboard_waiting: None,
+                }),
                 lifo_tags: None,
+                driving_rules: None,
             }],
             ..create_default_fleet()
         },
@@ -287,6 +298,7 @@ fn can_read_requested_times_with_configurable_capacity() {
                         times: None,
                         tag: Some("pickup".to_string()),
                         requested_time: Some("1970-01-01T00:00:10Z".to_string()),
+                        parking: None,
                     }],
                     demand: Some(vec![0, 1]),
                     named_demand: None,
@@ -299,6 +311,7 @@ fn can_read_requested_times_with_configurable_capacity() {
                         times: None,
                         tag: Some("delivery".to_string()),
                         requested_time: Some("1970-01-01T00:00:30Z".to_string()),
+                        parking: None,
                     }],
                     demand: Some(vec![0, 1]),
                     named_demand: None,
@@ -333,6 +346,49 @@ fn can_read_requested_times_with_configurable_capacity() {
     assert_eq!(delivery.dimens.get_job_requested_times().unwrap().get(&0), Some(&30.));
 }
 
+#[test]
+fn can_read_split_rest_parts_with_their_durations() {
+    let problem = Problem {
+        plan: Plan { jobs: vec![create_delivery_job("job1", (1., 0.))], ..create_empty_plan() },
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                driving_rules: Some(VehicleDrivingRules {
+                    max_driving_time: 35.,
+                    rest_duration: 10.,
+                    split_rest: Some(vec![3., 8.]),
+                    max_daily_driving_time: None,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+    let matrix = create_matrix_from_problem(&problem);
+
+    let problem = (problem, vec![matrix]).read_pragmatic().ok().unwrap();
+
+    let rests = problem
+        .jobs
+        .all()
+        .iter()
+        .filter_map(|job| job.as_single())
+        .filter(|single| single.dimens.get_job_type().is_some_and(|job_type| job_type == "rest"))
+        .map(|single| (single.dimens.get_job_id().unwrap().clone(), get_single_place(single.as_ref()).duration))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        rests,
+        vec![
+            ("my_vehicle_1_rest_0_1".to_string(), 10.),
+            ("my_vehicle_1_rest_0_2".to_string(), 3.),
+            ("my_vehicle_1_rest_0_3".to_string(), 8.),
+            ("my_vehicle_1_rest_0_4".to_string(), 10.),
+            ("my_vehicle_1_rest_0_5".to_string(), 3.),
+            ("my_vehicle_1_rest_0_6".to_string(), 8.),
+        ]
+    );
+}
+
 #[test]
 fn can_deserialize_minimal_problem_and_matrix() {
     let problem = (SIMPLE_PROBLEM.to_string(), vec![SIMPLE_MATRIX.to_string()]).read_pragmatic().ok().unwrap();
@@ -66,6 +66,7 @@ fn can_create_geo_json_for_cluster_geometry() {
         distance: 0,
         load: vec![],
         parking: None,
+        state_of_charge: None,
         activities: vec![
             Activity {
                 job_id: "job1".to_string(),
@@ -74,6 +75,7 @@ fn can_create_geo_json_for_cluster_geometry() {
                 time: Some(Interval { start: format_time(0.), end: format_time(1.) }),
                 job_tag: None,
                 commute: Some(Commute { forward: None, backward: None }),
+                lateness: None,
             },
             Activity {
                 job_id: "job2".to_string(),
@@ -93,6 +95,7 @@ fn can_create_geo_json_for_cluster_geometry() {
                         time: Interval { start: format_time(3.), end: format_time(4.) },
                     }),
                 }),
+                lateness: None,
             },
         ],
     };
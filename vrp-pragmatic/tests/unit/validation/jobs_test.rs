@@ -1,5 +1,6 @@
 use super::*;
 use crate::helpers::*;
+use vrp_core::prelude::Float;
 
 fn assert_result(code: &str, action: &str, result: Option<FormatError>) {
     assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
@@ -59,6 +60,18 @@ fn can_detect_negative_duration() {
     assert_result("E1106", "job1", result);
 }
 
+#[test]
+fn can_detect_negative_parking() {
+    let mut job = create_delivery_job("job1", (1., 0.));
+    job.deliveries.as_mut().unwrap()[0].places[0].parking = Some(-10.);
+    let problem = Problem { plan: Plan { jobs: vec![job], ..create_empty_plan() }, ..create_empty_problem() };
+
+    let result =
+        check_e1106_negative_duration(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem))).err();
+
+    assert_result("E1106", "job1", result);
+}
+
 #[test]
 fn can_detect_negative_demand() {
     let problem = Problem {
@@ -73,3 +86,132 @@ fn can_detect_negative_demand() {
 
     assert_result("E1107", "job1", result);
 }
+
+parameterized_test! {can_detect_invalid_soft_time_windows, (early_penalty, late_penalty, max_lateness, expected), {
+    can_detect_invalid_soft_time_windows_impl(early_penalty, late_penalty, max_lateness, expected);
+}}
+
+can_detect_invalid_soft_time_windows! {
+    case01: (None, None, 60., None),
+    case02: (Some(1.), Some(2.), 0., None),
+    case03: (None, None, -1., Some("E1110")),
+    case04: (Some(-1.), None, 60., Some("E1110")),
+    case05: (None, Some(-1.), 60., Some("E1110")),
+}
+
+fn can_detect_invalid_soft_time_windows_impl(
+    early_penalty: Option<Float>,
+    late_penalty: Option<Float>,
+    max_lateness: Float,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                soft_time_windows: Some(JobSoftTimeWindows { early_penalty, late_penalty, max_lateness }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1110_soft_time_windows_are_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_relative_max_ride_duration, (offset, factor, min, expected), {
+    can_detect_invalid_relative_max_ride_duration_impl(offset, factor, min, expected);
+}}
+
+can_detect_invalid_relative_max_ride_duration! {
+    case01: (None, 1.5, None, None),
+    case02: (Some(600.), 2., Some(1200.), None),
+    case03: (Some(-1.), 1.5, None, Some("E1111")),
+    case04: (None, -1.5, None, Some("E1111")),
+    case05: (None, 1.5, Some(-1.), Some("E1111")),
+}
+
+fn can_detect_invalid_relative_max_ride_duration_impl(
+    offset: Option<Float>,
+    factor: Float,
+    min: Option<Float>,
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                relative_max_ride_duration: Some(RelativeMaxRideDuration { offset, factor, min }),
+                ..create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1111_relative_max_ride_duration_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_soft_max_ride_duration, (soft_max_ride_duration, expected), {
+    can_detect_invalid_soft_max_ride_duration_impl(soft_max_ride_duration, expected);
+}}
+
+can_detect_invalid_soft_max_ride_duration! {
+    case01: (None, None),
+    case02: (Some(600.), None),
+    case03: (Some(-1.), Some("E1112")),
+}
+
+fn can_detect_invalid_soft_max_ride_duration_impl(soft_max_ride_duration: Option<Float>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job { soft_max_ride_duration, ..create_pickup_delivery_job("job1", (1., 0.), (2., 0.)) }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1112_soft_max_ride_duration_is_correct(&ValidationContext::new(
+        &problem,
+        None,
+        &CoordIndex::new(&problem),
+    ));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_dwell_time, (setup, per_unit, expected), {
+    can_detect_invalid_dwell_time_impl(setup, per_unit, expected);
+}}
+
+can_detect_invalid_dwell_time! {
+    case01: (None, None, None),
+    case02: (Some(60.), Some(vec![10., 0.]), None),
+    case03: (Some(-1.), None, Some("E1113")),
+    case04: (None, Some(vec![10., -1.]), Some("E1113")),
+}
+
+fn can_detect_invalid_dwell_time_impl(setup: Option<Float>, per_unit: Option<Vec<Float>>, expected: Option<&str>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                dwell_time: Some(JobDwellTime { setup, per_unit }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1113_dwell_time_is_correct(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected.map(|code| code.to_string()));
+}
@@ -207,3 +207,162 @@ fn can_detect_missing_value_objective_impl(objectives: Option<Vec<Objective>>, e
 
     assert_eq!(result.err().map(|e| e.code), expected);
 }
+
+parameterized_test! {can_detect_missing_lateness_objective, (objectives, expected), {
+    can_detect_missing_lateness_objective_impl(objectives, expected);
+}}
+
+can_detect_missing_lateness_objective! {
+    case01: (Some(vec![MinimizeUnassigned { breaks: None }, MinimizeCost]), Some("E1608".to_string())),
+    case02: (Some(vec![MinimizeUnassigned { breaks: None }, MinimizeLateness, MinimizeCost]), None),
+    case03: (None, None),
+}
+
+fn can_detect_missing_lateness_objective_impl(objectives: Option<Vec<Objective>>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                deliveries: Some(vec![create_task((1., 0.), None)]),
+                soft_time_windows: Some(JobSoftTimeWindows {
+                    early_penalty: None,
+                    late_penalty: None,
+                    max_lateness: 60.,
+                }),
+                ..create_job("job1")
+            }],
+            ..create_empty_plan()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1608_jobs_with_soft_time_windows_but_no_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_requested_time_penalty, (objective_penalty, tag_penalty, job_penalty, expected), {
+    can_detect_invalid_requested_time_penalty_impl(objective_penalty, tag_penalty, job_penalty, expected);
+}}
+
+can_detect_invalid_requested_time_penalty! {
+    case01: ((Some(1.), Some(2.)), (None, None), (None, Some(10.)), None),
+    case02: ((Some(-1.), None), (None, None), (None, None), Some("E1609")),
+    case03: ((None, None), (Some(-1.), None), (None, None), Some("E1609")),
+    case04: ((None, None), (None, None), (None, Some(-1.)), Some("E1609")),
+}
+
+fn can_detect_invalid_requested_time_penalty_impl(
+    objective_penalty: (Option<Float>, Option<Float>),
+    tag_penalty: (Option<Float>, Option<Float>),
+    job_penalty: (Option<Float>, Option<Float>),
+    expected: Option<&str>,
+) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                requested_time_penalty: Some(RequestedTimePenalty {
+                    early_penalty: job_penalty.0,
+                    late_penalty: job_penalty.1,
+                    shape: None,
+                }),
+                ..create_delivery_job("job1", (1., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        objectives: Some(vec![
+            MatchRequestedTime {
+                early_penalty: objective_penalty.0,
+                late_penalty: objective_penalty.1,
+                shape: None,
+                tag_penalties: Some(vec![TagRequestedTimePenalty {
+                    tag: "tag".to_string(),
+                    early_penalty: tag_penalty.0,
+                    late_penalty: tag_penalty.1,
+                    shape: None,
+                }]),
+            },
+            MinimizeCost,
+        ]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1609_requested_time_penalties_are_correct(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_invalid_requested_time_penalty_segments, (segments, expected), {
+    can_detect_invalid_requested_time_penalty_segments_impl(segments, expected);
+}}
+
+can_detect_invalid_requested_time_penalty_segments! {
+    case01: (vec![(0., 1.), (600., 5.)], None),
+    case02: (vec![(-1., 1.)], Some("E1609")),
+    case03: (vec![(600., -5.)], Some("E1609")),
+}
+
+fn can_detect_invalid_requested_time_penalty_segments_impl(segments: Vec<(Float, Float)>, expected: Option<&str>) {
+    let segments =
+        segments.into_iter().map(|(from, multiplier)| RequestedTimePenaltySegment { from, multiplier }).collect();
+    let problem = Problem {
+        objectives: Some(vec![
+            MatchRequestedTime {
+                early_penalty: None,
+                late_penalty: None,
+                shape: Some(RequestedTimePenaltyShape::Piecewise { segments }),
+                tag_penalties: None,
+            },
+            MinimizeCost,
+        ]),
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1609_requested_time_penalties_are_correct(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected.map(|code| code.to_string()));
+}
+
+parameterized_test! {can_detect_missing_excess_ride_time_objective, (objectives, expected), {
+    can_detect_missing_excess_ride_time_objective_impl(objectives, expected);
+}}
+
+can_detect_missing_excess_ride_time_objective! {
+    case01: (Some(vec![MinimizeUnassigned { breaks: None }, MinimizeCost]), Some("E1610".to_string())),
+    case02: (Some(vec![
+        MinimizeUnassigned { breaks: None },
+        MinimizeExcessRideTime { offset: None, factor: None, min: None },
+        MinimizeCost
+    ]), None),
+    case03: (None, None),
+}
+
+fn can_detect_missing_excess_ride_time_objective_impl(objectives: Option<Vec<Objective>>, expected: Option<String>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![Job {
+                soft_max_ride_duration: Some(600.),
+                ..create_pickup_delivery_job("job1", (1., 0.), (2., 0.))
+            }],
+            ..create_empty_plan()
+        },
+        objectives,
+        ..create_empty_problem()
+    };
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, None, &coord_index);
+    let objectives = get_objectives(&ctx).unwrap_or_default();
+
+    let result = check_e1610_jobs_with_soft_max_ride_duration_but_no_objective(&ctx, objectives.as_slice());
+
+    assert_eq!(result.err().map(|e| e.code), expected);
+}
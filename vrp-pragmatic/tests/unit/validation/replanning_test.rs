@@ -0,0 +1,153 @@
+use super::*;
+use crate::format_time;
+use crate::helpers::*;
+
+fn validate_result(ctx: &ValidationContext) -> Option<FormatError> {
+    let result = validate_replanning(ctx);
+
+    result.err().map(|result| {
+        assert_eq!(result.errors.len(), 1);
+        result.errors.first().cloned().unwrap()
+    })
+}
+
+fn create_vehicle_state(
+    vehicle_id: &str,
+    shift_index: Option<usize>,
+    location: Option<(f64, f64)>,
+    executed: Option<Vec<&str>>,
+) -> VehicleReplanning {
+    VehicleReplanning {
+        vehicle_id: vehicle_id.to_string(),
+        shift_index,
+        location: location.map(|location| location.to_loc()),
+        executed: executed.map(|executed| executed.iter().map(|id| id.to_string()).collect()),
+        onboard: None,
+    }
+}
+
+fn create_onboard_vehicle_state(job_id: &str, since: &str) -> VehicleReplanning {
+    VehicleReplanning {
+        onboard: Some(vec![OnboardJob { job_id: job_id.to_string(), since: since.to_string() }]),
+        ..create_vehicle_state("my_vehicle_1", None, None, None)
+    }
+}
+
+parameterized_test! {can_detect_replanning_errors, (now, vehicles, expected), {
+    can_detect_replanning_errors_impl(now, vehicles, expected);
+}}
+
+can_detect_replanning_errors! {
+    case01: (
+        "1970-01-01T00:00:10Z",
+        vec![create_vehicle_state("my_vehicle_1", None, None, Some(vec!["job1"]))],
+        None
+    ),
+    case02: ("not a time", vec![], Some(("E1700", "not a time"))),
+    case03: (
+        "1970-01-01T00:00:10Z",
+        vec![create_vehicle_state("my_vehicle_2", None, None, None)],
+        Some(("E1701", "my_vehicle_2"))
+    ),
+    case04: (
+        "1970-01-01T00:00:10Z",
+        vec![create_vehicle_state("my_vehicle_1", Some(1), None, None)],
+        Some(("E1701", "my_vehicle_1"))
+    ),
+    case05: (
+        "1970-01-01T00:00:10Z",
+        vec![
+            create_vehicle_state("my_vehicle_1", None, None, None),
+            create_vehicle_state("my_vehicle_1", Some(0), None, None)
+        ],
+        Some(("E1702", "my_vehicle_1:0"))
+    ),
+    case06: (
+        "1970-01-01T00:00:10Z",
+        vec![create_vehicle_state("my_vehicle_1", None, Some((1., 0.)), Some(vec!["job1"]))],
+        Some(("E1703", "my_vehicle_1"))
+    ),
+    case07: (
+        "1970-01-01T00:00:10Z",
+        vec![create_vehicle_state("my_vehicle_1", None, Some((1., 0.)), None)],
+        None
+    ),
+    case08: ("1970-01-01T00:00:10Z", vec![create_onboard_vehicle_state("job1", "1970-01-01T00:00:05Z")], None),
+    case09: (
+        "1970-01-01T00:00:10Z",
+        vec![create_onboard_vehicle_state("job2", "1970-01-01T00:00:05Z")],
+        Some(("E1704", "job2"))
+    ),
+    case10: (
+        "1970-01-01T00:00:10Z",
+        vec![create_onboard_vehicle_state("job3", "1970-01-01T00:00:05Z")],
+        Some(("E1704", "job3"))
+    ),
+    case11: (
+        "1970-01-01T00:00:10Z",
+        vec![create_onboard_vehicle_state("job1", "1970-01-01T00:00:15Z")],
+        Some(("E1705", "job1"))
+    ),
+    case12: ("1970-01-01T00:00:10Z", vec![create_onboard_vehicle_state("job1", "not a time")], Some(("E1705", "job1"))),
+}
+
+fn can_detect_replanning_errors_impl(now: &str, vehicles: Vec<VehicleReplanning>, expected: Option<(&str, &str)>) {
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.)), create_pickup_delivery_job("job2", (1., 0.), (2., 0.))],
+            replanning: Some(Replanning { now: now.to_string(), vehicles }),
+            ..create_empty_plan()
+        },
+        fleet: create_default_fleet(),
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    if let Some((code, action)) = expected {
+        assert_eq!(result.clone().map(|err| err.code), Some(code.to_string()));
+        assert!(result.map_or("".to_string(), |err| err.action).contains(action));
+    } else {
+        assert!(result.is_none());
+    }
+}
+
+parameterized_test! {can_detect_shift_start_before_now, (latest, executed, expected), {
+    can_detect_shift_start_before_now_impl(latest, executed, expected);
+}}
+
+can_detect_shift_start_before_now! {
+    case01_latest_after_now: (Some(20.), None, None),
+    case02_latest_at_now: (Some(10.), None, None),
+    case03_no_latest: (None, None, None),
+    case04_latest_before_now: (Some(5.), None, Some(("E1706", "my_vehicle_1"))),
+    case05_latest_before_now_executed: (Some(5.), Some(vec!["job1"]), None),
+}
+
+fn can_detect_shift_start_before_now_impl(
+    latest: Option<f64>,
+    executed: Option<Vec<&str>>,
+    expected: Option<(&str, &str)>,
+) {
+    let mut vehicle = create_default_vehicle_type();
+    vehicle.shifts[0].start.latest = latest.map(format_time);
+    let problem = Problem {
+        plan: Plan {
+            jobs: vec![create_delivery_job("job1", (1., 0.))],
+            replanning: Some(Replanning {
+                now: format_time(10.),
+                vehicles: vec![create_vehicle_state("my_vehicle_1", None, None, executed)],
+            }),
+            ..create_empty_plan()
+        },
+        fleet: Fleet { vehicles: vec![vehicle], ..create_default_fleet() },
+        ..create_empty_problem()
+    };
+
+    let result = validate_result(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.clone().map(|err| err.code), expected.map(|(code, _)| code.to_string()));
+    if let Some((_, action)) = expected {
+        assert!(result.map_or("".to_string(), |err| err.action).contains(action));
+    }
+}
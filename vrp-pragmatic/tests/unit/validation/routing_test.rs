@@ -1,4 +1,5 @@
 use super::*;
+use crate::format_time;
 use crate::helpers::*;
 
 #[test]
@@ -114,3 +115,34 @@ fn can_detect_missing_profile() {
 
     assert_eq!(result.err().map(|err| err.code), Some("E1505".to_string()));
 }
+
+parameterized_test! {can_detect_non_fifo_matrices, (matrices, expected), {
+    can_detect_non_fifo_matrices_impl(matrices, expected);
+}}
+
+can_detect_non_fifo_matrices! {
+    case01_fifo: (vec![(0., 100, 0), (10., 90, 0), (20., 200, 0)], None),
+    case02_non_fifo: (vec![(0., 100, 0), (10., 80, 0)], Some("E1506".to_string())),
+    case03_non_fifo_unsorted: (vec![(10., 80, 0), (0., 100, 0)], Some("E1506".to_string())),
+    case04_unreachable: (vec![(0., 100, 0), (10., 80, 1)], None),
+}
+
+fn can_detect_non_fifo_matrices_impl(matrices: Vec<(Float, i64, i64)>, expected: Option<String>) {
+    let problem = create_empty_problem();
+    let matrices = matrices
+        .into_iter()
+        .map(|(timestamp, travel_time, error_code)| Matrix {
+            profile: Some("car".to_string()),
+            timestamp: Some(format_time(timestamp)),
+            travel_times: vec![travel_time; 4],
+            distances: vec![1; 4],
+            error_codes: Some(vec![error_code; 4]),
+        })
+        .collect::<Vec<_>>();
+    let coord_index = CoordIndex::new(&problem);
+    let ctx = ValidationContext::new(&problem, Some(&matrices), &coord_index);
+
+    let result = check_e1506_time_dependent_matrices_fifo(&ctx);
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
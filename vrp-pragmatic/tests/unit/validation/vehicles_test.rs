@@ -137,3 +137,297 @@ fn can_handle_reload_resources_impl(resources: Option<Vec<&str>>, expected: Opti
 
     assert_eq!(result.err().map(|err| err.code), expected);
 }
+
+parameterized_test! {can_handle_reload_docking_resources, (capacity, expected), {
+    can_handle_reload_docking_resources_impl(capacity, expected);
+}}
+
+can_handle_reload_docking_resources! {
+    case01_valid: (2, None),
+    case02_no_doors: (0, Some("E1308".to_string())),
+}
+
+fn can_handle_reload_docking_resources_impl(capacity: usize, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    reloads: Some(vec![VehicleReload {
+                        resource_id: Some("dock".to_string()),
+                        ..create_default_reload()
+                    }]),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: Some(vec![VehicleResource::Docking { id: "dock".to_string(), capacity }]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1308_vehicle_reload_resources(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_handle_recharge_resources, (resource, expected), {
+    can_handle_recharge_resources_impl(resource, expected);
+}}
+
+can_handle_recharge_resources! {
+    case01_valid: (Some(VehicleResource::Charging { id: "c1".to_string(), connectors: 1 }), None),
+    case02_missing: (None, Some("E1319".to_string())),
+    case03_no_connectors: (Some(VehicleResource::Charging { id: "c1".to_string(), connectors: 0 }), Some("E1319".to_string())),
+    case04_wrong_type: (Some(VehicleResource::Reload { id: "c1".to_string(), capacity: vec![2] }), Some("E1319".to_string())),
+}
+
+fn can_handle_recharge_resources_impl(resource: Option<VehicleResource>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance: Some(10.),
+                        energy: None,
+                        stations: vec![VehicleRechargeStation {
+                            location: (1., 0.).to_loc(),
+                            duration: 0.,
+                            times: None,
+                            tag: None,
+                            power: None,
+                            resource_id: Some("c1".to_string()),
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            resources: resource.map(|resource| vec![resource]),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1319_vehicle_recharge_resources(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+type DriverData<'a> = (&'a str, Vec<(Float, Float)>, Option<(f64, f64)>);
+
+parameterized_test! {can_detect_invalid_drivers, (drivers, expected), {
+    can_detect_invalid_drivers_impl(drivers, expected);
+}}
+
+can_detect_invalid_drivers! {
+    case01: (vec![("d1", vec![(0., 100.)], None), ("d2", vec![(0., 100.)], Some((0., 0.)))], None),
+    case02: (vec![("d1", vec![(0., 100.)], None), ("d1", vec![(0., 100.)], None)], Some("E1313".to_string())),
+    case03: (vec![("d1", vec![], None)], Some("E1314".to_string())),
+    case04: (vec![("d1", vec![(100., 0.)], None)], Some("E1314".to_string())),
+    case05: (vec![("d1", vec![(0., 100.)], Some((1., 0.)))], Some("E1315".to_string())),
+}
+
+fn can_detect_invalid_drivers_impl(drivers: Vec<DriverData>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            drivers: Some(
+                drivers
+                    .into_iter()
+                    .map(|(id, shifts, depot)| Driver {
+                        id: id.to_string(),
+                        costs: None,
+                        shifts: shifts
+                            .into_iter()
+                            .map(|(earliest, latest)| DriverShift {
+                                earliest: format_time(earliest),
+                                latest: format_time(latest),
+                                depot: depot.map(|depot| depot.to_loc()),
+                            })
+                            .collect(),
+                        skills: None,
+                    })
+                    .collect(),
+            ),
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = validate_vehicles(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().and_then(|err| err.errors.first().map(|err| err.code.clone())), expected);
+}
+
+parameterized_test! {can_detect_invalid_driving_rules, (rules, expected), {
+    can_detect_invalid_driving_rules_impl(rules, expected);
+}}
+
+can_detect_invalid_driving_rules! {
+    case01_valid: ((16200., 2700., Some(vec![900., 1800.]), Some(32400.)), None),
+    case02_zero_driving: ((0., 2700., None, None), Some("E1316".to_string())),
+    case03_zero_rest: ((16200., 0., None, None), Some("E1316".to_string())),
+    case04_wrong_split: ((16200., 2700., Some(vec![900.]), None), Some("E1316".to_string())),
+    case05_long_split: ((16200., 2700., Some(vec![900., 3600.]), None), Some("E1316".to_string())),
+    case06_short_daily: ((16200., 2700., None, Some(3600.)), Some("E1316".to_string())),
+}
+
+fn can_detect_invalid_driving_rules_impl(
+    rules: (Float, Float, Option<Vec<Float>>, Option<Float>),
+    expected: Option<String>,
+) {
+    let (max_driving_time, rest_duration, split_rest, max_daily_driving_time) = rules;
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                driving_rules: Some(VehicleDrivingRules {
+                    max_driving_time,
+                    rest_duration,
+                    split_rest,
+                    max_daily_driving_time,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1316_vehicle_driving_rules(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_profile_scale, (scale, expected), {
+    can_detect_invalid_profile_scale_impl(scale, expected);
+}}
+
+can_detect_invalid_profile_scale! {
+    case01_valid_constant: (VehicleProfileScale::Constant(1.5), None),
+    case02_zero_constant: (VehicleProfileScale::Constant(0.), Some("E1317".to_string())),
+    case03_valid_time_of_day: (create_time_scales(&[(0., 10., 2.), (10., 20., 1.5)]), None),
+    case04_zero_factor: (create_time_scales(&[(0., 10., 0.)]), Some("E1317".to_string())),
+    case05_wrong_interval: (create_time_scales(&[(10., 0., 2.)]), Some("E1317".to_string())),
+    case06_overlapping_intervals: (create_time_scales(&[(5., 20., 2.), (0., 10., 1.5)]), Some("E1317".to_string())),
+}
+
+fn create_time_scales(scales: &[(Float, Float, Float)]) -> VehicleProfileScale {
+    VehicleProfileScale::TimeOfDay(
+        scales
+            .iter()
+            .map(|&(from, to, factor)| VehicleProfileTimeScale { from: format_time(from), to: format_time(to), factor })
+            .collect(),
+    )
+}
+
+fn can_detect_invalid_profile_scale_impl(scale: VehicleProfileScale, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                profile: VehicleProfile { matrix: "car".to_string(), scale: Some(scale) },
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result = check_e1317_vehicle_profile_scale(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_recharges_energy, (max_distance, energy, power, expected), {
+    can_detect_invalid_recharges_energy_impl(max_distance, energy, power, expected);
+}}
+
+can_detect_invalid_recharges_energy! {
+    case01_valid_distance: (Some(100.), None, None, None),
+    case02_no_limits: (None, None, None, Some("E1318".to_string())),
+    case03_valid_energy: (None, Some((60., Some(0.8), Some(0.1))), Some(50.), None),
+    case04_no_power: (None, Some((60., None, None)), None, Some("E1318".to_string())),
+    case05_zero_battery: (None, Some((0., None, None)), Some(50.), Some("E1318".to_string())),
+    case06_wrong_fraction: (None, Some((60., Some(1.5), None)), Some(50.), Some("E1318".to_string())),
+    case07_min_above_initial: (None, Some((60., Some(0.2), Some(0.5))), Some(50.), Some("E1318".to_string())),
+}
+
+fn can_detect_invalid_recharges_energy_impl(
+    max_distance: Option<Float>,
+    energy: Option<(Float, Option<Float>, Option<Float>)>,
+    power: Option<Float>,
+    expected: Option<String>,
+) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                shifts: vec![VehicleShift {
+                    recharges: Some(VehicleRecharges {
+                        max_distance,
+                        energy: energy.map(|(battery_capacity, initial_state_of_charge, min_state_of_charge)| {
+                            VehicleEnergy {
+                                battery_capacity,
+                                consumption: 0.2,
+                                profile_consumption: None,
+                                load_consumption: None,
+                                initial_state_of_charge,
+                                min_state_of_charge,
+                            }
+                        }),
+                        stations: vec![VehicleRechargeStation {
+                            location: (1., 0.).to_loc(),
+                            duration: 0.,
+                            times: None,
+                            tag: None,
+                            power,
+                            resource_id: None,
+                        }],
+                    }),
+                    ..create_default_vehicle_shift()
+                }],
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1318_vehicle_recharges_energy(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}
+
+parameterized_test! {can_detect_invalid_max_onboard_waiting, (max_onboard_waiting, expected), {
+    can_detect_invalid_max_onboard_waiting_impl(max_onboard_waiting, expected);
+}}
+
+can_detect_invalid_max_onboard_waiting! {
+    case01_no_limit: (None, None),
+    case02_valid: (Some(300.), None),
+    case03_negative: (Some(-1.), Some("E1320".to_string())),
+}
+
+fn can_detect_invalid_max_onboard_waiting_impl(max_onboard_waiting: Option<Float>, expected: Option<String>) {
+    let problem = Problem {
+        fleet: Fleet {
+            vehicles: vec![VehicleType {
+                limits: Some(VehicleLimits {
+                    max_distance: None,
+                    max_duration: None,
+                    tour_size: None,
+                    max_onboard_waiting,
+                }),
+                ..create_default_vehicle_type()
+            }],
+            ..create_default_fleet()
+        },
+        ..create_empty_problem()
+    };
+
+    let result =
+        check_e1320_vehicle_max_onboard_waiting(&ValidationContext::new(&problem, None, &CoordIndex::new(&problem)));
+
+    assert_eq!(result.err().map(|err| err.code), expected);
+}